@@ -7,7 +7,7 @@
 use crate::PyRudofConfig;
 use pyo3::{PyErr, PyResult, Python, exceptions::PyValueError, pyclass, pymethods};
 use rudof_lib::{
-    Rudof,
+    ResultLimits, Rudof,
     errors::{InputSpecError, RudofError},
     formats::{
         ComparisonFormat, ComparisonMode, ConversionFormat, ConversionMode, DCTapFormat, DataFormat, DataReaderMode,
@@ -16,6 +16,7 @@ use rudof_lib::{
         ResultShaclValidationFormat, ShExFormat, ShExValidationSortByMode, ShaclFormat, ShaclValidationMode,
         ShaclValidationSortByMode, ShapeMapFormat,
     },
+    types::ShaclFocus,
 };
 use std::{io::BufWriter, path::Path, str::FromStr};
 
@@ -636,6 +637,17 @@ impl PyRudof {
     ///     mode (ShaclValidationMode, optional): Validation engine. Defaults to ``ShaclValidationMode.Native``.
     ///         - ``Native``: Fast built-in engine (recommended)
     ///         - ``Sparql``: SPARQL-based engine (slower, for debugging)
+    ///     nodes (list[str], optional): Focus nodes to validate, as IRIs or prefixed names.
+    ///         Only the targets among them are validated.
+    ///     shapes (list[str], optional): Shapes to validate. Only their targets are validated.
+    ///     shape_map (list[str], optional): Nodes to validate against shapes whatever their
+    ///         targets, written as ``node@shape`` like ``"ex:alice@ex:PersonShape"``.
+    ///     fail_fast (bool, optional): Stop at the first result, to know whether the data conforms.
+    ///     max_results (int, optional): Stop after that many results.
+    ///     max_results_per_shape (int, optional): Keep that many results of each source shape
+    ///         and count the rest.
+    ///     max_results_per_component (int, optional): Keep that many results of each
+    ///         constraint component and count the rest.
     ///
     /// Returns:
     ///     ValidationReport: Detailed validation report with conformance status and violations.
@@ -646,14 +658,50 @@ impl PyRudof {
     /// Note:
     ///     - Native mode is recommended for production (faster)
     ///     - SPARQL mode useful for debugging complex constraints
-    #[pyo3(signature = (mode=None))]
-    pub fn validate_shacl(&mut self, mode: Option<&PyShaclValidationMode>) -> PyResult<()> {
+    #[pyo3(signature = (
+        mode=None,
+        nodes=None,
+        shapes=None,
+        shape_map=None,
+        fail_fast=false,
+        max_results=None,
+        max_results_per_shape=None,
+        max_results_per_component=None
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn validate_shacl(
+        &mut self,
+        mode: Option<&PyShaclValidationMode>,
+        nodes: Option<Vec<String>>,
+        shapes: Option<Vec<String>>,
+        shape_map: Option<Vec<String>>,
+        fail_fast: bool,
+        max_results: Option<usize>,
+        max_results_per_shape: Option<usize>,
+        max_results_per_component: Option<usize>,
+    ) -> PyResult<()> {
         let mode = cnv_shacl_validation_mode(mode);
+        let focus = ShaclFocus::new()
+            .with_nodes(&nodes.unwrap_or_default())
+            .with_shapes(&shapes.unwrap_or_default())
+            .with_shape_map(&shape_map.unwrap_or_default())
+            .map_err(cnv_err)?;
+        let limits = ResultLimits::new()
+            .with_fail_fast(fail_fast)
+            .with_max_results(max_results)
+            .with_max_results_per_shape(max_results_per_shape)
+            .with_max_results_per_component(max_results_per_component);
 
         let mut valiate_shacl = self.inner.validate_shacl();
         if let Some(mode) = mode {
             valiate_shacl = valiate_shacl.with_shacl_validation_mode(mode);
         }
+        if !focus.is_empty() {
+            valiate_shacl = valiate_shacl.with_focus(&focus);
+        }
+        if !limits.is_unlimited() {
+            valiate_shacl = valiate_shacl.with_result_limits(&limits);
+        }
         valiate_shacl.execute().map_err(cnv_err)?;
 
         Ok(())
@@ -879,6 +927,31 @@ impl PyRudof {
         Ok(())
     }
 
+    /// Computes a VoID / SPARQL Service Description of the current data.
+    ///
+    /// The description becomes the current Service Description. It contains the
+    /// triple, distinct subject and object counts, the class and property
+    /// partitions, and the datatype partitions of each property.
+    ///
+    /// Args:
+    ///     title (str, optional): Title of the described service. Defaults to ``None``.
+    ///     endpoint (str, optional): IRI of the SPARQL endpoint that serves the data. Defaults to ``None``.
+    ///
+    /// Raises:
+    ///     RudofError: If no data is loaded or the profiling queries fail.
+    #[pyo3(signature = (title=None, endpoint=None))]
+    pub fn describe_data(&mut self, title: Option<&str>, endpoint: Option<&str>) -> PyResult<()> {
+        let mut describe_data = self.inner.describe_data();
+        if let Some(title) = title {
+            describe_data = describe_data.with_title(title);
+        }
+        if let Some(endpoint) = endpoint {
+            describe_data = describe_data.with_endpoint(endpoint);
+        }
+        describe_data.execute().map_err(cnv_err)?;
+        Ok(())
+    }
+
     /// Writes the current Service Description to a file.
     ///
     /// Args:
@@ -1228,6 +1301,7 @@ pub enum PyResultDataFormat {
     PlantUML,
     Svg,
     Png,
+    Pg,
 }
 
 /// Output formats for SPARQL CONSTRUCT query results.
@@ -1313,6 +1387,10 @@ pub enum PyServiceDescriptionFormat {
     Internal,
     Json,
     Mie,
+    Turtle,
+    NTriples,
+    RdfXml,
+    JsonLd,
 }
 
 /// ShapeMap serialization formats.
@@ -1386,6 +1464,10 @@ pub enum PyResultShaclValidationFormat {
     Compact,
     Json,
     Csv,
+    NdJson,
+    Sarif,
+    JUnit,
+    Html,
 }
 
 #[pyclass(eq, eq_int, name = "ResultShexValidationFormat")]
@@ -1401,6 +1483,10 @@ pub enum PyResultShexValidationFormat {
     Compact,
     Json,
     Csv,
+    NdJson,
+    Sarif,
+    JUnit,
+    Html,
 }
 
 #[pyclass(eq, eq_int, name = "QueryType")]
@@ -1564,6 +1650,10 @@ fn cnv_shex_validation_format(format: &PyResultShexValidationFormat) -> &ResultS
         PyResultShexValidationFormat::Compact => &ResultShExValidationFormat::Compact,
         PyResultShexValidationFormat::Json => &ResultShExValidationFormat::Json,
         PyResultShexValidationFormat::Csv => &ResultShExValidationFormat::Csv,
+        PyResultShexValidationFormat::NdJson => &ResultShExValidationFormat::NdJson,
+        PyResultShexValidationFormat::Sarif => &ResultShExValidationFormat::Sarif,
+        PyResultShexValidationFormat::JUnit => &ResultShExValidationFormat::JUnit,
+        PyResultShexValidationFormat::Html => &ResultShExValidationFormat::Html,
     }
 }
 
@@ -1589,6 +1679,10 @@ fn cnv_shacl_validation_format(format: &PyResultShaclValidationFormat) -> &Resul
         PyResultShaclValidationFormat::Compact => &ResultShaclValidationFormat::Compact,
         PyResultShaclValidationFormat::Json => &ResultShaclValidationFormat::Json,
         PyResultShaclValidationFormat::Csv => &ResultShaclValidationFormat::Csv,
+        PyResultShaclValidationFormat::NdJson => &ResultShaclValidationFormat::NdJson,
+        PyResultShaclValidationFormat::Sarif => &ResultShaclValidationFormat::Sarif,
+        PyResultShaclValidationFormat::JUnit => &ResultShaclValidationFormat::JUnit,
+        PyResultShaclValidationFormat::Html => &ResultShaclValidationFormat::Html,
     }
 }
 
@@ -1634,6 +1728,10 @@ fn cnv_service_description_format(format: Option<&PyServiceDescriptionFormat>) -
         PyServiceDescriptionFormat::Internal => Some(&ResultServiceFormat::Internal),
         PyServiceDescriptionFormat::Mie => Some(&ResultServiceFormat::Mie),
         PyServiceDescriptionFormat::Json => Some(&ResultServiceFormat::Json),
+        PyServiceDescriptionFormat::Turtle => Some(&ResultServiceFormat::Turtle),
+        PyServiceDescriptionFormat::NTriples => Some(&ResultServiceFormat::NTriples),
+        PyServiceDescriptionFormat::RdfXml => Some(&ResultServiceFormat::RdfXml),
+        PyServiceDescriptionFormat::JsonLd => Some(&ResultServiceFormat::JsonLd),
     }
 }
 
@@ -1673,6 +1771,7 @@ fn cnv_result_data_format(format: Option<&PyResultDataFormat>) -> Option<&Result
         PyResultDataFormat::Svg => Some(&ResultDataFormat::Svg),
         PyResultDataFormat::Compact => Some(&ResultDataFormat::Compact),
         PyResultDataFormat::JsonLd => Some(&ResultDataFormat::JsonLd),
+        PyResultDataFormat::Pg => Some(&ResultDataFormat::Pg),
     }
 }
 
@@ -23,15 +23,17 @@ class ResultDataFormat(IntEnum):
     """Output format when serializing an RDF data graph."""
     Turtle = 0
     NTriples = 1
-    RdfXml = 2
-    TriG = 3
-    N3 = 4
-    NQuads = 5
-    Compact = 6
-    Json = 7
-    PlantUML = 8
-    Svg = 9
-    Png = 10
+    JsonLd = 2
+    RdfXml = 3
+    TriG = 4
+    N3 = 5
+    NQuads = 6
+    Compact = 7
+    Json = 8
+    PlantUML = 9
+    Svg = 10
+    Png = 11
+    Pg = 12
 
 class ShExFormat(IntEnum):
     """ShEx schema serialization format."""
@@ -51,6 +53,10 @@ class ResultShexValidationFormat(IntEnum):
     Compact = 7
     Json = 8
     Csv = 9
+    NdJson = 10
+    Sarif = 11
+    JUnit = 12
+    Html = 13
 
 class ShaclFormat(IntEnum):
     """SHACL shapes graph serialization format."""
@@ -89,6 +95,10 @@ class ResultShaclValidationFormat(IntEnum):
     Compact = 8
     Json = 9
     Csv = 10
+    NdJson = 11
+    Sarif = 12
+    JUnit = 13
+    Html = 14
 
 class ShapeMapFormat(IntEnum):
     """ShapeMap serialization format."""
@@ -145,6 +155,10 @@ class ServiceDescriptionFormat(IntEnum):
     Internal = 0
     Json = 1
     Mie = 2
+    Turtle = 3
+    NTriples = 4
+    RdfXml = 5
+    JsonLd = 6
 
 class QueryResultFormat(IntEnum):
     """Output format for SPARQL query results."""
@@ -516,11 +530,30 @@ class Rudof:
         """
         ...
 
-    def validate_shacl(self, mode: Optional[ShaclValidationMode] = None) -> None:
+    def validate_shacl(
+        self,
+        mode: Optional[ShaclValidationMode] = None,
+        nodes: Optional[List[str]] = None,
+        shapes: Optional[List[str]] = None,
+        shape_map: Optional[List[str]] = None,
+        fail_fast: bool = False,
+        max_results: Optional[int] = None,
+        max_results_per_shape: Optional[int] = None,
+        max_results_per_component: Optional[int] = None,
+    ) -> None:
         """Validate the loaded RDF data against the loaded SHACL shapes.
 
         Args:
             mode: Validation engine. Default: ``ShaclValidationMode.Native``.
+            nodes: Focus nodes to validate. Only the targets among them are validated.
+            shapes: Shapes to validate. Only their targets are validated.
+            shape_map: Nodes to validate against shapes whatever their targets,
+                as ``node@shape`` like ``"ex:alice@ex:PersonShape"``.
+            fail_fast: Stop at the first result, to know whether the data conforms.
+            max_results: Stop after that many results.
+            max_results_per_shape: Keep that many results of each source shape and count the rest.
+            max_results_per_component: Keep that many results of each constraint component
+                and count the rest.
 
         Raises:
             RudofError: If no data or shapes are loaded, or validation fails.
@@ -644,6 +677,24 @@ class Rudof:
         """
         ...
 
+    def describe_data(
+        self, title: Optional[str] = None, endpoint: Optional[str] = None
+    ) -> None:
+        """Compute a VoID / SPARQL service description of the loaded data.
+
+        The description becomes the current service description. It contains
+        the triple, distinct subject and object counts, the class and property
+        partitions, and the datatype partitions of each property.
+
+        Args:
+            title: Title of the described service.
+            endpoint: IRI of the SPARQL endpoint that serves the data.
+
+        Raises:
+            RudofError: If no data is loaded or profiling fails.
+        """
+        ...
+
     def serialize_service_description(
         self, format: Optional[ServiceDescriptionFormat] = None
     ) -> str:
@@ -159,6 +159,55 @@ impl Mie {
         self.prefixes = prefixes;
     }
 
+    /// Adds a prefix, unless its alias is already declared
+    pub fn add_prefix(&mut self, alias: &str, iri: &IriS) {
+        self.prefixes.entry(alias.to_string()).or_insert_with(|| iri.clone());
+    }
+
+    pub fn add_data_statistics(&mut self, name: &str, statistics: DataStatistics) {
+        self.data_statistics.insert(name.to_string(), statistics);
+    }
+
+    pub fn data_statistics(&self, name: &str) -> Option<&DataStatistics> {
+        self.data_statistics.get(name)
+    }
+
+    pub fn data_statistics_mut(&mut self, name: &str) -> Option<&mut DataStatistics> {
+        self.data_statistics.get_mut(name)
+    }
+
+    pub fn add_shape_expression(&mut self, name: &str, shape_expression: ShapeExpression) {
+        self.shape_expressions.insert(name.to_string(), shape_expression);
+    }
+
+    pub fn add_sample_rdf_entry(&mut self, name: &str, example: RdfExample) {
+        self.sample_rdf_entries.insert(name.to_string(), example);
+    }
+
+    pub fn add_cross_reference(&mut self, name: &str, cross_reference: CrossReference) {
+        self.cross_references.insert(name.to_string(), cross_reference);
+    }
+
+    pub fn schema_info(&self) -> &SchemaInfo {
+        &self.schema_info
+    }
+
+    pub fn prefixes(&self) -> &HashMap<String, IriS> {
+        &self.prefixes
+    }
+
+    pub fn shape_expressions(&self) -> &HashMap<String, ShapeExpression> {
+        &self.shape_expressions
+    }
+
+    pub fn sample_rdf_entries(&self) -> &HashMap<String, RdfExample> {
+        &self.sample_rdf_entries
+    }
+
+    pub fn cross_references(&self) -> &HashMap<String, CrossReference> {
+        &self.cross_references
+    }
+
     pub fn to_yaml(&self) -> Yaml {
         let mut result = LinkedHashMap::new();
         result.insert(Yaml::String("schema_info".to_string()), self.schema_info.to_yaml());
@@ -192,7 +241,66 @@ impl Mie {
     }
 }
 
+impl DataStatistics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_classes(mut self, classes: Option<isize>) -> Self {
+        self.classes = classes;
+        self
+    }
+
+    pub fn with_properties(mut self, properties: Option<isize>) -> Self {
+        self.properties = properties;
+        self
+    }
+
+    /// Adds the number of instances of a class
+    pub fn add_class_partition(&mut self, class: &str, count: isize) {
+        self.class_partitions.insert(class.to_string(), count);
+    }
+
+    /// Adds the number of triples of a property
+    pub fn add_property_partition(&mut self, property: &str, count: isize) {
+        self.property_partitions.insert(property.to_string(), count);
+    }
+
+    pub fn classes(&self) -> Option<isize> {
+        self.classes
+    }
+
+    pub fn properties(&self) -> Option<isize> {
+        self.properties
+    }
+
+    pub fn class_partitions(&self) -> &HashMap<String, isize> {
+        &self.class_partitions
+    }
+
+    pub fn property_partitions(&self) -> &HashMap<String, isize> {
+        &self.property_partitions
+    }
+
+    /// Adds the number of links to an external namespace, if it is known
+    pub fn add_cross_reference(&mut self, namespace: &str, count: Option<isize>) {
+        self.cross_references.insert(namespace.to_string(), count);
+    }
+
+    pub fn cross_references(&self) -> &HashMap<String, Option<isize>> {
+        &self.cross_references
+    }
+}
+
 impl SchemaInfo {
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    pub fn endpoint(&self) -> Option<&str> {
+        self.endpoint.as_deref()
+    }
+
     pub fn to_yaml(&self) -> Yaml {
         let mut result = LinkedHashMap::new();
         if let Some(title) = &self.title {
@@ -225,6 +333,24 @@ impl RdfExample {
         }
     }
 
+    pub fn with_description(mut self, description: Option<String>) -> Self {
+        self.description = description;
+        self
+    }
+
+    pub fn with_rdf(mut self, rdf: &str) -> Self {
+        self.rdf = rdf.to_string();
+        self
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn rdf(&self) -> &str {
+        &self.rdf
+    }
+
     pub fn to_yaml(&self) -> Yaml {
         let mut result = LinkedHashMap::new();
         if let Some(desc) = &self.description {
@@ -235,6 +361,26 @@ impl RdfExample {
 }
 
 impl ShapeExpression {
+    pub fn new(shape_expr: &str) -> Self {
+        ShapeExpression {
+            description: None,
+            shape_expr: shape_expr.to_string(),
+        }
+    }
+
+    pub fn with_description(mut self, description: Option<String>) -> Self {
+        self.description = description;
+        self
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn shape_expr(&self) -> &str {
+        &self.shape_expr
+    }
+
     pub fn to_yaml(&self) -> Yaml {
         let mut result = LinkedHashMap::new();
         if let Some(desc) = &self.description {
@@ -244,6 +390,29 @@ impl ShapeExpression {
     }
 }
 
+impl CrossReference {
+    pub fn new(sparql: &str) -> Self {
+        CrossReference {
+            description: None,
+            sparql: sparql.to_string(),
+            other_fields: HashMap::new(),
+        }
+    }
+
+    pub fn with_description(mut self, description: Option<String>) -> Self {
+        self.description = description;
+        self
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn sparql(&self) -> &str {
+        &self.sparql
+    }
+}
+
 impl Display for Mie {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         writeln!(f, "MIE")?;
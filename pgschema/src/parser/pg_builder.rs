@@ -98,7 +98,7 @@ fn get_id(id: String) -> Result<String, PgsError> {
 fn get_labels(labels: Vec<identifier>) -> Result<HashSet<LabelName>, PgsError> {
     let mut result = HashSet::new();
     for label in labels {
-        result.insert(label.as_str().into());
+        result.insert(unescape(label.as_str()).as_str().into());
     }
     Ok(result)
 }
@@ -113,7 +113,7 @@ fn get_properties(property_spec: Vec<Property>) -> Result<Record, PgsError> {
 }
 
 fn get_property(property: Property) -> Result<(String, HashSet<Value>), PgsError> {
-    let key = property.key.as_str().to_string();
+    let key = unescape(property.key.as_str());
     let values = get_values(property.values)?;
     Ok((key, values))
 }
@@ -140,8 +140,7 @@ fn get_values(values: Values) -> Result<HashSet<Value>, PgsError> {
 fn get_value(value: SingleValue) -> Result<Value, PgsError> {
     match value {
         SingleValue::StringValue(s) => {
-            let cleaned = remove_quotes(s.as_str());
-            Ok(Value::str(cleaned))
+            Ok(Value::str(&unescape(remove_quotes(s.as_str()))))
         },
         SingleValue::NumberValue(str_number_) => {
             let number = str_number_
@@ -160,6 +159,26 @@ fn get_value(value: SingleValue) -> Result<Value, PgsError> {
     }
 }
 
+/// Replaces the escape sequences of a quoted string by the characters they represent
+fn unescape(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            Some('t') => result.push('\t'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+    result
+}
+
 // This function has been obtained from:
 // https://stackoverflow.com/questions/65976432/how-to-remove-first-and-last-character-of-a-string-in-rust
 fn remove_quotes(s: &str) -> &str {
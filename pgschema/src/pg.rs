@@ -1,9 +1,10 @@
 use crate::{
     edge::Edge, edge_id::EdgeId, label_set::LabelSet, node::Node, node_id::NodeId, pgs_error::PgsError, record::Record,
-    type_name::LabelName,
+    type_name::LabelName, value::Value,
 };
 use either::Either;
-use std::{collections::HashMap, fmt::Display};
+use itertools::Itertools;
+use std::{collections::HashMap, fmt::Display, io};
 
 /// Simple representation of a property graph
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -110,6 +111,33 @@ impl PropertyGraph {
         }
     }
 
+    /// Iterates over the nodes of this graph paired with their names.
+    pub fn nodes(&self) -> impl Iterator<Item = (&str, &Node)> {
+        self.node_names
+            .iter()
+            .filter_map(|(name, id)| self.nodes.get(id).map(|node| (name.as_str(), node)))
+    }
+
+    /// Iterates over the edges of this graph paired with their names.
+    /// Edges declared without an identifier have no name.
+    pub fn edges(&self) -> impl Iterator<Item = (Option<&str>, &Edge)> {
+        let names: HashMap<&EdgeId, &str> = self
+            .edge_names
+            .iter()
+            .filter(|(name, _)| !name.is_empty())
+            .map(|(name, id)| (id, name.as_str()))
+            .collect();
+        self.edges.iter().map(move |(id, edge)| (names.get(id).copied(), edge))
+    }
+
+    /// Returns the name of the node with the given id.
+    pub fn node_name(&self, id: &NodeId) -> Option<&str> {
+        self.node_names
+            .iter()
+            .find(|(_, node_id)| *node_id == id)
+            .map(|(name, _)| name.as_str())
+    }
+
     pub fn node(&self, id: &NodeId) -> Option<&Node> {
         self.nodes.get(id)
     }
@@ -199,6 +227,99 @@ impl PropertyGraph {
     }
 }
 
+impl PropertyGraph {
+    /// Writes this graph using the same syntax accepted by the PG parser,
+    /// so the output can be loaded again with `PgBuilder::parse_pg`.
+    ///
+    /// Nodes are written sorted by name and edges by source, target and name
+    /// so the output is deterministic.
+    pub fn write_pg<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        let node_names: HashMap<&NodeId, &str> = self.node_names.iter().map(|(n, id)| (id, n.as_str())).collect();
+        for (name, node) in self.nodes().sorted_by_key(|(name, _)| *name) {
+            writeln!(writer, "({name}{})", show_labels_record(&node.labels, &node.properties))?;
+        }
+        let edges = self
+            .edges()
+            .map(|(name, edge)| {
+                let source = node_names.get(&edge.source).copied().unwrap_or_default();
+                let target = node_names.get(&edge.target).copied().unwrap_or_default();
+                (source, target, name, edge)
+            })
+            .sorted_by_key(|(source, target, name, edge)| (*source, *target, *name, edge.id.id));
+        for (source, target, name, edge) in edges {
+            let description = format!(
+                "{}{}",
+                name.unwrap_or_default(),
+                show_labels_record(&edge.labels, &edge.properties)
+            );
+            writeln!(writer, "({source}) -({})-> ({target})", description.trim_start())?;
+        }
+        Ok(())
+    }
+}
+
+fn show_labels_record(labels: &LabelSet, record: &Record) -> String {
+    let mut result = String::new();
+    if !labels.is_empty() {
+        let labels = labels.iter().map(|label| show_identifier(label)).join(", ");
+        result.push_str(&format!(" {{{labels}}}"));
+    }
+    let properties = record
+        .iter()
+        .sorted_by_key(|(key, _)| *key)
+        .map(|(key, values)| {
+            let values: Vec<String> = values.iter().sorted().map(show_value).collect();
+            let values = if values.len() == 1 {
+                values[0].clone()
+            } else {
+                format!("[{}]", values.join(", "))
+            };
+            format!("{}: {values}", show_identifier(key.str()))
+        })
+        .join(", ");
+    if !properties.is_empty() {
+        result.push_str(&format!(" [ {properties} ]"));
+    }
+    result
+}
+
+/// Labels and keys which are not plain identifiers are written as quoted strings
+fn show_identifier(name: &str) -> String {
+    if !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        name.to_string()
+    } else {
+        quote(name)
+    }
+}
+
+fn show_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => quote(s),
+        Value::Integer(i) => i.to_string(),
+        Value::Date(d) => format!("DATE \"{d}\""),
+        Value::Bool(true) => "TRUE".to_string(),
+        Value::Bool(false) => "FALSE".to_string(),
+    }
+}
+
+/// Quoted string with the escape sequences that the PG parser reads back
+fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
 impl Display for PropertyGraph {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for (node_id, node) in self.nodes.iter() {
@@ -224,3 +345,52 @@ impl Display for PropertyGraph {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::key::Key;
+    use crate::parser::pg_builder::PgBuilder;
+    use crate::value::Value;
+
+    #[test]
+    fn test_write_pg_round_trip() {
+        let input = r#"
+        (n1 {Person} [ name: "Alice", birthDate: DATE "2010-07-22", aliases: ["Ally", "Al"] ])
+        (n2 {Person, Student} [ name: "Bob", age: 23, enrolled: TRUE ])
+        (n3 {Account} [ balance: -5, note: "say \"hi\" to C:\\temp\n" ])
+        (n1) -(e1 {knows} [ since: 2020 ])-> (n2)
+        (n2) -({knows})-> (n1)
+        "#;
+        let pg = PgBuilder::new().parse_pg(input).unwrap();
+        let mut buffer = Vec::new();
+        pg.write_pg(&mut buffer).unwrap();
+        let written = String::from_utf8(buffer).unwrap();
+        let reparsed = PgBuilder::new().parse_pg(written.as_str()).unwrap();
+        assert_eq!(reparsed.node_count(), 3);
+        assert_eq!(reparsed.edge_count(), 2);
+        for (name, node) in pg.nodes() {
+            assert_eq!(reparsed.get_node_by_label(name).unwrap().content(), node.content());
+        }
+        assert_eq!(reparsed.edges().filter(|(name, _)| *name == Some("e1")).count(), 1);
+
+        // Writing the reparsed graph again gives the same text, so escapes do not accumulate
+        let mut buffer = Vec::new();
+        reparsed.write_pg(&mut buffer).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), written);
+    }
+
+    #[test]
+    fn test_parse_escaped_strings_and_negative_numbers() {
+        let pg = PgBuilder::new()
+            .parse_pg(r#"(n {T} [ s: "a \"b\" \\ c", n: -42 ])"#)
+            .unwrap();
+        let record = pg.get_node_by_label("n").unwrap().content();
+        assert!(
+            record
+                .get(&Key::new("s"))
+                .unwrap()
+                .contains(&Value::str("a \"b\" \\ c"))
+        );
+        assert!(record.get(&Key::new("n")).unwrap().contains(&Value::int(-42)));
+    }
+}
@@ -276,6 +276,7 @@ where
                     .map(|(key, value, err)| (Vec::new(), key, value, err))
                     .collect(),
                 failed_cardinality: Vec::new(),
+                last_match: Vec::new(),
             }))
         }
     }
@@ -458,6 +459,17 @@ where
             MatchTableIter::NonEmpty(cp) => cp.failed_cardinality(),
         }
     }
+
+    /// Assignment of values to components behind the last successful match,
+    /// as `(key, value, component)` triples. The component can be mapped back
+    /// to its condition with [`RbeTable::get_condition`]. Empty when nothing
+    /// has matched yet or when the match didn't consume any value.
+    pub fn last_match(&self) -> &[(K, V, Component)] {
+        match self {
+            MatchTableIter::Empty(_) => &[],
+            MatchTableIter::NonEmpty(cp) => cp.last_match(),
+        }
+    }
 }
 
 type IterState<K, V, R, Ctx, P> = MultiProduct<IntoIter<(K, V, Ctx, Component, MatchCond<K, V, R, Ctx, P>)>>;
@@ -484,6 +496,8 @@ where
     // ids) since translating `Component` back to the real key requires the
     // `RbeTable`, which this iterator doesn't hold onto.
     failed_cardinality: Vec<(Vec<(K, V)>, DerivError<Component>)>,
+    // Assignment of values to components of the last candidate that matched.
+    last_match: Vec<(K, V, Component)>,
 }
 
 impl<K, V, R, Ctx, P> IterCartesianProduct<K, V, R, Ctx, P>
@@ -502,6 +516,10 @@ where
     pub fn failed_cardinality(&self) -> &[(Vec<(K, V)>, DerivError<Component>)] {
         &self.failed_cardinality
     }
+
+    pub fn last_match(&self) -> &[(K, V, Component)] {
+        &self.last_match
+    }
 }
 
 impl<K, V, R, Ctx, P> Iterator for IterCartesianProduct<K, V, R, Ctx, P>
@@ -550,11 +568,12 @@ where
                         },
                     }
                 }
-                let bag = Bag::from_iter(vs.into_iter().map(|(_, _, _, c, _)| c));
+                let bag = Bag::from_iter(vs.iter().map(|(_, _, _, c, _)| *c));
                 match self.rbe.match_bag_interval(&bag, self.open) {
                     Ok(()) => {
                         //tracing::trace!("### Rbe {} matches bag {}", self.rbe, bag);
                         self.is_first = false;
+                        self.last_match = vs.into_iter().map(|(k, v, _, c, _)| (k, v, c)).collect();
                         Some(Ok(pending))
                     },
                     Err(err) => {
@@ -824,4 +843,23 @@ mod tests {
         assert_eq!(*key, 'p');
         assert_eq!(*value, 'z');
     }
+
+    #[test]
+    fn test_rbe_table_7_last_match() {
+        // { p x; p y } == { p is_x; p is_y }, each value assigned to its own component
+        let vs = vec![('p', 'x', ' '), ('p', 'y', ' ')];
+
+        let mut rbe_table: Table = RbeTable::new();
+        let c1 = rbe_table.add_component('p', &is_x());
+        let c2 = rbe_table.add_component('p', &is_y());
+        rbe_table.with_rbe(RbeStruct::and(vec![
+            RbeStruct::symbol(c1, 1, Max::IntMax(1)),
+            RbeStruct::symbol(c2, 1, Max::IntMax(1)),
+        ]));
+
+        let mut iter = rbe_table.matches(vs).unwrap();
+        assert!(iter.last_match().is_empty());
+        assert_eq!(iter.next(), Some(Ok(Pending::empty())));
+        assert_eq!(iter.last_match(), &[('p', 'x', c1), ('p', 'y', c2)]);
+    }
 }
@@ -1,7 +1,8 @@
 use crate::cli::parser::{
-    CompareArgs, CompletionArgs, ConfigArgs, ConvertArgs, DCTapArgs, DataArgs, GenerateArgs, MaterializeArgs, McpArgs,
-    NodeArgs, PgschemaArgs, PgschemaValidateArgs, QueryArgs, RdfConfigArgs, ServiceArgs, ShaclArgs, ShaclValidateArgs,
-    ShapemapArgs, ShellArgs, ShexArgs, ShexValidateArgs, SparqlArgs, ValidateArgs,
+    CompareArgs, CompletionArgs, ConfigArgs, ConvertArgs, DCTapArgs, DataArgs, DescribeArgs, GenerateArgs, InferArgs,
+    MaterializeArgs, McpArgs, NodeArgs, PgschemaArgs, PgschemaValidateArgs, QueryArgs, RdfConfigArgs, ServiceArgs,
+    ShaclArgs, ShaclValidateArgs, ShapemapArgs, ShellArgs, ShexArgs, ShexValidateArgs, SparqlArgs, ValidateArgs,
+    ValidationDiffArgs,
 };
 use clap::{Args, Parser, Subcommand};
 use std::path::PathBuf;
@@ -56,6 +57,8 @@ pub enum Command {
     Convert(ConvertArgs),
     /// Compare two shapes (which can be in different formats)
     Compare(CompareArgs),
+    /// Compare the results of two validation runs
+    ValidationDiff(ValidationDiffArgs),
     /// Show information about rdf config
     RdfConfig(RdfConfigArgs),
     /// Show information about SPARQL service
@@ -68,6 +71,10 @@ pub enum Command {
     Generate(GenerateArgs),
     /// Materialize an RDF graph from a ShEx schema and Map semantic-action state
     Materialize(MaterializeArgs),
+    /// Infer a schema from RDF data
+    Infer(InferArgs),
+    /// Compute a VoID / SPARQL service description of RDF data
+    Describe(DescribeArgs),
     /// Validate Property Graph data using PGSchema
     PgschemaValidate(PgschemaValidateArgs),
     /// Generates a shell completion script for the specified shell
@@ -42,6 +42,13 @@ pub struct DataArgs {
     )]
     pub reader_mode: DataReaderModeCli,
 
+    #[arg(
+        long = "max-errors",
+        value_name = "NUMBER",
+        help = "Number of syntax errors skipped in each source with the lax reader mode before loading fails"
+    )]
+    pub max_errors: Option<usize>,
+
     #[arg(
         short = 'r',
         long = "result-format",
@@ -0,0 +1,50 @@
+use crate::cli::parser::CommonArgsAll;
+use crate::cli::wrappers::{DataFormatCli, DataReaderModeCli, ResultServiceFormatCli};
+use clap::Args;
+use rudof_lib::formats::InputSpec;
+
+/// Arguments for the `describe` command
+#[derive(Debug, Clone, Args)]
+pub struct DescribeArgs {
+    #[clap(value_parser = clap::value_parser!(InputSpec))]
+    pub data: Vec<InputSpec>,
+
+    #[arg(
+        short = 't',
+        long = "data-format",
+        value_name = "FORMAT",
+        ignore_case = true,
+        help = "RDF Data format",
+        default_value_t = DataFormatCli::Turtle
+    )]
+    pub data_format: DataFormatCli,
+
+    #[arg(short = 'b', long = "base", value_name = "IRI", help = "Base IRI")]
+    pub base: Option<String>,
+
+    #[arg(
+        long = "reader-mode",
+        value_name = "MODE",
+        ignore_case = true,
+        help = "RDF Reader mode",
+        default_value_t = DataReaderModeCli::Strict,
+        value_enum
+    )]
+    pub reader_mode: DataReaderModeCli,
+
+    #[arg(long = "title", value_name = "TITLE", help = "Title of the described service")]
+    pub title: Option<String>,
+
+    #[arg(
+        short = 'r',
+        long = "result-format",
+        ignore_case = true,
+        value_name = "FORMAT",
+        help = "Output result service format",
+        default_value_t = ResultServiceFormatCli::Json
+    )]
+    pub result_service_format: ResultServiceFormatCli,
+
+    #[command(flatten)]
+    pub common: CommonArgsAll,
+}
@@ -0,0 +1,63 @@
+use crate::cli::parser::CommonArgsAll;
+use crate::cli::wrappers::{DataFormatCli, DataReaderModeCli, InferenceSchemaModeCli, ResultInferenceFormatCli};
+use clap::Args;
+use rudof_lib::formats::InputSpec;
+
+/// Arguments for the `infer` command
+#[derive(Debug, Clone, Args)]
+pub struct InferArgs {
+    #[clap(value_parser = clap::value_parser!(InputSpec))]
+    pub data: Vec<InputSpec>,
+
+    #[arg(
+        short = 't',
+        long = "data-format",
+        value_name = "FORMAT",
+        ignore_case = true,
+        help = "RDF Data format",
+        default_value_t = DataFormatCli::Turtle
+    )]
+    pub data_format: DataFormatCli,
+
+    #[arg(short = 'b', long = "base", value_name = "IRI", help = "Base IRI")]
+    pub base: Option<String>,
+
+    #[arg(
+        long = "reader-mode",
+        value_name = "MODE",
+        ignore_case = true,
+        help = "RDF Reader mode",
+        default_value_t = DataReaderModeCli::Strict,
+        value_enum
+    )]
+    pub reader_mode: DataReaderModeCli,
+
+    #[arg(
+        long = "schema-mode",
+        value_name = "MODE",
+        ignore_case = true,
+        help = "Kind of schema to infer",
+        default_value_t = InferenceSchemaModeCli::ShEx
+    )]
+    pub schema_mode: InferenceSchemaModeCli,
+
+    #[arg(
+        short = 'm',
+        long = "shapemap",
+        value_name = "INPUT",
+        help = "ShapeMap whose shape labels group the nodes it selects. If omitted, nodes are grouped by rdf:type"
+    )]
+    pub shapemap: Option<InputSpec>,
+
+    #[arg(
+        short = 'r',
+        long = "result-format",
+        ignore_case = true,
+        value_name = "FORMAT",
+        help = "Result schema format. If omitted, ShExC for ShEx schemas and Turtle for SHACL shapes"
+    )]
+    pub result_schema_format: Option<ResultInferenceFormatCli>,
+
+    #[command(flatten)]
+    pub common: CommonArgsAll,
+}
@@ -5,7 +5,9 @@ mod config;
 mod convert;
 mod data;
 mod dctap;
+mod describe;
 mod generate;
+mod infer;
 mod materialize;
 mod mcp;
 mod node;
@@ -22,6 +24,7 @@ mod shex;
 mod shex_validate;
 mod sparql;
 mod validate;
+mod validation_diff;
 
 pub use base::*;
 pub use compare::*;
@@ -30,7 +33,9 @@ pub use config::*;
 pub use convert::*;
 pub use data::*;
 pub use dctap::*;
+pub use describe::*;
 pub use generate::*;
+pub use infer::*;
 pub use materialize::*;
 pub use mcp::*;
 pub use node::*;
@@ -47,3 +52,4 @@ pub use shex::*;
 pub use shex_validate::*;
 pub use sparql::*;
 pub use validate::*;
+pub use validation_diff::*;
@@ -14,6 +14,31 @@ pub struct ServiceArgs {
     )]
     pub service: Option<InputSpec>,
 
+    #[arg(
+        long = "introspect",
+        help = "Build the service description and a MIE file by introspecting the data of the service URL, of the --data inputs or of the current session"
+    )]
+    pub introspect: bool,
+
+    #[arg(
+        short = 'd',
+        long = "data",
+        value_name = "INPUT",
+        num_args = 1..,
+        requires = "introspect",
+        conflicts_with = "service",
+        help = "RDF data to introspect, read with --format"
+    )]
+    pub data: Vec<InputSpec>,
+
+    #[arg(
+        long = "title",
+        value_name = "TITLE",
+        requires = "introspect",
+        help = "Title of the introspected service"
+    )]
+    pub title: Option<String>,
+
     #[arg(
         short = 'f',
         long = "format",
@@ -1,10 +1,11 @@
-use crate::cli::parser::CommonArgsAll;
+use crate::cli::parser::{CommonArgsAll, ProfileArgs, ResultLimitsArgs, ValidationPolicyArgs};
 use crate::cli::wrappers::{
     DataFormatCli, DataReaderModeCli, ResultShaclValidationFormatCli, ShaclFormatCli, ShaclValidationModeCli,
     ShaclValidationSortByModeCli,
 };
 use clap::Args;
 use rudof_lib::formats::InputSpec;
+use rudof_rdf::rdf_core::term::literal::Lang;
 
 /// Arguments for the `shacl-validate` command
 #[derive(Debug, Clone, Args)]
@@ -98,6 +99,67 @@ pub struct ShaclValidateArgs {
     )]
     pub sort_by: ShaclValidationSortByModeCli,
 
+    #[arg(
+        long = "timeout",
+        value_name = "MILLISECONDS",
+        help = "Time limit for the whole validation. Focus nodes not validated in time are reported as timeouts"
+    )]
+    pub timeout: Option<u64>,
+
+    #[arg(
+        long = "node-timeout",
+        value_name = "MILLISECONDS",
+        help = "Time limit for the validation of each node"
+    )]
+    pub node_timeout: Option<u64>,
+
+    #[arg(
+        long = "message-lang",
+        value_name = "LANG",
+        value_delimiter = ',',
+        value_parser = |lang: &str| Lang::new(lang),
+        help = "Languages of the SHACL result messages in order of preference, like es,en. \
+            Each result keeps its message in the first of them it has"
+    )]
+    pub message_langs: Vec<Lang>,
+
+    #[arg(
+        short = 'n',
+        long = "node",
+        value_name = "NODE",
+        value_delimiter = ',',
+        help = "Focus nodes to validate, as IRIs or prefixed names. Only the targets among them are validated"
+    )]
+    pub focus_nodes: Vec<String>,
+
+    #[arg(
+        long = "shape",
+        value_name = "SHAPE",
+        value_delimiter = ',',
+        help = "Shapes to validate, as IRIs or prefixed names. Only their targets are validated"
+    )]
+    pub focus_shapes: Vec<String>,
+
+    #[arg(
+        long = "focus",
+        value_name = "NODE@SHAPE",
+        value_delimiter = ',',
+        help = "Nodes to validate against shapes, whatever their targets, like ex:alice@ex:PersonShape"
+    )]
+    pub focus: Vec<String>,
+
+    #[arg(long = "progress", help = "Show a progress bar on stderr while validating")]
+    pub progress: bool,
+
+    #[command(flatten)]
+    pub limits: ResultLimitsArgs,
+
+    #[command(flatten)]
+    pub policy: ValidationPolicyArgs,
+
+    #[command(flatten)]
+    pub profiling: ProfileArgs,
+
     #[command(flatten)]
     pub common: CommonArgsAll,
 }
@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use crate::cli::parser::CommonArgsAll;
+use crate::cli::parser::{CommonArgsAll, ProfileArgs, ValidationPolicyArgs};
 use crate::cli::wrappers::{
     DataFormatCli, DataReaderModeCli, ResultShExValidationFormatCli, ShExFormatCli, ShExValidationSortByModeCli,
     ShapeMapFormatCli,
@@ -151,6 +151,36 @@ pub struct ShexValidateArgs {
     )]
     pub list_external_resolvers: bool,
 
+    #[arg(
+        long = "justify",
+        help = "Record why each node conforms or not (matched triples, partitions, references) \
+                and show it with the details and json result formats"
+    )]
+    pub justify: bool,
+
+    #[arg(
+        long = "timeout",
+        value_name = "MILLISECONDS",
+        help = "Time limit for the whole validation. Nodes not validated in time are reported as timeouts"
+    )]
+    pub timeout: Option<u64>,
+
+    #[arg(
+        long = "node-timeout",
+        value_name = "MILLISECONDS",
+        help = "Time limit for the validation of each node"
+    )]
+    pub node_timeout: Option<u64>,
+
+    #[arg(long = "progress", help = "Show a progress bar on stderr while validating")]
+    pub progress: bool,
+
+    #[command(flatten)]
+    pub policy: ValidationPolicyArgs,
+
+    #[command(flatten)]
+    pub profiling: ProfileArgs,
+
     #[command(flatten)]
     pub common: CommonArgsAll,
 }
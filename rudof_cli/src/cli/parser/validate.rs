@@ -3,10 +3,11 @@ use std::path::PathBuf;
 use crate::cli::parser::CommonArgsAll;
 use crate::cli::wrappers::{
     DataFormatCli, DataReaderModeCli, ResultValidationFormatCli, ShExFormatCli, ShaclValidationModeCli,
-    ShapeMapFormatCli, ValidationModeCli, ValidationSortByModeCli,
+    ShapeMapFormatCli, ValidationFailOnCli, ValidationModeCli, ValidationProfileFormatCli, ValidationSortByModeCli,
 };
 use clap::Args;
 use rudof_lib::formats::InputSpec;
+use rudof_rdf::rdf_core::term::literal::Lang;
 
 /// Arguments for the `validate` command
 #[derive(Debug, Clone, Args)]
@@ -135,6 +136,140 @@ pub struct ValidateArgs {
     #[arg(long = "map-state", value_name = "FILE", help = "MapState file name")]
     pub map_state: Option<PathBuf>,
 
+    #[arg(
+        long = "timeout",
+        value_name = "MILLISECONDS",
+        help = "Time limit for the whole validation. Nodes not validated in time are reported as timeouts"
+    )]
+    pub timeout: Option<u64>,
+
+    #[arg(
+        long = "node-timeout",
+        value_name = "MILLISECONDS",
+        help = "Time limit for the validation of each node"
+    )]
+    pub node_timeout: Option<u64>,
+
+    #[arg(
+        long = "message-lang",
+        value_name = "LANG",
+        value_delimiter = ',',
+        value_parser = |lang: &str| Lang::new(lang),
+        help = "Languages of the SHACL result messages in order of preference, like es,en. \
+            Each result keeps its message in the first of them it has"
+    )]
+    pub message_langs: Vec<Lang>,
+
+    #[arg(long = "progress", help = "Show a progress bar on stderr while validating")]
+    pub progress: bool,
+
+    #[command(flatten)]
+    pub limits: ResultLimitsArgs,
+
+    #[command(flatten)]
+    pub policy: ValidationPolicyArgs,
+
+    #[command(flatten)]
+    pub profiling: ProfileArgs,
+
     #[command(flatten)]
     pub common: CommonArgsAll,
 }
+
+/// Quality gate of the validation commands, which overrides the `validation_policy`
+/// section of the config file
+#[derive(Debug, Clone, Default, Args)]
+pub struct ValidationPolicyArgs {
+    #[arg(
+        long = "fail-on",
+        value_name = "SEVERITY",
+        ignore_case = true,
+        help = "Results that make the command exit with a failure code, default = violations if another \
+                policy option is set, never otherwise"
+    )]
+    pub fail_on: Option<ValidationFailOnCli>,
+
+    #[arg(
+        long = "max-violations",
+        value_name = "N",
+        help = "Number of violations that are still accepted"
+    )]
+    pub max_violations: Option<usize>,
+
+    #[arg(
+        long = "fail-on-shape",
+        value_name = "IRI",
+        help = "Only count the results of this shape, can be repeated"
+    )]
+    pub fail_on_shapes: Vec<String>,
+
+    #[arg(
+        long = "fail-on-component",
+        value_name = "IRI",
+        help = "Only count the results of this SHACL constraint component, can be repeated"
+    )]
+    pub fail_on_components: Vec<String>,
+}
+
+/// Limits on the number of SHACL validation results. The validation stops as
+/// soon as the maximum number of results is reached
+#[derive(Debug, Clone, Default, Args)]
+pub struct ResultLimitsArgs {
+    #[arg(
+        long = "fail-fast",
+        help = "Stop the SHACL validation at the first result, to know whether the data conforms"
+    )]
+    pub fail_fast: bool,
+
+    #[arg(
+        long = "max-results",
+        value_name = "N",
+        help = "Stop the SHACL validation after N results"
+    )]
+    pub max_results: Option<usize>,
+
+    #[arg(
+        long = "max-results-per-shape",
+        value_name = "N",
+        help = "Keep N SHACL results of each source shape and count the rest"
+    )]
+    pub max_results_per_shape: Option<usize>,
+
+    #[arg(
+        long = "max-results-per-component",
+        value_name = "N",
+        help = "Keep N SHACL results of each constraint component and count the rest"
+    )]
+    pub max_results_per_component: Option<usize>,
+
+    #[arg(
+        long = "detail-depth",
+        value_name = "N",
+        help = "Levels of nested results shown as sh:detail of sh:or, sh:xone, sh:node and \
+            sh:qualifiedValueShape results, 0 hides them"
+    )]
+    pub detail_depth: Option<usize>,
+}
+
+/// Profile of the time spent in each shape and constraint of a validation
+#[derive(Debug, Clone, Default, Args)]
+pub struct ProfileArgs {
+    #[arg(
+        long = "profile",
+        value_name = "FORMAT",
+        num_args = 0..=1,
+        default_missing_value = "table",
+        ignore_case = true,
+        help = "Profile the time, evaluations, cache hits and SPARQL queries of each shape and constraint, \
+            written as a table (default), json, or folded stacks for flame graphs"
+    )]
+    pub profile: Option<ValidationProfileFormatCli>,
+
+    #[arg(
+        long = "profile-output",
+        value_name = "FILE",
+        requires = "profile",
+        help = "File to write the profile to, stderr by default"
+    )]
+    pub profile_output: Option<PathBuf>,
+}
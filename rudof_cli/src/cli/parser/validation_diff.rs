@@ -0,0 +1,81 @@
+use crate::cli::parser::CommonArgsNoBackend;
+use crate::cli::wrappers::{ResultComparisonFormatCli, ValidationModeCli, ValidationReportFormatCli};
+use clap::{Args, ValueEnum};
+use rudof_lib::formats::InputSpec;
+
+/// Arguments for the `validation-diff` command
+#[derive(Debug, Clone, Args)]
+pub struct ValidationDiffArgs {
+    #[arg(
+        value_name = "OLD",
+        help = "Results of the previous validation (URI, file or - for stdin)"
+    )]
+    pub old: InputSpec,
+
+    #[arg(
+        value_name = "NEW",
+        help = "Results of the current validation (URI, file or - for stdin)"
+    )]
+    pub new: InputSpec,
+
+    #[arg(
+        short = 'm',
+        long = "mode",
+        value_name = "MODE",
+        ignore_case = true,
+        help = "Validation mode of both results",
+        default_value_t = ValidationModeCli::ShEx
+    )]
+    pub mode: ValidationModeCli,
+
+    #[arg(
+        long = "old-format",
+        value_name = "FORMAT",
+        ignore_case = true,
+        help = "Format of the old results, default = json for ShEx and turtle for SHACL"
+    )]
+    pub old_format: Option<ValidationReportFormatCli>,
+
+    #[arg(
+        long = "new-format",
+        value_name = "FORMAT",
+        ignore_case = true,
+        help = "Format of the new results, default = json for ShEx and turtle for SHACL"
+    )]
+    pub new_format: Option<ValidationReportFormatCli>,
+
+    #[arg(
+        short = 'r',
+        long = "result-format",
+        value_name = "FORMAT",
+        ignore_case = true,
+        help = "Result format",
+        default_value_t = ResultComparisonFormatCli::Internal
+    )]
+    pub result_format: ResultComparisonFormatCli,
+
+    #[arg(
+        long = "fail-on",
+        value_name = "CHANGES",
+        ignore_case = true,
+        help = "Changes that make the command exit with a failure code",
+        default_value = "regressions",
+        value_enum
+    )]
+    pub fail_on: FailOnCli,
+
+    #[command(flatten)]
+    pub common: CommonArgsNoBackend,
+}
+
+/// Changes between two validation results that fail a CI gate
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+#[clap(rename_all = "lower")]
+pub enum FailOnCli {
+    // Fail when some result is newly failing
+    Regressions,
+    // Fail on any change, including newly passing results and changed reasons
+    Changes,
+    // Never fail because of the changes
+    Never,
+}
@@ -1,6 +1,6 @@
 use crate::cli_wrapper;
 use clap::ValueEnum;
-use rudof_lib::formats::{ComparisonFormat, ComparisonMode, ResultComparisonFormat};
+use rudof_lib::formats::{ComparisonFormat, ComparisonMode, ResultComparisonFormat, ValidationReportFormat};
 use std::fmt::{Display, Formatter, Result};
 
 cli_wrapper!(
@@ -34,3 +34,17 @@ cli_wrapper!(
         Json,
     }
 );
+
+cli_wrapper!(
+    ValidationReportFormatCli,
+    ValidationReportFormat,
+    {
+        Json,
+        Turtle,
+        NTriples,
+        RdfXml,
+        TriG,
+        N3,
+        NQuads,
+    }
+);
@@ -43,5 +43,6 @@ cli_wrapper!(
         PlantUML,
         Svg,
         Png,
+        Pg,
     }
 );
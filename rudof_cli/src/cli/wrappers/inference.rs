@@ -0,0 +1,26 @@
+use crate::cli_wrapper;
+use clap::ValueEnum;
+use rudof_lib::formats::{InferenceSchemaMode, ResultInferenceFormat};
+use std::fmt::{Display, Formatter, Result};
+
+cli_wrapper!(
+    InferenceSchemaModeCli,
+    InferenceSchemaMode,
+    {
+        ShEx,
+        Shacl,
+    }
+);
+
+cli_wrapper!(
+    ResultInferenceFormatCli,
+    ResultInferenceFormat,
+    {
+        ShExC,
+        ShExJ,
+        Turtle,
+        NTriples,
+        RdfXml,
+        JsonLd,
+    }
+);
@@ -4,6 +4,7 @@ mod conversion;
 mod data;
 mod dctap;
 mod generation;
+mod inference;
 mod node;
 mod pgschema;
 mod query;
@@ -20,6 +21,7 @@ pub use conversion::*;
 pub use data::*;
 pub use dctap::*;
 pub use generation::*;
+pub use inference::*;
 pub use node::*;
 pub use pgschema::*;
 pub use query::*;
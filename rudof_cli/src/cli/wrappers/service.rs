@@ -10,5 +10,9 @@ cli_wrapper!(
         Internal,
         Mie,
         Json,
+        Turtle,
+        NTriples,
+        RdfXml,
+        JsonLd,
     }
 );
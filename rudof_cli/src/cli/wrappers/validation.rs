@@ -4,7 +4,8 @@ use anyhow::{Result as AnyhowResult, bail};
 use clap::ValueEnum;
 use rudof_lib::formats::{
     ResultPgSchemaValidationFormat, ResultShExValidationFormat, ResultShaclValidationFormat, ResultValidationFormat,
-    ShExValidationSortByMode, ShaclValidationMode, ShaclValidationSortByMode, ValidationMode, ValidationSortByMode,
+    ShExValidationSortByMode, ShaclValidationMode, ShaclValidationSortByMode, ValidationFailOn, ValidationMode,
+    ValidationProfileFormat, ValidationSortByMode,
 };
 use std::fmt::{Display, Formatter, Result};
 
@@ -18,6 +19,26 @@ cli_wrapper!(
     }
 );
 
+cli_wrapper!(
+    ValidationFailOnCli,
+    ValidationFailOn,
+    {
+        Never,
+        Violations,
+        Warnings
+    }
+);
+
+cli_wrapper!(
+    ValidationProfileFormatCli,
+    ValidationProfileFormat,
+    {
+        Table,
+        Json,
+        Folded
+    }
+);
+
 cli_wrapper!(
     ValidationSortByModeCli,
     ValidationSortByMode,
@@ -51,6 +72,10 @@ cli_wrapper!(
         Compact,
         Json,
         Csv,
+        NdJson,
+        Sarif,
+        JUnit,
+        Html,
     }
 );
 
@@ -81,7 +106,11 @@ cli_wrapper!(
         Compact,
         Details,
         Json,
-        Csv
+        Csv,
+        NdJson,
+        Sarif,
+        JUnit,
+        Html
     }
 );
 
@@ -110,10 +139,14 @@ cli_wrapper!(
         Compact,
         Json,
         Csv,
+        NdJson,
+        Sarif,
+        JUnit,
+        Html,
     }
 );
 
-cli_wrapper!(ResultPgSchemaValidationFormatCli, ResultPgSchemaValidationFormat, { Compact, Details, Json, Csv });
+cli_wrapper!(ResultPgSchemaValidationFormatCli, ResultPgSchemaValidationFormat, { Compact, Details, Json, Csv, Sarif, JUnit });
 
 impl From<ValidationSortByModeCli> for ShExValidationSortByModeCli {
     fn from(val: ValidationSortByModeCli) -> Self {
@@ -146,6 +179,10 @@ impl From<ResultValidationFormatCli> for ResultShExValidationFormatCli {
             ResultValidationFormatCli::Details => ResultShExValidationFormatCli::Details,
             ResultValidationFormatCli::Json => ResultShExValidationFormatCli::Json,
             ResultValidationFormatCli::Csv => ResultShExValidationFormatCli::Csv,
+            ResultValidationFormatCli::NdJson => ResultShExValidationFormatCli::NdJson,
+            ResultValidationFormatCli::Sarif => ResultShExValidationFormatCli::Sarif,
+            ResultValidationFormatCli::JUnit => ResultShExValidationFormatCli::JUnit,
+            ResultValidationFormatCli::Html => ResultShExValidationFormatCli::Html,
         }
     }
 }
@@ -163,6 +200,10 @@ impl From<ResultValidationFormatCli> for ResultShaclValidationFormatCli {
             ResultValidationFormatCli::Details => ResultShaclValidationFormatCli::Details,
             ResultValidationFormatCli::Json => ResultShaclValidationFormatCli::Json,
             ResultValidationFormatCli::Csv => ResultShaclValidationFormatCli::Csv,
+            ResultValidationFormatCli::NdJson => ResultShaclValidationFormatCli::NdJson,
+            ResultValidationFormatCli::Sarif => ResultShaclValidationFormatCli::Sarif,
+            ResultValidationFormatCli::JUnit => ResultShaclValidationFormatCli::JUnit,
+            ResultValidationFormatCli::Html => ResultShaclValidationFormatCli::Html,
         }
     }
 }
@@ -197,12 +238,16 @@ impl TryFrom<ResultValidationFormatCli> for ResultPgSchemaValidationFormatCli {
             ResultValidationFormatCli::Details => Ok(ResultPgSchemaValidationFormatCli::Details),
             ResultValidationFormatCli::Json => Ok(ResultPgSchemaValidationFormatCli::Json),
             ResultValidationFormatCli::Csv => Ok(ResultPgSchemaValidationFormatCli::Csv),
+            ResultValidationFormatCli::Sarif => Ok(ResultPgSchemaValidationFormatCli::Sarif),
+            ResultValidationFormatCli::JUnit => Ok(ResultPgSchemaValidationFormatCli::JUnit),
             ResultValidationFormatCli::Turtle => todo!("PGSchema validation doesn't support Turtle result format"),
             ResultValidationFormatCli::NTriples => todo!("PGSchema validation doesn't support NTriples result format"),
             ResultValidationFormatCli::RdfXml => todo!("PGSchema validation doesn't support RDF/XML result format"),
             ResultValidationFormatCli::TriG => todo!("PGSchema validation doesn't support TriG result format"),
             ResultValidationFormatCli::N3 => todo!("PGSchema validation doesn't support N3 result format"),
             ResultValidationFormatCli::NQuads => todo!("PGSchema validation doesn't support NQuads result format"),
+            ResultValidationFormatCli::NdJson => bail!("PGSchema validation doesn't support NDJSON result format"),
+            ResultValidationFormatCli::Html => bail!("PGSchema validation doesn't support HTML result format"),
         }
     }
 }
@@ -2,15 +2,17 @@ use crate::cli::parser::{
     Command as CliCommand, CommonArgs, CommonArgsAll, CommonArgsNoBackend, CommonArgsOutputForceOverWrite,
 };
 use crate::commands::{
-    CompareCommand, CompletionCommand, ConfigCommand, ConvertCommand, DataCommand, DctapCommand, GenerateCommand,
-    MaterializeCommand, McpCommand, NodeCommand, PgschemaCommand, PgschemaValidateCommand, QueryCommand,
-    RdfConfigCommand, ServiceCommand, ShaclCommand, ShaclValidateCommand, ShapemapCommand, ShexCommand,
-    ShexValidateCommand, SparqlCommand, ValidateCommand,
+    CompareCommand, CompletionCommand, ConfigCommand, ConvertCommand, DataCommand, DctapCommand, DescribeCommand,
+    GenerateCommand, InferCommand, MaterializeCommand, McpCommand, NodeCommand, PgschemaCommand,
+    PgschemaValidateCommand, QueryCommand, RdfConfigCommand, ServiceCommand, ShaclCommand, ShaclValidateCommand,
+    ShapemapCommand, ShexCommand, ShexValidateCommand, SparqlCommand, ValidateCommand, ValidationDiffCommand,
 };
 use crate::output::{ColorSupport, get_writer};
 use crate::shell::ShellCommand;
 use anyhow::Result;
+use rudof_lib::types::ValidationVerdict;
 use rudof_lib::{Rudof, RudofConfig};
+use std::fmt::{Display, Formatter};
 use std::io::Write;
 
 // ============================================================================
@@ -38,7 +40,7 @@ pub trait Command: Send + Sync {
 /// and UI preferences (like color and verbosity).
 pub struct CommandContext {
     /// Output writer (stdout, file, etc.)
-    pub writer: Box<dyn Write>,
+    pub writer: Box<dyn Write + Send>,
 
     /// Rudof (from rudof_lib)
     pub rudof: Rudof,
@@ -56,7 +58,7 @@ pub struct CommandContext {
 }
 
 impl CommandContext {
-    pub fn new(writer: Box<dyn Write>, rudof: Rudof, debug_level: u8, color: ColorSupport) -> Self {
+    pub fn new(writer: Box<dyn Write + Send>, rudof: Rudof, debug_level: u8, color: ColorSupport) -> Self {
         Self {
             writer,
             rudof,
@@ -97,6 +99,59 @@ impl CommandContext {
     }
 }
 
+// ============================================================================
+// Command Exit
+// ============================================================================
+
+/// Exit code of the validation commands when there are more violations than
+/// the validation policy allows
+pub const EXIT_VIOLATIONS: u8 = 2;
+
+/// Exit code of the validation commands when the validation policy fails on
+/// warnings and there are warnings, but not too many violations
+pub const EXIT_WARNINGS: u8 = 3;
+
+/// Error returned by a command that did its work, but whose outcome has to end
+/// the process with a specific exit code, such as a CI gate that did not pass.
+///
+/// Any other error ends the process with exit code 1.
+#[derive(Debug)]
+pub struct CommandExit {
+    code: u8,
+    message: String,
+}
+
+impl CommandExit {
+    pub fn new(code: u8, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+
+    pub fn code(&self) -> u8 {
+        self.code
+    }
+
+    /// Fails with the exit code of a validation whose results do not pass its policy
+    pub fn check_verdict(verdict: ValidationVerdict) -> Result<()> {
+        let code = match verdict {
+            ValidationVerdict::Passed => return Ok(()),
+            ValidationVerdict::Violations { .. } => EXIT_VIOLATIONS,
+            ValidationVerdict::Warnings { .. } => EXIT_WARNINGS,
+        };
+        Err(CommandExit::new(code, verdict.to_string()).into())
+    }
+}
+
+impl Display for CommandExit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for CommandExit {}
+
 // ============================================================================
 // Command Factory
 // ============================================================================
@@ -121,12 +176,15 @@ impl CommandFactory {
             CliCommand::DCTap(args) => Ok(Box::new(DctapCommand::new(args))),
             CliCommand::Convert(args) => Ok(Box::new(ConvertCommand::new(args))),
             CliCommand::Compare(args) => Ok(Box::new(CompareCommand::new(args))),
+            CliCommand::ValidationDiff(args) => Ok(Box::new(ValidationDiffCommand::new(args))),
             CliCommand::RdfConfig(args) => Ok(Box::new(RdfConfigCommand::new(args))),
             CliCommand::Service(args) => Ok(Box::new(ServiceCommand::new(args))),
             CliCommand::Query(args) => Ok(Box::new(QueryCommand::new(args))),
             CliCommand::Sparql(args) => Ok(Box::new(SparqlCommand::new(args))),
             CliCommand::Generate(args) => Ok(Box::new(GenerateCommand::new(args))),
             CliCommand::Materialize(args) => Ok(Box::new(MaterializeCommand::new(args))),
+            CliCommand::Infer(args) => Ok(Box::new(InferCommand::new(args))),
+            CliCommand::Describe(args) => Ok(Box::new(DescribeCommand::new(args))),
             CliCommand::PgschemaValidate(args) => Ok(Box::new(PgschemaValidateCommand::new(args))),
             CliCommand::Completion(args) => Ok(Box::new(CompletionCommand::new(args))),
             CliCommand::Config(args) => Ok(Box::new(ConfigCommand::new(args))),
@@ -214,6 +272,11 @@ pub(crate) fn extract_common(cmd: &CliCommand) -> CommonArgs {
             output: a.common.output.clone(),
             force_overwrite: a.common.force_overwrite,
         }),
+        CliCommand::ValidationDiff(a) => CommonArgs::NoBackend(CommonArgsNoBackend {
+            config: a.common.config.clone(),
+            output: a.common.output.clone(),
+            force_overwrite: a.common.force_overwrite,
+        }),
         CliCommand::RdfConfig(a) => CommonArgs::NoBackend(CommonArgsNoBackend {
             config: a.common.config.clone(),
             output: a.common.output.clone(),
@@ -246,6 +309,20 @@ pub(crate) fn extract_common(cmd: &CliCommand) -> CommonArgs {
             output: a.common.output.clone(),
             force_overwrite: a.common.force_overwrite,
         }),
+        CliCommand::Infer(a) => CommonArgs::All(CommonArgsAll {
+            config: a.common.config.clone(),
+            output: a.common.output.clone(),
+            force_overwrite: a.common.force_overwrite,
+            backend: a.common.backend.clone(),
+            endpoint: a.common.endpoint.clone(),
+        }),
+        CliCommand::Describe(a) => CommonArgs::All(CommonArgsAll {
+            config: a.common.config.clone(),
+            output: a.common.output.clone(),
+            force_overwrite: a.common.force_overwrite,
+            backend: a.common.backend.clone(),
+            endpoint: a.common.endpoint.clone(),
+        }),
         CliCommand::PgschemaValidate(a) => CommonArgs::OutputForceOverWrite(CommonArgsOutputForceOverWrite {
             output: a.common.output.clone(),
             force_overwrite: a.common.force_overwrite,
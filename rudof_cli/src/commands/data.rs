@@ -2,6 +2,7 @@ use crate::cli::parser::DataArgs;
 use crate::cli::wrappers::resolve_backend;
 use crate::commands::base::{Command, CommandContext};
 use anyhow::Result;
+use rudof_lib::RecoveredError;
 use rudof_lib::formats::BackendSpec;
 
 /// Implementation of the `data` command.
@@ -55,7 +56,12 @@ impl Command for DataCommand {
             if !self.args.prefixes.is_empty() {
                 loading = loading.with_prefixes(&self.args.prefixes);
             }
-            loading.execute()?;
+            if let Some(max_errors) = self.args.max_errors {
+                loading = loading.with_max_errors(max_errors);
+            }
+            let loaded = loading.execute();
+            report_skipped_errors(ctx.rudof.data_warnings());
+            loaded?;
         }
 
         ctx.rudof
@@ -66,3 +72,17 @@ impl Command for DataCommand {
         Ok(())
     }
 }
+
+/// Lists on stderr the syntax errors skipped in lax mode, so that they don't mix
+/// with the data written to the output
+fn report_skipped_errors(warnings: &[RecoveredError]) {
+    for warning in warnings {
+        eprintln!("Warning: {warning}");
+    }
+    if !warnings.is_empty() {
+        eprintln!(
+            "Skipped {} syntax error(s), the statements where they were found were not loaded",
+            warnings.len()
+        );
+    }
+}
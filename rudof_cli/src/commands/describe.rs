@@ -0,0 +1,74 @@
+use crate::cli::parser::DescribeArgs;
+use crate::cli::wrappers::resolve_backend;
+use crate::commands::base::{Command, CommandContext};
+use anyhow::Result;
+use rudof_lib::formats::BackendSpec;
+
+/// Implementation of the `describe` command.
+///
+/// Loads RDF data, computes a VoID / SPARQL service description with its
+/// statistics and writes it to the configured output.
+pub struct DescribeCommand {
+    args: DescribeArgs,
+}
+
+impl DescribeCommand {
+    pub fn new(args: DescribeArgs) -> Self {
+        Self { args }
+    }
+}
+
+impl Command for DescribeCommand {
+    fn name(&self) -> &'static str {
+        "describe"
+    }
+
+    /// Executes the describe command.
+    ///
+    /// With no `data` arguments (and no endpoint), the data already loaded in
+    /// the session is described. When the data comes from an endpoint given by
+    /// its URL, the URL is recorded as the endpoint of the described service.
+    fn execute(&self, ctx: &mut CommandContext) -> Result<()> {
+        let data_format = self.args.data_format.into();
+        let reader_mode = self.args.reader_mode.into();
+        let result_format = self.args.result_service_format.into();
+
+        let backend = resolve_backend(&self.args.common);
+        let endpoint = match &backend {
+            BackendSpec::Endpoint(endpoint) => Some(endpoint.clone()),
+            _ => None,
+        };
+        if !self.args.data.is_empty() || endpoint.is_some() {
+            let mut loading = ctx
+                .rudof
+                .load_data()
+                .with_data_format(&data_format)
+                .with_reader_mode(&reader_mode)
+                .with_backend(backend);
+            if !self.args.data.is_empty() {
+                loading = loading.with_data(&self.args.data);
+            }
+            if let Some(base) = self.args.base.as_deref() {
+                loading = loading.with_base(base);
+            }
+            loading.execute()?;
+        }
+
+        let mut describe = ctx.rudof.describe_data();
+        if let Some(title) = self.args.title.as_deref() {
+            describe = describe.with_title(title);
+        }
+        // Endpoints can also be names registered in the configuration
+        if let Some(endpoint) = endpoint.as_deref().filter(|endpoint| endpoint.contains("://")) {
+            describe = describe.with_endpoint(endpoint);
+        }
+        describe.execute()?;
+
+        ctx.rudof
+            .serialize_service_description(&mut ctx.writer)
+            .with_result_service_format(&result_format)
+            .execute()?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,81 @@
+use crate::cli::parser::InferArgs;
+use crate::cli::wrappers::resolve_backend;
+use crate::commands::base::{Command, CommandContext};
+use anyhow::Result;
+use rudof_lib::formats::{BackendSpec, InferenceSchemaMode, ResultInferenceFormat, ShExFormat, ShaclFormat};
+
+/// Implementation of the `infer` command.
+///
+/// Loads RDF data, infers a schema that describes it and writes the
+/// inferred schema to the configured output.
+pub struct InferCommand {
+    args: InferArgs,
+}
+
+impl InferCommand {
+    pub fn new(args: InferArgs) -> Self {
+        Self { args }
+    }
+}
+
+impl Command for InferCommand {
+    fn name(&self) -> &'static str {
+        "infer"
+    }
+
+    /// Executes the infer command.
+    ///
+    /// With no `data` arguments (and no endpoint), the schema is inferred from
+    /// the data already loaded in the session.
+    fn execute(&self, ctx: &mut CommandContext) -> Result<()> {
+        let data_format = self.args.data_format.into();
+        let reader_mode = self.args.reader_mode.into();
+        let schema_mode: InferenceSchemaMode = self.args.schema_mode.into();
+        let result_schema_format = self.args.result_schema_format.map(ResultInferenceFormat::from);
+
+        let backend = resolve_backend(&self.args.common);
+        if !self.args.data.is_empty() || matches!(backend, BackendSpec::Endpoint(_)) {
+            let mut loading = ctx
+                .rudof
+                .load_data()
+                .with_data_format(&data_format)
+                .with_reader_mode(&reader_mode)
+                .with_backend(backend);
+            if !self.args.data.is_empty() {
+                loading = loading.with_data(&self.args.data);
+            }
+            if let Some(base) = self.args.base.as_deref() {
+                loading = loading.with_base(base);
+            }
+            loading.execute()?;
+        }
+
+        let mut inference = ctx.rudof.infer_schema().with_schema_mode(&schema_mode);
+        if let Some(shapemap) = &self.args.shapemap {
+            inference = inference.with_shapemap(shapemap);
+        }
+        inference.execute()?;
+
+        match schema_mode {
+            InferenceSchemaMode::ShEx => {
+                let format = result_schema_format.map(ShExFormat::from).unwrap_or(ShExFormat::ShExC);
+                ctx.rudof
+                    .serialize_shex_schema(&mut ctx.writer)
+                    .with_result_shex_format(&format)
+                    .execute()?;
+            },
+            InferenceSchemaMode::Shacl => {
+                let format = match result_schema_format {
+                    Some(format) => ShaclFormat::try_from(format)?,
+                    None => ShaclFormat::Turtle,
+                };
+                ctx.rudof
+                    .serialize_shacl_shapes(&mut ctx.writer)
+                    .with_shacl_result_format(&format)
+                    .execute()?;
+            },
+        }
+
+        Ok(())
+    }
+}
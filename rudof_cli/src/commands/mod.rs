@@ -5,7 +5,9 @@ mod config;
 mod convert;
 mod data;
 mod dctap;
+mod describe;
 mod generate;
+mod infer;
 mod materialize;
 mod mcp;
 mod node;
@@ -21,16 +23,19 @@ mod shex;
 mod shex_validate;
 mod sparql;
 mod validate;
+mod validation_diff;
 
 pub(crate) use base::extract_common;
-pub use base::{Command, CommandContext, CommandFactory};
+pub use base::{Command, CommandContext, CommandExit, CommandFactory};
 pub use compare::CompareCommand;
 pub use completion::CompletionCommand;
 pub use config::ConfigCommand;
 pub use convert::ConvertCommand;
 pub use data::DataCommand;
 pub use dctap::DctapCommand;
+pub use describe::DescribeCommand;
 pub use generate::GenerateCommand;
+pub use infer::InferCommand;
 pub use materialize::MaterializeCommand;
 pub use mcp::McpCommand;
 pub use node::NodeCommand;
@@ -46,3 +51,4 @@ pub use shex::ShexCommand;
 pub use shex_validate::ShexValidateCommand;
 pub use sparql::SparqlCommand;
 pub use validate::ValidateCommand;
+pub use validation_diff::ValidationDiffCommand;
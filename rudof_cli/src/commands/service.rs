@@ -1,6 +1,8 @@
 use crate::cli::parser::ServiceArgs;
+use crate::cli::wrappers::ResultServiceFormatCli;
 use crate::commands::base::{Command, CommandContext};
-use anyhow::Result;
+use anyhow::{Result, bail};
+use rudof_lib::formats::{BackendSpec, InputSpec};
 
 /// Implementation of the `service` command.
 ///
@@ -29,12 +31,56 @@ impl Command for ServiceCommand {
     /// re-serializes whatever service description is already loaded in the
     /// session (useful in the interactive shell, where state persists
     /// across commands).
+    ///
+    /// With `--introspect`, the service description and a MIE file are built
+    /// by introspecting the endpoint given by `--service`, the `--data` inputs or
+    /// the data of the session. The MIE is written when the result format is `mie`.
     fn execute(&self, ctx: &mut CommandContext) -> Result<()> {
         let reader_mode = self.args.reader_mode.into();
         let format = self.args.service_format.into();
         let result_format = self.args.result_service_format.into();
 
-        if let Some(service) = &self.args.service {
+        if self.args.introspect {
+            let endpoint = match &self.args.service {
+                Some(InputSpec::Url(url)) => Some(url.to_string()),
+                Some(service) => bail!(
+                    "--introspect needs the URL of a SPARQL endpoint in --service, found {}",
+                    service.source_name()
+                ),
+                None => None,
+            };
+            if let Some(endpoint) = &endpoint {
+                ctx.rudof
+                    .load_data()
+                    .with_backend(BackendSpec::Endpoint(endpoint.clone()))
+                    .execute()?;
+            } else if !self.args.data.is_empty() {
+                let mut loading = ctx
+                    .rudof
+                    .load_data()
+                    .with_data(&self.args.data)
+                    .with_data_format(&format)
+                    .with_reader_mode(&reader_mode);
+                if let Some(base) = self.args.base_data.as_deref() {
+                    loading = loading.with_base(base);
+                }
+                loading.execute()?;
+            }
+
+            let mut build_mie = ctx.rudof.build_mie();
+            if let Some(title) = self.args.title.as_deref() {
+                build_mie = build_mie.with_title(title);
+            }
+            if let Some(endpoint) = endpoint.as_deref() {
+                build_mie = build_mie.with_endpoint(endpoint);
+            }
+            build_mie.execute()?;
+
+            if matches!(self.args.result_service_format, ResultServiceFormatCli::Mie) {
+                ctx.rudof.serialize_mie(&mut ctx.writer).execute()?;
+                return Ok(());
+            }
+        } else if let Some(service) = &self.args.service {
             let mut load_service_description = ctx
                 .rudof
                 .load_service_description(service)
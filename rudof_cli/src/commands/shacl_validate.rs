@@ -1,8 +1,12 @@
 use crate::cli::parser::ShaclValidateArgs;
 use crate::cli::wrappers::resolve_backend;
-use crate::commands::base::{Command, CommandContext};
+use crate::commands::base::{Command, CommandContext, CommandExit};
+use crate::output::{NdJsonStream, ProgressBar, write_validation_profile};
 use anyhow::Result;
-use rudof_lib::formats::BackendSpec;
+use rudof_lib::formats::{BackendSpec, ResultShaclValidationFormat};
+use rudof_lib::types::ShaclFocus;
+use rudof_lib::{ResultLimits, ShaclValidationEvent};
+use std::time::Duration;
 
 /// Implementation of the `shacl-validate` command.
 ///
@@ -32,7 +36,7 @@ impl Command for ShaclValidateCommand {
         let shacl_schema_format = self.args.shapes_format.into();
         let shacl_validation_mode = self.args.mode.into();
         let sort_order = self.args.sort_by.into();
-        let result_format = self.args.result_format.into();
+        let result_format: ResultShaclValidationFormat = self.args.result_format.into();
 
         let backend = resolve_backend(&self.args.common);
         let has_data_source = !self.args.data.is_empty() || matches!(backend, BackendSpec::Endpoint(_));
@@ -70,17 +74,96 @@ impl Command for ShaclValidateCommand {
             loading_schema.execute()?;
         }
 
-        ctx.rudof
-            .validate_shacl()
-            .with_shacl_validation_mode(&shacl_validation_mode)
-            .execute()?;
+        // With NDJSON the results are written as they are produced instead of at the end
+        let streaming = matches!(result_format, ResultShaclValidationFormat::NdJson);
+        let progress = self.args.progress.then(|| ProgressBar::new("Validating"));
+        let stream = streaming.then(|| NdJsonStream::new(&mut *ctx.writer));
+        let on_event = |event: ShaclValidationEvent| {
+            if let (
+                Some(progress),
+                ShaclValidationEvent::Progress {
+                    focus_nodes_done,
+                    focus_nodes_found,
+                    ..
+                },
+            ) = (&progress, &event)
+            {
+                progress.update(*focus_nodes_done, *focus_nodes_found);
+            }
+            if let Some(stream) = &stream {
+                stream.write(&event);
+            }
+        };
+
+        let focus = ShaclFocus::new()
+            .with_nodes(&self.args.focus_nodes)
+            .with_shapes(&self.args.focus_shapes)
+            .with_shape_map(&self.args.focus)?;
+        let limits = ResultLimits::new()
+            .with_fail_fast(self.args.limits.fail_fast)
+            .with_max_results(self.args.limits.max_results)
+            .with_max_results_per_shape(self.args.limits.max_results_per_shape)
+            .with_max_results_per_component(self.args.limits.max_results_per_component)
+            .with_detail_depth(self.args.limits.detail_depth);
 
         ctx.rudof
-            .serialize_shacl_validation_results(&mut ctx.writer)
-            .with_shacl_validation_sort_order_mode(&sort_order)
-            .with_result_shacl_validation_format(&result_format)
-            .execute()?;
+            .set_validation_profiling(self.args.profiling.profile.is_some());
+        let mut validation = ctx
+            .rudof
+            .validate_shacl()
+            .with_shacl_validation_mode(&shacl_validation_mode);
+        if let Some(timeout) = self.args.timeout {
+            validation = validation.with_timeout(Duration::from_millis(timeout));
+        }
+        if let Some(node_timeout) = self.args.node_timeout {
+            validation = validation.with_node_timeout(Duration::from_millis(node_timeout));
+        }
+        if !self.args.message_langs.is_empty() {
+            validation = validation.with_message_languages(&self.args.message_langs);
+        }
+        if !focus.is_empty() {
+            validation = validation.with_focus(&focus);
+        }
+        if !limits.is_unlimited() || limits.detail_depth().is_some() {
+            validation = validation.with_result_limits(&limits);
+        }
+        if progress.is_some() || stream.is_some() {
+            validation = validation.with_event_handler(&on_event);
+        }
+        let validated = validation.execute();
+        if let Some(progress) = &progress {
+            progress.finish();
+        }
+        validated?;
+        if let Some(stream) = stream {
+            stream.finish()?;
+        } else {
+            ctx.rudof
+                .serialize_shacl_validation_results(&mut ctx.writer)
+                .with_shacl_validation_sort_order_mode(&sort_order)
+                .with_result_shacl_validation_format(&result_format)
+                .execute()?;
+        }
+        ctx.writer.flush()?;
+        if let Some(format) = self.args.profiling.profile {
+            write_validation_profile(&ctx.rudof, format.into(), self.args.profiling.profile_output.as_deref())?;
+        }
 
-        Ok(())
+        let policy = &self.args.policy;
+        let fail_on = policy.fail_on.map(Into::into);
+        let mut check = ctx.rudof.check_shacl_validation_policy();
+        if let Some(fail_on) = fail_on.as_ref() {
+            check = check.with_fail_on(fail_on);
+        }
+        if let Some(max_violations) = policy.max_violations {
+            check = check.with_max_violations(max_violations);
+        }
+        if !policy.fail_on_shapes.is_empty() {
+            check = check.with_shapes(&policy.fail_on_shapes);
+        }
+        if !policy.fail_on_components.is_empty() {
+            check = check.with_components(&policy.fail_on_components);
+        }
+        CommandExit::check_verdict(check.execute()?)
     }
 }
@@ -1,11 +1,13 @@
 use crate::cli::parser::ShexValidateArgs;
 use crate::cli::wrappers::resolve_backend;
-use crate::commands::base::{Command, CommandContext};
+use crate::commands::base::{Command, CommandContext, CommandExit};
+use crate::output::{NdJsonStream, ProgressBar, write_validation_profile};
 use anyhow::{Context, Result};
-use rudof_lib::Rudof;
-use rudof_lib::formats::{BackendSpec, IriNormalizationMode};
+use rudof_lib::formats::{BackendSpec, IriNormalizationMode, ResultShExValidationFormat};
+use rudof_lib::{Rudof, ShExValidationEvent};
 use std::fs::File;
 use std::io::{BufWriter, Write};
+use std::time::Duration;
 
 /// Implementation of the `shex-validate` command.
 ///
@@ -40,7 +42,7 @@ impl Command for ShexValidateCommand {
         let reader_mode = self.args.reader_mode.into();
         let schema_format = self.args.schema_format.into();
         let sort_order = self.args.sort_by.into();
-        let result_format = self.args.result_format.into();
+        let result_format: ResultShExValidationFormat = self.args.result_format.into();
         let map_state = self.args.map_state.clone();
 
         // External-shape resolvers must be registered before `load_shex_schema`
@@ -49,6 +51,10 @@ impl Command for ShexValidateCommand {
         for spec in &self.args.external_resolvers {
             ctx.rudof.add_external_resolver(spec)?;
         }
+        // Same for justifications: the validator copies its config when the schema is loaded.
+        if self.args.justify {
+            ctx.rudof.set_shex_justify(true);
+        }
 
         let backend = resolve_backend(&self.args.common);
         let has_data_source = !self.args.data.is_empty() || matches!(backend, BackendSpec::Endpoint(_));
@@ -134,21 +140,70 @@ impl Command for ShexValidateCommand {
             node_shape.execute()?;
         }
 
-        ctx.rudof.validate_shex().execute()?;
+        // With NDJSON the results are written as they are decided instead of at the end
+        let streaming = matches!(result_format, ResultShExValidationFormat::NdJson);
+        let progress = self.args.progress.then(|| ProgressBar::new("Validating"));
+        let stream = streaming.then(|| NdJsonStream::new(&mut *ctx.writer));
+        let on_event = |event: ShExValidationEvent| {
+            if let (Some(progress), ShExValidationEvent::Progress { decided, entries }) = (&progress, &event) {
+                progress.update(*decided, *entries);
+            }
+            if let Some(stream) = &stream {
+                stream.write(&event);
+            }
+        };
 
         ctx.rudof
-            .serialize_shex_validation_results(&mut ctx.writer)
-            .with_shex_validation_sort_order_mode(&sort_order)
-            .with_result_shex_validation_format(&result_format)
-            .execute()?;
+            .set_validation_profiling(self.args.profiling.profile.is_some());
+        let mut validation = ctx.rudof.validate_shex();
+        if let Some(timeout) = self.args.timeout {
+            validation = validation.with_timeout(Duration::from_millis(timeout));
+        }
+        if let Some(node_timeout) = self.args.node_timeout {
+            validation = validation.with_node_timeout(Duration::from_millis(node_timeout));
+        }
+        if progress.is_some() || stream.is_some() {
+            validation = validation.with_event_handler(&on_event);
+        }
+        let validated = validation.execute();
+        if let Some(progress) = &progress {
+            progress.finish();
+        }
+        validated?;
+        if let Some(stream) = stream {
+            stream.finish()?;
+        } else {
+            ctx.rudof
+                .serialize_shex_validation_results(&mut ctx.writer)
+                .with_shex_validation_sort_order_mode(&sort_order)
+                .with_result_shex_validation_format(&result_format)
+                .execute()?;
+        }
 
         if let Some(map_state_path) = map_state {
             ctx.rudof
                 .serialize_map_state(&mut std::fs::File::create(map_state_path)?)
                 .execute()?;
         }
+        ctx.writer.flush()?;
+        if let Some(format) = self.args.profiling.profile {
+            write_validation_profile(&ctx.rudof, format.into(), self.args.profiling.profile_output.as_deref())?;
+        }
 
-        Ok(())
+        // ShEx results have no constraint components, so `--fail-on-component` is not used
+        let policy = &self.args.policy;
+        let fail_on = policy.fail_on.map(Into::into);
+        let mut check = ctx.rudof.check_shex_validation_policy();
+        if let Some(fail_on) = fail_on.as_ref() {
+            check = check.with_fail_on(fail_on);
+        }
+        if let Some(max_violations) = policy.max_violations {
+            check = check.with_max_violations(max_violations);
+        }
+        if !policy.fail_on_shapes.is_empty() {
+            check = check.with_shapes(&policy.fail_on_shapes);
+        }
+        CommandExit::check_verdict(check.execute()?)
     }
 }
 
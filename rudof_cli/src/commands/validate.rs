@@ -48,12 +48,27 @@ impl ValidateCommand {
             strict_iris: false,
             external_resolvers: Vec::new(),
             list_external_resolvers: false,
+            justify: false,
+            timeout: self.args.timeout,
+            node_timeout: self.args.node_timeout,
+            progress: self.args.progress,
+            policy: self.args.policy.clone(),
+            profiling: self.args.profiling.clone(),
             common: self.args.common.clone(),
         })
     }
 
     /// Convert ValidateArgs to ShaclValidateArgs  
     fn to_shacl_args(&self) -> Result<ShaclValidateArgs> {
+        // As in ShEx, a node and a shape label validate the node against the shape
+        let (focus_nodes, focus_shapes, focus) = match (&self.args.node, &self.args.shape) {
+            (Some(node), Some(shape)) => (Vec::new(), Vec::new(), vec![format!("{node}@{shape}")]),
+            (node, shape) => (
+                node.iter().cloned().collect(),
+                shape.iter().cloned().collect(),
+                Vec::new(),
+            ),
+        };
         Ok(ShaclValidateArgs {
             data: self.args.data.clone(),
             data_format: self.args.data_format,
@@ -65,6 +80,16 @@ impl ValidateCommand {
             mode: self.args.shacl_validation_mode,
             result_format: self.args.result_format.into(),
             sort_by: self.args.sort_by.into(),
+            timeout: self.args.timeout,
+            node_timeout: self.args.node_timeout,
+            message_langs: self.args.message_langs.clone(),
+            focus_nodes,
+            focus_shapes,
+            focus,
+            progress: self.args.progress,
+            limits: self.args.limits.clone(),
+            policy: self.args.policy.clone(),
+            profiling: self.args.profiling.clone(),
             common: self.args.common.clone(),
         })
     }
@@ -0,0 +1,80 @@
+use crate::cli::parser::{FailOnCli, ValidationDiffArgs};
+use crate::commands::base::{Command, CommandContext, CommandExit};
+use anyhow::Result;
+use rudof_lib::types::{ValidationChangeKind, ValidationDiff};
+
+/// Exit code when some result fails now that did not fail before
+pub const EXIT_REGRESSIONS: u8 = 2;
+
+/// Exit code when there are changes, but none of them newly failing, and
+/// `--fail-on changes` was given
+pub const EXIT_CHANGES: u8 = 3;
+
+/// Implementation of the `validation-diff` command.
+///
+/// This struct holds the specific arguments parsed by `clap` and
+/// implements the [Command] trait to execute ValidationDiff command logic.
+pub struct ValidationDiffCommand {
+    /// Arguments specific to ValidationDiff command.
+    args: ValidationDiffArgs,
+}
+
+impl ValidationDiffCommand {
+    pub fn new(args: ValidationDiffArgs) -> Self {
+        Self { args }
+    }
+}
+
+impl Command for ValidationDiffCommand {
+    /// Returns the unique identifier for this command.
+    fn name(&self) -> &'static str {
+        "validation-diff"
+    }
+
+    /// Executes the ValidationDiff command logic.
+    fn execute(&self, ctx: &mut CommandContext) -> Result<()> {
+        let mode = self.args.mode.into();
+        let old_format = self.args.old_format.map(Into::into);
+        let new_format = self.args.new_format.map(Into::into);
+        let result_format = self.args.result_format.into();
+
+        let mut comparison = ctx
+            .rudof
+            .compare_validation_results(&self.args.old, &self.args.new, &mode);
+        if let Some(old_format) = old_format.as_ref() {
+            comparison = comparison.with_old_format(old_format);
+        }
+        if let Some(new_format) = new_format.as_ref() {
+            comparison = comparison.with_new_format(new_format);
+        }
+        comparison.execute()?;
+
+        ctx.rudof
+            .serialize_validation_diff(&mut ctx.writer)
+            .with_result_format(&result_format)
+            .execute()?;
+        ctx.writer.flush()?;
+
+        match ctx.rudof.validation_diff() {
+            Some(diff) => gate(diff, self.args.fail_on),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Fails with the exit code that corresponds to the changes that fail the gate
+fn gate(diff: &ValidationDiff, fail_on: FailOnCli) -> Result<()> {
+    let newly_failing = diff.count_of(ValidationChangeKind::NewlyFailing);
+    match fail_on {
+        FailOnCli::Never => Ok(()),
+        _ if newly_failing > 0 => Err(CommandExit::new(
+            EXIT_REGRESSIONS,
+            format!("{newly_failing} result(s) failing that did not fail before"),
+        )
+        .into()),
+        FailOnCli::Changes if !diff.is_empty() => {
+            Err(CommandExit::new(EXIT_CHANGES, format!("{} result(s) changed", diff.changes().len())).into())
+        },
+        _ => Ok(()),
+    }
+}
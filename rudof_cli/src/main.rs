@@ -1,7 +1,7 @@
 #[cfg(not(target_family = "wasm"))]
 use rudof_cli::{
     cli::parser::{Cli, Command},
-    commands::{CommandContext, CommandFactory},
+    commands::{CommandContext, CommandExit, CommandFactory},
 };
 #[cfg(not(target_family = "wasm"))]
 use std::{env, io};
@@ -24,7 +24,16 @@ fn main() -> anyhow::Result<()> {
 
     // Dispatch the command if present, otherwise exit with a clean error message
     match &cli.command {
-        Some(cmd) => execute(cmd, cli.debug)?,
+        Some(cmd) => {
+            if let Err(error) = execute(cmd, cli.debug) {
+                // Commands that end with a specific exit code report why on stderr
+                if let Some(exit) = error.downcast_ref::<CommandExit>() {
+                    eprintln!("{exit}");
+                    std::process::exit(exit.code().into());
+                }
+                return Err(error);
+            }
+        },
         None => anyhow::bail!("Command not specified. Use --help for available commands."),
     }
 
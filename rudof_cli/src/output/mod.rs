@@ -1,5 +1,9 @@
 mod color;
+mod profile;
+mod progress;
 mod writer;
 
 pub use color::{ColorSupport, detect_color_support_cached};
+pub use profile::write_validation_profile;
+pub use progress::{NdJsonStream, ProgressBar};
 pub use writer::get_writer;
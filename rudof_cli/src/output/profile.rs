@@ -0,0 +1,27 @@
+//! Profile of a validation, written to stderr or to a file so that it doesn't
+//! mix with the results.
+
+use anyhow::{Context, Result};
+use rudof_lib::Rudof;
+use rudof_lib::formats::ValidationProfileFormat;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// Writes the profile of the last validation of `rudof` in `format` to `output`, or to stderr
+pub fn write_validation_profile(rudof: &Rudof, format: ValidationProfileFormat, output: Option<&Path>) -> Result<()> {
+    let mut writer: Box<dyn Write> = match output {
+        Some(path) => {
+            Box::new(BufWriter::new(File::create(path).with_context(|| {
+                format!("Failed to create profile file '{}'", path.display())
+            })?))
+        },
+        None => Box::new(io::stderr()),
+    };
+    rudof
+        .serialize_validation_profile(&mut writer)
+        .with_result_format(&format)
+        .execute()?;
+    writer.flush()?;
+    Ok(())
+}
@@ -0,0 +1,102 @@
+//! Live feedback while validating: a progress bar drawn on stderr and the
+//! NDJSON stream of validation events written to the output.
+
+use anyhow::Result;
+use serde::Serialize;
+use std::io::{self, IsTerminal, Write};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Width of the bar, in characters
+const BAR_WIDTH: usize = 30;
+
+/// Minimum time between two redraws of the bar
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+/// A progress bar drawn on stderr, so that it doesn't mix with the results.
+///
+/// Nothing is drawn when stderr is not a terminal.
+pub struct ProgressBar {
+    label: &'static str,
+    enabled: bool,
+    last_draw: Mutex<Option<Instant>>,
+}
+
+impl ProgressBar {
+    pub fn new(label: &'static str) -> Self {
+        Self {
+            label,
+            enabled: io::stderr().is_terminal(),
+            last_draw: Mutex::new(None),
+        }
+    }
+
+    /// Redraws the bar with `done` of `total` items, at most every [`REDRAW_INTERVAL`]
+    /// except for the last item, which is always drawn.
+    pub fn update(&self, done: usize, total: usize) {
+        if !self.enabled {
+            return;
+        }
+        let mut last_draw = self.last_draw.lock().unwrap();
+        let now = Instant::now();
+        let due = last_draw.is_none_or(|last| now.duration_since(last) >= REDRAW_INTERVAL);
+        if !due && done < total {
+            return;
+        }
+        *last_draw = Some(now);
+        let filled = (done * BAR_WIDTH)
+            .checked_div(total)
+            .unwrap_or(BAR_WIDTH)
+            .min(BAR_WIDTH);
+        eprint!(
+            "\r{} [{}{}] {done}/{total}",
+            self.label,
+            "#".repeat(filled),
+            "-".repeat(BAR_WIDTH - filled)
+        );
+    }
+
+    /// Ends the line of the bar so that later output starts on a new one
+    pub fn finish(&self) {
+        if self.enabled && self.last_draw.lock().unwrap().is_some() {
+            eprintln!();
+        }
+    }
+}
+
+/// Writes each event as a JSON line as soon as it is received.
+///
+/// Events may come from several threads; the first error is kept and returned by [`NdJsonStream::finish`].
+pub struct NdJsonStream<'a> {
+    writer: Mutex<(&'a mut (dyn Write + Send), Option<io::Error>)>,
+}
+
+impl<'a> NdJsonStream<'a> {
+    pub fn new(writer: &'a mut (dyn Write + Send)) -> Self {
+        Self {
+            writer: Mutex::new((writer, None)),
+        }
+    }
+
+    pub fn write<E: Serialize>(&self, event: &E) {
+        let mut guard = self.writer.lock().unwrap();
+        let (writer, error) = &mut *guard;
+        if error.is_some() {
+            return;
+        }
+        let written = serde_json::to_writer(&mut *writer, event)
+            .map_err(io::Error::from)
+            .and_then(|()| writeln!(writer))
+            .and_then(|()| writer.flush());
+        if let Err(e) = written {
+            *error = Some(e);
+        }
+    }
+
+    pub fn finish(self) -> Result<()> {
+        match self.writer.into_inner().unwrap().1 {
+            Some(error) => Err(error.into()),
+            None => Ok(()),
+        }
+    }
+}
@@ -13,7 +13,7 @@ use crate::output::color::{ColorSupport, detect_color_support_cached};
 /// 2. Checking for file existence and preventing accidental overwrites.
 /// 3. Detecting color support (enabled for TTYs, disabled for files).
 /// 4. Wrapping the output in a [BufWriter] for performance.
-pub fn get_writer(output: &Option<PathBuf>, force_overwrite: bool) -> Result<(Box<dyn Write>, ColorSupport)> {
+pub fn get_writer(output: &Option<PathBuf>, force_overwrite: bool) -> Result<(Box<dyn Write + Send>, ColorSupport)> {
     match output {
         // Handle stdout case (default)
         None => {
@@ -0,0 +1,67 @@
+use crate::{
+    Result, Rudof,
+    api::comparison::ComparisonOperations,
+    formats::{InputSpec, ValidationMode, ValidationReportFormat},
+};
+
+/// Builder for `compare_validation_results` operation.
+///
+/// Provides a fluent interface for configuring and executing the comparison of
+/// the results of two validation runs with optional parameters.
+pub struct CompareValidationResultsBuilder<'a> {
+    rudof: &'a mut Rudof,
+    old: &'a InputSpec,
+    new: &'a InputSpec,
+    mode: &'a ValidationMode,
+    old_format: Option<&'a ValidationReportFormat>,
+    new_format: Option<&'a ValidationReportFormat>,
+}
+
+impl<'a> CompareValidationResultsBuilder<'a> {
+    /// Creates a new builder instance.
+    ///
+    /// This is called internally by `Rudof::compare_validation_results()` and should not
+    /// be constructed directly.
+    pub(crate) fn new(rudof: &'a mut Rudof, old: &'a InputSpec, new: &'a InputSpec, mode: &'a ValidationMode) -> Self {
+        Self {
+            rudof,
+            old,
+            new,
+            mode,
+            old_format: None,
+            new_format: None,
+        }
+    }
+
+    /// Sets the format of the old validation results.
+    ///
+    /// # Arguments
+    ///
+    /// * `old_format` - The format the old results were written in
+    pub fn with_old_format(mut self, old_format: &'a ValidationReportFormat) -> Self {
+        self.old_format = Some(old_format);
+        self
+    }
+
+    /// Sets the format of the new validation results.
+    ///
+    /// # Arguments
+    ///
+    /// * `new_format` - The format the new results were written in
+    pub fn with_new_format(mut self, new_format: &'a ValidationReportFormat) -> Self {
+        self.new_format = Some(new_format);
+        self
+    }
+
+    /// Executes the comparison with the configured parameters.
+    pub fn execute(self) -> Result<()> {
+        <Rudof as ComparisonOperations>::compare_validation_results(
+            self.rudof,
+            self.old,
+            self.new,
+            self.mode,
+            self.old_format,
+            self.new_format,
+        )
+    }
+}
@@ -1,3 +1,7 @@
+mod compare_validation_results;
+mod serialize_validation_diff;
 mod show_schema_comparison;
 
+pub use compare_validation_results::CompareValidationResultsBuilder;
+pub use serialize_validation_diff::SerializeValidationDiffBuilder;
 pub use show_schema_comparison::ShowSchemaComparisonBuilder;
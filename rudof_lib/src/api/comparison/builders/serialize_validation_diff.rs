@@ -0,0 +1,41 @@
+use crate::{Result, Rudof, api::comparison::ComparisonOperations, formats::ResultComparisonFormat};
+use std::io;
+
+/// Builder for `serialize_validation_diff` operation.
+///
+/// Provides a fluent interface for configuring and executing the serialization
+/// of the last validation diff with optional parameters.
+pub struct SerializeValidationDiffBuilder<'a, W: io::Write> {
+    rudof: &'a Rudof,
+    writer: &'a mut W,
+    result_format: Option<&'a ResultComparisonFormat>,
+}
+
+impl<'a, W: io::Write> SerializeValidationDiffBuilder<'a, W> {
+    /// Creates a new builder instance.
+    ///
+    /// This is called internally by `Rudof::serialize_validation_diff()` and should not
+    /// be constructed directly.
+    pub(crate) fn new(rudof: &'a Rudof, writer: &'a mut W) -> Self {
+        Self {
+            rudof,
+            writer,
+            result_format: None,
+        }
+    }
+
+    /// Sets the output format for the validation diff.
+    ///
+    /// # Arguments
+    ///
+    /// * `result_format` - The format to use when serializing the validation diff
+    pub fn with_result_format(mut self, result_format: &'a ResultComparisonFormat) -> Self {
+        self.result_format = Some(result_format);
+        self
+    }
+
+    /// Executes the serialization operation with the configured parameters.
+    pub fn execute(self) -> Result<()> {
+        <Rudof as ComparisonOperations>::serialize_validation_diff(self.rudof, self.result_format, self.writer)
+    }
+}
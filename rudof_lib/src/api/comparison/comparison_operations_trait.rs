@@ -1,7 +1,10 @@
 use crate::{
     Result,
-    api::comparison::implementations::show_schema_comparison,
-    formats::{ComparisonFormat, ComparisonMode, DataReaderMode, InputSpec, ResultComparisonFormat},
+    api::comparison::implementations::{compare_validation_results, serialize_validation_diff, show_schema_comparison},
+    formats::{
+        ComparisonFormat, ComparisonMode, DataReaderMode, InputSpec, ResultComparisonFormat, ValidationMode,
+        ValidationReportFormat,
+    },
 };
 use std::io;
 
@@ -47,6 +50,45 @@ pub trait ComparisonOperations {
         result_format: Option<&ResultComparisonFormat>,
         writer: &mut W,
     ) -> Result<()>;
+
+    /// Compares the results of two validation runs, keeping the differences as
+    /// the current validation diff.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - Input specification of the results of the previous run
+    /// * `new` - Input specification of the results of the current run
+    /// * `mode` - Validation language of both results, ShEx or SHACL
+    /// * `old_format` - Optional format of the old results (JSON for ShEx and Turtle for SHACL if None)
+    /// * `new_format` - Optional format of the new results (JSON for ShEx and Turtle for SHACL if None)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the results cannot be read in the given format.
+    fn compare_validation_results(
+        &mut self,
+        old: &InputSpec,
+        new: &InputSpec,
+        mode: &ValidationMode,
+        old_format: Option<&ValidationReportFormat>,
+        new_format: Option<&ValidationReportFormat>,
+    ) -> Result<()>;
+
+    /// Serializes the current validation diff to the provided writer.
+    ///
+    /// # Arguments
+    ///
+    /// * `result_format` - Optional output format for the diff (uses default if None)
+    /// * `writer` - The destination to write the serialized diff to
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no validation results were compared or serialization fails.
+    fn serialize_validation_diff<W: io::Write>(
+        &self,
+        result_format: Option<&ResultComparisonFormat>,
+        writer: &mut W,
+    ) -> Result<()>;
 }
 
 impl ComparisonOperations for crate::Rudof {
@@ -85,4 +127,23 @@ impl ComparisonOperations for crate::Rudof {
             writer,
         )
     }
+
+    fn compare_validation_results(
+        &mut self,
+        old: &InputSpec,
+        new: &InputSpec,
+        mode: &ValidationMode,
+        old_format: Option<&ValidationReportFormat>,
+        new_format: Option<&ValidationReportFormat>,
+    ) -> Result<()> {
+        compare_validation_results(self, old, new, mode, old_format, new_format)
+    }
+
+    fn serialize_validation_diff<W: io::Write>(
+        &self,
+        result_format: Option<&ResultComparisonFormat>,
+        writer: &mut W,
+    ) -> Result<()> {
+        serialize_validation_diff(self, result_format, writer)
+    }
 }
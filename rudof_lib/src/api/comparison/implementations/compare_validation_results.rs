@@ -0,0 +1,168 @@
+use crate::{
+    Result, Rudof,
+    errors::ComparisonError,
+    formats::{InputSpec, ValidationMode, ValidationReportFormat},
+    types::{OutcomeKey, ValidationDiff, ValidationOutcome, shacl_outcomes},
+};
+use rudof_iri::MimeType;
+use rudof_rdf::rdf_core::vocabs::{RdfVocab, ShaclVocab};
+use rudof_rdf::rdf_core::{NeighsRDF, RDFFormat, Rdf};
+use rudof_rdf::rdf_impl::{OxigraphInMemory, ReaderMode};
+use serde::Deserialize;
+use shacl::validator::report::ValidationReport;
+use std::io;
+
+pub fn compare_validation_results(
+    rudof: &mut Rudof,
+    old: &InputSpec,
+    new: &InputSpec,
+    mode: &ValidationMode,
+    old_format: Option<&ValidationReportFormat>,
+    new_format: Option<&ValidationReportFormat>,
+) -> Result<()> {
+    let old = read_outcomes(old, mode, old_format)?;
+    let new = read_outcomes(new, mode, new_format)?;
+    rudof.validation_diff = Some(ValidationDiff::new(old, new));
+    Ok(())
+}
+
+/// ShEx results are only written as JSON, while SHACL reports are RDF graphs
+fn default_format(mode: &ValidationMode) -> ValidationReportFormat {
+    match mode {
+        ValidationMode::Shacl => ValidationReportFormat::Turtle,
+        _ => ValidationReportFormat::Json,
+    }
+}
+
+fn read_outcomes(
+    input: &InputSpec,
+    mode: &ValidationMode,
+    format: Option<&ValidationReportFormat>,
+) -> Result<Vec<ValidationOutcome>> {
+    let format = format.copied().unwrap_or_else(|| default_format(mode));
+    let source_name = input.source_name();
+    let failed = |error: String| ComparisonError::FailedReadingValidationResults {
+        mode: mode.to_string(),
+        source_name: source_name.clone(),
+        format: format.to_string(),
+        error,
+    };
+
+    let reader = input
+        .open_read(Some(format.mime_type()), "Validation results")
+        .map_err(|error| ComparisonError::DataSourceSpec {
+            message: format!("Failed to open validation results '{source_name}': {error}"),
+        })?;
+
+    let outcomes = match (mode, format) {
+        (ValidationMode::ShEx, ValidationReportFormat::Json) => read_shex_json(reader).map_err(failed)?,
+        (ValidationMode::ShEx, _) => {
+            return Err(ComparisonError::UnsupportedValidationReportFormat {
+                format: format.to_string(),
+            }
+            .into());
+        },
+        (ValidationMode::Shacl, ValidationReportFormat::Json) => read_shacl_json(reader).map_err(failed)?,
+        (ValidationMode::Shacl, _) => read_shacl_rdf(reader, &source_name, format.try_into()?).map_err(failed)?,
+        (ValidationMode::PGSchema, _) => {
+            return Err(ComparisonError::UnsupportedValidationDiffMode { mode: mode.to_string() }.into());
+        },
+    };
+    Ok(outcomes)
+}
+
+/// A result as written by the ShEx `json` result format
+#[derive(Deserialize)]
+struct ShExResultJson {
+    node: String,
+    shape: String,
+    status: String,
+    #[serde(default)]
+    reason: String,
+}
+
+fn read_shex_json<R: io::Read>(reader: R) -> std::result::Result<Vec<ValidationOutcome>, String> {
+    let results: Vec<ShExResultJson> = serde_json::from_reader(reader).map_err(|e| e.to_string())?;
+    Ok(results
+        .into_iter()
+        .map(|result| ValidationOutcome::shex(result.node, result.shape, &result.status, result.reason))
+        .collect())
+}
+
+/// A report as written by the SHACL `json` result format
+#[derive(Deserialize)]
+struct ShaclReportJson {
+    results: Vec<ShaclResultJson>,
+    #[serde(default)]
+    timeouts: Vec<ShaclResultJson>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ShaclResultJson {
+    focus_node: String,
+    result_severity: String,
+    source_constraint_component: String,
+    result_path: Option<String>,
+    value: Option<String>,
+    source_shape: Option<String>,
+    result_message: Option<String>,
+}
+
+impl ShaclResultJson {
+    fn key(&self) -> OutcomeKey {
+        OutcomeKey::shacl(
+            self.focus_node.clone(),
+            self.source_shape.clone(),
+            self.source_constraint_component.clone(),
+            self.result_path.clone(),
+        )
+    }
+}
+
+fn read_shacl_json<R: io::Read>(reader: R) -> std::result::Result<Vec<ValidationOutcome>, String> {
+    let report: ShaclReportJson = serde_json::from_reader(reader).map_err(|e| e.to_string())?;
+    let results = report.results.iter().map(|result| {
+        ValidationOutcome::shacl(
+            result.key(),
+            &result.result_severity,
+            result.result_message.as_deref(),
+            result.value.as_deref(),
+        )
+    });
+    let timeouts = report
+        .timeouts
+        .iter()
+        .map(|timeout| ValidationOutcome::shacl_timeout(timeout.key()));
+    Ok(results.chain(timeouts).collect())
+}
+
+/// Reads every `sh:ValidationReport` in the graph, so reports of several runs
+/// written to the same file are joined
+fn read_shacl_rdf<R: io::Read>(
+    mut reader: R,
+    source_name: &str,
+    format: RDFFormat,
+) -> std::result::Result<Vec<ValidationOutcome>, String> {
+    let mut graph = OxigraphInMemory::new();
+    graph
+        .merge_from_reader(&mut reader, source_name, &format, None, &ReaderMode::Strict)
+        .map_err(|e| e.to_string())?;
+
+    let report_type: <OxigraphInMemory as Rdf>::Term = ShaclVocab::sh_validation_report().into();
+    let report_nodes = graph
+        .subjects_for(&RdfVocab::rdf_type().into(), &report_type)
+        .map_err(|e| e.to_string())?;
+    if report_nodes.is_empty() {
+        return Err("No sh:ValidationReport found".to_string());
+    }
+
+    let (mut results, mut timeouts) = (Vec::new(), Vec::new());
+    for node in report_nodes {
+        let parsed = ValidationReport::parse(&mut graph, node).map_err(|e| e.to_string())?;
+        results.extend(parsed.results().iter().cloned());
+        timeouts.extend(parsed.timeouts().iter().cloned());
+    }
+    let report = ValidationReport::new().with_results(results).with_timeouts(timeouts);
+    Ok(shacl_outcomes(&report))
+}
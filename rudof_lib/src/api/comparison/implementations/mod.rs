@@ -1,8 +1,13 @@
+mod compare_validation_results;
+mod serialize_validation_diff;
 mod show_schema_comparison;
 
+pub use compare_validation_results::compare_validation_results;
+pub use serialize_validation_diff::serialize_validation_diff;
 pub use show_schema_comparison::show_schema_comparison;
 
 #[cfg(test)]
 mod tests {
+    mod compare_validation_results_tests;
     mod show_schema_comparison_tests;
 }
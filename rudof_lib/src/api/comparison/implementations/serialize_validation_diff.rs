@@ -0,0 +1,101 @@
+use crate::{
+    Result, Rudof,
+    errors::ComparisonError,
+    formats::ResultComparisonFormat,
+    types::{ValidationChangeKind, ValidationDiff},
+    utils::terminal_width,
+};
+use std::io;
+use tabled::builder::Builder;
+use tabled::settings::object::Segment;
+use tabled::settings::{Modify, Style, Width};
+
+pub fn serialize_validation_diff<W: io::Write>(
+    rudof: &Rudof,
+    result_format: Option<&ResultComparisonFormat>,
+    writer: &mut W,
+) -> Result<()> {
+    let validation_diff = rudof
+        .validation_diff
+        .as_ref()
+        .ok_or(ComparisonError::NoValidationDiffAvailable)?;
+    let failed = |e: io::Error| ComparisonError::FailedSerializingValidationDiff { error: e.to_string() };
+
+    match result_format.copied().unwrap_or_default() {
+        ResultComparisonFormat::Internal => {
+            serialize_validation_diff_internal(validation_diff, writer).map_err(failed)?
+        },
+        ResultComparisonFormat::Json => {
+            let json = serde_json::to_string_pretty(validation_diff)
+                .map_err(|e| ComparisonError::FailedSerializingValidationDiff { error: e.to_string() })?;
+            writeln!(writer, "{json}").map_err(failed)?;
+        },
+    }
+
+    Ok(())
+}
+
+/// Writes a section per kind of change, each change with its old and new
+/// reasons, followed by a table with the counts per shape and constraint
+fn serialize_validation_diff_internal<W: io::Write>(
+    validation_diff: &ValidationDiff,
+    writer: &mut W,
+) -> io::Result<()> {
+    if validation_diff.is_empty() {
+        return writeln!(writer, "No changes between the validation results");
+    }
+
+    let sections = [
+        (ValidationChangeKind::NewlyFailing, "Newly failing"),
+        (ValidationChangeKind::NewlyPassing, "Newly passing"),
+        (ValidationChangeKind::ChangedReason, "Changed reasons"),
+    ];
+    for (kind, title) in sections {
+        let count = validation_diff.count_of(kind);
+        if count == 0 {
+            continue;
+        }
+        writeln!(writer, "{title} ({count}):")?;
+        for change in validation_diff.changes_of(kind) {
+            let mut line = format!("  {}@{}", change.node, change.shape);
+            if let Some(constraint) = &change.constraint {
+                line.push_str(&format!(" {constraint}"));
+            }
+            if let Some(path) = &change.path {
+                line.push_str(&format!(" path {path}"));
+            }
+            writeln!(writer, "{line}")?;
+            if let Some(old_reason) = &change.old_reason {
+                writeln!(writer, "    was: {old_reason}")?;
+            }
+            if let Some(new_reason) = &change.new_reason {
+                writeln!(writer, "    now: {new_reason}")?;
+            }
+        }
+        writeln!(writer)?;
+    }
+
+    let mut builder = Builder::default();
+    builder.push_record([
+        "Shape",
+        "Constraint",
+        "Newly failing",
+        "Newly passing",
+        "Changed reasons",
+    ]);
+    for counts in validation_diff.summary() {
+        builder.push_record([
+            counts.shape.clone(),
+            counts.constraint.clone().unwrap_or_default(),
+            counts.newly_failing.to_string(),
+            counts.newly_passing.to_string(),
+            counts.changed_reasons.to_string(),
+        ]);
+    }
+    let table = builder
+        .build()
+        .with(Style::modern_rounded())
+        .with(Modify::new(Segment::all()).with(Width::wrap(terminal_width())))
+        .to_string();
+    writeln!(writer, "{table}")
+}
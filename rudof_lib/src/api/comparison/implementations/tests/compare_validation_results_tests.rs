@@ -0,0 +1,203 @@
+use crate::{
+    Rudof, RudofConfig,
+    api::comparison::implementations::{compare_validation_results, serialize_validation_diff},
+    api::data::implementations::load_data,
+    api::shacl::implementations::{load_shacl_schema, serialize_shacl_validation_results, validate_shacl},
+    formats::{
+        DataFormat, InputSpec, ResultComparisonFormat, ResultShaclValidationFormat, ShaclFormat, ValidationMode,
+        ValidationReportFormat,
+    },
+    types::{ValidationChangeKind, ValidationDiff},
+};
+
+const SHAPES: &str = r#"
+    @prefix ex: <http://example.org/> .
+    @prefix sh: <http://www.w3.org/ns/shacl#> .
+    @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+    ex:PersonShape
+        a sh:NodeShape ;
+        sh:targetClass ex:Person ;
+        sh:property [
+            sh:path ex:name ;
+            sh:datatype xsd:string ;
+            sh:minCount 1 ;
+        ] .
+"#;
+
+/// Helper: validates the data against the shapes and returns the report written in the format
+fn shacl_report(data: &str, format: ResultShaclValidationFormat) -> (String, Rudof) {
+    let mut rudof = Rudof::new(RudofConfig::default());
+    let shapes = InputSpec::str(SHAPES);
+    load_shacl_schema(&mut rudof, Some(&shapes), Some(&ShaclFormat::Turtle), None, None).unwrap();
+    let data = InputSpec::str(data);
+    load_data(
+        &mut rudof,
+        Some(&[data]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
+
+    let mut buffer = Vec::new();
+    serialize_shacl_validation_results(&rudof, None, Some(&format), &mut buffer).unwrap();
+    (String::from_utf8(buffer).unwrap(), rudof)
+}
+
+/// Helper: compares two results and returns the diff
+fn diff(old: &str, new: &str, mode: ValidationMode, format: ValidationReportFormat) -> (ValidationDiff, Rudof) {
+    let mut rudof = Rudof::new(RudofConfig::default());
+    compare_validation_results(
+        &mut rudof,
+        &InputSpec::str(old),
+        &InputSpec::str(new),
+        &mode,
+        Some(&format),
+        Some(&format),
+    )
+    .unwrap();
+    (rudof.validation_diff().unwrap().clone(), rudof)
+}
+
+const OLD_DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+    ex:alice a ex:Person .
+    ex:bob a ex:Person ; ex:name "Bob" .
+    ex:carol a ex:Person ; ex:name 42 .
+"#;
+
+const NEW_DATA: &str = r#"
+    @prefix ex: <http://example.org/> .
+    ex:alice a ex:Person ; ex:name "Alice" .
+    ex:bob a ex:Person .
+    ex:carol a ex:Person ; ex:name 43 .
+"#;
+
+#[test]
+fn test_compare_shex_json_results() {
+    let old = r#"[
+        {"node": "<http://example.org/alice>", "shape": "<http://example.org/Person>", "status": "conformant", "reason": "ok"},
+        {"node": "<http://example.org/bob>", "shape": "<http://example.org/Person>", "status": "nonconformant", "reason": "no name"},
+        {"node": "<http://example.org/carol>", "shape": "<http://example.org/Person>", "status": "nonconformant", "reason": "no name"},
+        {"node": "<http://example.org/dave>", "shape": "<http://example.org/Person>", "status": "nonconformant", "reason": "no name"}
+    ]"#;
+    let new = r#"[
+        {"node": "<http://example.org/alice>", "shape": "<http://example.org/Person>", "status": "nonconformant", "reason": "no name"},
+        {"node": "<http://example.org/bob>", "shape": "<http://example.org/Person>", "status": "conformant", "reason": "ok"},
+        {"node": "<http://example.org/carol>", "shape": "<http://example.org/Person>", "status": "nonconformant", "reason": "bad age"},
+        {"node": "<http://example.org/dave>", "shape": "<http://example.org/Person>", "status": "timeout", "reason": "timed out"}
+    ]"#;
+
+    let (diff, _) = diff(old, new, ValidationMode::ShEx, ValidationReportFormat::Json);
+
+    let newly_failing: Vec<_> = diff.changes_of(ValidationChangeKind::NewlyFailing).collect();
+    assert_eq!(newly_failing.len(), 1);
+    assert_eq!(newly_failing[0].node, "<http://example.org/alice>");
+    assert_eq!(diff.count_of(ValidationChangeKind::NewlyPassing), 1);
+    let changed: Vec<_> = diff.changes_of(ValidationChangeKind::ChangedReason).collect();
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].old_reason.as_deref(), Some("no name"));
+    assert_eq!(changed[0].new_reason.as_deref(), Some("bad age"));
+    // dave timed out, so it is not known to pass
+    assert!(diff.changes().iter().all(|change| !change.node.contains("dave")));
+    assert!(diff.has_regressions());
+
+    assert_eq!(diff.summary().len(), 1);
+    assert_eq!(diff.summary()[0].newly_failing, 1);
+    assert_eq!(diff.summary()[0].newly_passing, 1);
+    assert_eq!(diff.summary()[0].changed_reasons, 1);
+}
+
+#[test]
+fn test_compare_identical_results_has_no_changes() {
+    let results = r#"[
+        {"node": "<http://example.org/alice>", "shape": "<http://example.org/Person>", "status": "nonconformant", "reason": "no name"}
+    ]"#;
+
+    let (diff, rudof) = diff(results, results, ValidationMode::ShEx, ValidationReportFormat::Json);
+
+    assert!(diff.is_empty());
+    assert!(!diff.has_regressions());
+    let mut buffer = Vec::new();
+    serialize_validation_diff(&rudof, None, &mut buffer).unwrap();
+    assert!(String::from_utf8(buffer).unwrap().contains("No changes"));
+}
+
+#[test]
+fn test_compare_shacl_turtle_reports() {
+    let (old, _) = shacl_report(OLD_DATA, ResultShaclValidationFormat::Turtle);
+    let (new, _) = shacl_report(NEW_DATA, ResultShaclValidationFormat::Turtle);
+
+    let (diff, _) = diff(&old, &new, ValidationMode::Shacl, ValidationReportFormat::Turtle);
+
+    let newly_failing: Vec<_> = diff.changes_of(ValidationChangeKind::NewlyFailing).collect();
+    assert_eq!(newly_failing.len(), 1);
+    assert_eq!(newly_failing[0].node, "http://example.org/bob");
+    assert!(newly_failing[0].constraint.as_deref().unwrap().contains("MinCount"));
+    let newly_passing: Vec<_> = diff.changes_of(ValidationChangeKind::NewlyPassing).collect();
+    assert_eq!(newly_passing.len(), 1);
+    assert_eq!(newly_passing[0].node, "http://example.org/alice");
+    // carol still fails the datatype, but for another value
+    let changed: Vec<_> = diff.changes_of(ValidationChangeKind::ChangedReason).collect();
+    assert_eq!(changed.len(), 1);
+    assert_eq!(changed[0].node, "http://example.org/carol");
+    assert!(changed[0].constraint.as_deref().unwrap().contains("Datatype"));
+
+    // One row per constraint component of the shape
+    assert_eq!(diff.summary().len(), 2);
+}
+
+#[test]
+fn test_compare_shacl_json_reports_matches_in_memory_diff() {
+    let (old, old_rudof) = shacl_report(OLD_DATA, ResultShaclValidationFormat::Json);
+    let (new, new_rudof) = shacl_report(NEW_DATA, ResultShaclValidationFormat::Json);
+
+    let (diff, _) = diff(&old, &new, ValidationMode::Shacl, ValidationReportFormat::Json);
+
+    let in_memory = ValidationDiff::from_shacl(
+        old_rudof.shacl_validation_results.as_ref().unwrap(),
+        new_rudof.shacl_validation_results.as_ref().unwrap(),
+    );
+    assert_eq!(diff, in_memory);
+    assert_eq!(diff.changes().len(), 3);
+}
+
+#[test]
+fn test_serialize_validation_diff_json() {
+    let (old, _) = shacl_report(OLD_DATA, ResultShaclValidationFormat::Turtle);
+    let (new, _) = shacl_report(NEW_DATA, ResultShaclValidationFormat::Turtle);
+    let (_, rudof) = diff(&old, &new, ValidationMode::Shacl, ValidationReportFormat::Turtle);
+
+    let mut buffer = Vec::new();
+    serialize_validation_diff(&rudof, Some(&ResultComparisonFormat::Json), &mut buffer).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&buffer).unwrap();
+
+    let kinds: Vec<_> = json["changes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|change| change["kind"].as_str().unwrap())
+        .collect();
+    assert_eq!(kinds, vec!["newlyFailing", "newlyPassing", "changedReason"]);
+    assert!(json["summary"][0]["shape"].is_string());
+}
+
+#[test]
+fn test_compare_shex_results_as_rdf_fails() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+    let result = compare_validation_results(
+        &mut rudof,
+        &InputSpec::str("[]"),
+        &InputSpec::str("[]"),
+        &ValidationMode::ShEx,
+        Some(&ValidationReportFormat::Turtle),
+        None,
+    );
+    assert!(result.is_err());
+}
@@ -1,9 +1,11 @@
 mod config;
 mod reset_all;
+mod serialize_validation_profile;
 mod update_config;
 mod version;
 
 pub use config::ConfigBuilder;
 pub use reset_all::ResetAllBuilder;
+pub use serialize_validation_profile::SerializeValidationProfileBuilder;
 pub use update_config::UpdateConfigBuilder;
 pub use version::VersionBuilder;
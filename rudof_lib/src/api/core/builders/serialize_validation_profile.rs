@@ -0,0 +1,41 @@
+use crate::{Result, Rudof, api::core::CoreOperations, formats::ValidationProfileFormat};
+use std::io;
+
+/// Builder for `serialize_validation_profile` operation.
+///
+/// Provides a fluent interface for configuring and executing the serialization
+/// of the profile of the last validation with optional parameters.
+pub struct SerializeValidationProfileBuilder<'a, W: io::Write> {
+    rudof: &'a Rudof,
+    writer: &'a mut W,
+    result_format: Option<&'a ValidationProfileFormat>,
+}
+
+impl<'a, W: io::Write> SerializeValidationProfileBuilder<'a, W> {
+    /// Creates a new builder instance.
+    ///
+    /// This is called internally by `Rudof::serialize_validation_profile()` and should not
+    /// be constructed directly.
+    pub(crate) fn new(rudof: &'a Rudof, writer: &'a mut W) -> Self {
+        Self {
+            rudof,
+            writer,
+            result_format: None,
+        }
+    }
+
+    /// Sets the output format for the validation profile.
+    ///
+    /// # Arguments
+    ///
+    /// * `result_format` - The format to use when serializing the validation profile
+    pub fn with_result_format(mut self, result_format: &'a ValidationProfileFormat) -> Self {
+        self.result_format = Some(result_format);
+        self
+    }
+
+    /// Executes the serialization operation with the configured parameters.
+    pub fn execute(self) -> Result<()> {
+        <Rudof as CoreOperations>::serialize_validation_profile(self.rudof, self.result_format, self.writer)
+    }
+}
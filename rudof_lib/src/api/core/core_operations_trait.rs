@@ -1,7 +1,9 @@
 use crate::{
-    Rudof, RudofConfig,
-    api::core::implementations::{config, new, reset_all, update_config, version},
+    Result, Rudof, RudofConfig,
+    api::core::implementations::{config, new, reset_all, serialize_validation_profile, update_config, version},
+    formats::ValidationProfileFormat,
 };
+use std::io;
 
 /// Core operations for Rudof initialization and configuration.
 pub trait CoreOperations: Sized {
@@ -42,6 +44,22 @@ pub trait CoreOperations: Sized {
     /// This clears all loaded data, schemas, queries, and validation results,
     /// returning the instance to a clean state.
     fn reset_all(&mut self);
+
+    /// Serializes the profile of the last validation to the provided writer.
+    ///
+    /// # Arguments
+    ///
+    /// * `result_format` - Optional output format for the profile (table if None)
+    /// * `writer` - The destination to write the serialized profile to
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the last validation was not profiled or serialization fails.
+    fn serialize_validation_profile<W: io::Write>(
+        &self,
+        result_format: Option<&ValidationProfileFormat>,
+        writer: &mut W,
+    ) -> Result<()>;
 }
 
 impl CoreOperations for Rudof {
@@ -64,4 +82,12 @@ impl CoreOperations for Rudof {
     fn reset_all(&mut self) {
         reset_all(self)
     }
+
+    fn serialize_validation_profile<W: io::Write>(
+        &self,
+        result_format: Option<&ValidationProfileFormat>,
+        writer: &mut W,
+    ) -> Result<()> {
+        serialize_validation_profile(self, result_format, writer)
+    }
 }
@@ -1,11 +1,13 @@
 mod config;
 mod new;
 mod reset_all;
+mod serialize_validation_profile;
 mod update_config;
 mod version;
 
 pub use config::config;
 pub use new::new;
 pub use reset_all::reset_all;
+pub use serialize_validation_profile::serialize_validation_profile;
 pub use update_config::update_config;
 pub use version::version;
@@ -5,11 +5,16 @@ pub fn new(config: RudofConfig) -> Rudof {
         version: env!("CARGO_PKG_VERSION").to_string(),
         config,
         data: None,
+        data_warnings: Vec::new(),
         shacl_shapes: None,
+        shacl_shapes_source: None,
         shacl_validation_results: None,
         shex_schema: None,
         shex_schema_ir: None,
         shex_validation_results: None,
+        validation_diff: None,
+        profile_validation: false,
+        validation_profile: None,
         pg_schema: None,
         pg_schema_validation_results: None,
         shapemap: None,
@@ -17,6 +22,7 @@ pub fn new(config: RudofConfig) -> Rudof {
         query_results: None,
         dctap: None,
         service_description: None,
+        mie: None,
         rdf_config: None,
         shex_validator: None,
         typemap: None,
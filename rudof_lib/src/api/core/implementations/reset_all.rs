@@ -2,12 +2,16 @@ use crate::Rudof;
 
 pub fn reset_all(rudof: &mut Rudof) {
     rudof.data = None;
+    rudof.data_warnings.clear();
     rudof.shacl_shapes = None;
+    rudof.shacl_shapes_source = None;
     rudof.shacl_validation_results = None;
     rudof.shex_schema = None;
     rudof.shex_schema_ir = None;
     rudof.shex_validator = None;
     rudof.shex_validation_results = None;
+    rudof.validation_diff = None;
+    rudof.validation_profile = None;
     rudof.pg_schema = None;
     rudof.pg_schema_validation_results = None;
     rudof.typemap = None;
@@ -16,6 +20,7 @@ pub fn reset_all(rudof: &mut Rudof) {
     rudof.query_results = None;
     rudof.dctap = None;
     rudof.service_description = None;
+    rudof.mie = None;
     rudof.rdf_config = None;
     rudof.map_state = None;
 }
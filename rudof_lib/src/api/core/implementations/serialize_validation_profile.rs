@@ -0,0 +1,70 @@
+use crate::{Result, Rudof, errors::ValidationError, formats::ValidationProfileFormat, utils::terminal_width};
+use rudof_rdf::rdf_core::utils::Profiler;
+use std::io;
+use tabled::builder::Builder;
+use tabled::settings::object::{Columns, Segment};
+use tabled::settings::{Alignment, Modify, Style, Width};
+
+pub fn serialize_validation_profile<W: io::Write>(
+    rudof: &Rudof,
+    result_format: Option<&ValidationProfileFormat>,
+    writer: &mut W,
+) -> Result<()> {
+    let profiler = rudof
+        .validation_profile
+        .as_ref()
+        .ok_or(ValidationError::NoValidationProfileAvailable)?;
+    let failed = |e: io::Error| ValidationError::FailedSerializingValidationProfile { error: e.to_string() };
+
+    match result_format.copied().unwrap_or_default() {
+        ValidationProfileFormat::Table => serialize_validation_profile_table(profiler, writer).map_err(failed)?,
+        ValidationProfileFormat::Json => {
+            let json = serde_json::to_string_pretty(&profiler.entries())
+                .map_err(|e| ValidationError::FailedSerializingValidationProfile { error: e.to_string() })?;
+            writeln!(writer, "{json}").map_err(failed)?;
+        },
+        ValidationProfileFormat::Folded => {
+            // Folded stacks weighted by microseconds, the input of flame graph tools
+            for (stack, time) in profiler.folded() {
+                writeln!(writer, "{stack} {}", time.as_micros()).map_err(failed)?;
+            }
+        },
+    }
+
+    Ok(())
+}
+
+/// Writes a row per shape and constraint component, the slowest first
+fn serialize_validation_profile_table<W: io::Write>(profiler: &Profiler, writer: &mut W) -> io::Result<()> {
+    let entries = profiler.entries();
+    if entries.is_empty() {
+        return writeln!(writer, "No shapes were evaluated");
+    }
+
+    let mut builder = Builder::default();
+    builder.push_record([
+        "Shape",
+        "Component",
+        "Time (ms)",
+        "Evaluations",
+        "Cache hits",
+        "Queries",
+    ]);
+    for entry in entries {
+        builder.push_record([
+            entry.shape().to_string(),
+            entry.component().unwrap_or_default().to_string(),
+            format!("{:.3}", entry.time().as_secs_f64() * 1000.0),
+            entry.evaluations().to_string(),
+            entry.cache_hits().to_string(),
+            entry.queries().to_string(),
+        ]);
+    }
+    let table = builder
+        .build()
+        .with(Style::modern_rounded())
+        .with(Modify::new(Columns::new(2..)).with(Alignment::right()))
+        .with(Modify::new(Segment::all()).with(Width::wrap(terminal_width())))
+        .to_string();
+    writeln!(writer, "{table}")
+}
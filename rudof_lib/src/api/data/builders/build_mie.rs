@@ -0,0 +1,50 @@
+use crate::{Result, Rudof, api::data::DataOperations};
+
+/// Builder for `build_mie` operation.
+///
+/// Provides a fluent interface for configuring and executing the introspection
+/// of the current data into a MIE file.
+pub struct BuildMieBuilder<'a> {
+    rudof: &'a mut Rudof,
+    title: Option<&'a str>,
+    endpoint: Option<&'a str>,
+}
+
+impl<'a> BuildMieBuilder<'a> {
+    /// Creates a new builder instance.
+    ///
+    /// This is called internally by `Rudof::build_mie()` and should not
+    /// be constructed directly.
+    pub(crate) fn new(rudof: &'a mut Rudof) -> Self {
+        Self {
+            rudof,
+            title: None,
+            endpoint: None,
+        }
+    }
+
+    /// Sets the title of the MIE.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title added to the schema information of the MIE
+    pub fn with_title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the endpoint that serves the data.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - The IRI of the SPARQL endpoint added to the schema information of the MIE
+    pub fn with_endpoint(mut self, endpoint: &'a str) -> Self {
+        self.endpoint = Some(endpoint);
+        self
+    }
+
+    /// Executes the MIE building operation with the configured parameters.
+    pub fn execute(self) -> Result<()> {
+        <Rudof as DataOperations>::build_mie(self.rudof, self.title, self.endpoint)
+    }
+}
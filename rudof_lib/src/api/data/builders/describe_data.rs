@@ -0,0 +1,50 @@
+use crate::{Result, Rudof, api::data::DataOperations};
+
+/// Builder for `describe_data` operation.
+///
+/// Provides a fluent interface for configuring and executing the computation
+/// of a VoID / SPARQL service description of the current data.
+pub struct DescribeDataBuilder<'a> {
+    rudof: &'a mut Rudof,
+    title: Option<&'a str>,
+    endpoint: Option<&'a str>,
+}
+
+impl<'a> DescribeDataBuilder<'a> {
+    /// Creates a new builder instance.
+    ///
+    /// This is called internally by `Rudof::describe_data()` and should not
+    /// be constructed directly.
+    pub(crate) fn new(rudof: &'a mut Rudof) -> Self {
+        Self {
+            rudof,
+            title: None,
+            endpoint: None,
+        }
+    }
+
+    /// Sets the title of the described service.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - The title added to the service description
+    pub fn with_title(mut self, title: &'a str) -> Self {
+        self.title = Some(title);
+        self
+    }
+
+    /// Sets the endpoint of the described service.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - The IRI of the SPARQL endpoint that serves the data
+    pub fn with_endpoint(mut self, endpoint: &'a str) -> Self {
+        self.endpoint = Some(endpoint);
+        self
+    }
+
+    /// Executes the data description operation with the configured parameters.
+    pub fn execute(self) -> Result<()> {
+        <Rudof as DataOperations>::describe_data(self.rudof, self.title, self.endpoint)
+    }
+}
@@ -15,6 +15,7 @@ pub struct LoadDataBuilder<'a> {
     data_format: Option<&'a DataFormat>,
     base: Option<&'a str>,
     reader_mode: Option<&'a DataReaderMode>,
+    max_errors: Option<usize>,
     merge: Option<bool>,
     prefixes: Option<&'a [InputSpec]>,
     /// Which backend should hold the loaded data. Defaults to in-process memory.
@@ -33,6 +34,7 @@ impl<'a> LoadDataBuilder<'a> {
             data_format: None,
             base: None,
             reader_mode: None,
+            max_errors: None,
             merge: None,
             prefixes: None,
             backend: BackendSpec::default(),
@@ -69,6 +71,13 @@ impl<'a> LoadDataBuilder<'a> {
         self
     }
 
+    /// Sets how many syntax errors can be skipped in each source in lax mode
+    /// before loading fails.
+    pub fn with_max_errors(mut self, max_errors: usize) -> Self {
+        self.max_errors = Some(max_errors);
+        self
+    }
+
     /// Convenience shim — equivalent to `with_backend(BackendSpec::Endpoint(endpoint.into()))`.
     ///
     /// Provided so callers that only need the endpoint case don't have to
@@ -99,6 +108,7 @@ impl<'a> LoadDataBuilder<'a> {
                 self.base,
                 None,
                 self.reader_mode,
+                self.max_errors,
                 self.merge,
                 self.prefixes,
             ),
@@ -109,6 +119,7 @@ impl<'a> LoadDataBuilder<'a> {
                 self.base,
                 Some(url.as_str()),
                 self.reader_mode,
+                self.max_errors,
                 self.merge,
                 self.prefixes,
             ),
@@ -1,19 +1,25 @@
+mod build_mie;
 mod dereference;
+mod describe_data;
 mod list_endpoints;
 mod load_data;
 mod load_service_description;
 mod reset_data;
 mod reset_service_description;
 mod serialize_data;
+mod serialize_mie;
 mod serialize_service_description;
 mod show_node_info;
 
+pub use build_mie::BuildMieBuilder;
 pub use dereference::DereferenceBuilder;
+pub use describe_data::DescribeDataBuilder;
 pub use list_endpoints::ListEndpointsBuilder;
 pub use load_data::LoadDataBuilder;
 pub use load_service_description::LoadServiceDescriptionBuilder;
 pub use reset_data::ResetDataBuilder;
 pub use reset_service_description::ResetServiceDescriptionBuilder;
 pub use serialize_data::SerializeDataBuilder;
+pub use serialize_mie::SerializeMieBuilder;
 pub use serialize_service_description::SerializeServiceDescriptionBuilder;
 pub use show_node_info::ShowNodeInfoBuilder;
@@ -0,0 +1,25 @@
+use crate::{Result, Rudof, api::data::DataOperations};
+use std::io;
+
+/// Builder for `serialize_mie` operation.
+///
+/// Provides a fluent interface for executing MIE serialization operations.
+pub struct SerializeMieBuilder<'a, W: io::Write> {
+    rudof: &'a Rudof,
+    writer: &'a mut W,
+}
+
+impl<'a, W: io::Write> SerializeMieBuilder<'a, W> {
+    /// Creates a new builder instance.
+    ///
+    /// This is called internally by `Rudof::serialize_mie()` and should not
+    /// be constructed directly.
+    pub(crate) fn new(rudof: &'a Rudof, writer: &'a mut W) -> Self {
+        Self { rudof, writer }
+    }
+
+    /// Executes the MIE serialization operation.
+    pub fn execute(self) -> Result<()> {
+        <Rudof as DataOperations>::serialize_mie(self.rudof, self.writer)
+    }
+}
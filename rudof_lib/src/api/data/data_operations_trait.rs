@@ -1,8 +1,8 @@
 use crate::{
     Result, Rudof,
     api::data::implementations::{
-        dereference, list_endpoints, load_data, load_service_description, reset_data, reset_service_description,
-        serialize_data, serialize_service_description, show_node_info,
+        build_mie, dereference, describe_data, list_endpoints, load_data, load_service_description, reset_data,
+        reset_service_description, serialize_data, serialize_mie, serialize_service_description, show_node_info,
     },
     formats::{
         DataFormat, DataReaderMode, InputSpec, IriNormalizationMode, NodeInspectionMode, ResultDataFormat,
@@ -22,6 +22,7 @@ pub trait DataOperations {
     /// * `base` - Optional base IRI for resolving relative IRIs (uses default if None)
     /// * `endpoint` - Optional SPARQL endpoint URL to load data from. If stablished it overrides data (uses None by default)
     /// * `reader_mode` - The parsing mode (uses default if None)
+    /// * `max_errors` - Number of syntax errors skipped in each source in lax mode before loading fails (no limit if None)
     ///
     /// # Errors
     ///
@@ -33,6 +34,7 @@ pub trait DataOperations {
         base: Option<&str>,
         endpoint: Option<&str>,
         reader_mode: Option<&DataReaderMode>,
+        max_errors: Option<usize>,
         merge: Option<bool>,
         prefixes: Option<&[InputSpec]>,
     ) -> Result<()>;
@@ -95,6 +97,51 @@ pub trait DataOperations {
     /// Resets the current service description.
     fn reset_service_description(&mut self);
 
+    /// Computes a VoID / SPARQL service description of the current data and
+    /// stores it as the current service description.
+    ///
+    /// The description contains the triple, distinct subject and object counts,
+    /// the class and property partitions, and the datatype partitions of each property.
+    /// Property graphs are described through their RDF representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Optional title of the described service
+    /// * `endpoint` - Optional IRI of the SPARQL endpoint that serves the data
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no data is loaded or the profiling queries fail.
+    fn describe_data(&mut self, title: Option<&str>, endpoint: Option<&str>) -> Result<()>;
+
+    /// Builds a MIE file by introspecting the current data and stores it as the current MIE.
+    ///
+    /// The MIE contains the statistics of the service description of the data, which
+    /// also becomes the current service description, the prefixes of the data and, for the
+    /// classes with more instances, sample RDF, an inferred ShEx shape and the links to
+    /// external namespaces. The introspection is configured by the `[rdf2mie]` section.
+    ///
+    /// # Arguments
+    ///
+    /// * `title` - Optional title of the MIE
+    /// * `endpoint` - Optional IRI of the SPARQL endpoint that serves the data
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no data is loaded or the introspection queries fail.
+    fn build_mie(&mut self, title: Option<&str>, endpoint: Option<&str>) -> Result<()>;
+
+    /// Serializes the current MIE as JSON.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The destination to write the serialized MIE to
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no MIE has been built or the serialization fails.
+    fn serialize_mie<W: io::Write>(&self, writer: &mut W) -> Result<()>;
+
     /// Shows detailed information about a node in the current RDF data.
     ///
     /// # Arguments
@@ -158,10 +205,21 @@ impl DataOperations for Rudof {
         base: Option<&str>,
         endpoint: Option<&str>,
         reader_mode: Option<&DataReaderMode>,
+        max_errors: Option<usize>,
         merge: Option<bool>,
         prefixes: Option<&[InputSpec]>,
     ) -> Result<()> {
-        load_data(self, data, data_format, base, endpoint, reader_mode, merge, prefixes)
+        load_data(
+            self,
+            data,
+            data_format,
+            base,
+            endpoint,
+            reader_mode,
+            max_errors,
+            merge,
+            prefixes,
+        )
     }
 
     fn serialize_data<W: io::Write>(
@@ -198,6 +256,18 @@ impl DataOperations for Rudof {
         reset_service_description(self)
     }
 
+    fn describe_data(&mut self, title: Option<&str>, endpoint: Option<&str>) -> Result<()> {
+        describe_data(self, title, endpoint)
+    }
+
+    fn build_mie(&mut self, title: Option<&str>, endpoint: Option<&str>) -> Result<()> {
+        build_mie(self, title, endpoint)
+    }
+
+    fn serialize_mie<W: io::Write>(&self, writer: &mut W) -> Result<()> {
+        serialize_mie(self, writer)
+    }
+
     fn show_node_info<W: io::Write>(
         &mut self,
         node: &str,
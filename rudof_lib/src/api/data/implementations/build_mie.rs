@@ -0,0 +1,18 @@
+use super::describe_data;
+use crate::{Result, Rudof, errors::DataError};
+use shapes_converter::Rdf2Mie;
+
+pub fn build_mie(rudof: &mut Rudof, title: Option<&str>, endpoint: Option<&str>) -> Result<()> {
+    let rdf2mie = Rdf2Mie::new(rudof.config.rdf2mie(), rudof.config.rdf2shex());
+    let (service_description, mie) = describe_data::with_rdf_data(rudof, |rdf_data| {
+        let service_description = describe_data::profile(rdf_data, title, endpoint)?;
+        let mie = rdf2mie
+            .introspect(rdf_data, &service_description)
+            .map_err(|e| Box::new(DataError::FailedBuildingMie { error: e.to_string() }))?;
+        Ok((service_description, mie))
+    })?;
+    rudof.service_description = Some(service_description);
+    rudof.mie = Some(mie);
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use crate::{Result, Rudof, errors::DataError, types::Data};
+use rudof_iri::IriS;
+use sparql_service::{RdfData, ServiceDescription, VoidProfiler};
+use std::str::FromStr;
+
+pub fn describe_data(rudof: &mut Rudof, title: Option<&str>, endpoint: Option<&str>) -> Result<()> {
+    let service_description = with_rdf_data(rudof, |rdf_data| profile(rdf_data, title, endpoint))?;
+    rudof.service_description = Some(service_description);
+    Ok(())
+}
+
+/// Runs `f` on the current data, with its store ready for SPARQL queries
+pub(crate) fn with_rdf_data<T>(rudof: &mut Rudof, f: impl FnOnce(&RdfData) -> Result<T>) -> Result<T> {
+    let pg2rdf_config = rudof.config.pg2rdf().clone();
+    let data = rudof.data.as_mut().ok_or(Box::new(DataError::NoDataLoaded))?;
+
+    // Property graphs are described through their RDF representation
+    let mut converted;
+    let rdf_data = match data {
+        Data::RDFData(rdf_data) => rdf_data.as_mut(),
+        Data::PGData(pg) => {
+            converted = Data::pg_to_rdf(pg, &pg2rdf_config)?;
+            &mut converted
+        },
+    };
+    rdf_data.check_store().map_err(|e| failed(e.to_string()))?;
+    f(rdf_data)
+}
+
+/// Computes the VoID / SPARQL service description of some data
+pub(crate) fn profile(rdf_data: &RdfData, title: Option<&str>, endpoint: Option<&str>) -> Result<ServiceDescription> {
+    let endpoint = endpoint
+        .map(|endpoint| IriS::from_str(endpoint).map_err(|e| failed(e.to_string())))
+        .transpose()?;
+    let service_description = VoidProfiler::new()
+        .with_title(title.map(str::to_string))
+        .with_endpoint(endpoint)
+        .profile(rdf_data)
+        .map_err(|e| failed(e.to_string()))?;
+    Ok(service_description)
+}
+
+fn failed(error: String) -> Box<DataError> {
+    Box::new(DataError::FailedDescribingData { error })
+}
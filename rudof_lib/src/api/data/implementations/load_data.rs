@@ -23,10 +23,12 @@ pub fn load_data(
     base: Option<&str>,
     endpoint: Option<&str>,
     reader_mode: Option<&DataReaderMode>,
+    max_errors: Option<usize>,
     merge: Option<bool>,
     prefixes: Option<&[InputSpec]>,
 ) -> Result<()> {
     let (data_format, reader_mode, base, merge) = init_defaults(rudof, data_format, reader_mode, base, merge)?;
+    rudof.data_warnings.clear();
     match (data, endpoint) {
         (Some(_data), Some(_endpoint)) => Err(Box::new(DataError::DataSourceSpec {
             message: "Cannot specify both data and endpoint. Please choose one or the other.".to_string(),
@@ -34,7 +36,7 @@ pub fn load_data(
         .into()),
         (Some(data), None) => match data_format {
             DataFormat::Pg => load_data_from_specs_pg(rudof, data, merge),
-            _ => load_data_from_specs_rdf(rudof, data, data_format, base, reader_mode, merge, prefixes),
+            _ => load_data_from_specs_rdf(rudof, data, data_format, base, reader_mode, max_errors, merge, prefixes),
         },
         (None, Some(endpoint)) => load_data_from_endpoint(rudof, endpoint),
         (None, None) => Err(Box::new(DataError::DataSourceSpec {
@@ -82,6 +84,7 @@ fn load_data_from_specs_rdf(
     data_format: DataFormat,
     base: IriS,
     reader_mode: DataReaderMode,
+    max_errors: Option<usize>,
     merge: bool,
     prefixes: Option<&[InputSpec]>,
 ) -> Result<()> {
@@ -101,6 +104,7 @@ fn load_data_from_specs_rdf(
             &data_format,
             &base,
             &reader_mode,
+            max_errors,
             merge,
         )?;
     }
@@ -182,12 +186,16 @@ fn read_rdf_data<R: Read>(
     data_format: &DataFormat,
     base: &IriS,
     reader_mode: &DataReaderMode,
+    max_errors: Option<usize>,
     merge: bool,
 ) -> Result<()> {
     if !merge || rudof.data.is_none() || matches!(rudof.data, Some(ref data) if data.is_pg()) {
         let rdf_data = init_rdf_data_with_config(rudof)?;
         rudof.data = Some(rdf_data);
     }
+    let rdf_data = rudof.data.as_mut().unwrap().unwrap_rdf_mut();
+    rdf_data.set_max_errors(max_errors);
+    let known_errors = rdf_data.recovered_errors().len();
 
     // Only Turtle uses `@prefix` declarations that default prefixes can
     // usefully supplement; other formats either have no textual prefix
@@ -205,14 +213,19 @@ fn read_rdf_data<R: Read>(
             })
         })?;
         let header = default_prefix_header(rudof, &content, PrefixDirective::Turtle);
-        let mut prefixed_reader = io::Cursor::new(format!("{header}{content}").into_bytes());
-        rudof.data.as_mut().unwrap().unwrap_rdf_mut().merge_from_reader(
-            &mut prefixed_reader,
-            source_name,
-            &(*data_format).try_into()?,
-            Some(base.as_str()),
-            &(*reader_mode).into(),
-        )
+        rudof
+            .data
+            .as_mut()
+            .unwrap()
+            .unwrap_rdf_mut()
+            .merge_from_reader_with_header(
+                &header,
+                &mut content.as_bytes(),
+                source_name,
+                &(*data_format).try_into()?,
+                Some(base.as_str()),
+                &(*reader_mode).into(),
+            )
     } else {
         rudof.data.as_mut().unwrap().unwrap_rdf_mut().merge_from_reader(
             data_reader,
@@ -223,6 +236,11 @@ fn read_rdf_data<R: Read>(
         )
     };
 
+    // The errors skipped before loading failed are kept too, they show what led to the failure
+    let rdf_data = rudof.data.as_mut().unwrap().unwrap_rdf_mut();
+    let recovered = rdf_data.recovered_errors()[known_errors..].to_vec();
+    rudof.data_warnings.extend(recovered);
+
     parse_result.map_err(|error| {
         Box::new(DataError::FailedParsingRdfData {
             source_name: source_name.to_string(),
@@ -1,14 +1,19 @@
+mod build_mie;
 mod dereference;
+mod describe_data;
 mod list_endpoints;
 mod load_data;
 mod load_service_description;
 mod reset_data;
 mod reset_service_description;
 mod serialize_data;
+mod serialize_mie;
 mod serialize_service_description;
 mod show_node_info;
 
+pub use build_mie::build_mie;
 pub use dereference::dereference;
+pub use describe_data::describe_data;
 pub use list_endpoints::list_endpoints;
 pub use load_data::load_data;
 #[cfg(feature = "qlever")]
@@ -17,11 +22,14 @@ pub use load_service_description::load_service_description;
 pub use reset_data::reset_data;
 pub use reset_service_description::reset_service_description;
 pub use serialize_data::serialize_data;
+pub use serialize_mie::serialize_mie;
 pub use serialize_service_description::serialize_service_description;
 pub use show_node_info::show_node_info;
 
 #[cfg(test)]
 mod tests {
+    mod build_mie_tests;
+    mod describe_data_tests;
     mod load_data_tests;
     mod load_service_description_tests;
     mod show_node_info_tests;
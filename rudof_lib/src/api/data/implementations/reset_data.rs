@@ -2,4 +2,5 @@ use crate::Rudof;
 
 pub fn reset_data(rudof: &mut Rudof) {
     rudof.data = None;
+    rudof.data_warnings.clear();
 }
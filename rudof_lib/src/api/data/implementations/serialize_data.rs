@@ -1,8 +1,9 @@
-use crate::{Result, Rudof, errors::DataError, formats::ResultDataFormat};
+use crate::{Result, Rudof, RudofConfig, errors::DataError, formats::ResultDataFormat, types::Data};
 use rudof_rdf::rdf_core::visualizer::{
     VisualRDFGraph,
     uml_converter::{UmlConverter, UmlGenerationMode},
 };
+use sparql_service::RdfData;
 use std::io;
 
 pub fn serialize_data<W: io::Write>(
@@ -10,10 +11,15 @@ pub fn serialize_data<W: io::Write>(
     result_data_format: Option<&ResultDataFormat>,
     writer: &mut W,
 ) -> Result<()> {
-    let result_data_format = result_data_format.copied().unwrap_or_default();
-
     let data = rudof.data.as_ref().ok_or(Box::new(DataError::NoDataLoaded))?;
 
+    // Property graphs are written in their own syntax unless another format is requested
+    let result_data_format = match result_data_format {
+        Some(format) => *format,
+        None if data.is_pg() => ResultDataFormat::Pg,
+        None => ResultDataFormat::default(),
+    };
+
     if data.is_rdf() {
         serialize_rdf_data(rudof, result_data_format, writer)
     } else {
@@ -34,12 +40,24 @@ fn serialize_pg_data<W: io::Write>(
 
     let graph = data.unwrap_pg_mut();
 
-    write!(writer, "{graph}").map_err(|e| {
-        Box::new(DataError::FailedSerializingData {
-            format: result_data_format.to_string(),
-            error: e.to_string(),
-        })
-    })?;
+    if result_data_format == ResultDataFormat::Pg {
+        graph.write_pg(writer).map_err(|e| {
+            Box::new(DataError::FailedSerializingData {
+                format: result_data_format.to_string(),
+                error: e.to_string(),
+            })
+        })?;
+    } else if result_data_format == ResultDataFormat::Compact {
+        write!(writer, "{graph}").map_err(|e| {
+            Box::new(DataError::FailedSerializingData {
+                format: result_data_format.to_string(),
+                error: e.to_string(),
+            })
+        })?;
+    } else {
+        let rdf_data = Data::pg_to_rdf(graph, rudof.config.pg2rdf())?;
+        write_rdf(&rudof.config, &rdf_data, result_data_format, writer)?;
+    }
 
     Ok(())
 }
@@ -55,8 +73,28 @@ fn serialize_rdf_data<W: io::Write>(
         Err(Box::new(DataError::NoRdfDataLoaded))?
     }
 
+    if result_data_format == ResultDataFormat::Pg {
+        let graph = Data::rdf_to_pg(data.unwrap_rdf_mut(), rudof.config.rdf2pg())?;
+        graph.write_pg(writer).map_err(|e| {
+            Box::new(DataError::FailedSerializingData {
+                format: result_data_format.to_string(),
+                error: e.to_string(),
+            })
+        })?;
+        return Ok(());
+    }
+
+    write_rdf(&rudof.config, data.unwrap_rdf_mut(), result_data_format, writer)
+}
+
+fn write_rdf<W: io::Write>(
+    config: &RudofConfig,
+    rdf_data: &RdfData,
+    result_data_format: ResultDataFormat,
+    writer: &mut W,
+) -> Result<()> {
     if result_data_format.is_rdf_format() {
-        data.unwrap_rdf_mut()
+        rdf_data
             .serialize(&result_data_format.try_into()?, writer)
             .map_err(|e| {
                 Box::new(DataError::FailedSerializingData {
@@ -65,8 +103,8 @@ fn serialize_rdf_data<W: io::Write>(
                 })
             })?;
     } else {
-        let visualization_config = rudof.config.rdf_data().rdf_visualization_config().clone();
-        let converter = VisualRDFGraph::from_rdf(data.unwrap_rdf_mut(), visualization_config).map_err(|e| {
+        let visualization_config = config.rdf_data().rdf_visualization_config().clone();
+        let converter = VisualRDFGraph::from_rdf(rdf_data, visualization_config).map_err(|e| {
             Box::new(DataError::FailedSerializingData {
                 format: result_data_format.to_string(),
                 error: e.to_string(),
@@ -79,7 +117,7 @@ fn serialize_rdf_data<W: io::Write>(
                     writer,
                     result_data_format.try_into()?,
                     &UmlGenerationMode::all(),
-                    rudof.config.shex2uml().plantuml_path(),
+                    config.shex2uml().plantuml_path(),
                 )
                 .map_err(|e| {
                     Box::new(DataError::FailedSerializingData {
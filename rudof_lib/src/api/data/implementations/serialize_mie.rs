@@ -0,0 +1,12 @@
+use crate::{Result, Rudof, errors::DataError};
+use std::io;
+
+pub fn serialize_mie<W: io::Write>(rudof: &Rudof, writer: &mut W) -> Result<()> {
+    let mie = rudof.mie.as_ref().ok_or(Box::new(DataError::NoMie))?;
+    serde_json::to_writer_pretty(&mut *writer, mie)
+        .map_err(|e| e.to_string())
+        .and_then(|_| writeln!(writer).map_err(|e| e.to_string()))
+        .map_err(|error| Box::new(DataError::FailedSerializingMie { error }))?;
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use crate::{
+    Rudof, RudofConfig,
+    api::data::implementations::{build_mie::build_mie, load_data::load_data, serialize_mie::serialize_mie},
+    formats::{DataFormat, DataReaderMode, InputSpec},
+};
+
+const PEOPLE: &str = r#"prefix ex: <http://example.org/>
+    prefix wd: <http://www.wikidata.org/entity/>
+    ex:alice a ex:Person ; ex:name "Alice" ; ex:sameAs wd:Q1 .
+    ex:bob a ex:Person ; ex:name "Bob" ."#;
+
+fn load(rudof: &mut Rudof, data: &str) {
+    load_data(
+        rudof,
+        Some(&[InputSpec::str(data)]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        Some(&DataReaderMode::Strict),
+        None,
+        Some(false),
+        None,
+    )
+    .unwrap();
+}
+
+#[test]
+fn test_build_mie() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+    load(&mut rudof, PEOPLE);
+
+    build_mie(&mut rudof, Some("People"), Some("http://example.org/sparql")).unwrap();
+
+    assert!(rudof.service_description.is_some());
+    let mie = rudof.mie.as_ref().unwrap();
+    assert_eq!(mie.schema_info().title(), Some("People"));
+    assert!(mie.sample_rdf_entries().contains_key("ex:Person"));
+    assert!(mie.shape_expressions().contains_key("ex:Person"));
+    assert!(mie.cross_references().contains_key("wd"));
+
+    let mut buffer = Vec::new();
+    serialize_mie(&rudof, &mut buffer).unwrap();
+    let json = String::from_utf8(buffer).unwrap();
+    assert!(json.contains("\"shape_expressions\""));
+    assert!(json.contains("http://www.wikidata.org/entity/"));
+}
+
+#[test]
+fn test_build_mie_without_data_fails() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+    assert!(build_mie(&mut rudof, None, None).is_err());
+    let mut buffer = Vec::new();
+    assert!(serialize_mie(&rudof, &mut buffer).is_err());
+}
@@ -0,0 +1,62 @@
+use crate::{
+    Rudof, RudofConfig,
+    api::data::implementations::{
+        describe_data::describe_data, load_data::load_data,
+        serialize_service_description::serialize_service_description,
+    },
+    formats::{DataFormat, DataReaderMode, InputSpec, ResultServiceFormat},
+};
+
+fn load(rudof: &mut Rudof, data: &str) {
+    load_data(
+        rudof,
+        Some(&[InputSpec::str(data)]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        Some(&DataReaderMode::Strict),
+        None,
+        Some(false),
+        None,
+    )
+    .unwrap();
+}
+
+fn serialize_to_string(rudof: &Rudof, format: ResultServiceFormat) -> String {
+    let mut buffer = Vec::new();
+    serialize_service_description(rudof, Some(&format), &mut buffer).unwrap();
+    String::from_utf8(buffer).unwrap()
+}
+
+#[test]
+fn test_describe_data() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+    load(
+        &mut rudof,
+        r#"prefix ex: <http://example.org/>
+           ex:alice a ex:Person ; ex:name "Alice" ; ex:age 23 .
+           ex:bob a ex:Person ; ex:name "Bob" ."#,
+    );
+
+    describe_data(&mut rudof, Some("People"), Some("http://example.org/sparql")).unwrap();
+
+    let service = rudof.service_description.as_ref().unwrap();
+    let graph = service.default_dataset().unwrap().default_graph().unwrap();
+    assert_eq!(graph.triples().unwrap().to_string(), "5");
+    assert_eq!(graph.class_partition().len(), 1);
+    assert_eq!(graph.property_partition().len(), 3);
+
+    let turtle = serialize_to_string(&rudof, ResultServiceFormat::Turtle);
+    assert!(turtle.contains("sd:endpoint ex:sparql"));
+    assert!(turtle.contains("void:class ex:Person"));
+    assert!(turtle.contains("voidext:datatype xsd:integer"));
+
+    let mie = serialize_to_string(&rudof, ResultServiceFormat::Mie);
+    assert!(mie.contains("People"));
+}
+
+#[test]
+fn test_describe_data_without_data_fails() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+    assert!(describe_data(&mut rudof, None, None).is_err());
+}
@@ -29,6 +29,7 @@ fn test_load_data_rdf_success() {
         None,
         None,
         Some(&DataReaderMode::Strict),
+        None,
         Some(false),
         None,
     )
@@ -45,6 +46,79 @@ fn test_load_data_rdf_success() {
     );
 }
 
+#[test]
+fn test_load_data_lax_reports_skipped_errors() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let rdf =
+        InputSpec::str("@prefix ex: <http://example.org/> .\nex:alice a ex:Person ex:Other .\nex:bob a ex:Person .\n");
+
+    load_data(
+        &mut rudof,
+        Some(&[rdf]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        Some(&DataReaderMode::Lax),
+        None,
+        Some(false),
+        None,
+    )
+    .unwrap();
+
+    let warnings = rudof.data_warnings();
+    assert!(!warnings.is_empty());
+    assert_eq!(warnings[0].span().map(|span| span.line()), Some(2));
+    assert_eq!(warnings[0].text(), Some("ex:alice a ex:Person ex:Other ."));
+}
+
+#[test]
+fn test_load_data_lax_fails_after_max_errors() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let rdf = InputSpec::str(
+        "@prefix ex: <http://example.org/> .\nex:alice a ex:Person ex:Other .\nex:bob a ex:Person ex:Other .\n",
+    );
+
+    let result = load_data(
+        &mut rudof,
+        Some(&[rdf]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        Some(&DataReaderMode::Lax),
+        Some(1),
+        Some(false),
+        None,
+    );
+
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("more than 1 syntax errors"), "{error}");
+    assert_eq!(rudof.data_warnings().len(), 2);
+}
+
+#[test]
+fn test_load_data_strict_has_no_warnings() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let rdf = InputSpec::str(r#"@prefix ex: <http://example.org/> . ex:alice a ex:Person ."#);
+
+    load_data(
+        &mut rudof,
+        Some(&[rdf]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        Some(&DataReaderMode::Strict),
+        Some(0),
+        Some(false),
+        None,
+    )
+    .unwrap();
+
+    assert!(rudof.data_warnings().is_empty());
+}
+
 #[test]
 fn test_serialize_data_rdf_jsonld() {
     let mut rudof = Rudof::new(RudofConfig::default());
@@ -58,6 +132,7 @@ fn test_serialize_data_rdf_jsonld() {
         None,
         None,
         Some(&DataReaderMode::Strict),
+        None,
         Some(false),
         None,
     )
@@ -84,6 +159,7 @@ fn test_serialize_data_rdf_json_alias() {
         None,
         None,
         Some(&DataReaderMode::Strict),
+        None,
         Some(false),
         None,
     )
@@ -112,6 +188,7 @@ fn test_load_data_rdf_merge() {
         None,
         None,
         None,
+        None,
         Some(false),
         None,
     )
@@ -124,6 +201,7 @@ fn test_load_data_rdf_merge() {
         None,
         None,
         None,
+        None,
         Some(true),
         None,
     )
@@ -155,6 +233,7 @@ fn test_load_data_rdf_replace() {
         None,
         None,
         None,
+        None,
         Some(false),
         None,
     )
@@ -167,6 +246,7 @@ fn test_load_data_rdf_replace() {
         None,
         None,
         None,
+        None,
         Some(false),
         None,
     )
@@ -198,6 +278,7 @@ fn test_load_data_invalid_rdf() {
         None,
         None,
         None,
+        None,
     );
 
     assert!(result.is_err());
@@ -221,6 +302,7 @@ fn test_load_data_pg_success() {
         None,
         None,
         None,
+        None,
         Some(false),
         None,
     )
@@ -255,6 +337,7 @@ fn test_load_data_pg_merge() {
         None,
         None,
         None,
+        None,
         Some(true),
         None,
     )
@@ -267,6 +350,7 @@ fn test_load_data_pg_merge() {
         None,
         None,
         None,
+        None,
         Some(true),
         None,
     )
@@ -298,6 +382,7 @@ fn test_load_data_pg_replace() {
         None,
         None,
         None,
+        None,
         Some(false),
         None,
     )
@@ -310,6 +395,7 @@ fn test_load_data_pg_replace() {
         None,
         None,
         None,
+        None,
         Some(false),
         None,
     )
@@ -341,11 +427,76 @@ fn test_load_data_pg_invalid_syntax() {
         None,
         None,
         None,
+        None,
     );
 
     assert!(result.is_err());
 }
 
+#[test]
+fn test_serialize_pg_data_as_turtle() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let pg_data = InputSpec::str(
+        r#"
+        (alice {Person} [ name: "Alice" ])
+        (bob   {Person} [ name: "Robert" ])
+        (alice) -(e1 {knows} [ since: 2020 ])-> (bob)
+        "#,
+    );
+
+    load_data(
+        &mut rudof,
+        Some(&[pg_data]),
+        Some(&DataFormat::Pg),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let serialized = serialize_to_string(&mut rudof, Some(ResultDataFormat::NTriples));
+
+    assert!(serialized.contains(
+        "<http://example.org/pg/node/alice> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://example.org/pg/label/Person>"
+    ));
+    assert!(serialized.contains(
+        "<http://example.org/pg/node/alice> <http://example.org/pg/property/knows> <http://example.org/pg/node/bob>"
+    ));
+    assert!(serialized.contains("<http://example.org/pg/edge/e1> <http://example.org/pg/property/since>"));
+}
+
+#[test]
+fn test_serialize_rdf_data_as_pg() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let rdf = InputSpec::str(
+        r#"prefix : <http://example.org/>
+           :alice a :Person ; :name "Alice" ; :knows :bob ."#,
+    );
+
+    load_data(
+        &mut rudof,
+        Some(&[rdf]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let serialized = serialize_to_string(&mut rudof, Some(ResultDataFormat::Pg));
+
+    assert!(serialized.contains(r#"(alice {":Person"} [ ":name": "Alice", iri: "http://example.org/alice" ])"#));
+    assert!(serialized.contains(r#"(alice) -({":knows"})-> (bob)"#));
+}
+
 #[test]
 fn test_load_data_endpoint_success() {
     let mut rudof = Rudof::new(RudofConfig::default());
@@ -359,6 +510,7 @@ fn test_load_data_endpoint_success() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -379,6 +531,7 @@ fn test_load_data_endpoint_uses_configured_prefixmap_for_known_endpoint() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -410,6 +563,7 @@ fn test_load_data_conflicting_sources() {
         None,
         None,
         None,
+        None,
     );
 
     assert!(result.is_err());
@@ -419,7 +573,7 @@ fn test_load_data_conflicting_sources() {
 fn test_load_data_no_source() {
     let mut rudof = Rudof::new(RudofConfig::default());
 
-    let result = load_data(&mut rudof, None, None, None, None, None, None, None);
+    let result = load_data(&mut rudof, None, None, None, None, None, None, None, None);
 
     assert!(result.is_err());
 }
@@ -0,0 +1,56 @@
+use crate::{
+    Result, Rudof,
+    api::inference::InferenceOperations,
+    formats::{InferenceSchemaMode, InputSpec},
+};
+
+/// Builder for `infer_schema` operation.
+///
+/// Provides a fluent interface for configuring and executing the inference
+/// of a schema from the currently loaded data.
+pub struct InferSchemaBuilder<'a> {
+    rudof: &'a mut Rudof,
+    schema_mode: Option<&'a InferenceSchemaMode>,
+    shapemap: Option<&'a InputSpec>,
+}
+
+impl<'a> InferSchemaBuilder<'a> {
+    /// Creates a new builder instance.
+    ///
+    /// This is called internally by `Rudof::infer_schema()` and should not
+    /// be constructed directly.
+    pub(crate) fn new(rudof: &'a mut Rudof) -> Self {
+        Self {
+            rudof,
+            schema_mode: None,
+            shapemap: None,
+        }
+    }
+
+    /// Sets the kind of schema to infer.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_mode` - The kind of schema to infer
+    pub fn with_schema_mode(mut self, schema_mode: &'a InferenceSchemaMode) -> Self {
+        self.schema_mode = Some(schema_mode);
+        self
+    }
+
+    /// Sets a shapemap whose shape selectors group the nodes it selects.
+    ///
+    /// When omitted, nodes are grouped by their types.
+    ///
+    /// # Arguments
+    ///
+    /// * `shapemap` - Input specification of a compact shapemap
+    pub fn with_shapemap(mut self, shapemap: &'a InputSpec) -> Self {
+        self.shapemap = Some(shapemap);
+        self
+    }
+
+    /// Executes the inference with the configured parameters.
+    pub fn execute(self) -> Result<()> {
+        <Rudof as InferenceOperations>::infer_schema(self.rudof, self.schema_mode, self.shapemap)
+    }
+}
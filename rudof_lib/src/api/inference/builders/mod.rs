@@ -0,0 +1,3 @@
+mod infer_schema;
+
+pub use infer_schema::InferSchemaBuilder;
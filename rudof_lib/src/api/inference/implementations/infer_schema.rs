@@ -0,0 +1,141 @@
+use crate::{
+    Result, Rudof,
+    api::shex::implementations::compile_shex_schema,
+    errors::{DataError, InferenceError},
+    formats::{DataReaderMode, InferenceSchemaMode, InputSpec},
+    types::Data,
+    utils::get_base_iri,
+};
+use rudof_iri::IriS;
+use rudof_rdf::rdf_core::{Rdf, term::Object};
+use shacl::ir::IRSchema;
+use shapes_converter::{NodeGroup, Rdf2ShEx, Rdf2Shacl};
+use shex_ast::{ShapeExprLabel, ShapeMapParser, shapemap::NodeSelector};
+use sparql_service::RdfData;
+use std::{collections::BTreeMap, io::Read};
+
+pub fn infer_schema(
+    rudof: &mut Rudof,
+    schema_mode: Option<&InferenceSchemaMode>,
+    shapemap: Option<&InputSpec>,
+) -> Result<()> {
+    let schema_mode = schema_mode.copied().unwrap_or_default();
+    let base = get_base_iri(rudof, None)?;
+    let pg2rdf_config = rudof.config.pg2rdf().clone();
+    let data = rudof.data.as_mut().ok_or(Box::new(DataError::NoDataLoaded))?;
+
+    // Property graphs are profiled through their RDF representation
+    let mut converted;
+    let rdf_data = match data {
+        Data::RDFData(rdf_data) => rdf_data.as_mut(),
+        Data::PGData(pg) => {
+            converted = Data::pg_to_rdf(pg, &pg2rdf_config)?;
+            &mut converted
+        },
+    };
+
+    match schema_mode {
+        InferenceSchemaMode::ShEx => {
+            let converter = Rdf2ShEx::new(rudof.config.rdf2shex());
+            let groups = match shapemap {
+                Some(shapemap) => shapemap_groups(rdf_data, shapemap, &base)?,
+                None => converter.groups_by_type(rdf_data).map_err(|e| failed(schema_mode, e))?,
+            };
+            let schema = converter
+                .infer_groups(rdf_data, &groups)
+                .map_err(|e| failed(schema_mode, e))?;
+            let shapes_base = rudof.config.rdf2shex().shapes_base_iri().clone();
+            compile_shex_schema(rudof, shapes_base, schema, &DataReaderMode::default())?;
+        },
+        InferenceSchemaMode::Shacl => {
+            let converter = Rdf2Shacl::new(rudof.config.rdf2shacl());
+            let groups = match shapemap {
+                Some(shapemap) => shapemap_groups(rdf_data, shapemap, &base)?,
+                None => converter.groups_by_type(rdf_data).map_err(|e| failed(schema_mode, e))?,
+            };
+            let schema = converter
+                .infer_groups(rdf_data, &groups)
+                .map_err(|e| failed(schema_mode, e))?;
+            let shapes = IRSchema::compile(&schema).map_err(|e| failed(schema_mode, e))?;
+            rudof.shacl_shapes = Some(shapes);
+        },
+    }
+
+    Ok(())
+}
+
+/// Groups the nodes selected by a shapemap by their shape labels
+fn shapemap_groups(rdf_data: &mut RdfData, shapemap: &InputSpec, base: &IriS) -> Result<Vec<NodeGroup>> {
+    let source_name = shapemap.source_name();
+    let read_error = |error: String| InferenceError::FailedReadingShapemap {
+        source_name: source_name.clone(),
+        error,
+    };
+    let mut content = String::new();
+    shapemap
+        .open_read(None, "ShapeMap data")
+        .map_err(|e| read_error(e.to_string()))?
+        .read_to_string(&mut content)
+        .map_err(|e| read_error(e.to_string()))?;
+
+    // Without a schema, shape labels are resolved with the prefixes of the data
+    let prefixmap = rdf_data.prefixmap().unwrap_or_default();
+    let shapemap = ShapeMapParser::parse(
+        &content,
+        &Some(prefixmap.clone()),
+        &Some(base.clone()),
+        &Some(prefixmap),
+        &Some(base.clone()),
+    )
+    .map_err(|e| read_error(e.to_string()))?;
+
+    let needs_store = shapemap.iter().any(|asc| {
+        matches!(
+            asc.node_selector,
+            NodeSelector::Sparql { .. } | NodeSelector::TriplePattern { .. }
+        )
+    });
+    if needs_store {
+        rdf_data
+            .check_store()
+            .map_err(|e| InferenceError::FailedResolvingShapemap { error: e.to_string() })?;
+    }
+
+    let mut groups: BTreeMap<IriS, Vec<Object>> = BTreeMap::new();
+    for (term, label) in shapemap
+        .node_shapes(&*rdf_data)
+        .map_err(|e| InferenceError::FailedResolvingShapemap { error: e.to_string() })?
+    {
+        let label = match label {
+            ShapeExprLabel::IriRef { value } => {
+                value
+                    .get_iri()
+                    .cloned()
+                    .map_err(|_| InferenceError::InvalidShapeLabel {
+                        label: label.to_string(),
+                    })?
+            },
+            _ => Err(InferenceError::InvalidShapeLabel {
+                label: label.to_string(),
+            })?,
+        };
+        let node: Object = term.try_into().map_err(|_| InferenceError::FailedResolvingShapemap {
+            error: "selected node is not an RDF term".to_string(),
+        })?;
+        let nodes = groups.entry(label).or_default();
+        if !nodes.contains(&node) {
+            nodes.push(node);
+        }
+    }
+    Ok(groups
+        .into_iter()
+        .map(|(label, nodes)| NodeGroup::new(label, nodes))
+        .collect())
+}
+
+fn failed(schema_mode: InferenceSchemaMode, error: impl ToString) -> InferenceError {
+    InferenceError::FailedInference {
+        mode: schema_mode.to_string(),
+        error: error.to_string(),
+    }
+}
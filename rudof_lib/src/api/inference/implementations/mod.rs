@@ -0,0 +1,8 @@
+mod infer_schema;
+
+pub use infer_schema::infer_schema;
+
+#[cfg(test)]
+mod tests {
+    mod infer_schema_tests;
+}
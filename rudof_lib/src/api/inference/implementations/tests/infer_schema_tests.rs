@@ -0,0 +1,136 @@
+use crate::{
+    Rudof, RudofConfig,
+    api::data::implementations::load_data,
+    api::inference::implementations::infer_schema,
+    api::shacl::implementations::{serialize_shacl_schema, validate_shacl},
+    api::shex::implementations::{load_shapemap, serialize_shex_schema, validate_shex},
+    errors::{DataError, RudofError},
+    formats::{DataFormat, InferenceSchemaMode, InputSpec, ShExFormat, ShaclFormat, ShapeMapFormat},
+};
+
+const PEOPLE: &str = r#"
+    prefix : <http://example.org/>
+    :alice a :Person ; :name "Alice" ; :knows :bob ; :worksFor :acme .
+    :bob a :Person ; :name "Bob" .
+    :acme a :Company ; :name "ACME" .
+"#;
+
+fn load(rudof: &mut Rudof, data: &str) {
+    load_data(
+        rudof,
+        Some(&[InputSpec::str(data)]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+}
+
+/// Helper: serialize the current ShEx schema as ShExC
+fn serialize_to_string(rudof: &Rudof) -> String {
+    let mut buffer = Vec::new();
+    serialize_shex_schema(
+        rudof,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(false),
+        Some(&ShExFormat::ShExC),
+        &mut buffer,
+    )
+    .unwrap();
+    String::from_utf8(buffer).unwrap()
+}
+
+#[test]
+fn test_infer_shex_by_type() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+    load(&mut rudof, PEOPLE);
+
+    infer_schema(&mut rudof, Some(&InferenceSchemaMode::ShEx), None).unwrap();
+
+    let shex = serialize_to_string(&rudof);
+    assert!(shex.contains("shapes:Person"), "{shex}");
+    assert!(shex.contains("shapes:Company"), "{shex}");
+    assert!(shex.contains(":worksFor @shapes:Company ?"), "{shex}");
+    assert!(shex.contains("infer:support"), "{shex}");
+}
+
+#[test]
+fn test_inferred_schema_validates_its_data() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+    load(&mut rudof, PEOPLE);
+    infer_schema(&mut rudof, None, None).unwrap();
+
+    load_shapemap(
+        &mut rudof,
+        &InputSpec::str("<http://example.org/alice>@<http://example.org/shapes/Person>"),
+        Some(&ShapeMapFormat::Compact),
+        None,
+        None,
+    )
+    .unwrap();
+    validate_shex(&mut rudof, None, None, None, None).unwrap();
+
+    let results = rudof.shex_validation_results.as_ref().unwrap();
+    assert!(results.iter().count() > 0);
+    assert!(results.iter().all(|(_, _, status)| status.is_conformant()));
+}
+
+#[test]
+fn test_infer_shex_by_shapemap() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+    load(&mut rudof, PEOPLE);
+
+    let shapemap = InputSpec::str(
+        "<http://example.org/alice>@<http://example.org/Employee>, <http://example.org/bob>@<http://example.org/Employee>",
+    );
+    infer_schema(&mut rudof, None, Some(&shapemap)).unwrap();
+
+    let shex = serialize_to_string(&rudof);
+    assert!(shex.contains(":Employee"), "{shex}");
+    assert!(!shex.contains("shapes:Company"), "{shex}");
+    // Without a class, rdf:type is just another predicate
+    assert!(!shex.contains("EXTRA"), "{shex}");
+}
+
+#[test]
+fn test_infer_shacl_by_type() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+    load(&mut rudof, PEOPLE);
+
+    infer_schema(&mut rudof, Some(&InferenceSchemaMode::Shacl), None).unwrap();
+
+    let mut buffer = Vec::new();
+    serialize_shacl_schema(&rudof, Some(&ShaclFormat::Turtle), &mut buffer).unwrap();
+    let shacl = String::from_utf8(buffer).unwrap();
+    assert!(shacl.contains("shapes:Person"), "{shacl}");
+    assert!(shacl.contains("sh:targetClass :Company"), "{shacl}");
+    assert!(shacl.contains("sh:class :Company"), "{shacl}");
+}
+
+#[test]
+fn test_inferred_shapes_validate_their_data() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+    load(&mut rudof, PEOPLE);
+    infer_schema(&mut rudof, Some(&InferenceSchemaMode::Shacl), None).unwrap();
+
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
+
+    assert!(rudof.shacl_validation_results.as_ref().unwrap().conforms());
+}
+
+#[test]
+fn test_infer_schema_no_data_error() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let result = infer_schema(&mut rudof, None, None);
+
+    assert!(matches!(result, Err(RudofError::Data(e)) if matches!(*e, DataError::NoDataLoaded)));
+}
@@ -0,0 +1,32 @@
+use crate::{
+    Result, Rudof,
+    api::inference::implementations::infer_schema,
+    formats::{InferenceSchemaMode, InputSpec},
+};
+
+/// Operations for inferring schemas from RDF data.
+pub trait InferenceOperations {
+    /// Infers a schema from the currently loaded data and keeps it as the current schema.
+    ///
+    /// Nodes are grouped by their types, or by the selectors of a shapemap, and
+    /// every group becomes a shape. The thresholds that decide which constraints
+    /// are emitted are taken from the configuration of the schema mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `schema_mode` - Optional kind of schema to infer (defaults to ShEx when `None`)
+    /// * `shapemap` - Optional shapemap whose shape selectors group the nodes it selects
+    ///   (nodes are grouped by their types when `None`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no data is loaded, if the shapemap cannot be read or
+    /// resolved, or if the inference fails.
+    fn infer_schema(&mut self, schema_mode: Option<&InferenceSchemaMode>, shapemap: Option<&InputSpec>) -> Result<()>;
+}
+
+impl InferenceOperations for Rudof {
+    fn infer_schema(&mut self, schema_mode: Option<&InferenceSchemaMode>, shapemap: Option<&InputSpec>) -> Result<()> {
+        infer_schema(self, schema_mode, shapemap)
+    }
+}
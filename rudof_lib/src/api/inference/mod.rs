@@ -0,0 +1,5 @@
+pub mod builders;
+pub mod implementations;
+mod inference_operations_trait;
+
+pub use inference_operations_trait::InferenceOperations;
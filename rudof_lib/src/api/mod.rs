@@ -9,6 +9,7 @@ pub mod core;
 pub mod data;
 pub mod dctap;
 pub mod generation;
+pub mod inference;
 pub mod map_state;
 pub mod materialize;
 pub mod pgschema;
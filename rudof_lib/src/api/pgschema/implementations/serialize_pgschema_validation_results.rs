@@ -1,4 +1,11 @@
-use crate::{Result, Rudof, errors::PgSchemaError, formats::ResultPgSchemaValidationFormat};
+use crate::{
+    Result, Rudof,
+    display::{CiCase, CiLevel, CiOutcome, CiReport},
+    errors::PgSchemaError,
+    formats::ResultPgSchemaValidationFormat,
+};
+use either::Either;
+use pgschema::validation_result::ValidationResult;
 use std::io;
 
 pub fn serialize_pgschema_validation_results<W: io::Write>(
@@ -29,6 +36,16 @@ pub fn serialize_pgschema_validation_results<W: io::Write>(
                 .as_csv(writer, show_colors.unwrap_or(true))
                 .map_err(|e| PgSchemaError::FailedIoOperation { error: e.to_string() })?;
         },
+        ResultPgSchemaValidationFormat::Sarif => {
+            ci_report(rudof, pgschema_validation_results)
+                .write_sarif(writer)
+                .map_err(|e| PgSchemaError::FailedIoOperation { error: e.to_string() })?;
+        },
+        ResultPgSchemaValidationFormat::JUnit => {
+            ci_report(rudof, pgschema_validation_results)
+                .write_junit(writer)
+                .map_err(|e| PgSchemaError::FailedIoOperation { error: e.to_string() })?;
+        },
         ResultPgSchemaValidationFormat::Details => {
             todo!("Implement details format for Property Graph schema validation results serialization");
         },
@@ -36,3 +53,29 @@ pub fn serialize_pgschema_validation_results<W: io::Write>(
 
     Ok(())
 }
+
+/// Arranges the results as a case per node and type.
+///
+/// Property graphs are not read with positions, so the cases have no location.
+fn ci_report(rudof: &Rudof, results: &ValidationResult) -> CiReport {
+    let mut report = CiReport::new(&rudof.version);
+    for association in &results.associations {
+        let message = match &association.details {
+            Either::Left(errors) => errors.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("; "),
+            Either::Right(evidences) => evidences.iter().map(|e| e.to_string()).collect::<Vec<_>>().join("; "),
+        };
+        report.add_case(CiCase {
+            suite: association.type_name.clone(),
+            name: association.node_id.clone(),
+            rule: association.type_name.clone(),
+            outcome: if association.conforms {
+                CiOutcome::Passed
+            } else {
+                CiOutcome::Failed(CiLevel::Error)
+            },
+            message,
+            location: None,
+        });
+    }
+    report
+}
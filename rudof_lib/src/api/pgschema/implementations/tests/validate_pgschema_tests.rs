@@ -43,6 +43,7 @@ fn test_validate_and_serialize_pgschema() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -94,5 +95,14 @@ n3_wrong: AdultStudentType
     );
     assert!(json.contains("n2_wrong") || json.contains("n3_wrong"));
     assert!(csv.contains("n2_wrong") || csv.contains("n3_wrong"));
+
+    let sarif = serialize_validation_to_string(&rudof, Some(ResultPgSchemaValidationFormat::Sarif), None);
+    let sarif: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+    let results = sarif["runs"][0]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|result| result["ruleId"] == "AdultStudentType"));
+
+    let junit = serialize_validation_to_string(&rudof, Some(ResultPgSchemaValidationFormat::JUnit), None);
+    assert!(junit.contains(r#"<testsuite name="AdultStudentType" tests="3" failures="2" skipped="0">"#));
     println!("===== test_validate_and_serialize_pgschema =====\nValidation completed successfully");
 }
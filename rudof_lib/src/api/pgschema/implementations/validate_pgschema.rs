@@ -6,11 +6,22 @@ use crate::{
 use pgschema::{pgs::PropertyGraphSchema, type_map::TypeMap};
 
 pub fn validate_pgschema(rudof: &mut Rudof) -> Result<()> {
+    let rdf2pg_config = rudof.config.rdf2pg().clone();
     let (data, pg_schema, typemap) = validate_loaded_data_schema_and_typemap(rudof)?;
 
+    // RDF data is validated through its property graph representation
+    let mut converted;
+    let pg = match data {
+        Data::PGData(pg) => pg.as_mut(),
+        Data::RDFData(rdf_data) => {
+            converted = Data::rdf_to_pg(rdf_data, &rdf2pg_config)?;
+            &mut converted
+        },
+    };
+
     let pg_schema_validation_results =
         typemap
-            .validate(pg_schema, data.unwrap_pg_mut())
+            .validate(pg_schema, pg)
             .map_err(|error| PgSchemaError::FailedPgschemaValidation {
                 error: error.to_string(),
             })?;
@@ -23,10 +34,6 @@ pub fn validate_pgschema(rudof: &mut Rudof) -> Result<()> {
 fn validate_loaded_data_schema_and_typemap(rudof: &mut Rudof) -> Result<(&mut Data, &PropertyGraphSchema, &TypeMap)> {
     let data = rudof.data.as_mut().ok_or(Box::new(DataError::NoDataLoaded))?;
 
-    if !data.is_pg() {
-        Err(Box::new(DataError::NoPgDataLoaded))?
-    }
-
     let pg_schema = rudof.pg_schema.as_ref().ok_or(PgSchemaError::NoPgschemaLoaded)?;
 
     let typemap = rudof.typemap.as_ref().ok_or(PgSchemaError::NoTypemapLoaded)?;
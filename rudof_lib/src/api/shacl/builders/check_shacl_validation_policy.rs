@@ -0,0 +1,80 @@
+use crate::{Result, Rudof, api::shacl::ShaclOperations, formats::ValidationFailOn, types::ValidationVerdict};
+
+/// Builder for `check_shacl_validation_policy` operation.
+///
+/// Provides a fluent interface for checking SHACL validation results against
+/// the configured validation policy, overriding some of its entries.
+pub struct CheckShaclValidationPolicyBuilder<'a> {
+    rudof: &'a Rudof,
+    fail_on: Option<&'a ValidationFailOn>,
+    max_violations: Option<usize>,
+    shapes: Option<&'a [String]>,
+    components: Option<&'a [String]>,
+}
+
+impl<'a> CheckShaclValidationPolicyBuilder<'a> {
+    /// Creates a new builder instance.
+    ///
+    /// This is called internally by `Rudof::check_shacl_validation_policy()` and should not
+    /// be constructed directly.
+    pub(crate) fn new(rudof: &'a Rudof) -> Self {
+        Self {
+            rudof,
+            fail_on: None,
+            max_violations: None,
+            shapes: None,
+            components: None,
+        }
+    }
+
+    /// Sets the severity of the results that make the validation fail.
+    ///
+    /// # Arguments
+    ///
+    /// * `fail_on` - The severity that fails the validation
+    pub fn with_fail_on(mut self, fail_on: &'a ValidationFailOn) -> Self {
+        self.fail_on = Some(fail_on);
+        self
+    }
+
+    /// Sets the number of violations that are accepted.
+    ///
+    /// # Arguments
+    ///
+    /// * `max_violations` - The number of accepted violations
+    pub fn with_max_violations(mut self, max_violations: usize) -> Self {
+        self.max_violations = Some(max_violations);
+        self
+    }
+
+    /// Sets the source shapes whose results are counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `shapes` - The IRIs of the shapes
+    pub fn with_shapes(mut self, shapes: &'a [String]) -> Self {
+        self.shapes = Some(shapes);
+        self
+    }
+
+    /// Sets the constraint components whose results are counted.
+    ///
+    /// # Arguments
+    ///
+    /// * `components` - The IRIs of the constraint components
+    pub fn with_components(mut self, components: &'a [String]) -> Self {
+        self.components = Some(components);
+        self
+    }
+
+    /// Executes the policy check with the configured parameters.
+    pub fn execute(self) -> Result<ValidationVerdict> {
+        <Rudof as ShaclOperations>::check_shacl_validation_policy(
+            self.rudof,
+            self.fail_on,
+            self.max_violations,
+            self.shapes,
+            self.components,
+        )
+    }
+}
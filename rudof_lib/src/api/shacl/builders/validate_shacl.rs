@@ -1,4 +1,8 @@
-use crate::{Result, Rudof, api::shacl::ShaclOperations, formats::ShaclValidationMode};
+use crate::{Result, Rudof, api::shacl::ShaclOperations, formats::ShaclValidationMode, types::ShaclFocus};
+use rudof_rdf::rdf_core::term::literal::Lang;
+use rudof_rdf::rdf_core::utils::CancellationToken;
+use shacl::validator::{ResultLimits, ShaclValidationEvent};
+use std::time::Duration;
 
 /// Builder for `validate_shacl` operation.
 ///
@@ -7,6 +11,13 @@ use crate::{Result, Rudof, api::shacl::ShaclOperations, formats::ShaclValidation
 pub struct ValidateShaclBuilder<'a> {
     rudof: &'a mut Rudof,
     mode: Option<&'a ShaclValidationMode>,
+    timeout: Option<Duration>,
+    node_timeout: Option<Duration>,
+    message_languages: Option<&'a [Lang]>,
+    focus: Option<&'a ShaclFocus>,
+    limits: Option<&'a ResultLimits>,
+    cancellation: Option<&'a CancellationToken>,
+    on_event: Option<&'a (dyn Fn(ShaclValidationEvent) + Sync)>,
 }
 
 impl<'a> ValidateShaclBuilder<'a> {
@@ -15,7 +26,17 @@ impl<'a> ValidateShaclBuilder<'a> {
     /// This is called internally by `Rudof::validate_shacl()` and should not
     /// be constructed directly.
     pub(crate) fn new(rudof: &'a mut Rudof) -> Self {
-        Self { rudof, mode: None }
+        Self {
+            rudof,
+            mode: None,
+            timeout: None,
+            node_timeout: None,
+            message_languages: None,
+            focus: None,
+            limits: None,
+            cancellation: None,
+            on_event: None,
+        }
     }
 
     /// Sets the validation mode.
@@ -28,8 +49,89 @@ impl<'a> ValidateShaclBuilder<'a> {
         self
     }
 
+    /// Sets the time limit for the whole validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Time after which the focus nodes not yet validated are reported as timeouts
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the time limit for the validation of each focus node.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Time after which a focus node is reported as a timeout
+    pub fn with_node_timeout(mut self, timeout: Duration) -> Self {
+        self.node_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the languages of the result messages, in order of preference.
+    ///
+    /// # Arguments
+    ///
+    /// * `langs` - Languages whose message each result keeps, the first one it has. Empty keeps them all
+    pub fn with_message_languages(mut self, langs: &'a [Lang]) -> Self {
+        self.message_languages = Some(langs);
+        self
+    }
+
+    /// Restricts the validation to some focus nodes and shapes.
+    ///
+    /// # Arguments
+    ///
+    /// * `focus` - The focus nodes, shapes and `node@shape` associations to validate
+    pub fn with_focus(mut self, focus: &'a ShaclFocus) -> Self {
+        self.focus = Some(focus);
+        self
+    }
+
+    /// Sets the limits on the number of results of the validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `limits` - Fail-fast mode, maximum number of results and caps per shape or per component
+    pub fn with_result_limits(mut self, limits: &'a ResultLimits) -> Self {
+        self.limits = Some(limits);
+        self
+    }
+
+    /// Sets a token that stops the validation when it is cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `cancellation` - The token to check while validating
+    pub fn with_cancellation(mut self, cancellation: &'a CancellationToken) -> Self {
+        self.cancellation = Some(cancellation);
+        self
+    }
+
+    /// Sets a handler called with each result as soon as it is produced and
+    /// with the progress of the validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_event` - The handler, called from several threads as shapes are validated in parallel
+    pub fn with_event_handler(mut self, on_event: &'a (dyn Fn(ShaclValidationEvent) + Sync)) -> Self {
+        self.on_event = Some(on_event);
+        self
+    }
+
     /// Executes the SHACL validation operation with the configured parameters.
     pub fn execute(self) -> Result<()> {
-        <Rudof as ShaclOperations>::validate_shacl(self.rudof, self.mode)
+        <Rudof as ShaclOperations>::validate_shacl(
+            self.rudof,
+            self.mode,
+            self.timeout,
+            self.node_timeout,
+            self.message_languages,
+            self.focus,
+            self.limits,
+            self.cancellation,
+            self.on_event,
+        )
     }
 }
@@ -0,0 +1,69 @@
+use crate::{
+    Result, Rudof, config::ValidationPolicyConfig, errors::ShaclError, formats::ValidationFailOn,
+    types::ValidationVerdict,
+};
+use shacl::ir::IRSchema;
+use shacl::types::Severity;
+
+pub fn check_shacl_validation_policy(
+    rudof: &Rudof,
+    fail_on: Option<&ValidationFailOn>,
+    max_violations: Option<usize>,
+    shapes: Option<&[String]>,
+    components: Option<&[String]>,
+) -> Result<ValidationVerdict> {
+    let report = rudof
+        .shacl_validation_results
+        .as_ref()
+        .ok_or(ShaclError::NoShaclValidationResultsAvailable)?;
+
+    let config = rudof.config.validation_policy();
+    let policy = config
+        .clone()
+        .with_fail_on(fail_on.copied().or(config.fail_on))
+        .with_max_violations(max_violations.or(config.max_violations))
+        .with_shapes(shapes.map_or_else(|| config.shapes.clone(), <[String]>::to_vec))
+        .with_components(components.map_or_else(|| config.components.clone(), <[String]>::to_vec));
+    let policy = match rudof.shacl_shapes.as_ref() {
+        Some(schema) if !policy.shapes().is_empty() => with_property_shapes(policy, schema),
+        _ => policy,
+    };
+
+    let (mut violations, mut warnings) = (0, 0);
+    for result in report.results() {
+        let shape = result.source().map(|source| source.to_string());
+        let component = result.constraint_component().to_string();
+        if !policy.applies_to(shape.as_deref(), Some(&component)) {
+            continue;
+        }
+        match result.severity() {
+            Severity::Violation => violations += 1,
+            Severity::Warning => warnings += 1,
+            _ => {},
+        }
+    }
+    Ok(policy.verdict(violations, warnings))
+}
+
+/// Results of property shapes have the property shape as source, so the
+/// property shapes of the listed shapes, often blank nodes, are listed too
+fn with_property_shapes(policy: ValidationPolicyConfig, schema: &IRSchema) -> ValidationPolicyConfig {
+    let mut pending: Vec<_> = schema
+        .iter()
+        .filter(|(id, _)| policy.applies_to(Some(&id.to_string()), None))
+        .map(|(_, shape)| shape)
+        .collect();
+    let mut shapes = policy.shapes().to_vec();
+    while let Some(shape) = pending.pop() {
+        for idx in shape.property_shapes() {
+            if let Some(property_shape) = schema.get_shape_from_idx(idx) {
+                let id = property_shape.id().to_string();
+                if !shapes.contains(&id) {
+                    shapes.push(id);
+                    pending.push(property_shape);
+                }
+            }
+        }
+    }
+    policy.with_shapes(shapes)
+}
@@ -66,14 +66,17 @@ fn read_shacl_schema(
                 message: format!("Failed to read shacl schema source '{}': {error}", schema.source_name()),
             })?;
         let header = default_prefix_header(rudof, &content, PrefixDirective::Turtle);
-        let mut prefixed_reader = std::io::Cursor::new(format!("{header}{content}").into_bytes());
-        OxigraphInMemory::from_reader(
-            &mut prefixed_reader,
-            &schema.source_name(),
-            &schema_format.try_into()?,
-            Some(base.as_str()),
-            &reader_mode.into(),
-        )
+        let mut rdf_graph = OxigraphInMemory::new();
+        rdf_graph
+            .merge_from_reader_with_header(
+                &header,
+                &mut content.as_bytes(),
+                &schema.source_name(),
+                &schema_format.try_into()?,
+                Some(base.as_str()),
+                &reader_mode.into(),
+            )
+            .map(|_| rdf_graph)
     } else {
         OxigraphInMemory::from_reader(
             &mut schema_reader,
@@ -108,6 +111,10 @@ fn read_shacl_schema(
                 error: e.to_string(),
             })?,
     );
+    rudof.shacl_shapes_source = match schema {
+        InputSpec::Path(_) | InputSpec::Url(_) => schema.as_iri().ok(),
+        InputSpec::Stdin | InputSpec::Str(_) => None,
+    };
 
     Ok(())
 }
@@ -139,6 +146,7 @@ fn extract_shacl_shapes_from_data(rudof: &mut Rudof) -> Result<()> {
                 error: e.to_string(),
             })?,
     );
+    rudof.shacl_shapes_source = None;
 
     Ok(())
 }
@@ -1,3 +1,4 @@
+mod check_shacl_validation_policy;
 mod load_shacl_schema;
 mod reset_shacl_schema;
 mod reset_shacl_validation;
@@ -5,6 +6,7 @@ mod serialize_shacl_schema;
 mod serialize_shacl_validation_results;
 mod validate_shacl;
 
+pub use check_shacl_validation_policy::check_shacl_validation_policy;
 pub use load_shacl_schema::load_shacl_schema;
 pub use reset_shacl_schema::reset_shacl_schema;
 pub use reset_shacl_validation::reset_shacl_validation;
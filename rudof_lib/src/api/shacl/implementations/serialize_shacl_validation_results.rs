@@ -1,12 +1,14 @@
 use crate::display::Table;
 use crate::{
     Result, Rudof,
+    display::{CiCase, CiLevel, CiLocation, CiOutcome, CiReport, HtmlOutcome, HtmlReport, HtmlRow, detail_lines},
     errors::ShaclError,
     formats::{ResultShaclValidationFormat, ShaclValidationSortByMode},
     utils::terminal_width,
 };
 use rudof_rdf::{rdf_core::BuildRDF, rdf_impl::OxigraphInMemory};
 use shacl::types::Severity;
+use shacl::validator::ShaclValidationEvent;
 use shacl::validator::report::ValidationReport;
 use std::io;
 
@@ -38,8 +40,28 @@ pub fn serialize_shacl_validation_results<W: io::Write>(
                 .table(writer, Some(true), Some(true), Some(terminal_width()))
                 .map_err(|e| ShaclError::FailedIoOperation { error: e.to_string() })?;
         },
+        ResultShaclValidationFormat::NdJson => {
+            serialize_shacl_validation_results_ndjson(serialize_shacl_validation_results, writer)?;
+        },
+        ResultShaclValidationFormat::Sarif => {
+            ci_report(rudof, serialize_shacl_validation_results)
+                .write_sarif(writer)
+                .map_err(|e| ShaclError::FailedIoOperation { error: e.to_string() })?;
+        },
+        ResultShaclValidationFormat::JUnit => {
+            ci_report(rudof, serialize_shacl_validation_results)
+                .write_junit(writer)
+                .map_err(|e| ShaclError::FailedIoOperation { error: e.to_string() })?;
+        },
+        ResultShaclValidationFormat::Html => {
+            html_report(rudof, serialize_shacl_validation_results)
+                .write(writer)
+                .map_err(|e| ShaclError::FailedIoOperation { error: e.to_string() })?;
+        },
         ResultShaclValidationFormat::Json => {
-            todo!("Generation of JSON for SHACL validation report is not implemented yet")
+            let json = serde_json::to_string_pretty(serialize_shacl_validation_results)
+                .map_err(|e| ShaclError::FailedIoOperation { error: e.to_string() })?;
+            writeln!(writer, "{json}").map_err(|e| ShaclError::FailedIoOperation { error: e.to_string() })?;
         },
         _ => {
             serialize_shacl_validation_results_rdf(
@@ -78,10 +100,174 @@ fn serialize_shacl_validation_results_minimal<W: io::Write>(
         )
         .map_err(|e| ShaclError::FailedIoOperation { error: e.to_string() })?;
     }
+    if shacl_validation_results.omitted_count() > 0 {
+        writeln!(
+            writer,
+            "{} more results omitted by the limits per shape or component",
+            shacl_validation_results.omitted_count()
+        )
+        .map_err(|e| ShaclError::FailedIoOperation { error: e.to_string() })?;
+    }
+    if shacl_validation_results.stopped_early() {
+        writeln!(
+            writer,
+            "Validation stopped after {} results",
+            shacl_validation_results.results().len()
+        )
+        .map_err(|e| ShaclError::FailedIoOperation { error: e.to_string() })?;
+    }
+    if !shacl_validation_results.timeouts().is_empty() {
+        writeln!(
+            writer,
+            "Validation incomplete, {} nodes timed out",
+            shacl_validation_results.timeouts().len()
+        )
+        .map_err(|e| ShaclError::FailedIoOperation { error: e.to_string() })?;
+    }
+
+    Ok(())
+}
 
+/// Writes each result, and each timed out focus node, as a line with the same
+/// shape as the events streamed while validating
+fn serialize_shacl_validation_results_ndjson<W: io::Write>(
+    shacl_validation_results: &ValidationReport,
+    writer: &mut W,
+) -> Result<()> {
+    let results = shacl_validation_results
+        .results()
+        .iter()
+        .cloned()
+        .map(ShaclValidationEvent::Result);
+    let timeouts = shacl_validation_results
+        .timeouts()
+        .iter()
+        .cloned()
+        .map(ShaclValidationEvent::Timeout);
+    for event in results.chain(timeouts) {
+        let line = serde_json::to_string(&event).map_err(|e| ShaclError::FailedIoOperation { error: e.to_string() })?;
+        writeln!(writer, "{line}").map_err(|e| ShaclError::FailedIoOperation { error: e.to_string() })?;
+    }
     Ok(())
 }
 
+/// Arranges the results as a case per result and timed out focus node, grouped
+/// by source shape and located where the failing triple, or the shape, was read.
+/// Results without a known position are located at the shapes file when it was
+/// read from one.
+///
+/// A conforming report has no results, so it is written as a single passed case.
+fn ci_report(rudof: &Rudof, shacl_validation_results: &ValidationReport) -> CiReport {
+    let location = rudof
+        .shacl_shapes_source
+        .as_ref()
+        .and_then(|source| CiLocation::from_iri(source.as_str()));
+    let nodes_pm = shacl_validation_results.nodes_prefixmap();
+    let shapes_pm = shacl_validation_results.shapes_prefixmap();
+
+    let mut report = CiReport::new(&rudof.version);
+    let results = shacl_validation_results.results().iter().map(|result| {
+        let outcome = match result.severity() {
+            Severity::Violation => CiOutcome::Failed(CiLevel::Error),
+            Severity::Warning => CiOutcome::Failed(CiLevel::Warning),
+            _ => CiOutcome::Failed(CiLevel::Note),
+        };
+        (result, outcome)
+    });
+    let timeouts = shacl_validation_results
+        .timeouts()
+        .iter()
+        .map(|timeout| (timeout, CiOutcome::Skipped));
+    for (result, outcome) in results.chain(timeouts) {
+        let rule = shapes_pm.show(result.constraint_component());
+        let message = result
+            .message()
+            .get(None)
+            .or_else(|| result.message().iter().map(|(_, message)| message).min())
+            .cloned()
+            .unwrap_or_else(|| format!("{rule} not satisfied"));
+        report.add_case(CiCase {
+            suite: result
+                .source()
+                .map(|source| shapes_pm.show(source))
+                .unwrap_or_else(|| "shapes".to_string()),
+            name: nodes_pm.show(result.focus_node()),
+            rule,
+            outcome,
+            message,
+            location: result
+                .span()
+                .or(result.shape_span())
+                .and_then(CiLocation::from_span)
+                .or_else(|| location.clone()),
+        });
+    }
+    if report.is_empty() {
+        report.add_case(CiCase {
+            suite: "shapes".to_string(),
+            name: "conforms".to_string(),
+            rule: "conforms".to_string(),
+            outcome: CiOutcome::Passed,
+            message: "Conforms".to_string(),
+            location,
+        });
+    }
+    report
+}
+
+/// Arranges the results, and the timed out focus nodes, as a row each with the
+/// messages and `sh:detail` results of the validation result as details
+fn html_report(rudof: &Rudof, shacl_validation_results: &ValidationReport) -> HtmlReport {
+    let nodes_pm = shacl_validation_results.nodes_prefixmap();
+    let shapes_pm = shacl_validation_results.shapes_prefixmap();
+    let shown = |value: String| Some(value).filter(|value| !value.is_empty());
+
+    let mut report = HtmlReport::new(
+        "SHACL validation report",
+        &rudof.version,
+        shacl_validation_results.conforms(),
+        "Severities",
+    );
+    let results = shacl_validation_results.results().iter().map(|result| {
+        let outcome = match result.severity() {
+            Severity::Violation => HtmlOutcome::Failed,
+            Severity::Warning => HtmlOutcome::Warning,
+            _ => HtmlOutcome::Info,
+        };
+        (result, shapes_pm.qualify(&result.severity().into()), outcome)
+    });
+    let timeouts = shacl_validation_results
+        .timeouts()
+        .iter()
+        .map(|timeout| (timeout, "Timeout".to_string(), HtmlOutcome::Skipped));
+    for (result, label, outcome) in results.chain(timeouts) {
+        let mut messages: Vec<_> = result.message().iter().collect();
+        messages.sort();
+        let message = messages
+            .iter()
+            .map(|(lang, message)| match lang {
+                Some(lang) => format!("{message} ({lang})"),
+                None => message.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("; ");
+        let details = Some(detail_lines(result, nodes_pm).join("\n")).filter(|details| !details.is_empty());
+        report.add_row(HtmlRow {
+            node: nodes_pm.show(result.focus_node()),
+            shape: shown(shapes_pm.show(&result.source())).unwrap_or_else(|| "shapes".to_string()),
+            label,
+            outcome,
+            component: Some(shapes_pm.show(result.constraint_component())),
+            path: shown(shapes_pm.show(&result.path())),
+            value: shown(nodes_pm.show(&result.value())),
+            location: result.span().map(|span| span.to_string()),
+            message,
+            details,
+        });
+    }
+    report
+}
+
 fn serialize_shacl_validation_results_rdf<W: io::Write>(
     shacl_validation_results: &ValidationReport,
     result_shacl_validation_format: ResultShaclValidationFormat,
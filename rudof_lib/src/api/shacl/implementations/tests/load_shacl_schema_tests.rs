@@ -59,6 +59,7 @@ fn test_extract_shacl_shapes_from_loaded_data() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -120,6 +121,7 @@ fn test_extract_shacl_shapes_mixed_data() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -162,6 +164,7 @@ fn test_extract_shacl_shapes_from_data_no_shapes() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -252,6 +255,7 @@ fn test_extract_and_serialize_complex_shapes() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -299,6 +303,7 @@ fn test_extract_shapes_then_load_separate_schema() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -377,6 +382,7 @@ fn test_extract_shacl_shapes_with_lists() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -437,6 +443,7 @@ fn test_extract_shapes_multiple_data_sources() {
         None,
         None,
         None,
+        None,
         Some(true),
         None,
     )
@@ -486,6 +493,7 @@ fn test_extract_shapes_with_merge() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -513,6 +521,7 @@ fn test_extract_shapes_with_merge() {
         None,
         None,
         None,
+        None,
         Some(true),
         None,
     )
@@ -1,13 +1,20 @@
 use crate::{
     Rudof, RudofConfig,
+    api::core::implementations::serialize_validation_profile,
     api::data::implementations::load_data,
+    api::shacl::implementations::check_shacl_validation_policy::check_shacl_validation_policy,
     api::shacl::implementations::load_shacl_schema::load_shacl_schema,
     api::shacl::implementations::serialize_shacl_validation_results::serialize_shacl_validation_results,
     api::shacl::implementations::validate_shacl::validate_shacl,
     formats::{
-        DataFormat, InputSpec, ResultShaclValidationFormat, ShaclFormat, ShaclValidationMode, ShaclValidationSortByMode,
+        DataFormat, InputSpec, ResultShaclValidationFormat, ShaclFormat, ShaclValidationMode,
+        ShaclValidationSortByMode, ValidationFailOn, ValidationProfileFormat,
     },
+    types::{ShaclFocus, ValidationVerdict},
 };
+use rudof_rdf::rdf_core::term::literal::Lang;
+use shacl::validator::{ResultLimits, ShaclValidationEvent};
+use std::time::Duration;
 
 /// Helper: serialize validation results to string
 fn serialize_validation_to_string(
@@ -78,11 +85,12 @@ fn test_validate_shacl_conforming_data() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
     // Validate
-    validate_shacl(&mut rudof, None).unwrap();
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
 
     let result = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Minimal));
 
@@ -147,11 +155,12 @@ fn test_validate_shacl_non_conforming_data() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
     // Validate
-    validate_shacl(&mut rudof, None).unwrap();
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
 
     let result = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Minimal));
 
@@ -188,7 +197,7 @@ fn test_validate_shacl_without_data() {
     load_shacl_schema(&mut rudof, Some(&schema), Some(&ShaclFormat::Turtle), None, None).unwrap();
 
     // Try to validate without data
-    let result = validate_shacl(&mut rudof, None);
+    let result = validate_shacl(&mut rudof, None, None, None, None, None, None, None, None);
 
     assert!(result.is_err());
 }
@@ -217,11 +226,12 @@ fn test_validate_shacl_without_schema() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
     // Try to validate without schema
-    let result = validate_shacl(&mut rudof, None);
+    let result = validate_shacl(&mut rudof, None, None, None, None, None, None, None, None);
 
     assert!(result.is_err());
 }
@@ -268,10 +278,11 @@ fn test_validate_shacl_datatype_violations() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
-    validate_shacl(&mut rudof, None).unwrap();
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
 
     let result = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Minimal));
 
@@ -331,10 +342,11 @@ fn test_validate_shacl_min_max_violations() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
-    validate_shacl(&mut rudof, None).unwrap();
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
 
     let result = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Minimal));
 
@@ -394,10 +406,11 @@ fn test_validate_shacl_pattern_violations() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
-    validate_shacl(&mut rudof, None).unwrap();
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
 
     let result = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Minimal));
 
@@ -464,10 +477,11 @@ fn test_validate_shacl_node_shape_violations() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
-    validate_shacl(&mut rudof, None).unwrap();
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
 
     let result = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Minimal));
 
@@ -520,10 +534,11 @@ fn test_serialize_validation_results_compact() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
-    validate_shacl(&mut rudof, None).unwrap();
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
 
     let result = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Compact));
 
@@ -577,10 +592,11 @@ fn test_serialize_validation_results_details() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
-    validate_shacl(&mut rudof, None).unwrap();
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
 
     let result = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Details));
 
@@ -635,10 +651,11 @@ fn test_serialize_validation_results_turtle() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
-    validate_shacl(&mut rudof, None).unwrap();
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
 
     let result = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Turtle));
 
@@ -705,11 +722,23 @@ fn test_validate_shacl_with_validation_mode() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
     // Validate with default mode
-    validate_shacl(&mut rudof, Some(&ShaclValidationMode::default())).unwrap();
+    validate_shacl(
+        &mut rudof,
+        Some(&ShaclValidationMode::default()),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
 
     let result = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Minimal));
 
@@ -778,10 +807,11 @@ fn test_validate_multiple_violations() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
-    validate_shacl(&mut rudof, None).unwrap();
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
 
     let result = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Minimal));
 
@@ -793,3 +823,867 @@ fn test_validate_multiple_violations() {
         result
     );
 }
+
+#[test]
+fn test_validate_shacl_node_timeout_reports_incomplete() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let schema = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:PersonShape
+            a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [ sh:path ex:name ; sh:minCount 1 ] .
+        "#,
+    );
+    load_shacl_schema(&mut rudof, Some(&schema), Some(&ShaclFormat::Turtle), None, None).unwrap();
+
+    let data = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:Alice a ex:Person ; ex:name "Alice Smith" .
+        "#,
+    );
+    load_data(
+        &mut rudof,
+        Some(&[data]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    validate_shacl(
+        &mut rudof,
+        None,
+        None,
+        Some(Duration::ZERO),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let result = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Minimal));
+    assert!(result.contains("Does not conform"));
+    assert!(result.contains("1 nodes timed out"));
+}
+
+#[test]
+fn test_validate_shacl_streams_events() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let schema = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:PersonShape
+            a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [ sh:path ex:name ; sh:minCount 1 ] .
+        "#,
+    );
+    load_shacl_schema(&mut rudof, Some(&schema), Some(&ShaclFormat::Turtle), None, None).unwrap();
+
+    let data = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:Alice a ex:Person ; ex:name "Alice Smith" .
+        ex:Bob a ex:Person .
+        "#,
+    );
+    load_data(
+        &mut rudof,
+        Some(&[data]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let events = std::sync::Mutex::new(Vec::new());
+    validate_shacl(
+        &mut rudof,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(&|event| events.lock().unwrap().push(event)),
+    )
+    .unwrap();
+
+    let events = events.into_inner().unwrap();
+    let results = events
+        .iter()
+        .filter(|event| matches!(event, ShaclValidationEvent::Result(_)))
+        .count();
+    assert_eq!(
+        results,
+        rudof.shacl_validation_results.as_ref().unwrap().results().len()
+    );
+    assert!(events.iter().any(|event| matches!(
+        event,
+        ShaclValidationEvent::Progress {
+            focus_nodes_done: 2,
+            focus_nodes_found: 2,
+            ..
+        }
+    )));
+
+    let serialized = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::NdJson));
+    let lines: Vec<serde_json::Value> = serialized
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0]["type"], "result");
+    assert_eq!(lines[0]["result"]["resultSeverity"], "Violation");
+    assert!(lines[0]["result"]["focusNode"].as_str().unwrap().contains("Bob"));
+}
+
+#[test]
+fn test_serialize_validation_results_sarif_and_junit() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let shapes_path = std::env::temp_dir().join("rudof_test_serialize_validation_results_sarif_and_junit.ttl");
+    std::fs::write(
+        &shapes_path,
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:PersonShape
+            a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [
+                sh:path ex:name ;
+                sh:minCount 1 ;
+            ] ;
+            sh:property [
+                sh:path ex:age ;
+                sh:datatype xsd:integer ;
+                sh:severity sh:Warning ;
+            ] .
+        "#,
+    )
+    .unwrap();
+    let schema = InputSpec::Path(shapes_path.clone());
+    load_shacl_schema(&mut rudof, Some(&schema), Some(&ShaclFormat::Turtle), None, None).unwrap();
+
+    let data = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:Alice a ex:Person ; ex:age "unknown" .
+        "#,
+    );
+    load_data(
+        &mut rudof,
+        Some(&[data]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
+
+    // The missing name is a violation and the wrong datatype a warning
+    let sarif = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Sarif));
+    let sarif: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+    let results = sarif["runs"][0]["results"].as_array().unwrap();
+    let mut levels: Vec<_> = results.iter().map(|result| result["level"].as_str().unwrap()).collect();
+    levels.sort();
+    assert_eq!(levels, ["error", "warning"]);
+    let uri = results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"]
+        .as_str()
+        .unwrap();
+    assert!(uri.ends_with("rudof_test_serialize_validation_results_sarif_and_junit.ttl"));
+
+    // JUnit has no warnings, so only the violation is a failure
+    let junit = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::JUnit));
+    assert!(junit.contains(r#"<testsuites name="rudof" tests="2" failures="1" skipped="0">"#));
+    assert_eq!(junit.matches("<failure ").count(), 1);
+    assert_eq!(junit.matches("<system-out>").count(), 1);
+
+    std::fs::remove_file(shapes_path).unwrap();
+}
+
+#[test]
+fn test_serialize_validation_results_locations() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let schema = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:PersonShape a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [ sh:path ex:name ; sh:minCount 1 ] ;
+            sh:property [ sh:path ex:age ; sh:datatype xsd:integer ] .
+        "#,
+    );
+    load_shacl_schema(&mut rudof, Some(&schema), Some(&ShaclFormat::Turtle), None, None).unwrap();
+
+    let data_path = std::env::temp_dir().join("rudof_test_serialize_validation_results_locations.ttl");
+    std::fs::write(
+        &data_path,
+        "@prefix ex: <http://example.org/> .\n\nex:Alice a ex:Person ;\n    ex:age \"unknown\" .\n",
+    )
+    .unwrap();
+    let data = InputSpec::Path(data_path.clone());
+    load_data(
+        &mut rudof,
+        Some(&[data]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
+
+    // The missing name is located at the focus node and the wrong age at its triple
+    let sarif = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Sarif));
+    let sarif: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+    let mut regions: Vec<_> = sarif["runs"][0]["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|result| {
+            let region = &result["locations"][0]["physicalLocation"]["region"];
+            (
+                region["startLine"].as_u64().unwrap(),
+                region["startColumn"].as_u64().unwrap(),
+            )
+        })
+        .collect();
+    regions.sort();
+    assert_eq!(regions, [(3, 12), (4, 12)]);
+
+    let junit = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::JUnit));
+    assert!(junit.contains(r#"line="4""#));
+
+    let details = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Details));
+    assert!(details.contains("Location"));
+    assert!(details.contains("rudof_test_serialize_validation_results_locations.ttl:4:12"));
+
+    std::fs::remove_file(data_path).unwrap();
+}
+
+#[test]
+fn test_serialize_conforming_results_junit() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let schema = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:PersonShape a sh:NodeShape ; sh:targetClass ex:Person .
+        "#,
+    );
+    load_shacl_schema(&mut rudof, Some(&schema), Some(&ShaclFormat::Turtle), None, None).unwrap();
+    let data = InputSpec::str("@prefix ex: <http://example.org/> . ex:Alice a ex:Person .");
+    load_data(
+        &mut rudof,
+        Some(&[data]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
+
+    let junit = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::JUnit));
+    assert!(junit.contains(r#"<testsuites name="rudof" tests="1" failures="0" skipped="0">"#));
+    assert!(!junit.contains("file="));
+
+    let sarif = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Sarif));
+    let sarif: serde_json::Value = serde_json::from_str(&sarif).unwrap();
+    assert!(sarif["runs"][0]["results"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn test_serialize_validation_results_html() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let schema = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:PersonShape
+            a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [
+                sh:path ex:name ;
+                sh:minCount 1 ;
+                sh:message "A person needs a name" ;
+            ] ;
+            sh:property [
+                sh:path ex:age ;
+                sh:datatype xsd:integer ;
+                sh:severity sh:Warning ;
+            ] .
+        "#,
+    );
+    load_shacl_schema(&mut rudof, Some(&schema), Some(&ShaclFormat::Turtle), None, None).unwrap();
+
+    let data = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:Alice a ex:Person ; ex:age "unknown" .
+        ex:Bob a ex:Person .
+        "#,
+    );
+    load_data(
+        &mut rudof,
+        Some(&[data]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
+
+    let result = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Html));
+
+    assert!(result.starts_with("<!DOCTYPE html>"));
+    assert!(!result.contains("<script src"));
+    assert!(result.contains("<h3>Constraint components</h3>"));
+    assert!(result.contains("sh:MinCountConstraintComponent"));
+    assert!(result.contains("A person needs a name"));
+    assert_eq!(result.matches(r#"<tr class="failed""#).count(), 2);
+    assert_eq!(result.matches(r#"<tr class="warning""#).count(), 1);
+    assert_eq!(result.matches("<details id=").count(), 2);
+}
+
+#[test]
+fn test_serialize_validation_results_json() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let schema = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:PersonShape
+            a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [
+                sh:path ex:name ;
+                sh:minCount 1 ;
+            ] .
+        "#,
+    );
+    load_shacl_schema(&mut rudof, Some(&schema), Some(&ShaclFormat::Turtle), None, None).unwrap();
+
+    let data = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:Alice a ex:Person ; ex:name "Alice" .
+        ex:Bob a ex:Person .
+        "#,
+    );
+    load_data(
+        &mut rudof,
+        Some(&[data]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
+
+    let result = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Json));
+    let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+
+    assert_eq!(json["conforms"], false);
+    assert_eq!(json["results"].as_array().unwrap().len(), 1);
+    assert_eq!(json["results"][0]["focusNode"], "http://example.org/Bob");
+    assert!(json["timeouts"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn test_check_shacl_validation_policy() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let schema = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:PersonShape
+            a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [
+                sh:path ex:name ;
+                sh:minCount 1 ;
+            ] ;
+            sh:property [
+                sh:path ex:age ;
+                sh:datatype xsd:integer ;
+                sh:severity sh:Warning ;
+            ] .
+        "#,
+    );
+    load_shacl_schema(&mut rudof, Some(&schema), Some(&ShaclFormat::Turtle), None, None).unwrap();
+
+    let data = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:Alice a ex:Person ; ex:name "Alice" ; ex:age "unknown" .
+        ex:Bob a ex:Person .
+        ex:Carol a ex:Person .
+        "#,
+    );
+    load_data(
+        &mut rudof,
+        Some(&[data]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
+
+    // With the default policy the validation never fails
+    assert!(
+        check_shacl_validation_policy(&rudof, None, None, None, None)
+            .unwrap()
+            .passed()
+    );
+    assert_eq!(
+        check_shacl_validation_policy(&rudof, Some(&ValidationFailOn::Violations), None, None, None).unwrap(),
+        ValidationVerdict::Violations {
+            violations: 2,
+            allowed: 0
+        }
+    );
+    assert!(
+        check_shacl_validation_policy(&rudof, Some(&ValidationFailOn::Violations), Some(2), None, None)
+            .unwrap()
+            .passed()
+    );
+    assert_eq!(
+        check_shacl_validation_policy(&rudof, Some(&ValidationFailOn::Warnings), Some(2), None, None).unwrap(),
+        ValidationVerdict::Warnings { warnings: 1 }
+    );
+
+    // Only the results of the listed components are counted
+    let datatype = vec!["sh:DatatypeConstraintComponent".to_string()];
+    assert!(
+        check_shacl_validation_policy(&rudof, None, None, None, Some(&datatype))
+            .unwrap()
+            .passed()
+    );
+    // The results of the property shapes are counted with the node shape that declares them
+    let person = vec!["http://example.org/PersonShape".to_string()];
+    assert!(
+        !check_shacl_validation_policy(&rudof, None, None, Some(&person), None)
+            .unwrap()
+            .passed()
+    );
+    let shapes = vec!["http://example.org/OtherShape".to_string()];
+    assert!(
+        check_shacl_validation_policy(&rudof, None, None, Some(&shapes), None)
+            .unwrap()
+            .passed()
+    );
+}
+
+#[test]
+fn test_validate_shacl_result_messages() {
+    let schema = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+
+        ex:PersonShape
+            a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [
+                sh:path ex:name ;
+                sh:minCount 1 ;
+            ] ;
+            sh:property [
+                sh:path ex:age ;
+                sh:maxInclusive 150 ;
+                sh:message "{$this} is {$value}, older than {$maxInclusive}"@en ;
+            ] .
+        "#,
+    );
+    let data = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:Alice a ex:Person ; ex:age 200 .
+        "#,
+    );
+    let messages = |langs: Option<&[Lang]>| {
+        let mut rudof = Rudof::new(RudofConfig::default());
+        load_shacl_schema(&mut rudof, Some(&schema), Some(&ShaclFormat::Turtle), None, None).unwrap();
+        load_data(
+            &mut rudof,
+            Some(std::slice::from_ref(&data)),
+            Some(&DataFormat::Turtle),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        validate_shacl(&mut rudof, None, None, None, langs, None, None, None, None).unwrap();
+        let result = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Json));
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let mut messages: Vec<String> = json["results"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|result| result["resultMessage"].as_str().unwrap().to_string())
+            .collect();
+        messages.sort();
+        messages
+    };
+
+    // The messages of the shapes are templates and the components have default messages
+    assert_eq!(
+        messages(None),
+        vec![
+            "ex:Alice has less than 1 values for ex:name",
+            "ex:Alice is 200, older than 150"
+        ]
+    );
+    // The default messages are available in other languages
+    let spanish = [Lang::new("es").unwrap()];
+    assert_eq!(
+        messages(Some(&spanish)),
+        vec![
+            "ex:Alice is 200, older than 150",
+            "ex:Alice tiene menos de 1 valores para ex:name"
+        ]
+    );
+}
+
+#[test]
+fn test_validate_shacl_focus() {
+    let schema = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:PersonShape
+            a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [
+                sh:path ex:name ;
+                sh:minCount 1 ;
+            ] .
+
+        ex:CompanyShape
+            a sh:NodeShape ;
+            sh:targetClass ex:Company ;
+            sh:property [
+                sh:path ex:employee ;
+                sh:node ex:PersonShape ;
+            ] .
+        "#,
+    );
+    let data = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:Alice a ex:Person ; ex:name "Alice" .
+        ex:Bob a ex:Person .
+        ex:Carol ex:age 30 .
+        ex:Acme a ex:Company ; ex:employee ex:Bob .
+        "#,
+    );
+    let focus_nodes = |focus: &ShaclFocus| {
+        let mut rudof = Rudof::new(RudofConfig::default());
+        load_shacl_schema(&mut rudof, Some(&schema), Some(&ShaclFormat::Turtle), None, None).unwrap();
+        load_data(
+            &mut rudof,
+            Some(std::slice::from_ref(&data)),
+            Some(&DataFormat::Turtle),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        validate_shacl(&mut rudof, None, None, None, None, Some(focus), None, None, None).unwrap();
+        let result = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Json));
+        let json: serde_json::Value = serde_json::from_str(&result).unwrap();
+        let mut nodes: Vec<String> = json["results"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|result| result["focusNode"].as_str().unwrap().to_string())
+            .collect();
+        nodes.sort();
+        nodes
+    };
+
+    assert_eq!(
+        focus_nodes(&ShaclFocus::new()),
+        vec!["http://example.org/Acme", "http://example.org/Bob"]
+    );
+    // Selected nodes restrict the targets
+    assert_eq!(
+        focus_nodes(&ShaclFocus::new().with_nodes(&["ex:Alice".to_string()])),
+        Vec::<String>::new()
+    );
+    assert_eq!(
+        focus_nodes(&ShaclFocus::new().with_nodes(&["<http://example.org/Acme>".to_string()])),
+        vec!["http://example.org/Acme"]
+    );
+    // Selected shapes validate their targets and the shapes they depend on when needed
+    assert_eq!(
+        focus_nodes(&ShaclFocus::new().with_shapes(&["ex:CompanyShape".to_string()])),
+        vec!["http://example.org/Acme"]
+    );
+    // Associations validate a node whatever the targets
+    let shape_map = ShaclFocus::new()
+        .with_shape_map(&["ex:Carol@ex:PersonShape".to_string()])
+        .unwrap();
+    assert_eq!(focus_nodes(&shape_map), vec!["http://example.org/Carol"]);
+    assert!(ShaclFocus::new().with_shape_map(&["ex:Carol".to_string()]).is_err());
+}
+
+#[test]
+fn test_validate_shacl_result_limits() {
+    let schema = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:PersonShape
+            a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [
+                sh:path ex:name ;
+                sh:minCount 1 ;
+            ] .
+        "#,
+    );
+    let data = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:Alice a ex:Person .
+        ex:Bob a ex:Person .
+        ex:Carol a ex:Person .
+        "#,
+    );
+    let validate = |limits: &ResultLimits| {
+        let mut rudof = Rudof::new(RudofConfig::default());
+        load_shacl_schema(&mut rudof, Some(&schema), Some(&ShaclFormat::Turtle), None, None).unwrap();
+        load_data(
+            &mut rudof,
+            Some(std::slice::from_ref(&data)),
+            Some(&DataFormat::Turtle),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        validate_shacl(&mut rudof, None, None, None, None, None, Some(limits), None, None).unwrap();
+        let result = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Json));
+        serde_json::from_str::<serde_json::Value>(&result).unwrap()
+    };
+
+    let json = validate(&ResultLimits::new().with_fail_fast(true));
+    assert_eq!(json["results"].as_array().unwrap().len(), 1);
+    assert_eq!(json["stoppedEarly"], true);
+
+    let json = validate(&ResultLimits::new().with_max_results_per_shape(Some(2)));
+    assert_eq!(json["results"].as_array().unwrap().len(), 2);
+    assert_eq!(json["omitted"][0]["count"], 1);
+    assert!(json.get("stoppedEarly").is_none());
+}
+
+#[test]
+fn test_validate_shacl_or_details() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+    let schema = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:ContactShape
+            a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:or (
+                [ sh:path ex:email ; sh:minCount 1 ]
+                [ sh:path ex:phone ; sh:minCount 1 ]
+            ) .
+        "#,
+    );
+    let data = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:Alice a ex:Person .
+        "#,
+    );
+    load_shacl_schema(&mut rudof, Some(&schema), Some(&ShaclFormat::Turtle), None, None).unwrap();
+    load_data(
+        &mut rudof,
+        Some(std::slice::from_ref(&data)),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
+
+    let turtle = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Turtle));
+    assert!(turtle.contains("sh:detail"));
+    let details = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Details));
+    assert!(details.contains("> sh:MinCountConstraintComponent on ex:Alice at ex:phone"));
+    let html = serialize_validation_to_string(&rudof, None, Some(ResultShaclValidationFormat::Html));
+    assert!(html.contains("&gt; sh:MinCountConstraintComponent"));
+}
+
+#[test]
+fn test_validate_shacl_profile() {
+    let schema = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+
+        ex:PersonShape
+            a sh:NodeShape ;
+            sh:targetClass ex:Person ;
+            sh:property [
+                sh:path ex:name ;
+                sh:minCount 1 ;
+            ] .
+        "#,
+    );
+    let data = InputSpec::str(
+        r#"
+        @prefix ex: <http://example.org/> .
+
+        ex:Alice a ex:Person ; ex:name "Alice" .
+        ex:Bob a ex:Person .
+        "#,
+    );
+    let mut rudof = Rudof::new(RudofConfig::default());
+    load_shacl_schema(&mut rudof, Some(&schema), Some(&ShaclFormat::Turtle), None, None).unwrap();
+    load_data(
+        &mut rudof,
+        Some(std::slice::from_ref(&data)),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
+    assert!(rudof.validation_profile().is_none());
+    assert!(serialize_validation_profile(&rudof, None, &mut Vec::new()).is_err());
+
+    rudof.set_validation_profiling(true);
+    validate_shacl(&mut rudof, None, None, None, None, None, None, None, None).unwrap();
+    let serialize = |format: ValidationProfileFormat| {
+        let mut buffer = Vec::new();
+        serialize_validation_profile(&rudof, Some(&format), &mut buffer).unwrap();
+        String::from_utf8(buffer).unwrap()
+    };
+
+    let json: serde_json::Value = serde_json::from_str(&serialize(ValidationProfileFormat::Json)).unwrap();
+    let entries = json.as_array().unwrap();
+    assert!(entries.iter().any(|entry| entry["shape"] == "ex:PersonShape"
+        && entry.get("component").is_none()
+        && entry["evaluations"] == 1));
+    assert!(
+        entries
+            .iter()
+            .any(|entry| entry["component"] == "sh:MinCountConstraintComponent" && entry["timeMs"].is_number())
+    );
+
+    let table = serialize(ValidationProfileFormat::Table);
+    assert!(table.contains("Evaluations"));
+    assert!(table.contains("ex:PersonShape"));
+
+    let folded = serialize(ValidationProfileFormat::Folded);
+    assert!(
+        folded
+            .lines()
+            .any(|line| line.starts_with("ex:PersonShape;") && line.contains(";sh:MinCountConstraintComponent "))
+    );
+}
@@ -2,24 +2,98 @@ use crate::{
     Result, Rudof,
     errors::{DataError, ShaclError},
     formats::ShaclValidationMode,
-    types::Data,
+    types::{Data, ShaclFocus},
 };
+use rudof_rdf::rdf_core::Rdf;
+use rudof_rdf::rdf_core::term::literal::Lang;
+use rudof_rdf::rdf_core::utils::{CancellationToken, Profiler, ValidationBudget};
 use shacl::ir::IRSchema;
 use shacl::validator::processor::{GraphValidation, ShaclProcessor};
 use shacl::validator::store::Graph;
+use shacl::validator::{ResultLimits, ShaclValidationEvent, ValidationFocus};
+use std::time::Duration;
 
-pub fn validate_shacl(rudof: &mut Rudof, mode: Option<&ShaclValidationMode>) -> Result<()> {
+pub fn validate_shacl(
+    rudof: &mut Rudof,
+    mode: Option<&ShaclValidationMode>,
+    timeout: Option<Duration>,
+    node_timeout: Option<Duration>,
+    message_languages: Option<&[Lang]>,
+    focus: Option<&ShaclFocus>,
+    limits: Option<&ResultLimits>,
+    cancellation: Option<&CancellationToken>,
+    on_event: Option<&(dyn Fn(ShaclValidationEvent) + Sync)>,
+) -> Result<()> {
+    let pg2rdf_config = rudof.config.pg2rdf().clone();
+    let config = rudof.config.shacl();
+    // Each validation records a profile of its own
+    let profiler = if rudof.profile_validation {
+        Profiler::new()
+    } else {
+        Profiler::disabled()
+    };
+    let mut budget = ValidationBudget::new()
+        .with_timeout(timeout.or(config.timeout()))
+        .with_node_timeout(node_timeout.or(config.node_timeout()))
+        .with_profiler(profiler.clone());
+    if let Some(cancellation) = cancellation {
+        budget = budget.with_cancellation(cancellation.clone());
+    }
+    let message_languages = message_languages.unwrap_or(config.message_languages()).to_vec();
+    let limits = limits.copied().unwrap_or_default();
+    let limits = limits.with_detail_depth(limits.detail_depth().or(Some(config.detail_depth())));
     let (data, shacl_schema_ir) = validate_loaded_data_schema_and_shapes(rudof)?;
 
+    // The focus nodes and shapes can use the prefixes of the data and of the shapes graph
+    let focus = match focus {
+        Some(focus) => {
+            let mut prefixmap = shacl_schema_ir.prefix_map().clone();
+            if let Data::RDFData(rdf_data) = &data
+                && let Some(data_prefixmap) = rdf_data.prefixmap()
+            {
+                prefixmap.merge(data_prefixmap);
+            }
+            focus.resolve(&prefixmap)?
+        },
+        None => ValidationFocus::default(),
+    };
+
     let mode = mode.copied().unwrap_or_default();
 
-    let graph: Graph = data.unwrap_rdf_mut().clone().into();
+    // Property graphs are validated through their RDF representation
+    let graph: Graph = match data {
+        Data::RDFData(rdf_data) => {
+            // The data is cloned, so the token only aborts the requests to SPARQL endpoints made by this validation
+            let mut rdf_data = rdf_data.as_ref().clone();
+            rdf_data.set_cancellation(&budget.start_run());
+            rdf_data.into()
+        },
+        Data::PGData(pg) => Data::pg_to_rdf(pg, &pg2rdf_config)?.into(),
+    };
     let mut validator: GraphValidation = graph.into();
 
-    let result = ShaclProcessor::validate(&mut validator, shacl_schema_ir, &mode.into())
-        .map_err(|e| ShaclError::FailedShaclValidation { error: e.to_string() })?;
+    // Streamed results keep only the message in the preferred language, as the ones of the report
+    let on_event = on_event.unwrap_or(&|_| {});
+    let on_event = |event| match event {
+        ShaclValidationEvent::Result(result) => on_event(ShaclValidationEvent::Result(
+            result.with_message_languages(&message_languages),
+        )),
+        event => on_event(event),
+    };
+
+    let result = ShaclProcessor::validate_focused(
+        &mut validator,
+        shacl_schema_ir,
+        &mode.into(),
+        &focus,
+        &limits,
+        &budget,
+        &on_event,
+    );
+    rudof.validation_profile = profiler.is_enabled().then_some(profiler);
+    let result = result.map_err(|e| ShaclError::FailedShaclValidation { error: e.to_string() })?;
 
-    rudof.shacl_validation_results = Some(result);
+    rudof.shacl_validation_results = Some(result.with_message_languages(&message_languages));
 
     Ok(())
 }
@@ -27,10 +101,6 @@ pub fn validate_shacl(rudof: &mut Rudof, mode: Option<&ShaclValidationMode>) ->
 fn validate_loaded_data_schema_and_shapes(rudof: &mut Rudof) -> Result<(&mut Data, &IRSchema)> {
     let data = rudof.data.as_mut().ok_or(Box::new(DataError::NoDataLoaded))?;
 
-    if !data.is_rdf() {
-        Err(Box::new(DataError::NoRdfDataLoaded))?
-    }
-
     let shacl_schema_ir = rudof.shacl_shapes.as_ref().ok_or(ShaclError::NoShaclShapesLoaded)?;
 
     Ok((data, shacl_schema_ir))
@@ -1,15 +1,20 @@
 use crate::{
     Result,
     api::shacl::implementations::{
-        load_shacl_schema, reset_shacl_schema, reset_shacl_validation, serialize_shacl_schema,
-        serialize_shacl_validation_results, validate_shacl,
+        check_shacl_validation_policy, load_shacl_schema, reset_shacl_schema, reset_shacl_validation,
+        serialize_shacl_schema, serialize_shacl_validation_results, validate_shacl,
     },
     formats::{
         DataReaderMode, InputSpec, ResultShaclValidationFormat, ShaclFormat, ShaclValidationMode,
-        ShaclValidationSortByMode,
+        ShaclValidationSortByMode, ValidationFailOn,
     },
+    types::{ShaclFocus, ValidationVerdict},
 };
+use rudof_rdf::rdf_core::term::literal::Lang;
+use rudof_rdf::rdf_core::utils::CancellationToken;
+use shacl::validator::{ResultLimits, ShaclValidationEvent};
 use std::io;
+use std::time::Duration;
 
 /// Operations for SHACL (Shapes Constraint Language) validation.
 pub trait ShaclOperations {
@@ -61,11 +66,36 @@ pub trait ShaclOperations {
     /// # Arguments
     ///
     /// * `mode` - Optional validation mode (uses default if None)
+    /// * `timeout` - Optional limit for the whole validation (uses the configured one if None)
+    /// * `node_timeout` - Optional limit for each focus node (uses the configured one if None)
+    /// * `message_languages` - Optional languages of the result messages in order of preference
+    ///   (uses the configured ones if None)
+    /// * `focus` - Optional focus nodes and shapes the validation is restricted to
+    ///   (validates every shape with targets against all its targets if None)
+    /// * `limits` - Optional limits on the number of results (keeps every result if None)
+    /// * `cancellation` - Optional token to stop the validation from another thread
+    /// * `on_event` - Optional handler called with each result as soon as it is produced
+    ///   and with the progress of the validation
+    ///
+    /// The focus nodes that are not validated when a time limit runs out, or
+    /// when `cancellation` is cancelled, are reported as timeouts. The ones that
+    /// are not validated because the maximum number of results was reached are
+    /// left out of the report, which is marked as stopped early.
     ///
     /// # Errors
     ///
     /// Returns an error if no SHACL schema or shapes is loaded.
-    fn validate_shacl(&mut self, mode: Option<&ShaclValidationMode>) -> Result<()>;
+    fn validate_shacl(
+        &mut self,
+        mode: Option<&ShaclValidationMode>,
+        timeout: Option<Duration>,
+        node_timeout: Option<Duration>,
+        message_languages: Option<&[Lang]>,
+        focus: Option<&ShaclFocus>,
+        limits: Option<&ResultLimits>,
+        cancellation: Option<&CancellationToken>,
+        on_event: Option<&(dyn Fn(ShaclValidationEvent) + Sync)>,
+    ) -> Result<()>;
 
     /// Serializes the SHACL validation results to a writer.
     ///
@@ -85,6 +115,28 @@ pub trait ShaclOperations {
         writer: &mut W,
     ) -> Result<()>;
 
+    /// Checks the SHACL validation results against the validation policy.
+    ///
+    /// Each argument overrides the corresponding entry of the configured policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `fail_on` - Optional severity that makes the validation fail
+    /// * `max_violations` - Optional number of violations that are accepted
+    /// * `shapes` - Optional source shapes whose results are counted
+    /// * `components` - Optional constraint components whose results are counted
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no validation results are available.
+    fn check_shacl_validation_policy(
+        &self,
+        fail_on: Option<&ValidationFailOn>,
+        max_violations: Option<usize>,
+        shapes: Option<&[String]>,
+        components: Option<&[String]>,
+    ) -> Result<ValidationVerdict>;
+
     /// Resets the SHACL validation.
     fn reset_shacl_validation(&mut self);
 }
@@ -108,8 +160,28 @@ impl ShaclOperations for crate::Rudof {
         reset_shacl_schema(self)
     }
 
-    fn validate_shacl(&mut self, mode: Option<&ShaclValidationMode>) -> Result<()> {
-        validate_shacl(self, mode)
+    fn validate_shacl(
+        &mut self,
+        mode: Option<&ShaclValidationMode>,
+        timeout: Option<Duration>,
+        node_timeout: Option<Duration>,
+        message_languages: Option<&[Lang]>,
+        focus: Option<&ShaclFocus>,
+        limits: Option<&ResultLimits>,
+        cancellation: Option<&CancellationToken>,
+        on_event: Option<&(dyn Fn(ShaclValidationEvent) + Sync)>,
+    ) -> Result<()> {
+        validate_shacl(
+            self,
+            mode,
+            timeout,
+            node_timeout,
+            message_languages,
+            focus,
+            limits,
+            cancellation,
+            on_event,
+        )
     }
 
     fn serialize_shacl_validation_results<W: io::Write>(
@@ -126,6 +198,16 @@ impl ShaclOperations for crate::Rudof {
         )
     }
 
+    fn check_shacl_validation_policy(
+        &self,
+        fail_on: Option<&ValidationFailOn>,
+        max_violations: Option<usize>,
+        shapes: Option<&[String]>,
+        components: Option<&[String]>,
+    ) -> Result<ValidationVerdict> {
+        check_shacl_validation_policy(self, fail_on, max_violations, shapes, components)
+    }
+
     fn reset_shacl_validation(&mut self) {
         reset_shacl_validation(self)
     }
@@ -1,5 +1,6 @@
 mod add_node_shape_to_shapemap;
 mod check_shex_schema;
+mod check_shex_validation_policy;
 mod compile_shex_schema_to_file;
 mod load_shapemap;
 mod load_shex_schema;
@@ -14,6 +15,7 @@ mod validate_shex;
 
 pub use add_node_shape_to_shapemap::AddNodeShapeToShapemapBuilder;
 pub use check_shex_schema::CheckShexSchemaBuilder;
+pub use check_shex_validation_policy::CheckShexValidationPolicyBuilder;
 pub use compile_shex_schema_to_file::CompileShexSchemaToFileBuilder;
 pub use load_shapemap::LoadShapemapBuilder;
 pub use load_shex_schema::LoadShexSchemaBuilder;
@@ -1,4 +1,7 @@
 use crate::{Result, Rudof, api::shex::ShExOperations};
+use rudof_rdf::rdf_core::utils::CancellationToken;
+use shex_validation::ShExValidationEvent;
+use std::time::Duration;
 
 /// Builder for `validate_shex` operation.
 ///
@@ -6,6 +9,10 @@ use crate::{Result, Rudof, api::shex::ShExOperations};
 /// operations.
 pub struct ValidateShexBuilder<'a> {
     rudof: &'a mut Rudof,
+    timeout: Option<Duration>,
+    node_timeout: Option<Duration>,
+    cancellation: Option<&'a CancellationToken>,
+    on_event: Option<&'a (dyn Fn(ShExValidationEvent) + Sync)>,
 }
 
 impl<'a> ValidateShexBuilder<'a> {
@@ -14,11 +21,64 @@ impl<'a> ValidateShexBuilder<'a> {
     /// This is called internally by `Rudof::validate_shex()` and should not
     /// be constructed directly.
     pub(crate) fn new(rudof: &'a mut Rudof) -> Self {
-        Self { rudof }
+        Self {
+            rudof,
+            timeout: None,
+            node_timeout: None,
+            cancellation: None,
+            on_event: None,
+        }
+    }
+
+    /// Sets the time limit for the whole validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Time after which the undecided entries get a timeout status
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the time limit for the validation of each shape map entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Time after which an undecided entry gets a timeout status
+    pub fn with_node_timeout(mut self, timeout: Duration) -> Self {
+        self.node_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets a token that stops the validation when it is cancelled.
+    ///
+    /// # Arguments
+    ///
+    /// * `cancellation` - The token to check while validating
+    pub fn with_cancellation(mut self, cancellation: &'a CancellationToken) -> Self {
+        self.cancellation = Some(cancellation);
+        self
+    }
+
+    /// Sets a handler called with each shape map entry as soon as it is decided
+    /// and with the progress of the validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `on_event` - The handler, called from several threads with parallel validation
+    pub fn with_event_handler(mut self, on_event: &'a (dyn Fn(ShExValidationEvent) + Sync)) -> Self {
+        self.on_event = Some(on_event);
+        self
     }
 
     /// Executes the ShEx validation operation.
     pub fn execute(self) -> Result<()> {
-        <Rudof as ShExOperations>::validate_shex(self.rudof)
+        <Rudof as ShExOperations>::validate_shex(
+            self.rudof,
+            self.timeout,
+            self.node_timeout,
+            self.cancellation,
+            self.on_event,
+        )
     }
 }
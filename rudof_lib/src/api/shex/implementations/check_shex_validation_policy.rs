@@ -0,0 +1,28 @@
+use crate::{Result, Rudof, errors::ShExError, formats::ValidationFailOn, types::ValidationVerdict};
+
+/// ShEx results have no severity, so every non-conformant node and shape
+/// counts as a violation
+pub fn check_shex_validation_policy(
+    rudof: &Rudof,
+    fail_on: Option<&ValidationFailOn>,
+    max_violations: Option<usize>,
+    shapes: Option<&[String]>,
+) -> Result<ValidationVerdict> {
+    let results = rudof
+        .shex_validation_results
+        .as_ref()
+        .ok_or(ShExError::NoShexValidationResultsAvailable)?;
+
+    let config = rudof.config.validation_policy();
+    let policy = config
+        .clone()
+        .with_fail_on(fail_on.copied().or(config.fail_on))
+        .with_max_violations(max_violations.or(config.max_violations))
+        .with_shapes(shapes.map_or_else(|| config.shapes.clone(), <[String]>::to_vec));
+
+    let violations = results
+        .iter()
+        .filter(|(_, shape, status)| status.is_non_conformant() && policy.applies_to(Some(&shape.to_string()), None))
+        .count();
+    Ok(policy.verdict(violations, 0))
+}
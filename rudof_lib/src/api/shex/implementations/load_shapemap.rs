@@ -7,6 +7,7 @@ use crate::{
     types::Data,
     utils::get_base_iri,
 };
+use prefixmap::PrefixMap;
 use rudof_iri::IriS;
 use rudof_rdf::rdf_core::Rdf;
 use shex_ast::{ShapeMapParser, shapemap::QueryShapeMap};
@@ -20,12 +21,18 @@ pub fn load_shapemap(
     base_shapes: Option<&str>,
 ) -> Result<()> {
     let (shapemap_format, base_nodes, base_shapes) = init_defaults(rudof, shapemap_format, base_nodes, base_shapes)?;
+    let pg_prefixmap = rudof.config.pg2rdf().prefixmap();
 
     let (data, shex_validator) = validate_loaded_data_and_schema(rudof)?;
 
     match shapemap_format {
         ShapeMapFormat::Compact => {
-            let shapemap = read_shapemap_compact(shapemap, data, shex_validator, base_nodes, base_shapes)?;
+            // Nodes of property graphs are referred to by the IRIs minted when converting them to RDF
+            let nodes_prefixmap = match data {
+                Data::RDFData(rdf_data) => rdf_data.prefixmap().unwrap_or_default(),
+                Data::PGData(_) => pg_prefixmap,
+            };
+            let shapemap = read_shapemap_compact(shapemap, nodes_prefixmap, shex_validator, base_nodes, base_shapes)?;
             rudof.shapemap = Some(shapemap);
         },
         _ => {
@@ -50,10 +57,6 @@ fn init_defaults(
 fn validate_loaded_data_and_schema(rudof: &mut Rudof) -> Result<(&mut Data, &ShExValidator)> {
     let data = rudof.data.as_mut().ok_or(Box::new(DataError::NoDataLoaded))?;
 
-    if !data.is_rdf() {
-        Err(Box::new(DataError::NoRdfDataLoaded))?
-    }
-
     let shex_validator = rudof.shex_validator.as_ref().ok_or(ShExError::NoShExSchemaLoaded)?;
 
     Ok((data, shex_validator))
@@ -61,7 +64,7 @@ fn validate_loaded_data_and_schema(rudof: &mut Rudof) -> Result<(&mut Data, &ShE
 
 fn read_shapemap_compact(
     shapemap: &InputSpec,
-    data: &mut Data,
+    nodes_prefixmap: PrefixMap,
     shex_validator: &ShExValidator,
     base_nodes: IriS,
     base_shapes: IriS,
@@ -84,7 +87,7 @@ fn read_shapemap_compact(
 
     let shapemap = ShapeMapParser::parse(
         shapemap_string.as_str(),
-        &Some(data.unwrap_rdf_mut().prefixmap().unwrap_or_default()),
+        &Some(nodes_prefixmap),
         &Some(base_nodes),
         &Some(shex_validator.shapes_prefixmap()),
         &Some(base_shapes),
@@ -79,7 +79,6 @@ fn load_shex_schema_shexc<R: io::Read>(
             format: "ShExC".to_string(),
         })?;
     let header = default_prefix_header(rudof, &content, PrefixDirective::Sparql);
-    let prefixed_reader = io::Cursor::new(format!("{header}{content}"));
     #[cfg(target_family = "wasm")]
     let source_iri = {
         IriS::from_str(source_name).map_err(|error| IriError::ParseError {
@@ -106,13 +105,12 @@ fn load_shex_schema_shexc<R: io::Read>(
         })?
     };
 
-    let schema = ShExParser::from_reader(prefixed_reader, Some(base_schema.clone()), &source_iri).map_err(|error| {
-        ShExError::FailedParsingShExSchema {
+    let schema = ShExParser::parse_with_header(&header, &content, Some(base_schema.clone()), &source_iri, source_name)
+        .map_err(|error| ShExError::FailedParsingShExSchema {
             error: error.to_string(),
             source_name: source_name.to_string(),
             format: "ShExC".to_string(),
-        }
-    })?;
+        })?;
 
     compile_shex_schema(rudof, base_schema, schema, reader_mode)?;
 
@@ -137,7 +135,12 @@ fn load_shex_schema_shexj<R: io::Read>(
     Ok(())
 }
 
-fn compile_shex_schema(rudof: &mut Rudof, base: IriS, schema: ShExSchema, reader_mode: &DataReaderMode) -> Result<()> {
+pub fn compile_shex_schema(
+    rudof: &mut Rudof,
+    base: IriS,
+    schema: ShExSchema,
+    reader_mode: &DataReaderMode,
+) -> Result<()> {
     // Build the registry with all extensions pre-registered so that the Arc<Mutex<MapState>>
     // inside MapActionExtension is shared with every closure compiled from the schema.
     let map_state = rudof.map_state.clone().unwrap_or_default();
@@ -1,5 +1,6 @@
 mod add_node_shape_to_shapemap;
 mod check_shex_schema;
+mod check_shex_validation_policy;
 mod compile_shex_schema_to_file;
 mod load_shapemap;
 mod load_shex_schema;
@@ -14,9 +15,10 @@ mod validate_shex;
 
 pub use add_node_shape_to_shapemap::add_node_shape_to_shapemap;
 pub use check_shex_schema::check_shex_schema;
+pub use check_shex_validation_policy::check_shex_validation_policy;
 pub use compile_shex_schema_to_file::compile_shex_schema_to_file;
 pub use load_shapemap::load_shapemap;
-pub use load_shex_schema::load_shex_schema;
+pub use load_shex_schema::{compile_shex_schema, load_shex_schema};
 pub use load_shex_schema_precompiled::load_shex_schema_precompiled;
 pub use reset_shapemap::reset_shapemap;
 pub use reset_shex::reset_shex;
@@ -1,10 +1,16 @@
 use crate::{
     Result, Rudof,
+    display::{CiCase, CiLevel, CiLocation, CiOutcome, CiReport, HtmlOutcome, HtmlReport, HtmlRow},
     errors::ShExError,
     formats::{ResultShExValidationFormat, ShExValidationSortByMode},
     utils::terminal_width,
 };
-use std::io;
+use prefixmap::PrefixMap;
+use rudof_rdf::rdf_core::term::Object;
+use shex_ast::ir::shape_label::ShapeLabel;
+use shex_ast::shapemap::{ResultShapeMap, ValidationStatus};
+use shex_validation::ShExValidationEvent;
+use std::{collections::HashMap, io};
 
 pub fn serialize_shex_validation_results<W: io::Write>(
     rudof: &Rudof,
@@ -37,11 +43,23 @@ pub fn serialize_shex_validation_results<W: io::Write>(
         },
         ResultShExValidationFormat::Details => {
             shex_validation_results
-                .as_table(writer, Some(&sort_order.into()), Some(true), Some(terminal_width()))
+                .as_table(
+                    &mut *writer,
+                    Some(&sort_order.into()),
+                    Some(true),
+                    Some(terminal_width()),
+                )
                 .map_err(|e| ShExError::FailedSerializingShExValidationResults {
                     format: "details".to_string(),
                     error: e.to_string(),
                 })?;
+            // Justification trees are only present when they were enabled in the validator
+            let mut justifications: Vec<_> = shex_validation_results.justifications().collect();
+            justifications.sort_by(|(n1, s1, _), (n2, s2, _)| n1.cmp(n2).then(s1.cmp(s2)));
+            for (_, _, justification) in justifications {
+                writeln!(writer, "\n{justification}")
+                    .map_err(|e| ShExError::FailedIoOperation { error: e.to_string() })?;
+            }
         },
         ResultShExValidationFormat::Json => {
             let str = serde_json::to_string_pretty(&shex_validation_results).map_err(|e| {
@@ -53,6 +71,37 @@ pub fn serialize_shex_validation_results<W: io::Write>(
 
             writeln!(writer, "{str}").map_err(|e| ShExError::FailedIoOperation { error: e.to_string() })?;
         },
+        ResultShExValidationFormat::NdJson => {
+            let sort_order = sort_order.into();
+            for (node, shape, status) in shex_validation_results.iter_sorted(&sort_order) {
+                let event = ShExValidationEvent::Result {
+                    node: node.clone(),
+                    shape: shape.clone(),
+                    status: Box::new(status.clone()),
+                };
+                let line =
+                    serde_json::to_string(&event).map_err(|e| ShExError::FailedSerializingShExValidationResults {
+                        format: "ndjson".to_string(),
+                        error: e.to_string(),
+                    })?;
+                writeln!(writer, "{line}").map_err(|e| ShExError::FailedIoOperation { error: e.to_string() })?;
+            }
+        },
+        ResultShExValidationFormat::Sarif => {
+            ci_report(rudof, shex_validation_results, &sort_order)
+                .write_sarif(writer)
+                .map_err(|e| ShExError::FailedIoOperation { error: e.to_string() })?;
+        },
+        ResultShExValidationFormat::JUnit => {
+            ci_report(rudof, shex_validation_results, &sort_order)
+                .write_junit(writer)
+                .map_err(|e| ShExError::FailedIoOperation { error: e.to_string() })?;
+        },
+        ResultShExValidationFormat::Html => {
+            html_report(rudof, shex_validation_results, &sort_order)
+                .write(writer)
+                .map_err(|e| ShExError::FailedIoOperation { error: e.to_string() })?;
+        },
         _ => {
             todo!("Implement serialization for the specified format: {result_shex_validation_format:?}");
         },
@@ -70,3 +119,86 @@ fn init_defaults(
         result_shex_validation_format.copied().unwrap_or_default(),
     )
 }
+
+/// Arranges the results as a case per node and shape, located where the node
+/// appears in the data, or else where the shape was declared, when it is known
+fn ci_report(rudof: &Rudof, results: &ResultShapeMap, sort_order: &ShExValidationSortByMode) -> CiReport {
+    let shape_sources: HashMap<&ShapeLabel, CiLocation> = rudof
+        .shex_schema_ir
+        .iter()
+        .flat_map(|schema| schema.shapes())
+        .filter_map(|(label, source, _)| CiLocation::from_iri(source.as_str()).map(|location| (label, location)))
+        .collect();
+
+    let mut report = CiReport::new(&rudof.version);
+    for (node, shape, status) in results.iter_sorted(&sort_order.into()) {
+        let outcome = match status {
+            ValidationStatus::Conformant(_) => CiOutcome::Passed,
+            ValidationStatus::NonConformant(_) | ValidationStatus::Inconsistent(_, _) => {
+                CiOutcome::Failed(CiLevel::Error)
+            },
+            ValidationStatus::Pending | ValidationStatus::Timeout(_) => CiOutcome::Skipped,
+        };
+        let shape_label = show_shape_label(shape, results.shapes_prefixmap());
+        report.add_case(CiCase {
+            name: show_node(node.as_object(), results.nodes_prefixmap()),
+            rule: shape_label.clone(),
+            suite: shape_label,
+            outcome,
+            message: status.reason(),
+            location: results
+                .node_span(node)
+                .or(results.shape_span(shape))
+                .and_then(CiLocation::from_span)
+                .or_else(|| shape_sources.get(shape).cloned()),
+        });
+    }
+    report
+}
+
+/// Arranges the results as a row per node and shape, with the justification
+/// of the entry as its details when justifications were recorded
+fn html_report(rudof: &Rudof, results: &ResultShapeMap, sort_order: &ShExValidationSortByMode) -> HtmlReport {
+    let justifications: HashMap<_, _> = results
+        .justifications()
+        .map(|(node, shape, justification)| ((node, shape), justification))
+        .collect();
+    let conforms = results.iter().all(|(_, _, status)| status.is_conformant());
+
+    let mut report = HtmlReport::new("ShEx validation report", &rudof.version, conforms, "Statuses");
+    for (node, shape, status) in results.iter_sorted(&sort_order.into()) {
+        let outcome = match status {
+            ValidationStatus::Conformant(_) => HtmlOutcome::Passed,
+            ValidationStatus::NonConformant(_) | ValidationStatus::Inconsistent(_, _) => HtmlOutcome::Failed,
+            ValidationStatus::Pending | ValidationStatus::Timeout(_) => HtmlOutcome::Skipped,
+        };
+        report.add_row(HtmlRow {
+            node: show_node(node.as_object(), results.nodes_prefixmap()),
+            shape: show_shape_label(shape, results.shapes_prefixmap()),
+            label: status.code(),
+            outcome,
+            location: results.node_span(node).map(|span| span.to_string()),
+            message: status.reason(),
+            details: justifications
+                .get(&(node, shape))
+                .map(|justification| justification.to_string()),
+            ..Default::default()
+        });
+    }
+    report
+}
+
+fn show_node(node: &Object, prefixmap: &PrefixMap) -> String {
+    match node {
+        Object::Iri(iri) => prefixmap.qualify(iri),
+        _ => node.to_string(),
+    }
+}
+
+fn show_shape_label(label: &ShapeLabel, prefixmap: &PrefixMap) -> String {
+    match label {
+        ShapeLabel::Iri(iri) => prefixmap.qualify(iri),
+        ShapeLabel::BNode(bnode) => format!("_:{bnode}"),
+        ShapeLabel::Start => "Start".to_string(),
+    }
+}
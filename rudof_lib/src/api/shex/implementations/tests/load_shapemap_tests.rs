@@ -37,6 +37,7 @@ fn test_load_shapemap_compact_success() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -88,6 +89,7 @@ fn test_load_shapemap_replace() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -163,6 +165,7 @@ fn test_load_shapemap_no_schema_error() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -193,6 +196,7 @@ fn test_load_shapemap_invalid_syntax() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -234,6 +238,7 @@ fn test_load_shapemap_with_base_nodes() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -287,6 +292,7 @@ fn test_serialize_shapemap_json() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
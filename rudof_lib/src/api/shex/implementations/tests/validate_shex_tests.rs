@@ -1,16 +1,22 @@
 use crate::{
     Rudof, RudofConfig,
     api::data::implementations::load_data,
+    api::shex::implementations::check_shex_validation_policy::check_shex_validation_policy,
     api::shex::implementations::load_shapemap::load_shapemap,
     api::shex::implementations::load_shex_schema::load_shex_schema,
     api::shex::implementations::serialize_shex_validation_results::serialize_shex_validation_results,
     api::shex::implementations::validate_shex::validate_shex,
     formats::{
         DataFormat, InputSpec, ResultShExValidationFormat, ShExFormat, ShExValidationSortByMode, ShapeMapFormat,
+        ValidationFailOn,
     },
+    types::ValidationVerdict,
 };
 use regex::Regex;
-//use std::str::FromStr;
+use rudof_rdf::rdf_core::utils::CancellationToken;
+use shex_validation::ShExValidationEvent;
+use std::path::PathBuf;
+use std::str::FromStr;
 
 /// Helper: serialize validation results to string
 fn serialize_validation_results_to_string(
@@ -46,6 +52,7 @@ fn test_validate_shex_success() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -80,7 +87,7 @@ fn test_validate_shex_success() {
     .unwrap();
 
     // Validate
-    validate_shex(&mut rudof).unwrap();
+    validate_shex(&mut rudof, None, None, None, None).unwrap();
 
     assert!(rudof.shex_validation_results.is_some());
 
@@ -97,12 +104,67 @@ fn test_validate_shex_success() {
     );
 }
 
+#[test]
+fn test_validate_shex_pg_data() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let data = InputSpec::str(r#"(alice {Person} [ name: "Alice", age: 23 ])"#);
+
+    load_data(
+        &mut rudof,
+        Some(&[data]),
+        Some(&DataFormat::Pg),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let schema = InputSpec::str(
+        r#"PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+           PREFIX pg: <http://example.org/pg/property/>
+           <PersonShape> {
+             pg:name xsd:string ;
+             pg:age xsd:integer
+           }"#,
+    );
+
+    load_shex_schema(
+        &mut rudof,
+        &schema,
+        Some(&ShExFormat::ShExC),
+        Some("http://example.org/"),
+        None,
+    )
+    .unwrap();
+
+    let shapemap = InputSpec::str(r#"node:alice@<PersonShape>"#);
+
+    load_shapemap(
+        &mut rudof,
+        &shapemap,
+        Some(&ShapeMapFormat::Compact),
+        None,
+        Some("http://example.org/"),
+    )
+    .unwrap();
+
+    validate_shex(&mut rudof, None, None, None, None).unwrap();
+
+    let results = rudof.shex_validation_results.as_ref().unwrap();
+    assert!(results.iter().all(|(_, _, status)| status.is_conformant()));
+    assert_eq!(results.iter().count(), 1);
+}
+
 #[test]
 fn test_validate_shex_no_data_error() {
     let mut rudof = Rudof::new(RudofConfig::default());
 
     // Try to validate without data
-    let result = validate_shex(&mut rudof);
+    let result = validate_shex(&mut rudof, None, None, None, None);
 
     assert!(result.is_err());
 }
@@ -126,11 +188,12 @@ fn test_validate_shex_no_schema_error() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
     // Try to validate without schema
-    let result = validate_shex(&mut rudof);
+    let result = validate_shex(&mut rudof, None, None, None, None);
 
     assert!(result.is_err());
 }
@@ -154,6 +217,7 @@ fn test_validate_shex_no_shapemap_error() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -166,7 +230,7 @@ fn test_validate_shex_no_shapemap_error() {
     load_shex_schema(&mut rudof, &schema, Some(&ShExFormat::ShExC), None, None).unwrap();
 
     // Try to validate without shapemap
-    let result = validate_shex(&mut rudof);
+    let result = validate_shex(&mut rudof, None, None, None, None);
 
     assert!(result.is_err());
 }
@@ -191,6 +255,7 @@ fn test_validate_shex_validation_failure() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -212,7 +277,7 @@ fn test_validate_shex_validation_failure() {
     load_shapemap(&mut rudof, &shapemap, Some(&ShapeMapFormat::Compact), None, None).unwrap();
 
     // Validate - should succeed but with validation failure result
-    validate_shex(&mut rudof).unwrap();
+    validate_shex(&mut rudof, None, None, None, None).unwrap();
 
     assert!(rudof.shex_validation_results.is_some());
 
@@ -245,6 +310,7 @@ fn test_serialize_validation_results_compact() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -263,7 +329,7 @@ fn test_serialize_validation_results_compact() {
 
     load_shapemap(&mut rudof, &shapemap, Some(&ShapeMapFormat::Compact), None, None).unwrap();
 
-    validate_shex(&mut rudof).unwrap();
+    validate_shex(&mut rudof, None, None, None, None).unwrap();
 
     // Test compact format
     let serialized =
@@ -299,6 +365,7 @@ fn test_serialize_validation_results_json() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -317,7 +384,7 @@ fn test_serialize_validation_results_json() {
 
     load_shapemap(&mut rudof, &shapemap, Some(&ShapeMapFormat::Compact), None, None).unwrap();
 
-    validate_shex(&mut rudof).unwrap();
+    validate_shex(&mut rudof, None, None, None, None).unwrap();
 
     // Test JSON format
     let serialized = serialize_validation_results_to_string(&mut rudof, None, Some(ResultShExValidationFormat::Json));
@@ -351,6 +418,7 @@ fn test_serialize_validation_results_csv() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -369,7 +437,7 @@ fn test_serialize_validation_results_csv() {
 
     load_shapemap(&mut rudof, &shapemap, Some(&ShapeMapFormat::Compact), None, None).unwrap();
 
-    validate_shex(&mut rudof).unwrap();
+    validate_shex(&mut rudof, None, None, None, None).unwrap();
 
     // Test CSV format
     let serialized = serialize_validation_results_to_string(&mut rudof, None, Some(ResultShExValidationFormat::Csv));
@@ -403,6 +471,7 @@ fn test_serialize_validation_results_details() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -421,13 +490,13 @@ fn test_serialize_validation_results_details() {
 
     load_shapemap(&mut rudof, &shapemap, Some(&ShapeMapFormat::Compact), None, None).unwrap();
 
-    validate_shex(&mut rudof).unwrap();
+    validate_shex(&mut rudof, None, None, None, None).unwrap();
 
     // Test details format
     let serialized =
         serialize_validation_results_to_string(&mut rudof, None, Some(ResultShExValidationFormat::Details));
 
-    let re = Regex::new(r"(?m)^│\s+Node\s+│\s+Shape\s+│\s+Status\s+│\s+Details\s+│$").unwrap();
+    let re = Regex::new(r"(?m)^│\s+Node\s+│\s+Shape\s+│\s+Status\s+│\s+Details\s+│\s+Location\s+│$").unwrap();
 
     assert!(re.is_match(&serialized));
 
@@ -437,6 +506,50 @@ fn test_serialize_validation_results_details() {
     );
 }
 
+#[test]
+fn test_serialize_validation_results_details_with_justifications() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+    // The validator takes its config when the schema is loaded
+    rudof.set_shex_justify(true);
+
+    let data = InputSpec::str(
+        r#"PREFIX ex: <http://example.org/>
+           ex:alice ex:name "Alice" ."#,
+    );
+    load_data(
+        &mut rudof,
+        Some(&[data]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let schema = InputSpec::str(
+        r#"PREFIX ex: <http://example.org/>
+           PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+           ex:PersonShape { ex:name xsd:string }"#,
+    );
+    load_shex_schema(&mut rudof, &schema, Some(&ShExFormat::ShExC), None, None).unwrap();
+
+    let shapemap = InputSpec::str(r#"ex:alice@ex:PersonShape"#);
+    load_shapemap(&mut rudof, &shapemap, Some(&ShapeMapFormat::Compact), None, None).unwrap();
+
+    validate_shex(&mut rudof, None, None, None, None).unwrap();
+
+    let details = serialize_validation_results_to_string(&mut rudof, None, Some(ResultShExValidationFormat::Details));
+    assert!(details.contains("ShapeLabel ex:alice@ex:PersonShape: conforms"));
+    assert!(details.contains(r#"- ex:name "Alice" matched by xsd:string"#));
+
+    let json = serialize_validation_results_to_string(&mut rudof, None, Some(ResultShExValidationFormat::Json));
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value[0]["justification"]["conforms"], true);
+}
+
 #[test]
 fn test_serialize_validation_results_no_results_error() {
     let rudof = Rudof::new(RudofConfig::default());
@@ -471,6 +584,7 @@ fn test_validate_shex_multiple_nodes() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -495,7 +609,7 @@ fn test_validate_shex_multiple_nodes() {
     load_shapemap(&mut rudof, &shapemap, Some(&ShapeMapFormat::Compact), None, None).unwrap();
 
     // Validate
-    validate_shex(&mut rudof).unwrap();
+    validate_shex(&mut rudof, None, None, None, None).unwrap();
 
     assert!(rudof.shex_validation_results.is_some());
 
@@ -530,6 +644,7 @@ fn test_validate_shex_iri_pattern_with_base_schema() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -557,7 +672,7 @@ fn test_validate_shex_iri_pattern_with_base_schema() {
     )
     .unwrap();
 
-    validate_shex(&mut rudof).unwrap();
+    validate_shex(&mut rudof, None, None, None, None).unwrap();
     let serialized =
         serialize_validation_results_to_string(&mut rudof, None, Some(ResultShExValidationFormat::Compact));
     assert!(
@@ -582,6 +697,7 @@ fn test_validate_shex_bnode_focus_in_shapemap() {
         None,
         None,
         None,
+        None,
     )
     .unwrap();
 
@@ -595,7 +711,7 @@ fn test_validate_shex_bnode_focus_in_shapemap() {
     let shapemap = InputSpec::str(r#"_:abcd@<http://a.example/S1>"#);
     load_shapemap(&mut rudof, &shapemap, Some(&ShapeMapFormat::Compact), None, None).unwrap();
 
-    validate_shex(&mut rudof).unwrap();
+    validate_shex(&mut rudof, None, None, None, None).unwrap();
     let serialized =
         serialize_validation_results_to_string(&mut rudof, None, Some(ResultShExValidationFormat::Compact));
     assert!(
@@ -607,3 +723,322 @@ fn test_validate_shex_bnode_focus_in_shapemap() {
         "Expected FAIL (BNode focus against IRI shape):\n{serialized}",
     );
 }
+
+#[test]
+fn test_validate_shex_cancelled_reports_timeouts() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let data = InputSpec::str(r#"<alice> <name> "Alice" ."#);
+    load_data(
+        &mut rudof,
+        Some(&[data]),
+        Some(&DataFormat::Turtle),
+        Some("http://example.org/"),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let schema =
+        InputSpec::str(r#"PREFIX xsd: <http://www.w3.org/2001/XMLSchema#> <PersonShape> { <name> xsd:string }"#);
+    load_shex_schema(
+        &mut rudof,
+        &schema,
+        Some(&ShExFormat::ShExC),
+        Some("http://example.org/"),
+        None,
+    )
+    .unwrap();
+
+    let shapemap = InputSpec::str(r#"<alice>@<PersonShape>"#);
+    load_shapemap(
+        &mut rudof,
+        &shapemap,
+        Some(&ShapeMapFormat::Compact),
+        Some("http://example.org/"),
+        Some("http://example.org/"),
+    )
+    .unwrap();
+
+    let token = CancellationToken::new();
+    token.cancel();
+    validate_shex(&mut rudof, None, None, Some(&token), None).unwrap();
+
+    let results = rudof.shex_validation_results.as_ref().unwrap();
+    assert!(results.iter().all(|(_, _, status)| status.is_timeout()));
+
+    // Without the token the same validation succeeds
+    validate_shex(&mut rudof, Some(std::time::Duration::from_secs(600)), None, None, None).unwrap();
+    let results = rudof.shex_validation_results.as_ref().unwrap();
+    assert!(results.iter().all(|(_, _, status)| status.is_conformant()));
+}
+
+#[test]
+fn test_validate_shex_streams_events() {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let data = InputSpec::str(r#"<alice> <name> "Alice" . <bob> <name> 23 ."#);
+    load_data(
+        &mut rudof,
+        Some(&[data]),
+        Some(&DataFormat::Turtle),
+        Some("http://example.org/"),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let schema =
+        InputSpec::str(r#"PREFIX xsd: <http://www.w3.org/2001/XMLSchema#> <PersonShape> { <name> xsd:string }"#);
+    load_shex_schema(
+        &mut rudof,
+        &schema,
+        Some(&ShExFormat::ShExC),
+        Some("http://example.org/"),
+        None,
+    )
+    .unwrap();
+
+    let shapemap = InputSpec::str(r#"<alice>@<PersonShape>, <bob>@<PersonShape>"#);
+    load_shapemap(
+        &mut rudof,
+        &shapemap,
+        Some(&ShapeMapFormat::Compact),
+        Some("http://example.org/"),
+        Some("http://example.org/"),
+    )
+    .unwrap();
+
+    let events = std::sync::Mutex::new(Vec::new());
+    validate_shex(
+        &mut rudof,
+        None,
+        None,
+        None,
+        Some(&|event| events.lock().unwrap().push(event)),
+    )
+    .unwrap();
+
+    let events = events.into_inner().unwrap();
+    let mut statuses: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event {
+            ShExValidationEvent::Result { node, status, .. } => Some(format!("{node} {}", status.code())),
+            _ => None,
+        })
+        .collect();
+    statuses.sort();
+    assert_eq!(
+        statuses,
+        vec![
+            "http://example.org/alice conformant",
+            "http://example.org/bob nonconformant"
+        ]
+    );
+    assert_eq!(
+        events.last(),
+        Some(&ShExValidationEvent::Progress { decided: 2, entries: 2 })
+    );
+
+    // After the fact the results are written with the same lines
+    let serialized = serialize_validation_results_to_string(&mut rudof, None, Some(ResultShExValidationFormat::NdJson));
+    let lines: Vec<serde_json::Value> = serialized
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["type"], "result");
+    assert_eq!(lines[0]["status"], "conformant");
+    assert_eq!(lines[1]["status"], "nonconformant");
+}
+
+/// Helper: loads a schema from a file and validates one conformant and one non-conformant node.
+/// The schema file is left in place so results can point to it.
+fn validate_people_from_schema_file(schema_file: &str) -> (Rudof, PathBuf) {
+    let mut rudof = Rudof::new(RudofConfig::default());
+
+    let data = InputSpec::str(
+        r#"PREFIX ex: <http://example.org/>
+           ex:alice ex:name "Alice" ; ex:age 23 .
+           ex:bob ex:name "Bob" ; ex:age "unknown" ."#,
+    );
+    load_data(
+        &mut rudof,
+        Some(&[data]),
+        Some(&DataFormat::Turtle),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+
+    let schema_path = std::env::temp_dir().join(schema_file);
+    std::fs::write(
+        &schema_path,
+        r#"PREFIX ex: <http://example.org/>
+           PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+           ex:PersonShape {
+             ex:name xsd:string ;
+             ex:age xsd:integer
+           }"#,
+    )
+    .unwrap();
+    let schema = InputSpec::Path(schema_path.clone());
+    load_shex_schema(&mut rudof, &schema, Some(&ShExFormat::ShExC), None, None).unwrap();
+
+    let shapemap = InputSpec::str("ex:alice@ex:PersonShape, ex:bob@ex:PersonShape");
+    load_shapemap(&mut rudof, &shapemap, Some(&ShapeMapFormat::Compact), None, None).unwrap();
+
+    validate_shex(&mut rudof, None, None, None, None).unwrap();
+    (rudof, schema_path)
+}
+
+#[test]
+fn test_serialize_validation_results_sarif() {
+    let schema_file = "rudof_test_serialize_validation_results_sarif.shex";
+    let (mut rudof, schema_path) = validate_people_from_schema_file(schema_file);
+
+    let serialized = serialize_validation_results_to_string(&mut rudof, None, Some(ResultShExValidationFormat::Sarif));
+    let sarif: serde_json::Value = serde_json::from_str(&serialized).unwrap();
+
+    assert_eq!(sarif["version"], "2.1.0");
+    assert_eq!(sarif["runs"][0]["tool"]["driver"]["name"], "rudof");
+
+    // Only the non-conformant entry is reported, located at the schema file
+    let results = sarif["runs"][0]["results"].as_array().unwrap();
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0]["level"], "error");
+    assert!(results[0]["ruleId"].as_str().unwrap().contains("PersonShape"));
+    assert!(
+        results[0]["locations"][0]["logicalLocations"][0]["name"]
+            .as_str()
+            .unwrap()
+            .contains("bob")
+    );
+    let uri = results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"]
+        .as_str()
+        .unwrap();
+    assert!(uri.ends_with(schema_file));
+
+    std::fs::remove_file(schema_path).unwrap();
+}
+
+#[test]
+fn test_serialize_validation_results_junit() {
+    let (mut rudof, schema_path) =
+        validate_people_from_schema_file("rudof_test_serialize_validation_results_junit.shex");
+
+    let serialized = serialize_validation_results_to_string(&mut rudof, None, Some(ResultShExValidationFormat::JUnit));
+
+    assert!(serialized.starts_with("<?xml"));
+    assert!(serialized.contains(r#"<testsuites name="rudof" tests="2" failures="1" skipped="0">"#));
+    assert_eq!(serialized.matches("<testcase ").count(), 2);
+    assert_eq!(serialized.matches("<failure ").count(), 1);
+    assert!(serialized.contains(r#"file=""#));
+
+    std::fs::remove_file(schema_path).unwrap();
+}
+
+#[test]
+fn test_serialize_validation_results_html() {
+    let (mut rudof, schema_path) =
+        validate_people_from_schema_file("rudof_test_serialize_validation_results_html.shex");
+
+    let serialized = serialize_validation_results_to_string(&mut rudof, None, Some(ResultShExValidationFormat::Html));
+    std::fs::remove_file(schema_path).unwrap();
+
+    assert!(serialized.starts_with("<!DOCTYPE html>"));
+    assert!(serialized.contains("Does not conform"));
+    // Everything is inlined, so the report can be opened offline
+    assert!(!serialized.contains("<script src"));
+    assert!(!serialized.contains("<link"));
+    // Nodes are prefixed and link to their details
+    assert!(serialized.contains(r##"<a href="#node-1">ex:bob</a>"##));
+    assert!(serialized.contains(r#"<details id="node-1">"#));
+    assert_eq!(serialized.matches(r#"<tr class="failed""#).count(), 1);
+    assert_eq!(serialized.matches(r#"<tr class="passed""#).count(), 1);
+}
+
+#[test]
+fn test_check_shex_validation_policy() {
+    let config = RudofConfig::from_str("[validation_policy]\nmax_violations = 1").unwrap();
+    let mut rudof = Rudof::new(config);
+
+    let data = InputSpec::str(
+        r#"
+            <alice> <name> "Alice" .
+            <bob> <name> 23 .
+            <carol> <age> 40 .
+        "#,
+    );
+    load_data(
+        &mut rudof,
+        Some(&[data]),
+        Some(&DataFormat::Turtle),
+        Some("http://example.org/"),
+        None,
+        None,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    let schema = InputSpec::str(
+        r#"PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+           <PersonShape> { <name> xsd:string }
+           <AgedShape> { <age> xsd:integer }"#,
+    );
+    load_shex_schema(
+        &mut rudof,
+        &schema,
+        Some(&ShExFormat::ShExC),
+        Some("http://example.org/"),
+        None,
+    )
+    .unwrap();
+    let shapemap =
+        InputSpec::str("<alice>@<PersonShape>, <bob>@<PersonShape>, <carol>@<PersonShape>, <carol>@<AgedShape>");
+    load_shapemap(
+        &mut rudof,
+        &shapemap,
+        Some(&ShapeMapFormat::Compact),
+        Some("http://example.org/"),
+        Some("http://example.org/"),
+    )
+    .unwrap();
+    validate_shex(&mut rudof, None, None, None, None).unwrap();
+
+    // bob and carol do not conform to PersonShape, one more than the configured limit
+    assert_eq!(
+        check_shex_validation_policy(&rudof, None, None, None).unwrap(),
+        ValidationVerdict::Violations {
+            violations: 2,
+            allowed: 1
+        }
+    );
+    assert!(
+        check_shex_validation_policy(&rudof, None, Some(2), None)
+            .unwrap()
+            .passed()
+    );
+    let aged = vec!["http://example.org/AgedShape".to_string()];
+    assert!(
+        check_shex_validation_policy(&rudof, None, Some(0), Some(&aged))
+            .unwrap()
+            .passed()
+    );
+    assert!(
+        check_shex_validation_policy(&rudof, Some(&ValidationFailOn::Never), Some(0), None)
+            .unwrap()
+            .passed()
+    );
+}
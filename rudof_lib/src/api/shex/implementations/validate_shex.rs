@@ -4,12 +4,45 @@ use crate::{
     types::Data,
 };
 use rudof_rdf::rdf_core::Rdf;
+use rudof_rdf::rdf_core::utils::{CancellationToken, Profiler, ValidationBudget};
 use shex_ast::{ir::schema_ir::SchemaIR as ShExSchemaIR, shapemap::NodeSelector, shapemap::QueryShapeMap};
-use shex_validation::Validator as ShExValidator;
+use shex_validation::{ShExValidationEvent, Validator as ShExValidator};
+use std::time::Duration;
+
+pub fn validate_shex(
+    rudof: &mut Rudof,
+    timeout: Option<Duration>,
+    node_timeout: Option<Duration>,
+    cancellation: Option<&CancellationToken>,
+    on_event: Option<&(dyn Fn(ShExValidationEvent) + Sync)>,
+) -> Result<()> {
+    let pg2rdf_config = rudof.config.pg2rdf().clone();
+    let config = rudof.config.shex_validator();
+    // Each validation records a profile of its own
+    let profiler = if rudof.profile_validation {
+        Profiler::new()
+    } else {
+        Profiler::disabled()
+    };
+    let mut budget = ValidationBudget::new()
+        .with_timeout(timeout.or(config.timeout()))
+        .with_node_timeout(node_timeout.or(config.node_timeout()))
+        .with_profiler(profiler.clone());
+    if let Some(cancellation) = cancellation {
+        budget = budget.with_cancellation(cancellation.clone());
+    }
 
-pub fn validate_shex(rudof: &mut Rudof) -> Result<()> {
     let (data, shex_schema, shapemap, shex_validator) = prepare_loaded_data_schema_and_shapemap(rudof)?;
-    let rdf_data = data.unwrap_rdf_mut();
+
+    // Property graphs are validated through their RDF representation
+    let mut converted;
+    let rdf_data = match data {
+        Data::RDFData(rdf_data) => rdf_data.as_mut(),
+        Data::PGData(pg) => {
+            converted = Data::pg_to_rdf(pg, &pg2rdf_config)?;
+            &mut converted
+        },
+    };
 
     let needs_store = shapemap.iter().any(|asc| {
         matches!(
@@ -24,10 +57,21 @@ pub fn validate_shex(rudof: &mut Rudof) -> Result<()> {
             .map_err(|e| ShExError::FailedInitializingQueryStore { error: e.to_string() })?;
     }
 
+    // Requests to SPARQL endpoints are aborted when the whole validation is interrupted
+    rdf_data.set_cancellation(&budget.start_run());
+
     let nodes_prefixmap = rdf_data.prefixmap().unwrap_or_default();
-    let result = shex_validator
-        .validate_shapemap(shapemap, rdf_data, shex_schema, &Some(nodes_prefixmap))
-        .map_err(|e| ShExError::FailedShExValidation { error: e.to_string() })?;
+    let result = shex_validator.validate_shapemap_with_events(
+        shapemap,
+        rdf_data,
+        shex_schema,
+        &Some(nodes_prefixmap),
+        &budget,
+        on_event.unwrap_or(&|_| {}),
+    );
+    rdf_data.set_cancellation(&CancellationToken::new());
+    rudof.validation_profile = profiler.is_enabled().then_some(profiler);
+    let result = result.map_err(|e| ShExError::FailedShExValidation { error: e.to_string() })?;
 
     // Read back the map state that was mutated by MapActionExtension closures during validation.
     // The SchemaIR's registry holds an Arc<Mutex<MapState>> that is shared with every compiled
@@ -49,10 +93,6 @@ fn prepare_loaded_data_schema_and_shapemap(
 ) -> Result<(&mut Data, &ShExSchemaIR, &QueryShapeMap, &ShExValidator)> {
     let data = rudof.data.as_mut().ok_or(Box::new(DataError::NoDataLoaded))?;
 
-    if !data.is_rdf() {
-        Err(Box::new(DataError::NoRdfDataLoaded))?
-    }
-
     let shex_schema_ir = rudof.shex_schema_ir.as_ref().ok_or(ShExError::NoShExSchemaLoaded)?;
     let shex_validator = rudof.shex_validator.as_ref().ok_or(ShExError::NoShExSchemaLoaded)?;
 
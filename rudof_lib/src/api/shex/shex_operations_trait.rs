@@ -1,16 +1,20 @@
 use crate::{
     Result,
     api::shex::implementations::{
-        add_node_shape_to_shapemap, check_shex_schema, compile_shex_schema_to_file, load_shapemap, load_shex_schema,
-        load_shex_schema_precompiled, reset_shapemap, reset_shex, reset_shex_schema, serialize_shapemap,
-        serialize_shex_schema, serialize_shex_validation_results, validate_shex,
+        add_node_shape_to_shapemap, check_shex_schema, check_shex_validation_policy, compile_shex_schema_to_file,
+        load_shapemap, load_shex_schema, load_shex_schema_precompiled, reset_shapemap, reset_shex, reset_shex_schema,
+        serialize_shapemap, serialize_shex_schema, serialize_shex_validation_results, validate_shex,
     },
     formats::{
         DataReaderMode, InputSpec, IriNormalizationMode, ResultShExValidationFormat, ShExFormat,
-        ShExValidationSortByMode, ShapeMapFormat,
+        ShExValidationSortByMode, ShapeMapFormat, ValidationFailOn,
     },
+    types::ValidationVerdict,
 };
+use rudof_rdf::rdf_core::utils::CancellationToken;
+use shex_validation::ShExValidationEvent;
 use std::io;
+use std::time::Duration;
 
 /// Operations for ShEx (Shape Expressions) schema validation.
 pub trait ShExOperations {
@@ -147,10 +151,27 @@ pub trait ShExOperations {
 
     /// Validates the current RDF data using the loaded ShEx schema and shape map.
     ///
+    /// The shape map entries that are not decided when a time limit runs out, or
+    /// when `cancellation` is cancelled, get a timeout status.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - Optional limit for the whole validation (uses the configured one if None)
+    /// * `node_timeout` - Optional limit for each entry (uses the configured one if None)
+    /// * `cancellation` - Optional token to stop the validation from another thread
+    /// * `on_event` - Optional handler called with each entry as soon as it is decided
+    ///   and with the progress of the validation
+    ///
     /// # Errors
     ///
     /// Returns an error if no schema or shape map is loaded.
-    fn validate_shex(&mut self) -> Result<()>;
+    fn validate_shex(
+        &mut self,
+        timeout: Option<Duration>,
+        node_timeout: Option<Duration>,
+        cancellation: Option<&CancellationToken>,
+        on_event: Option<&(dyn Fn(ShExValidationEvent) + Sync)>,
+    ) -> Result<()>;
 
     /// Serializes the ShEx validation results to a writer.
     ///
@@ -170,6 +191,26 @@ pub trait ShExOperations {
         writer: &mut W,
     ) -> Result<()>;
 
+    /// Checks the ShEx validation results against the validation policy.
+    ///
+    /// Each argument overrides the corresponding entry of the configured policy.
+    ///
+    /// # Arguments
+    ///
+    /// * `fail_on` - Optional severity that makes the validation fail
+    /// * `max_violations` - Optional number of non-conformant results that are accepted
+    /// * `shapes` - Optional shapes whose results are counted
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no validation results are available.
+    fn check_shex_validation_policy(
+        &self,
+        fail_on: Option<&ValidationFailOn>,
+        max_violations: Option<usize>,
+        shapes: Option<&[String]>,
+    ) -> Result<ValidationVerdict>;
+
     /// Resets the shex validation.
     fn reset_shex(&mut self);
 
@@ -280,8 +321,14 @@ impl ShExOperations for crate::Rudof {
         reset_shapemap(self)
     }
 
-    fn validate_shex(&mut self) -> Result<()> {
-        validate_shex(self)
+    fn validate_shex(
+        &mut self,
+        timeout: Option<Duration>,
+        node_timeout: Option<Duration>,
+        cancellation: Option<&CancellationToken>,
+        on_event: Option<&(dyn Fn(ShExValidationEvent) + Sync)>,
+    ) -> Result<()> {
+        validate_shex(self, timeout, node_timeout, cancellation, on_event)
     }
 
     fn serialize_shex_validation_results<W: io::Write>(
@@ -293,6 +340,15 @@ impl ShExOperations for crate::Rudof {
         serialize_shex_validation_results(self, sort_order, result_shex_validation_format, writer)
     }
 
+    fn check_shex_validation_policy(
+        &self,
+        fail_on: Option<&ValidationFailOn>,
+        max_violations: Option<usize>,
+        shapes: Option<&[String]>,
+    ) -> Result<ValidationVerdict> {
+        check_shex_validation_policy(self, fail_on, max_violations, shapes)
+    }
+
     fn reset_shex(&mut self) {
         reset_shex(self)
     }
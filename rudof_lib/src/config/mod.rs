@@ -1,5 +1,7 @@
 mod common;
 mod rudof;
+mod validation_policy;
 
 pub use common::CommonConfig;
 pub use rudof::RudofConfig;
+pub use validation_policy::ValidationPolicyConfig;
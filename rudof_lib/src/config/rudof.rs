@@ -1,4 +1,4 @@
-use crate::config::CommonConfig;
+use crate::config::{CommonConfig, ValidationPolicyConfig};
 use dctap::TapConfig;
 use rudof_config::{ConfigError, TomlConfig, find_config_files_from, merge_tables, read_toml_table, user_config_file};
 use rudof_rdf::rdf_core::RdfDataConfig;
@@ -7,7 +7,10 @@ use serde::{Deserialize, Serialize};
 #[cfg(not(target_family = "wasm"))]
 use shacl::validator::ShaclConfig;
 use shapes_comparator::ComparatorConfig;
-use shapes_converter::{ShEx2HtmlConfig, ShEx2SparqlConfig, ShEx2UmlConfig, Shacl2ShExConfig, Tap2ShExConfig};
+use shapes_converter::{
+    Pg2RdfConfig, Rdf2MieConfig, Rdf2PgConfig, Rdf2ShExConfig, Rdf2ShaclConfig, ShEx2HtmlConfig, ShEx2SparqlConfig,
+    ShEx2UmlConfig, Shacl2ShExConfig, Tap2ShExConfig,
+};
 use shex_validation::{ShExConfig, ValidatorConfig};
 use sparql_service::ServiceConfig;
 use std::path::Path;
@@ -49,10 +52,22 @@ pub struct RudofConfig {
     pub(crate) tap2shex: Tap2ShExConfig,
     #[serde(rename = "shex2sparql")]
     pub(crate) shex2sparql: ShEx2SparqlConfig,
+    #[serde(rename = "pg2rdf")]
+    pub(crate) pg2rdf: Pg2RdfConfig,
+    #[serde(rename = "rdf2pg")]
+    pub(crate) rdf2pg: Rdf2PgConfig,
+    #[serde(rename = "rdf2shex")]
+    pub(crate) rdf2shex: Rdf2ShExConfig,
+    #[serde(rename = "rdf2shacl")]
+    pub(crate) rdf2shacl: Rdf2ShaclConfig,
+    #[serde(rename = "rdf2mie")]
+    pub(crate) rdf2mie: Rdf2MieConfig,
     #[serde(rename = "service")]
     pub(crate) service: ServiceConfig,
     #[serde(rename = "comparator")]
     pub(crate) comparator: ComparatorConfig,
+    #[serde(rename = "validation_policy")]
+    pub(crate) validation_policy: ValidationPolicyConfig,
 }
 
 impl RudofConfig {
@@ -73,7 +88,13 @@ impl RudofConfig {
             tap: Self::default_tap_config(),
             tap2shex: Self::default_tap2shex_config(),
             shex2sparql: Self::default_shex2sparql_config(),
+            pg2rdf: Self::default_pg2rdf_config(),
+            rdf2pg: Self::default_rdf2pg_config(),
+            rdf2shex: Self::default_rdf2shex_config(),
+            rdf2shacl: Self::default_rdf2shacl_config(),
+            rdf2mie: Self::default_rdf2mie_config(),
             comparator: Self::default_comparator_config(),
+            validation_policy: Self::default_validation_policy_config(),
         };
         cfg.resolve();
         cfg
@@ -197,6 +218,31 @@ impl RudofConfig {
         self
     }
 
+    pub fn with_pg2rdf(mut self, cfg: Pg2RdfConfig) -> Self {
+        self.pg2rdf = cfg;
+        self
+    }
+
+    pub fn with_rdf2pg(mut self, cfg: Rdf2PgConfig) -> Self {
+        self.rdf2pg = cfg;
+        self
+    }
+
+    pub fn with_rdf2shex(mut self, cfg: Rdf2ShExConfig) -> Self {
+        self.rdf2shex = cfg;
+        self
+    }
+
+    pub fn with_rdf2shacl(mut self, cfg: Rdf2ShaclConfig) -> Self {
+        self.rdf2shacl = cfg;
+        self
+    }
+
+    pub fn with_rdf2mie(mut self, cfg: Rdf2MieConfig) -> Self {
+        self.rdf2mie = cfg;
+        self
+    }
+
     pub fn with_service(mut self, cfg: ServiceConfig) -> Self {
         self.service = cfg;
         self
@@ -206,6 +252,11 @@ impl RudofConfig {
         self.comparator = cfg;
         self
     }
+
+    pub fn with_validation_policy(mut self, cfg: ValidationPolicyConfig) -> Self {
+        self.validation_policy = cfg;
+        self
+    }
 }
 
 impl RudofConfig {
@@ -262,9 +313,33 @@ impl RudofConfig {
         &self.shex2sparql
     }
 
+    pub fn pg2rdf(&self) -> &Pg2RdfConfig {
+        &self.pg2rdf
+    }
+
+    pub fn rdf2pg(&self) -> &Rdf2PgConfig {
+        &self.rdf2pg
+    }
+
+    pub fn rdf2shex(&self) -> &Rdf2ShExConfig {
+        &self.rdf2shex
+    }
+
+    pub fn rdf2shacl(&self) -> &Rdf2ShaclConfig {
+        &self.rdf2shacl
+    }
+
+    pub fn rdf2mie(&self) -> &Rdf2MieConfig {
+        &self.rdf2mie
+    }
+
     pub fn comparator(&self) -> &ComparatorConfig {
         &self.comparator
     }
+
+    pub fn validation_policy(&self) -> &ValidationPolicyConfig {
+        &self.validation_policy
+    }
 }
 
 impl RudofConfig {
@@ -321,7 +396,13 @@ impl RudofConfig {
     #[inline] fn default_tap_config() -> TapConfig { TapConfig::default() }
     #[inline] fn default_tap2shex_config() -> Tap2ShExConfig { Tap2ShExConfig::default() }
     #[inline] fn default_shex2sparql_config() -> ShEx2SparqlConfig { ShEx2SparqlConfig::default() }
+    #[inline] fn default_pg2rdf_config() -> Pg2RdfConfig { Pg2RdfConfig::default() }
+    #[inline] fn default_rdf2pg_config() -> Rdf2PgConfig { Rdf2PgConfig::default() }
+    #[inline] fn default_rdf2shex_config() -> Rdf2ShExConfig { Rdf2ShExConfig::default() }
+    #[inline] fn default_rdf2shacl_config() -> Rdf2ShaclConfig { Rdf2ShaclConfig::default() }
+    #[inline] fn default_rdf2mie_config() -> Rdf2MieConfig { Rdf2MieConfig::default() }
     #[inline] fn default_comparator_config() -> ComparatorConfig { ComparatorConfig::default() }
+    #[inline] fn default_validation_policy_config() -> ValidationPolicyConfig { ValidationPolicyConfig::default() }
 
     /// Resolves cross-section inheritance after all config layers have been merged
     pub fn resolve(&mut self) {
@@ -497,6 +578,86 @@ mod tests {
         assert_eq!(cfg.shacl().rdf_data(), cfg.rdf_data());
     }
 
+    #[test]
+    fn pg_conversion_sections_are_read() {
+        let cfg = RudofConfig::from_str(
+            r#"
+            [pg2rdf]
+            node_base_iri = "http://ex/node/"
+            [rdf2pg]
+            iri_key = "uri"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(cfg.pg2rdf().node_base_iri().as_str(), "http://ex/node/");
+        assert!(cfg.pg2rdf().labels_as_classes());
+        assert_eq!(cfg.rdf2pg().iri_key(), Some("uri"));
+    }
+
+    #[test]
+    fn rdf2shex_section_is_read() {
+        let cfg = RudofConfig::from_str(
+            r#"
+            [rdf2shex]
+            min_support = 0.5
+            annotations = false
+            "#,
+        )
+        .unwrap();
+        assert_eq!(cfg.rdf2shex().min_support(), 0.5);
+        assert!(!cfg.rdf2shex().annotations());
+        assert_eq!(cfg.rdf2shex().max_value_set_size(), 5);
+    }
+
+    #[test]
+    fn rdf2shacl_section_is_read() {
+        let cfg = RudofConfig::from_str(
+            r#"
+            [rdf2shacl]
+            min_confidence = 0.8
+            patterns = false
+            "#,
+        )
+        .unwrap();
+        assert_eq!(cfg.rdf2shacl().min_confidence(), 0.8);
+        assert!(!cfg.rdf2shacl().patterns());
+        assert_eq!(cfg.rdf2shacl().min_support(), 0.1);
+    }
+
+    #[test]
+    fn rdf2mie_section_is_read() {
+        let cfg = RudofConfig::from_str(
+            r#"
+            [rdf2mie]
+            max_classes = 5
+            shapes = false
+            "#,
+        )
+        .unwrap();
+        assert_eq!(cfg.rdf2mie().max_classes(), 5);
+        assert!(!cfg.rdf2mie().shapes());
+        assert_eq!(cfg.rdf2mie().examples_per_class(), 1);
+    }
+
+    #[test]
+    fn validation_policy_section_is_read() {
+        let cfg = RudofConfig::from_str(
+            r#"
+            [validation_policy]
+            fail_on = "warnings"
+            max_violations = 3
+            components = ["sh:MinCountConstraintComponent"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            cfg.validation_policy().fail_on(),
+            crate::formats::ValidationFailOn::Warnings
+        );
+        assert_eq!(cfg.validation_policy().max_violations(), 3);
+        assert!(cfg.validation_policy().shapes().is_empty());
+    }
+
     #[test]
     fn rudof_config_toml_round_trip() {
         let original = RudofConfig::from_str(
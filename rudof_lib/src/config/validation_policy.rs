@@ -0,0 +1,202 @@
+use crate::formats::ValidationFailOn;
+use crate::types::ValidationVerdict;
+use rudof_config::TomlConfig;
+use rudof_rdf::rdf_core::vocabs::ShaclVocab;
+use serde::{Deserialize, Serialize};
+
+/// Quality gate applied to the results of a validation
+///
+/// Only the results of the listed `shapes` and constraint `components` are
+/// counted, or all of them when the lists are empty. Giving any limit or list
+/// without `fail_on` fails on violations.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct ValidationPolicyConfig {
+    /// Severity of the results that make the validation fail
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) fail_on: Option<ValidationFailOn>,
+
+    /// Number of violations that are still accepted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) max_violations: Option<usize>,
+
+    /// Shapes whose results are counted. For SHACL, the results of their
+    /// property shapes are counted too
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) shapes: Vec<String>,
+
+    /// SHACL constraint components whose results are counted. `sh:` can be
+    /// used as prefix of the SHACL namespace
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) components: Vec<String>,
+}
+
+/// Constructor and setters
+impl ValidationPolicyConfig {
+    /// Creates a new [`ValidationPolicyConfig`] that never fails.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets `fail_on` and returns itself
+    pub fn with_fail_on(mut self, fail_on: Option<ValidationFailOn>) -> Self {
+        self.fail_on = fail_on;
+        self
+    }
+
+    /// Sets `max_violations` and returns itself
+    pub fn with_max_violations(mut self, max_violations: Option<usize>) -> Self {
+        self.max_violations = max_violations;
+        self
+    }
+
+    /// Sets `shapes` and returns itself
+    pub fn with_shapes(mut self, shapes: Vec<String>) -> Self {
+        self.shapes = shapes;
+        self
+    }
+
+    /// Sets `components` and returns itself
+    pub fn with_components(mut self, components: Vec<String>) -> Self {
+        self.components = components;
+        self
+    }
+}
+
+/// Accessor methods
+impl ValidationPolicyConfig {
+    /// Returns the severity that makes the validation fail.
+    /// If `fail_on` is not set, it is `violations` when a limit or a list is set
+    /// and `never` otherwise
+    pub fn fail_on(&self) -> ValidationFailOn {
+        match self.fail_on {
+            Some(fail_on) => fail_on,
+            None if self.max_violations.is_some() || !self.shapes.is_empty() || !self.components.is_empty() => {
+                ValidationFailOn::Violations
+            },
+            None => ValidationFailOn::Never,
+        }
+    }
+
+    /// Returns the number of violations that are accepted, 0 by default
+    pub fn max_violations(&self) -> usize {
+        self.max_violations.unwrap_or_default()
+    }
+
+    pub fn shapes(&self) -> &[String] {
+        &self.shapes
+    }
+
+    pub fn components(&self) -> &[String] {
+        &self.components
+    }
+
+    /// Whether a result of the shape and constraint component is counted.
+    /// Results with no constraint component, like ShEx ones, are only filtered by shape
+    pub fn applies_to(&self, shape: Option<&str>, component: Option<&str>) -> bool {
+        let shape_matches =
+            self.shapes.is_empty() || shape.is_some_and(|shape| self.shapes.iter().any(|s| same_iri(s, shape)));
+        let component_matches = match component {
+            Some(component) if !self.components.is_empty() => {
+                self.components.iter().any(|c| same_iri(&expand_sh(c), component))
+            },
+            _ => true,
+        };
+        shape_matches && component_matches
+    }
+
+    /// Decides whether the counted violations and warnings pass the policy
+    pub fn verdict(&self, violations: usize, warnings: usize) -> ValidationVerdict {
+        let fail_on = self.fail_on();
+        if fail_on == ValidationFailOn::Never {
+            return ValidationVerdict::Passed;
+        }
+        if violations > self.max_violations() {
+            return ValidationVerdict::Violations {
+                violations,
+                allowed: self.max_violations(),
+            };
+        }
+        if fail_on == ValidationFailOn::Warnings && warnings > 0 {
+            return ValidationVerdict::Warnings { warnings };
+        }
+        ValidationVerdict::Passed
+    }
+}
+
+/// Compares two IRIs, written with or without angle brackets
+fn same_iri(iri1: &str, iri2: &str) -> bool {
+    let unwrap = |iri: &str| iri.trim_start_matches('<').trim_end_matches('>').to_string();
+    unwrap(iri1) == unwrap(iri2)
+}
+
+fn expand_sh(component: &str) -> String {
+    match component.strip_prefix("sh:") {
+        Some(local) => format!("{}{local}", ShaclVocab::SH),
+        None => component.to_string(),
+    }
+}
+
+impl TomlConfig for ValidationPolicyConfig {}
+
+#[cfg(test)]
+mod tests {
+    use super::ValidationPolicyConfig;
+    use crate::formats::ValidationFailOn;
+    use crate::types::ValidationVerdict;
+    use rudof_config::TomlConfig;
+
+    #[test]
+    fn defaults_never_fail() {
+        let c = ValidationPolicyConfig::default();
+        assert_eq!(c.fail_on(), ValidationFailOn::Never);
+        assert_eq!(c.verdict(10, 10), ValidationVerdict::Passed);
+    }
+
+    #[test]
+    fn max_violations_fails_on_violations() {
+        let c = ValidationPolicyConfig::from_toml_str("max_violations = 2").unwrap();
+        assert_eq!(c.fail_on(), ValidationFailOn::Violations);
+        assert_eq!(c.verdict(2, 5), ValidationVerdict::Passed);
+        assert_eq!(
+            c.verdict(3, 0),
+            ValidationVerdict::Violations {
+                violations: 3,
+                allowed: 2
+            }
+        );
+    }
+
+    #[test]
+    fn fail_on_warnings() {
+        let c = ValidationPolicyConfig::from_toml_str(r#"fail_on = "warnings""#).unwrap();
+        assert_eq!(c.verdict(0, 1), ValidationVerdict::Warnings { warnings: 1 });
+        assert!(matches!(c.verdict(1, 1), ValidationVerdict::Violations { .. }));
+    }
+
+    #[test]
+    fn applies_to_listed_shapes_and_components() {
+        let c = ValidationPolicyConfig::new()
+            .with_shapes(vec!["http://example.org/PersonShape".to_string()])
+            .with_components(vec!["sh:MinCountConstraintComponent".to_string()]);
+        let min_count = "http://www.w3.org/ns/shacl#MinCountConstraintComponent";
+        assert!(c.applies_to(Some("<http://example.org/PersonShape>"), Some(min_count)));
+        assert!(c.applies_to(Some("http://example.org/PersonShape"), None));
+        assert!(!c.applies_to(Some("http://example.org/OtherShape"), Some(min_count)));
+        assert!(!c.applies_to(
+            Some("http://example.org/PersonShape"),
+            Some("http://www.w3.org/ns/shacl#DatatypeConstraintComponent")
+        ));
+        assert!(!c.applies_to(None, Some(min_count)));
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let c = ValidationPolicyConfig::new()
+            .with_fail_on(Some(ValidationFailOn::Warnings))
+            .with_max_violations(Some(3));
+        let s = c.to_toml_string().unwrap();
+        let d = ValidationPolicyConfig::from_toml_str(&s).unwrap();
+        assert_eq!(c, d);
+    }
+}
@@ -0,0 +1,285 @@
+use rudof_rdf::rdf_core::SourceSpan;
+use serde_json::{Value, json};
+use std::collections::HashMap;
+use std::io::{self, Write};
+use std::path::Path;
+use url::Url;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+const RUDOF_URI: &str = "https://rudof-project.github.io/rudof";
+
+/// Severity of a failed case, following the SARIF result levels
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum CiLevel {
+    Error,
+    Warning,
+    Note,
+}
+
+/// Outcome of checking one node against one shape
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum CiOutcome {
+    Passed,
+    Failed(CiLevel),
+    /// The check could not be decided, e.g. because validation timed out
+    Skipped,
+}
+
+/// Place in a file where a case can be annotated
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub(crate) struct CiLocation {
+    uri: String,
+    /// Line and column in the file, both starting at 1
+    region: Option<(usize, usize)>,
+}
+
+impl CiLocation {
+    /// Builds a location from the IRI of a source.
+    ///
+    /// Files under the current directory are shown relative to it, which is
+    /// how code scanning tools match them against the files of a repository.
+    /// File IRIs that don't name an existing file, like the ones given to
+    /// schemas read from a string, have no location.
+    pub fn from_iri(iri: &str) -> Option<Self> {
+        let url = Url::parse(iri).ok()?;
+        if url.scheme() != "file" {
+            return Some(CiLocation {
+                uri: iri.to_string(),
+                region: None,
+            });
+        }
+        let path = url.to_file_path().ok().filter(|path| path.is_file())?;
+        let uri = relative_to_current_dir(&path).unwrap_or_else(|| iri.to_string());
+        Some(CiLocation { uri, region: None })
+    }
+
+    /// Builds a location from a position in a source, whose name is either an IRI
+    /// or a path relative to the current directory
+    pub fn from_span(span: &SourceSpan) -> Option<Self> {
+        let location = match Url::parse(span.source()) {
+            Ok(_) => Self::from_iri(span.source()),
+            Err(_) => {
+                let path = Path::new(span.source());
+                path.is_file().then(|| CiLocation {
+                    uri: relative_to_current_dir(path).unwrap_or_else(|| span.source().to_string()),
+                    region: None,
+                })
+            },
+        }?;
+        Some(CiLocation {
+            region: Some((span.line(), span.column())),
+            ..location
+        })
+    }
+}
+
+fn relative_to_current_dir(path: &Path) -> Option<String> {
+    let current_dir = std::env::current_dir().ok()?.canonicalize().ok()?;
+    let path = path.canonicalize().ok()?;
+    let relative = path.strip_prefix(current_dir).ok()?;
+    let parts: Vec<_> = relative.iter().map(|part| part.to_string_lossy()).collect();
+    Some(parts.join("/"))
+}
+
+/// One node checked against one shape or type
+#[derive(Clone, Debug)]
+pub(crate) struct CiCase {
+    /// Group of the case, usually the shape or type being checked
+    pub suite: String,
+    /// Name of the case, usually the node being checked
+    pub name: String,
+    /// Identifier of the rule that produced a failure
+    pub rule: String,
+    pub outcome: CiOutcome,
+    pub message: String,
+    pub location: Option<CiLocation>,
+}
+
+/// Validation results arranged for continuous integration tools.
+///
+/// The same cases can be written as SARIF, for code scanning annotations, or
+/// as JUnit XML, for test dashboards.
+#[derive(Debug)]
+pub(crate) struct CiReport {
+    version: String,
+    cases: Vec<CiCase>,
+}
+
+impl CiReport {
+    pub fn new(version: &str) -> Self {
+        CiReport {
+            version: version.to_string(),
+            cases: Vec::new(),
+        }
+    }
+
+    pub fn add_case(&mut self, case: CiCase) {
+        self.cases.push(case);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cases.is_empty()
+    }
+
+    /// Writes the report as a SARIF 2.1.0 log with a single run.
+    ///
+    /// Only failed and skipped cases become results, passed cases are not reported.
+    pub fn write_sarif<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let reported: Vec<&CiCase> = self
+            .cases
+            .iter()
+            .filter(|case| case.outcome != CiOutcome::Passed)
+            .collect();
+
+        let mut rule_indexes: HashMap<&str, usize> = HashMap::new();
+        let mut rules = Vec::new();
+        for case in &reported {
+            if !rule_indexes.contains_key(case.rule.as_str()) {
+                rule_indexes.insert(&case.rule, rules.len());
+                rules.push(json!({
+                    "id": case.rule,
+                    "shortDescription": { "text": case.rule },
+                }));
+            }
+        }
+
+        let results: Vec<Value> = reported
+            .iter()
+            .map(|case| sarif_result(case, rule_indexes[case.rule.as_str()]))
+            .collect();
+
+        let log = json!({
+            "$schema": SARIF_SCHEMA,
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "rudof",
+                        "version": self.version,
+                        "informationUri": RUDOF_URI,
+                        "rules": rules,
+                    }
+                },
+                "results": results,
+            }]
+        });
+        serde_json::to_writer_pretty(&mut *writer, &log)?;
+        writeln!(writer)
+    }
+
+    /// Writes the report as JUnit XML, with a test suite per group and a test case per case
+    pub fn write_junit<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let mut suites: Vec<(&str, Vec<&CiCase>)> = Vec::new();
+        for case in &self.cases {
+            match suites.iter_mut().find(|(suite, _)| *suite == case.suite) {
+                Some((_, cases)) => cases.push(case),
+                None => suites.push((&case.suite, vec![case])),
+            }
+        }
+
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        let (failures, skipped) = counts(self.cases.iter());
+        writeln!(
+            writer,
+            r#"<testsuites name="rudof" tests="{}" failures="{failures}" skipped="{skipped}">"#,
+            self.cases.len()
+        )?;
+        for (suite, cases) in suites {
+            let (failures, skipped) = counts(cases.iter().copied());
+            writeln!(
+                writer,
+                r#"  <testsuite name="{}" tests="{}" failures="{failures}" skipped="{skipped}">"#,
+                escape_xml(suite),
+                cases.len()
+            )?;
+            for case in cases {
+                write_junit_case(writer, case)?;
+            }
+            writeln!(writer, "  </testsuite>")?;
+        }
+        writeln!(writer, "</testsuites>")
+    }
+}
+
+fn sarif_result(case: &CiCase, rule_index: usize) -> Value {
+    let level = match case.outcome {
+        CiOutcome::Failed(CiLevel::Error) => "error",
+        CiOutcome::Failed(CiLevel::Warning) => "warning",
+        CiOutcome::Failed(CiLevel::Note) | CiOutcome::Skipped | CiOutcome::Passed => "note",
+    };
+    let mut location = json!({
+        "logicalLocations": [{ "name": case.name, "kind": "resource" }]
+    });
+    if let Some(source) = &case.location {
+        location["physicalLocation"] = json!({ "artifactLocation": { "uri": source.uri } });
+        if let Some((line, column)) = source.region {
+            location["physicalLocation"]["region"] = json!({ "startLine": line, "startColumn": column });
+        }
+    }
+    let mut result = json!({
+        "ruleId": case.rule,
+        "ruleIndex": rule_index,
+        "level": level,
+        "message": { "text": case.message },
+        "locations": [location],
+        "properties": { "node": case.name, "shape": case.suite },
+    });
+    if case.outcome == CiOutcome::Skipped {
+        result["kind"] = json!("open");
+    }
+    result
+}
+
+fn write_junit_case<W: Write>(writer: &mut W, case: &CiCase) -> io::Result<()> {
+    write!(
+        writer,
+        r#"    <testcase name="{}" classname="{}""#,
+        escape_xml(&case.name),
+        escape_xml(&case.suite)
+    )?;
+    if let Some(location) = &case.location {
+        write!(writer, r#" file="{}""#, escape_xml(&location.uri))?;
+        if let Some((line, _)) = location.region {
+            write!(writer, r#" line="{line}""#)?;
+        }
+    }
+    let message = escape_xml(&case.message);
+    match case.outcome {
+        CiOutcome::Passed => writeln!(writer, "/>"),
+        CiOutcome::Failed(CiLevel::Error) => writeln!(
+            writer,
+            ">\n      <failure message=\"{message}\" type=\"{}\">{message}</failure>\n    </testcase>",
+            escape_xml(&case.rule)
+        ),
+        // JUnit has no notion of warnings, so they pass and keep their message as output
+        CiOutcome::Failed(CiLevel::Warning | CiLevel::Note) => {
+            writeln!(writer, ">\n      <system-out>{message}</system-out>\n    </testcase>")
+        },
+        CiOutcome::Skipped => writeln!(writer, ">\n      <skipped message=\"{message}\"/>\n    </testcase>"),
+    }
+}
+
+fn counts<'a>(cases: impl Iterator<Item = &'a CiCase>) -> (usize, usize) {
+    cases.fold((0, 0), |(failures, skipped), case| match case.outcome {
+        CiOutcome::Failed(CiLevel::Error) => (failures + 1, skipped),
+        CiOutcome::Skipped => (failures, skipped + 1),
+        _ => (failures, skipped),
+    })
+}
+
+fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            c if c.is_control() && c != '\t' => {},
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
@@ -0,0 +1,460 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+/// Kind of outcome of a row, used to colour it and to count it in the dashboard
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub(crate) enum HtmlOutcome {
+    #[default]
+    Passed,
+    Failed,
+    Warning,
+    Info,
+    Skipped,
+}
+
+impl HtmlOutcome {
+    const ALL: [HtmlOutcome; 5] = [
+        HtmlOutcome::Passed,
+        HtmlOutcome::Failed,
+        HtmlOutcome::Warning,
+        HtmlOutcome::Info,
+        HtmlOutcome::Skipped,
+    ];
+
+    fn class(&self) -> &'static str {
+        match self {
+            HtmlOutcome::Passed => "passed",
+            HtmlOutcome::Failed => "failed",
+            HtmlOutcome::Warning => "warning",
+            HtmlOutcome::Info => "info",
+            HtmlOutcome::Skipped => "skipped",
+        }
+    }
+
+    fn title(&self) -> &'static str {
+        match self {
+            HtmlOutcome::Passed => "Passed",
+            HtmlOutcome::Failed => "Failed",
+            HtmlOutcome::Warning => "Warnings",
+            HtmlOutcome::Info => "Info",
+            HtmlOutcome::Skipped => "Skipped",
+        }
+    }
+}
+
+/// One node checked against one shape, or one SHACL validation result
+#[derive(Clone, Debug, Default)]
+pub(crate) struct HtmlRow {
+    pub node: String,
+    pub shape: String,
+    /// Label of the outcome as the validator names it, e.g. a status or a severity
+    pub label: String,
+    pub outcome: HtmlOutcome,
+    pub component: Option<String>,
+    pub path: Option<String>,
+    pub value: Option<String>,
+    /// Position in a source, shown as `file:line:column`
+    pub location: Option<String>,
+    pub message: String,
+    /// Longer explanation, shown preformatted in the node details
+    pub details: Option<String>,
+}
+
+/// Validation results rendered as a single HTML page.
+///
+/// The page has no external dependencies: styles and the scripts that sort
+/// and filter the results table are inlined, so it can be shared as a file.
+#[derive(Debug)]
+pub(crate) struct HtmlReport {
+    title: String,
+    version: String,
+    conforms: bool,
+    /// Heading of the summary that counts the rows per label
+    labels_heading: String,
+    rows: Vec<HtmlRow>,
+}
+
+impl HtmlReport {
+    pub fn new(title: &str, version: &str, conforms: bool, labels_heading: &str) -> Self {
+        HtmlReport {
+            title: title.to_string(),
+            version: version.to_string(),
+            conforms,
+            labels_heading: labels_heading.to_string(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn add_row(&mut self, row: HtmlRow) {
+        self.rows.push(row);
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        let anchors = Anchors::new(&self.rows);
+        let title = escape_html(&self.title);
+
+        writeln!(writer, "<!DOCTYPE html>")?;
+        writeln!(writer, "<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">")?;
+        writeln!(
+            writer,
+            "<title>{title}</title>\n<style>{STYLE}</style>\n</head>\n<body>"
+        )?;
+        writeln!(writer, "<h1>{title}</h1>")?;
+        let (verdict_class, verdict) = match self.conforms {
+            true => ("passed", "Conforms"),
+            false => ("failed", "Does not conform"),
+        };
+        writeln!(
+            writer,
+            "<p class=\"verdict {verdict_class}\">{verdict}</p>\n<p class=\"meta\">Generated by rudof {}</p>",
+            escape_html(&self.version)
+        )?;
+
+        self.write_dashboard(writer, &anchors)?;
+        self.write_results(writer, &anchors)?;
+        self.write_nodes(writer, &anchors)?;
+
+        writeln!(writer, "<script>{SCRIPT}</script>\n</body>\n</html>")
+    }
+
+    fn write_dashboard<W: Write>(&self, writer: &mut W, anchors: &Anchors) -> io::Result<()> {
+        writeln!(
+            writer,
+            "<section id=\"dashboard\">\n<h2>Summary</h2>\n<div class=\"cards\">"
+        )?;
+        for outcome in HtmlOutcome::ALL {
+            let count = self.rows.iter().filter(|row| row.outcome == outcome).count();
+            if count > 0 {
+                writeln!(
+                    writer,
+                    "<div class=\"card {}\"><span>{count}</span>{}</div>",
+                    outcome.class(),
+                    outcome.title()
+                )?;
+            }
+        }
+        writeln!(writer, "</div>")?;
+
+        // Conformance per shape, with a column per kind of outcome that appears in the report
+        let outcomes: Vec<HtmlOutcome> = HtmlOutcome::ALL
+            .into_iter()
+            .filter(|outcome| self.rows.iter().any(|row| row.outcome == *outcome))
+            .collect();
+        writeln!(
+            writer,
+            "<h3>Shapes</h3>\n<table class=\"summary\">\n<thead><tr><th>Shape</th>"
+        )?;
+        for outcome in &outcomes {
+            write!(writer, "<th>{}</th>", outcome.title())?;
+        }
+        writeln!(writer, "</tr></thead>\n<tbody>")?;
+        for shape in anchors.shapes() {
+            write!(
+                writer,
+                "<tr id=\"{}\"><td><a href=\"#\" data-filter=\"{}\">{}</a></td>",
+                anchors.shape(shape),
+                escape_html(shape),
+                escape_html(shape)
+            )?;
+            for outcome in &outcomes {
+                let count = self
+                    .rows
+                    .iter()
+                    .filter(|row| row.shape == shape && row.outcome == *outcome)
+                    .count();
+                write!(writer, "<td class=\"count {}\">{count}</td>", outcome.class())?;
+            }
+            writeln!(writer, "</tr>")?;
+        }
+        writeln!(writer, "</tbody>\n</table>")?;
+
+        self.write_counts(writer, &self.labels_heading, |row| Some(&row.label))?;
+        if self.rows.iter().any(|row| row.component.is_some()) {
+            self.write_counts(writer, "Constraint components", |row| row.component.as_ref())?;
+        }
+        writeln!(writer, "</section>")
+    }
+
+    /// Writes a table with the number of rows for each value of a column
+    fn write_counts<W: Write>(
+        &self,
+        writer: &mut W,
+        heading: &str,
+        key: impl Fn(&HtmlRow) -> Option<&String>,
+    ) -> io::Result<()> {
+        let mut counts: Vec<(&String, usize)> = Vec::new();
+        for value in self.rows.iter().filter_map(&key) {
+            match counts.iter_mut().find(|(seen, _)| *seen == value) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((value, 1)),
+            }
+        }
+        counts.sort_by(|(v1, c1), (v2, c2)| c2.cmp(c1).then(v1.cmp(v2)));
+
+        writeln!(
+            writer,
+            "<h3>{heading}</h3>\n<table class=\"summary\">\n<thead><tr><th>{heading}</th><th>Count</th></tr></thead>\n<tbody>"
+        )?;
+        for (value, count) in counts {
+            let value = escape_html(value);
+            writeln!(
+                writer,
+                "<tr><td><a href=\"#\" data-filter=\"{value}\">{value}</a></td><td class=\"count\">{count}</td></tr>"
+            )?;
+        }
+        writeln!(writer, "</tbody>\n</table>")
+    }
+
+    fn write_results<W: Write>(&self, writer: &mut W, anchors: &Anchors) -> io::Result<()> {
+        writeln!(writer, "<section id=\"results\">\n<h2>Results</h2>")?;
+        writeln!(
+            writer,
+            "<div class=\"filters\"><input id=\"filter\" type=\"search\" placeholder=\"Filter results\">\
+             <select id=\"outcome\"><option value=\"\">All outcomes</option>"
+        )?;
+        for outcome in HtmlOutcome::ALL {
+            writeln!(
+                writer,
+                "<option value=\"{}\">{}</option>",
+                outcome.class(),
+                outcome.title()
+            )?;
+        }
+        writeln!(writer, "</select><span id=\"shown\"></span></div>")?;
+
+        let with_component = self.rows.iter().any(|row| row.component.is_some());
+        let with_path = self.rows.iter().any(|row| row.path.is_some());
+        let with_value = self.rows.iter().any(|row| row.value.is_some());
+        let with_location = self.rows.iter().any(|row| row.location.is_some());
+        let mut headers = vec!["Node", "Shape", "Outcome"];
+        if with_component {
+            headers.push("Component");
+        }
+        if with_path {
+            headers.push("Path");
+        }
+        if with_value {
+            headers.push("Value");
+        }
+        if with_location {
+            headers.push("Location");
+        }
+        headers.push("Message");
+
+        writeln!(writer, "<table id=\"results-table\" class=\"sortable\">\n<thead><tr>")?;
+        for header in headers {
+            write!(writer, "<th>{header}</th>")?;
+        }
+        writeln!(writer, "\n</tr></thead>\n<tbody>")?;
+        for row in &self.rows {
+            let outcome = row.outcome.class();
+            write!(
+                writer,
+                "<tr class=\"{outcome}\" data-outcome=\"{outcome}\"><td><a href=\"#{}\">{}</a></td>\
+                 <td><a href=\"#{}\">{}</a></td><td>{}</td>",
+                anchors.node(&row.node),
+                escape_html(&row.node),
+                anchors.shape(&row.shape),
+                escape_html(&row.shape),
+                escape_html(&row.label)
+            )?;
+            for (shown, cell) in [
+                (with_component, &row.component),
+                (with_path, &row.path),
+                (with_value, &row.value),
+                (with_location, &row.location),
+            ] {
+                if shown {
+                    write!(writer, "<td>{}</td>", escape_html(cell.as_deref().unwrap_or_default()))?;
+                }
+            }
+            writeln!(writer, "<td>{}</td></tr>", escape_html(&row.message))?;
+        }
+        writeln!(writer, "</tbody>\n</table>\n</section>")
+    }
+
+    fn write_nodes<W: Write>(&self, writer: &mut W, anchors: &Anchors) -> io::Result<()> {
+        writeln!(writer, "<section id=\"nodes\">\n<h2>Nodes</h2>")?;
+        for node in anchors.nodes() {
+            let rows: Vec<&HtmlRow> = self.rows.iter().filter(|row| row.node == node).collect();
+            let failed = rows.iter().filter(|row| row.outcome == HtmlOutcome::Failed).count();
+            writeln!(
+                writer,
+                "<details id=\"{}\"><summary>{} <small>{} results, {failed} failed</small></summary>\n<ul>",
+                anchors.node(node),
+                escape_html(node),
+                rows.len()
+            )?;
+            for row in rows {
+                write!(
+                    writer,
+                    "<li class=\"{}\"><a href=\"#{}\">{}</a>: {} &mdash; {}",
+                    row.outcome.class(),
+                    anchors.shape(&row.shape),
+                    escape_html(&row.shape),
+                    escape_html(&row.label),
+                    escape_html(&row.message)
+                )?;
+                if let Some(details) = &row.details {
+                    write!(writer, "<pre>{}</pre>", escape_html(details))?;
+                }
+                writeln!(writer, "</li>")?;
+            }
+            writeln!(writer, "</ul>\n</details>")?;
+        }
+        writeln!(writer, "</section>")
+    }
+}
+
+/// Identifiers of the elements that nodes and shapes link to
+struct Anchors<'a> {
+    nodes: Vec<&'a str>,
+    shapes: Vec<&'a str>,
+    node_ids: HashMap<&'a str, usize>,
+    shape_ids: HashMap<&'a str, usize>,
+}
+
+impl<'a> Anchors<'a> {
+    fn new(rows: &'a [HtmlRow]) -> Self {
+        let mut anchors = Anchors {
+            nodes: Vec::new(),
+            shapes: Vec::new(),
+            node_ids: HashMap::new(),
+            shape_ids: HashMap::new(),
+        };
+        for row in rows {
+            if !anchors.node_ids.contains_key(row.node.as_str()) {
+                anchors.node_ids.insert(&row.node, anchors.nodes.len());
+                anchors.nodes.push(&row.node);
+            }
+            if !anchors.shape_ids.contains_key(row.shape.as_str()) {
+                anchors.shape_ids.insert(&row.shape, anchors.shapes.len());
+                anchors.shapes.push(&row.shape);
+            }
+        }
+        anchors
+    }
+
+    fn nodes(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.nodes.iter().copied()
+    }
+
+    fn shapes(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.shapes.iter().copied()
+    }
+
+    fn node(&self, node: &str) -> String {
+        format!("node-{}", self.node_ids[node])
+    }
+
+    fn shape(&self, shape: &str) -> String {
+        format!("shape-{}", self.shape_ids[shape])
+    }
+}
+
+fn escape_html(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+const STYLE: &str = r#"
+body { font-family: system-ui, sans-serif; margin: 2em; color: #222; }
+h1 { margin-bottom: 0.2em; }
+.meta { color: #666; margin-top: 0; }
+.verdict { font-size: 1.3em; font-weight: bold; }
+.verdict.passed { color: #1a7f37; }
+.verdict.failed { color: #cf222e; }
+.cards { display: flex; gap: 1em; flex-wrap: wrap; }
+.card { border-radius: 6px; padding: 0.6em 1.2em; background: #f3f4f6; min-width: 6em; }
+.card span { display: block; font-size: 1.8em; font-weight: bold; }
+.card.passed span { color: #1a7f37; }
+.card.failed span { color: #cf222e; }
+.card.warning span { color: #9a6700; }
+.card.info span { color: #0969da; }
+.card.skipped span { color: #6e7781; }
+table { border-collapse: collapse; margin: 0.5em 0 1.5em; }
+th, td { border: 1px solid #d0d7de; padding: 0.3em 0.6em; text-align: left; vertical-align: top; }
+th { background: #f6f8fa; }
+table.sortable th { cursor: pointer; user-select: none; }
+table.sortable th.asc::after { content: " \25B2"; }
+table.sortable th.desc::after { content: " \25BC"; }
+td.count { text-align: right; }
+tr.failed td:nth-child(3), li.failed { color: #cf222e; }
+tr.warning td:nth-child(3), li.warning { color: #9a6700; }
+tr.info td:nth-child(3), li.info { color: #0969da; }
+tr.passed td:nth-child(3), li.passed { color: #1a7f37; }
+tr.skipped td:nth-child(3), li.skipped { color: #6e7781; }
+.filters { display: flex; gap: 1em; align-items: center; margin-bottom: 0.5em; }
+.filters input { min-width: 20em; }
+details { margin: 0.3em 0; }
+details:target, tr:target { background: #fff8c5; }
+pre { background: #f6f8fa; padding: 0.5em; overflow-x: auto; }
+"#;
+
+const SCRIPT: &str = r#"
+(function () {
+  var table = document.getElementById("results-table");
+  var rows = Array.prototype.slice.call(table.tBodies[0].rows);
+  var filter = document.getElementById("filter");
+  var outcome = document.getElementById("outcome");
+  var shown = document.getElementById("shown");
+
+  function apply() {
+    var text = filter.value.toLowerCase();
+    var count = 0;
+    rows.forEach(function (row) {
+      var visible = (!text || row.textContent.toLowerCase().indexOf(text) >= 0)
+        && (!outcome.value || row.dataset.outcome === outcome.value);
+      row.style.display = visible ? "" : "none";
+      if (visible) { count++; }
+    });
+    shown.textContent = count + " of " + rows.length + " shown";
+  }
+  filter.addEventListener("input", apply);
+  outcome.addEventListener("change", apply);
+
+  Array.prototype.forEach.call(table.tHead.rows[0].cells, function (th, column) {
+    th.addEventListener("click", function () {
+      var asc = !th.classList.contains("asc");
+      Array.prototype.forEach.call(table.tHead.rows[0].cells, function (other) {
+        other.classList.remove("asc", "desc");
+      });
+      th.classList.add(asc ? "asc" : "desc");
+      rows.sort(function (a, b) {
+        var x = a.cells[column].textContent, y = b.cells[column].textContent;
+        return (asc ? 1 : -1) * x.localeCompare(y, undefined, { numeric: true });
+      });
+      rows.forEach(function (row) { table.tBodies[0].appendChild(row); });
+    });
+  });
+
+  document.querySelectorAll("a[data-filter]").forEach(function (link) {
+    link.addEventListener("click", function (event) {
+      event.preventDefault();
+      filter.value = link.dataset.filter;
+      outcome.value = "";
+      apply();
+      document.getElementById("results").scrollIntoView();
+    });
+  });
+
+  document.querySelectorAll("a[href^='#node-']").forEach(function (link) {
+    link.addEventListener("click", function () {
+      var target = document.getElementById(link.getAttribute("href").substring(1));
+      if (target) { target.open = true; }
+    });
+  });
+
+  apply();
+})();
+"#;
@@ -1,5 +1,10 @@
+mod ci_report;
 mod color;
+mod html_report;
 mod table;
 
+pub(crate) use ci_report::{CiCase, CiLevel, CiLocation, CiOutcome, CiReport};
 pub use color::Color;
+pub(crate) use html_report::{HtmlOutcome, HtmlReport, HtmlRow};
 pub use table::Table;
+pub(crate) use table::detail_lines;
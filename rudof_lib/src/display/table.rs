@@ -1,7 +1,7 @@
 use crate::display::Color;
 use colored::{ColoredString, Colorize};
 use prefixmap::PrefixMap;
-use shacl::validator::report::ValidationReport;
+use shacl::validator::report::{ValidationReport, ValidationResult};
 use std::io::{Error, Write};
 use tabled::builder::Builder;
 use tabled::settings::object::Segment;
@@ -27,7 +27,7 @@ impl Table for ValidationReport {
         colored: Option<bool>,
         terminal_width: Option<usize>,
     ) -> Result<(), Error> {
-        if self.results().is_empty() {
+        if self.conforms() {
             return write!(writer, "No Errors found");
         }
 
@@ -43,7 +43,7 @@ impl Table for ValidationReport {
             "Path",
             "Value",
             "Source shape",
-            "Details",
+            "Location",
         ];
         if detailed {
             header.push("Details");
@@ -56,8 +56,13 @@ impl Table for ValidationReport {
             PrefixMap::basic().with_hyperlink(true).without_default_colors()
         };
 
-        for result in self.results() {
-            let severity_str = pm.qualify(&result.severity().into());
+        let timeouts = self.timeouts().iter().map(|timeout| (timeout, true));
+        for (result, is_timeout) in self.results().iter().map(|result| (result, false)).chain(timeouts) {
+            // Timeouts are not violations of any constraint, so they are labelled apart
+            let severity_str = match is_timeout {
+                true => "Timeout".to_string(),
+                false => pm.qualify(&result.severity().into()),
+            };
             let severity = match colored {
                 true => severity_str.color(result.severity().color()),
                 false => ColoredString::from(severity_str),
@@ -68,9 +73,10 @@ impl Table for ValidationReport {
             let path = self.nodes_prefixmap().show(&result.path());
             let source = self.nodes_prefixmap().show(&result.source());
             let value = self.nodes_prefixmap().show(&result.value());
-            let details: String;
+            let location = result.span().map(|span| span.to_string()).unwrap_or_default();
+            let mut details: String;
 
-            let mut record = vec![&severity, &node, &component, &path, &value, &source];
+            let mut record = vec![&severity, &node, &component, &path, &value, &source, &location];
 
             if detailed {
                 details = result
@@ -80,6 +86,11 @@ impl Table for ValidationReport {
                         None => format!("{}- {}\n", acc, msg),
                         Some(lang) => format!("{}- {}: {}\n", acc, lang, msg),
                     });
+                details.extend(
+                    detail_lines(result, self.nodes_prefixmap())
+                        .into_iter()
+                        .map(|line| format!("{line}\n")),
+                );
                 record.push(&details);
             }
             builder.push_record(record);
@@ -89,6 +100,48 @@ impl Table for ValidationReport {
             .with(Style::modern_rounded())
             .with(Modify::new(Segment::all()).with(Width::wrap(terminal_width)))
             .to_string();
-        write!(writer, "{table}")
+        write!(writer, "{table}")?;
+        for omitted in self.omitted() {
+            write!(
+                writer,
+                "\n{} more results of {} {}",
+                omitted.count(),
+                self.nodes_prefixmap().show(&omitted.source()),
+                self.nodes_prefixmap().show(omitted.constraint_component())
+            )?;
+        }
+        if self.stopped_early() {
+            write!(writer, "\nValidation stopped after {} results", self.results().len())?;
+        }
+        Ok(())
+    }
+}
+
+/// Lines with the `sh:detail` results of a result, indented by their level,
+/// each with its component, focus node, path, value and message
+pub(crate) fn detail_lines(result: &ValidationResult, pm: &PrefixMap) -> Vec<String> {
+    fn add_lines(result: &ValidationResult, pm: &PrefixMap, level: usize, lines: &mut Vec<String>) {
+        for detail in result.details() {
+            let mut line = format!(
+                "{}> {} on {}",
+                "  ".repeat(level),
+                pm.show(detail.constraint_component()),
+                pm.show(detail.focus_node())
+            );
+            if let Some(path) = detail.path() {
+                line.push_str(&format!(" at {}", pm.show(path)));
+            }
+            if let Some(value) = detail.value() {
+                line.push_str(&format!(" with {}", pm.show(value)));
+            }
+            if let Some((_, message)) = detail.message().iter().next() {
+                line.push_str(&format!(": {message}"));
+            }
+            lines.push(line);
+            add_lines(detail, pm, level + 1, lines);
+        }
     }
+    let mut lines = Vec::new();
+    add_lines(result, pm, 0, &mut lines);
+    lines
 }
@@ -49,4 +49,31 @@ pub enum ComparisonError {
 
     #[error("Error getting the current directory: {error}")]
     CurrentDirError { error: String },
+
+    /// The format of the validation results to compare is not supported.
+    #[error(
+        "Unsupported format for validation results: '{format}'. Valid formats are: 'json', 'turtle', 'ntriples', 'rdfxml', 'trig', 'n3', 'nquads'"
+    )]
+    UnsupportedValidationReportFormat { format: String },
+
+    /// Only ShEx and SHACL validation results can be compared.
+    #[error("Cannot compare validation results of mode '{mode}'. Valid modes are: 'shex', 'shacl'")]
+    UnsupportedValidationDiffMode { mode: String },
+
+    /// The validation results could not be read.
+    #[error("Error reading {mode} validation results from '{source_name}' as {format}: {error}")]
+    FailedReadingValidationResults {
+        mode: String,
+        source_name: String,
+        format: String,
+        error: String,
+    },
+
+    /// There are no validation results compared yet.
+    #[error("No validation diff available. Compare two validation results first")]
+    NoValidationDiffAvailable,
+
+    /// The validation diff could not be written.
+    #[error("Error writing the validation diff: {error}")]
+    FailedSerializingValidationDiff { error: String },
 }
@@ -31,7 +31,7 @@ pub enum DataError {
 
     /// The result data format specified is not supported by Rudof.
     #[error(
-        "Unsupported result data format: '{format}'. Valid formats are: 'turtle', 'ntriples', 'rdfxml', 'trig', 'n3', 'nquads', 'compact', 'json', 'plantuml', 'svg', 'png'"
+        "Unsupported result data format: '{format}'. Valid formats are: 'turtle', 'ntriples', 'rdfxml', 'trig', 'n3', 'nquads', 'compact', 'json', 'plantuml', 'svg', 'png', 'pg'"
     )]
     UnsupportedResultDataFormat { format: String },
 
@@ -74,6 +74,22 @@ pub enum DataError {
         error: String,
     },
 
+    /// Failed to compute the description of the loaded data.
+    #[error("Failed to describe the loaded data: {error}")]
+    FailedDescribingData { error: String },
+
+    /// Failed to build a MIE by introspecting the loaded data.
+    #[error("Failed to build a MIE from the loaded data: {error}")]
+    FailedBuildingMie { error: String },
+
+    /// Failed to serialize the MIE.
+    #[error("Failed to serialize the MIE: {error}")]
+    FailedSerializingMie { error: String },
+
+    /// No MIE built.
+    #[error("No MIE built")]
+    NoMie,
+
     /// Failed to serialize Rdf Data.
     #[error("Failed to serialize Rdf Data with format '{result_data_format}': {error}")]
     FailedSerializingRdfData { result_data_format: String, error: String },
@@ -102,6 +118,14 @@ pub enum DataError {
     #[error("Failed to serialize data to {format}: {error}")]
     FailedSerializingData { format: String, error: String },
 
+    /// Failed to convert Property Graph data to RDF.
+    #[error("Failed to convert Property Graph data to RDF: {error}")]
+    FailedConvertingPgToRdf { error: String },
+
+    /// Failed to convert RDF data to a Property Graph.
+    #[error("Failed to convert RDF data to a Property Graph: {error}")]
+    FailedConvertingRdfToPg { error: String },
+
     /// Failed to parse a node selector.
     #[error("Failed parsing node selector '{node}': {error}")]
     FailedNodeSelectorParse { node: String, error: String },
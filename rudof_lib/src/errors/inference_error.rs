@@ -0,0 +1,35 @@
+use thiserror::Error;
+
+/// Errors that can occur during schema inference operations in Rudof.
+#[derive(Error, Debug)]
+pub enum InferenceError {
+    /// The requested schema mode is not supported.
+    #[error("Unsupported schema mode for inference: '{mode}'. Valid modes are: shex, shacl")]
+    UnsupportedSchemaMode { mode: String },
+
+    /// The requested result format is not supported.
+    #[error(
+        "Unsupported result format for inference: '{format}'. Valid formats are: shexc, shexj, turtle, ntriples, rdfxml, jsonld"
+    )]
+    UnsupportedResultInferenceFormat { format: String },
+
+    /// The requested result format cannot be used for the schema mode.
+    #[error("Unsupported result format '{format}' for {mode} schemas")]
+    UnsupportedResultFormat { format: String, mode: String },
+
+    /// The shapemap used to group nodes could not be read or parsed.
+    #[error("Failed to read shapemap '{source_name}': {error}")]
+    FailedReadingShapemap { source_name: String, error: String },
+
+    /// The nodes selected by the shapemap could not be resolved.
+    #[error("Failed to resolve the nodes selected by the shapemap: {error}")]
+    FailedResolvingShapemap { error: String },
+
+    /// A shape selector of the shapemap cannot be used as the label of an inferred shape.
+    #[error("Shape label '{label}' cannot be used to group nodes: only IRIs are allowed")]
+    InvalidShapeLabel { label: String },
+
+    /// The inference itself failed.
+    #[error("Failed to infer {mode} schema: {error}")]
+    FailedInference { mode: String, error: String },
+}
@@ -3,6 +3,7 @@ mod conversion_error;
 mod data_error;
 mod dctap_error;
 mod generation_error;
+mod inference_error;
 mod input_spec_error;
 mod iri_error;
 mod map_state_error;
@@ -24,6 +25,7 @@ pub use conversion_error::ConversionError;
 pub use data_error::DataError;
 pub use dctap_error::DCTapError;
 pub use generation_error::GenerationError;
+pub use inference_error::InferenceError;
 pub use input_spec_error::InputSpecError;
 pub use iri_error::IriError;
 pub use map_state_error::MapStateError;
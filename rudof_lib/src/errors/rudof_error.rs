@@ -73,6 +73,10 @@ pub enum RudofError {
     #[error("Generate error: {0}")]
     Generate(#[from] GenerationError),
 
+    /// Schema inference errors.
+    #[error("Inference error: {0}")]
+    Inference(#[from] InferenceError),
+
     /// IRI-related errors.
     #[error("IRI error: {0}")]
     Iri(#[from] IriError),
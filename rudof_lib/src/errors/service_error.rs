@@ -4,6 +4,8 @@ use thiserror::Error;
 #[derive(Error, Debug)]
 pub enum ServiceError {
     /// The service result format specified is not supported by Rudof.
-    #[error("Unsupported service result format: '{format}'. Valid formats are: 'internal', 'mie', 'json'")]
+    #[error(
+        "Unsupported service result format: '{format}'. Valid formats are: 'internal', 'mie', 'json', 'turtle', 'ntriples', 'rdfxml', 'jsonld'"
+    )]
     UnsupportedResultServiceFormat { format: String },
 }
@@ -40,6 +40,14 @@ pub enum ShaclError {
     #[error("SHACL validation failed: {error}")]
     FailedShaclValidation { error: String },
 
+    /// A focus node or shape of the validation is not an IRI or a prefixed name.
+    #[error("Invalid focus node or shape '{term}': {error}")]
+    InvalidFocusTerm { term: String, error: String },
+
+    /// An association of the validation focus is not written as `node@shape`.
+    #[error("Invalid focus association '{association}', expected 'node@shape'")]
+    InvalidFocusAssociation { association: String },
+
     /// No SHACL validation results available.
     #[error("No SHACL validation results available")]
     NoShaclValidationResultsAvailable,
@@ -66,4 +66,20 @@ pub enum ValidationError {
         "Unsupported Property Graph schema validation result format: '{format}'. Valid formats are: 'compact', 'details', 'json', 'csv'"
     )]
     NoSupportedPgSchemaValidationResultFormat { format: String },
+
+    /// The severity that makes a validation fail is not supported by Rudof.
+    #[error("Unsupported validation fail on: '{fail_on}'. Valid options are: 'never', 'violations', 'warnings'")]
+    UnsupportedValidationFailOn { fail_on: String },
+
+    /// The validation profile format is not supported by Rudof.
+    #[error("Unsupported validation profile format: '{format}'. Valid formats are: 'table', 'json', 'folded'")]
+    UnsupportedValidationProfileFormat { format: String },
+
+    /// The last validation was not profiled.
+    #[error("No validation profile available. Enable profiling before validating")]
+    NoValidationProfileAvailable,
+
+    /// The validation profile could not be written.
+    #[error("Error writing the validation profile: {error}")]
+    FailedSerializingValidationProfile { error: String },
 }
@@ -3,6 +3,7 @@ use crate::{
     formats::{DCTapFormat, ShExFormat, ShaclFormat},
 };
 use rudof_iri::MimeType;
+use rudof_rdf::rdf_core::RDFFormat;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
@@ -48,6 +49,28 @@ pub enum ResultComparisonFormat {
     Json,
 }
 
+/// Formats of the validation results compared by a validation diff.
+///
+/// These are the formats that ShEx and SHACL validation results can be written in
+/// and read back from.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ValidationReportFormat {
+    /// JSON - results written with the `json` result format
+    Json,
+    /// Turtle - Compact RDF format
+    Turtle,
+    /// N-Triples - Line-based RDF format
+    NTriples,
+    /// RDF/XML - XML-based RDF serialization
+    RdfXml,
+    /// TriG - extends Turtle with support for named graphs
+    TriG,
+    /// Notation3 - superset of Turtle with additional features
+    N3,
+    /// N-Quads - extends N-Triples with support for named graphs
+    NQuads,
+}
+
 // ============================================================================
 // ComparisonMode
 // ============================================================================
@@ -190,3 +213,72 @@ impl FromStr for ResultComparisonFormat {
         }
     }
 }
+
+// ============================================================================
+// ValidationReportFormat
+// ============================================================================
+
+impl Display for ValidationReportFormat {
+    fn fmt(&self, dest: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            ValidationReportFormat::Json => write!(dest, "json"),
+            ValidationReportFormat::Turtle => write!(dest, "turtle"),
+            ValidationReportFormat::NTriples => write!(dest, "ntriples"),
+            ValidationReportFormat::RdfXml => write!(dest, "rdfxml"),
+            ValidationReportFormat::TriG => write!(dest, "trig"),
+            ValidationReportFormat::N3 => write!(dest, "n3"),
+            ValidationReportFormat::NQuads => write!(dest, "nquads"),
+        }
+    }
+}
+
+impl FromStr for ValidationReportFormat {
+    type Err = ComparisonError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "json" => Ok(ValidationReportFormat::Json),
+            "turtle" => Ok(ValidationReportFormat::Turtle),
+            "ntriples" => Ok(ValidationReportFormat::NTriples),
+            "rdfxml" => Ok(ValidationReportFormat::RdfXml),
+            "trig" => Ok(ValidationReportFormat::TriG),
+            "n3" => Ok(ValidationReportFormat::N3),
+            "nquads" => Ok(ValidationReportFormat::NQuads),
+            other => Err(ComparisonError::UnsupportedValidationReportFormat {
+                format: other.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<ValidationReportFormat> for RDFFormat {
+    type Error = ComparisonError;
+
+    fn try_from(format: ValidationReportFormat) -> Result<Self, Self::Error> {
+        match format {
+            ValidationReportFormat::Turtle => Ok(RDFFormat::Turtle),
+            ValidationReportFormat::NTriples => Ok(RDFFormat::NTriples),
+            ValidationReportFormat::RdfXml => Ok(RDFFormat::Rdfxml),
+            ValidationReportFormat::TriG => Ok(RDFFormat::TriG),
+            ValidationReportFormat::N3 => Ok(RDFFormat::N3),
+            ValidationReportFormat::NQuads => Ok(RDFFormat::NQuads),
+            ValidationReportFormat::Json => Err(ComparisonError::UnsupportedValidationReportFormat {
+                format: format.to_string(),
+            }),
+        }
+    }
+}
+
+impl MimeType for ValidationReportFormat {
+    fn mime_type(&self) -> &'static str {
+        match &self {
+            ValidationReportFormat::Json => "application/json",
+            ValidationReportFormat::Turtle => "text/turtle",
+            ValidationReportFormat::NTriples => "application/n-triples",
+            ValidationReportFormat::RdfXml => "application/rdf+xml",
+            ValidationReportFormat::TriG => "application/trig",
+            ValidationReportFormat::N3 => "text/n3",
+            ValidationReportFormat::NQuads => "application/n-quads",
+        }
+    }
+}
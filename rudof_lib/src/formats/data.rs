@@ -78,6 +78,8 @@ pub enum ResultDataFormat {
     Svg,
     /// PNG - Portable Network Graphics image format for visual output
     Png,
+    /// Property Graph format - the syntax accepted when loading PG data
+    Pg,
 }
 
 // ============================================================================
@@ -294,6 +296,7 @@ impl FromStr for ResultDataFormat {
             "plantuml" => Ok(ResultDataFormat::PlantUML),
             "svg" => Ok(ResultDataFormat::Svg),
             "png" => Ok(ResultDataFormat::Png),
+            "pg" => Ok(ResultDataFormat::Pg),
             _ => Err(DataError::UnsupportedResultDataFormat { format: s.to_string() }),
         }
     }
@@ -314,6 +317,7 @@ impl Display for ResultDataFormat {
             ResultDataFormat::PlantUML => write!(dest, "plantuml"),
             ResultDataFormat::Svg => write!(dest, "svg"),
             ResultDataFormat::Png => write!(dest, "png"),
+            ResultDataFormat::Pg => write!(dest, "pg"),
         }
     }
 }
@@ -0,0 +1,121 @@
+use crate::{
+    errors::InferenceError,
+    formats::{ShExFormat, ShaclFormat},
+};
+use std::fmt;
+use std::str::FromStr;
+
+/// Kinds of schemas that Rudof can infer from RDF data.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum InferenceSchemaMode {
+    /// ShEx (Shape Expressions) schema (default)
+    #[default]
+    ShEx,
+    /// SHACL (Shapes Constraint Language) shapes graph
+    Shacl,
+}
+
+impl fmt::Display for InferenceSchemaMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InferenceSchemaMode::ShEx => write!(f, "shex"),
+            InferenceSchemaMode::Shacl => write!(f, "shacl"),
+        }
+    }
+}
+
+impl FromStr for InferenceSchemaMode {
+    type Err = InferenceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "shex" => Ok(InferenceSchemaMode::ShEx),
+            "shacl" => Ok(InferenceSchemaMode::Shacl),
+            other => Err(InferenceError::UnsupportedSchemaMode {
+                mode: other.to_string(),
+            }),
+        }
+    }
+}
+
+/// Formats in which Rudof can write inferred schemas.
+///
+/// ShEx schemas can be written in all of them, SHACL shapes graphs only in the RDF ones.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ResultInferenceFormat {
+    /// ShExC - Compact ShEx syntax
+    ShExC,
+    /// ShExJ - ShEx JSON format
+    ShExJ,
+    /// Turtle - Compact RDF format
+    Turtle,
+    /// N-Triples - Line-based RDF format
+    NTriples,
+    /// RDF/XML - XML-based RDF serialization
+    RdfXml,
+    /// JSON-LD - JSON format for Linked Data
+    JsonLd,
+}
+
+impl From<ResultInferenceFormat> for ShExFormat {
+    fn from(format: ResultInferenceFormat) -> Self {
+        match format {
+            ResultInferenceFormat::ShExC => ShExFormat::ShExC,
+            ResultInferenceFormat::ShExJ => ShExFormat::ShExJ,
+            ResultInferenceFormat::Turtle => ShExFormat::Turtle,
+            ResultInferenceFormat::NTriples => ShExFormat::NTriples,
+            ResultInferenceFormat::RdfXml => ShExFormat::RdfXml,
+            ResultInferenceFormat::JsonLd => ShExFormat::JsonLd,
+        }
+    }
+}
+
+impl TryFrom<ResultInferenceFormat> for ShaclFormat {
+    type Error = InferenceError;
+
+    fn try_from(format: ResultInferenceFormat) -> Result<Self, Self::Error> {
+        match format {
+            ResultInferenceFormat::Turtle => Ok(ShaclFormat::Turtle),
+            ResultInferenceFormat::NTriples => Ok(ShaclFormat::NTriples),
+            ResultInferenceFormat::RdfXml => Ok(ShaclFormat::RdfXml),
+            ResultInferenceFormat::JsonLd => Ok(ShaclFormat::JsonLd),
+            ResultInferenceFormat::ShExC | ResultInferenceFormat::ShExJ => {
+                Err(InferenceError::UnsupportedResultFormat {
+                    format: format.to_string(),
+                    mode: InferenceSchemaMode::Shacl.to_string(),
+                })
+            },
+        }
+    }
+}
+
+impl fmt::Display for ResultInferenceFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResultInferenceFormat::ShExC => write!(f, "shexc"),
+            ResultInferenceFormat::ShExJ => write!(f, "shexj"),
+            ResultInferenceFormat::Turtle => write!(f, "turtle"),
+            ResultInferenceFormat::NTriples => write!(f, "ntriples"),
+            ResultInferenceFormat::RdfXml => write!(f, "rdfxml"),
+            ResultInferenceFormat::JsonLd => write!(f, "jsonld"),
+        }
+    }
+}
+
+impl FromStr for ResultInferenceFormat {
+    type Err = InferenceError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "shexc" => Ok(ResultInferenceFormat::ShExC),
+            "shexj" => Ok(ResultInferenceFormat::ShExJ),
+            "turtle" => Ok(ResultInferenceFormat::Turtle),
+            "ntriples" => Ok(ResultInferenceFormat::NTriples),
+            "rdfxml" => Ok(ResultInferenceFormat::RdfXml),
+            "jsonld" => Ok(ResultInferenceFormat::JsonLd),
+            other => Err(InferenceError::UnsupportedResultInferenceFormat {
+                format: other.to_string(),
+            }),
+        }
+    }
+}
@@ -4,6 +4,7 @@ mod conversion;
 mod data;
 mod dctap;
 mod generation;
+mod inference;
 mod input_spec;
 mod node;
 mod pgschema;
@@ -21,6 +22,7 @@ pub use conversion::*;
 pub use data::*;
 pub use dctap::*;
 pub use generation::*;
+pub use inference::*;
 pub use input_spec::*;
 pub use node::*;
 pub use pgschema::*;
@@ -1,4 +1,5 @@
 use crate::errors::ServiceError;
+use rudof_rdf::rdf_core::RDFFormat;
 pub use sparql_service::ServiceDescriptionFormat;
 use std::fmt::{Display, Formatter};
 use std::str::FromStr;
@@ -13,6 +14,14 @@ pub enum ResultServiceFormat {
     Mie,
     /// JSON format - machine-readable JSON serialization
     Json,
+    /// Turtle format - RDF using the Service Description and VoID vocabularies
+    Turtle,
+    /// N-Triples format - RDF using the Service Description and VoID vocabularies
+    NTriples,
+    /// RDF/XML format - RDF using the Service Description and VoID vocabularies
+    RdfXml,
+    /// JSON-LD format - RDF using the Service Description and VoID vocabularies
+    JsonLd,
 }
 
 impl FromStr for ResultServiceFormat {
@@ -23,6 +32,10 @@ impl FromStr for ResultServiceFormat {
             "internal" => Ok(ResultServiceFormat::Internal),
             "mie" => Ok(ResultServiceFormat::Mie),
             "json" => Ok(ResultServiceFormat::Json),
+            "turtle" => Ok(ResultServiceFormat::Turtle),
+            "ntriples" => Ok(ResultServiceFormat::NTriples),
+            "rdfxml" => Ok(ResultServiceFormat::RdfXml),
+            "jsonld" => Ok(ResultServiceFormat::JsonLd),
             other => Err(ServiceError::UnsupportedResultServiceFormat {
                 format: other.to_string(),
             }),
@@ -36,6 +49,10 @@ impl From<ResultServiceFormat> for ServiceDescriptionFormat {
             ResultServiceFormat::Internal => ServiceDescriptionFormat::Internal,
             ResultServiceFormat::Mie => ServiceDescriptionFormat::Mie,
             ResultServiceFormat::Json => ServiceDescriptionFormat::Json,
+            ResultServiceFormat::Turtle => ServiceDescriptionFormat::Rdf(RDFFormat::Turtle),
+            ResultServiceFormat::NTriples => ServiceDescriptionFormat::Rdf(RDFFormat::NTriples),
+            ResultServiceFormat::RdfXml => ServiceDescriptionFormat::Rdf(RDFFormat::Rdfxml),
+            ResultServiceFormat::JsonLd => ServiceDescriptionFormat::Rdf(RDFFormat::JsonLd),
         }
     }
 }
@@ -46,6 +63,10 @@ impl Display for ResultServiceFormat {
             ResultServiceFormat::Internal => write!(dest, "internal"),
             ResultServiceFormat::Mie => write!(dest, "mie"),
             ResultServiceFormat::Json => write!(dest, "json"),
+            ResultServiceFormat::Turtle => write!(dest, "turtle"),
+            ResultServiceFormat::NTriples => write!(dest, "ntriples"),
+            ResultServiceFormat::RdfXml => write!(dest, "rdfxml"),
+            ResultServiceFormat::JsonLd => write!(dest, "jsonld"),
         }
     }
 }
@@ -1,5 +1,6 @@
 use crate::{errors::ValidationError, formats::ShapeMapFormat};
 use rudof_rdf::rdf_core::RDFFormat;
+use serde::{Deserialize, Serialize};
 use shacl::validator::ShaclValidationMode as InnerShaclValidationMode;
 use shacl::validator::report::ValidationReportSorting;
 use shex_ast::shapemap::result_shape_map::SortMode;
@@ -100,6 +101,14 @@ pub enum ResultValidationFormat {
     Json,
     /// CSV format - comma-separated values for spreadsheet tools
     Csv,
+    /// NDJSON format - one JSON object per line, streamed while validating
+    NdJson,
+    /// SARIF format - static analysis results for code scanning tools
+    Sarif,
+    /// JUnit XML format - test report for CI pipelines
+    JUnit,
+    /// HTML format - self-contained report with a summary dashboard
+    Html,
 }
 
 /// Output formats for ShEx validation results supported by Rudof.
@@ -128,6 +137,14 @@ pub enum ResultShExValidationFormat {
     Json,
     /// CSV format - comma-separated values for spreadsheet tools
     Csv,
+    /// NDJSON format - one JSON object per line, streamed while validating
+    NdJson,
+    /// SARIF format - static analysis results for code scanning tools
+    Sarif,
+    /// JUnit XML format - test report for CI pipelines
+    JUnit,
+    /// HTML format - self-contained report with a summary dashboard
+    Html,
 }
 
 /// Output formats for SHACL validation results.
@@ -158,6 +175,14 @@ pub enum ResultShaclValidationFormat {
     Json,
     /// CSV format - comma-separated values for spreadsheet tools
     Csv,
+    /// NDJSON format - one JSON object per line, streamed while validating
+    NdJson,
+    /// SARIF format - static analysis results for code scanning tools
+    Sarif,
+    /// JUnit XML format - test report for CI pipelines
+    JUnit,
+    /// HTML format - self-contained report with a summary dashboard
+    Html,
 }
 
 /// Output formats for Property Graph schema validation results.
@@ -174,6 +199,35 @@ pub enum ResultPgSchemaValidationFormat {
     Json,
     /// CSV format - comma-separated values for spreadsheet tools
     Csv,
+    /// SARIF format - static analysis results for code scanning tools
+    Sarif,
+    /// JUnit XML format - test report for CI pipelines
+    JUnit,
+}
+
+/// Severity of the results that make a validation fail its quality gate.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ValidationFailOn {
+    /// The validation never fails because of its results (default)
+    #[default]
+    Never,
+    /// Fails when there are violations
+    Violations,
+    /// Fails when there are violations or warnings
+    Warnings,
+}
+
+/// Output formats of the profile of a validation.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Default)]
+pub enum ValidationProfileFormat {
+    /// Table with a row per shape and constraint component, the slowest first (default)
+    #[default]
+    Table,
+    /// JSON array with the same rows as the table
+    Json,
+    /// Folded stacks weighted by microseconds, the input of flame graph tools
+    Folded,
 }
 
 // ============================================================================
@@ -391,7 +445,7 @@ impl ResultValidationFormat {
     /// Checks if this is a Property Graph-compatible format.
     ///
     /// # Returns
-    /// `true` for Compact, Details, Json, and Csv.
+    /// `true` for Compact, Details, Json, Csv, Sarif and JUnit.
     pub fn is_pg_format(&self) -> bool {
         matches!(
             self,
@@ -399,6 +453,8 @@ impl ResultValidationFormat {
                 | ResultValidationFormat::Details
                 | ResultValidationFormat::Json
                 | ResultValidationFormat::Csv
+                | ResultValidationFormat::Sarif
+                | ResultValidationFormat::JUnit
         )
     }
 }
@@ -416,6 +472,10 @@ impl Display for ResultValidationFormat {
             ResultValidationFormat::Json => write!(dest, "json"),
             ResultValidationFormat::Details => write!(dest, "details"),
             ResultValidationFormat::Csv => write!(dest, "csv"),
+            ResultValidationFormat::NdJson => write!(dest, "ndjson"),
+            ResultValidationFormat::Sarif => write!(dest, "sarif"),
+            ResultValidationFormat::JUnit => write!(dest, "junit"),
+            ResultValidationFormat::Html => write!(dest, "html"),
         }
     }
 }
@@ -435,6 +495,10 @@ impl FromStr for ResultValidationFormat {
             "details" => Ok(ResultValidationFormat::Details),
             "json" => Ok(ResultValidationFormat::Json),
             "csv" => Ok(ResultValidationFormat::Csv),
+            "ndjson" => Ok(ResultValidationFormat::NdJson),
+            "sarif" => Ok(ResultValidationFormat::Sarif),
+            "junit" => Ok(ResultValidationFormat::JUnit),
+            "html" => Ok(ResultValidationFormat::Html),
             other => Err(ValidationError::UnsupportedValidationResultFormat {
                 format: other.to_string(),
             }),
@@ -455,6 +519,10 @@ impl From<ResultValidationFormat> for ResultShExValidationFormat {
             ResultValidationFormat::Details => ResultShExValidationFormat::Details,
             ResultValidationFormat::Json => ResultShExValidationFormat::Json,
             ResultValidationFormat::Csv => ResultShExValidationFormat::Csv,
+            ResultValidationFormat::NdJson => ResultShExValidationFormat::NdJson,
+            ResultValidationFormat::Sarif => ResultShExValidationFormat::Sarif,
+            ResultValidationFormat::JUnit => ResultShExValidationFormat::JUnit,
+            ResultValidationFormat::Html => ResultShExValidationFormat::Html,
         }
     }
 }
@@ -472,6 +540,10 @@ impl From<ResultValidationFormat> for ResultShaclValidationFormat {
             ResultValidationFormat::Details => ResultShaclValidationFormat::Details,
             ResultValidationFormat::Json => ResultShaclValidationFormat::Json,
             ResultValidationFormat::Csv => ResultShaclValidationFormat::Csv,
+            ResultValidationFormat::NdJson => ResultShaclValidationFormat::NdJson,
+            ResultValidationFormat::Sarif => ResultShaclValidationFormat::Sarif,
+            ResultValidationFormat::JUnit => ResultShaclValidationFormat::JUnit,
+            ResultValidationFormat::Html => ResultShaclValidationFormat::Html,
         }
     }
 }
@@ -485,6 +557,8 @@ impl TryFrom<ResultValidationFormat> for ResultPgSchemaValidationFormat {
             ResultValidationFormat::Details => Ok(ResultPgSchemaValidationFormat::Details),
             ResultValidationFormat::Json => Ok(ResultPgSchemaValidationFormat::Json),
             ResultValidationFormat::Csv => Ok(ResultPgSchemaValidationFormat::Csv),
+            ResultValidationFormat::Sarif => Ok(ResultPgSchemaValidationFormat::Sarif),
+            ResultValidationFormat::JUnit => Ok(ResultPgSchemaValidationFormat::JUnit),
             other => Err(ValidationError::UnsupportedConversionToPgSchemaValidationResultFormat {
                 format: other.to_string(),
             }),
@@ -509,6 +583,10 @@ impl Display for ResultShExValidationFormat {
             ResultShExValidationFormat::Json => write!(dest, "json"),
             ResultShExValidationFormat::Details => write!(dest, "details"),
             ResultShExValidationFormat::Csv => write!(dest, "csv"),
+            ResultShExValidationFormat::NdJson => write!(dest, "ndjson"),
+            ResultShExValidationFormat::Sarif => write!(dest, "sarif"),
+            ResultShExValidationFormat::JUnit => write!(dest, "junit"),
+            ResultShExValidationFormat::Html => write!(dest, "html"),
         }
     }
 }
@@ -553,6 +631,10 @@ impl FromStr for ResultShExValidationFormat {
             "details" => Ok(ResultShExValidationFormat::Details),
             "json" => Ok(ResultShExValidationFormat::Json),
             "csv" => Ok(ResultShExValidationFormat::Csv),
+            "ndjson" => Ok(ResultShExValidationFormat::NdJson),
+            "sarif" => Ok(ResultShExValidationFormat::Sarif),
+            "junit" => Ok(ResultShExValidationFormat::JUnit),
+            "html" => Ok(ResultShExValidationFormat::Html),
             other => Err(ValidationError::UnsupportedShExValidationResultFormat {
                 format: other.to_string(),
             }),
@@ -578,6 +660,10 @@ impl Display for ResultShaclValidationFormat {
             ResultShaclValidationFormat::Details => write!(dest, "details"),
             ResultShaclValidationFormat::Json => write!(dest, "json"),
             ResultShaclValidationFormat::Csv => write!(dest, "csv"),
+            ResultShaclValidationFormat::NdJson => write!(dest, "ndjson"),
+            ResultShaclValidationFormat::Sarif => write!(dest, "sarif"),
+            ResultShaclValidationFormat::JUnit => write!(dest, "junit"),
+            ResultShaclValidationFormat::Html => write!(dest, "html"),
         }
     }
 }
@@ -616,6 +702,10 @@ impl FromStr for ResultShaclValidationFormat {
             "details" => Ok(ResultShaclValidationFormat::Details),
             "json" => Ok(ResultShaclValidationFormat::Json),
             "csv" => Ok(ResultShaclValidationFormat::Csv),
+            "ndjson" => Ok(ResultShaclValidationFormat::NdJson),
+            "sarif" => Ok(ResultShaclValidationFormat::Sarif),
+            "junit" => Ok(ResultShaclValidationFormat::JUnit),
+            "html" => Ok(ResultShaclValidationFormat::Html),
             other => Err(ValidationError::UnsupportedShaclValidationResultFormat {
                 format: other.to_string(),
             }),
@@ -634,6 +724,8 @@ impl Display for ResultPgSchemaValidationFormat {
             ResultPgSchemaValidationFormat::Details => "details",
             ResultPgSchemaValidationFormat::Json => "json",
             ResultPgSchemaValidationFormat::Csv => "csv",
+            ResultPgSchemaValidationFormat::Sarif => "sarif",
+            ResultPgSchemaValidationFormat::JUnit => "junit",
         };
         write!(f, "{}", s)
     }
@@ -648,9 +740,69 @@ impl FromStr for ResultPgSchemaValidationFormat {
             "details" => Ok(ResultPgSchemaValidationFormat::Details),
             "json" => Ok(ResultPgSchemaValidationFormat::Json),
             "csv" => Ok(ResultPgSchemaValidationFormat::Csv),
+            "sarif" => Ok(ResultPgSchemaValidationFormat::Sarif),
+            "junit" => Ok(ResultPgSchemaValidationFormat::JUnit),
             other => Err(ValidationError::NoSupportedPgSchemaValidationResultFormat {
                 format: other.to_string(),
             }),
         }
     }
 }
+
+// ============================================================================
+// ValidationFailOn
+// ============================================================================
+
+impl Display for ValidationFailOn {
+    fn fmt(&self, dest: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            ValidationFailOn::Never => write!(dest, "never"),
+            ValidationFailOn::Violations => write!(dest, "violations"),
+            ValidationFailOn::Warnings => write!(dest, "warnings"),
+        }
+    }
+}
+
+impl FromStr for ValidationFailOn {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "never" => Ok(ValidationFailOn::Never),
+            "violations" => Ok(ValidationFailOn::Violations),
+            "warnings" => Ok(ValidationFailOn::Warnings),
+            other => Err(ValidationError::UnsupportedValidationFailOn {
+                fail_on: other.to_string(),
+            }),
+        }
+    }
+}
+
+// ============================================================================
+// ValidationProfileFormat
+// ============================================================================
+
+impl Display for ValidationProfileFormat {
+    fn fmt(&self, dest: &mut Formatter<'_>) -> Result<(), std::fmt::Error> {
+        match self {
+            ValidationProfileFormat::Table => write!(dest, "table"),
+            ValidationProfileFormat::Json => write!(dest, "json"),
+            ValidationProfileFormat::Folded => write!(dest, "folded"),
+        }
+    }
+}
+
+impl FromStr for ValidationProfileFormat {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "table" => Ok(ValidationProfileFormat::Table),
+            "json" => Ok(ValidationProfileFormat::Json),
+            "folded" => Ok(ValidationProfileFormat::Folded),
+            other => Err(ValidationError::UnsupportedValidationProfileFormat {
+                format: other.to_string(),
+            }),
+        }
+    }
+}
@@ -44,5 +44,26 @@ pub use crate::config::RudofConfig;
 #[cfg(not(target_family = "wasm"))]
 pub use rudof::*;
 
+// Reexport the token used to cancel long-running validations
+#[cfg(not(target_family = "wasm"))]
+pub use rudof_rdf::rdf_core::utils::CancellationToken;
+
+// Reexport the profile recorded by validations
+#[cfg(not(target_family = "wasm"))]
+pub use rudof_rdf::rdf_core::utils::{ProfileEntry, Profiler};
+
+// Reexport the syntax errors skipped while loading data in lax mode
+#[cfg(not(target_family = "wasm"))]
+pub use rudof_rdf::rdf_impl::RecoveredError;
+
+// Reexport the events emitted while validating
+#[cfg(not(target_family = "wasm"))]
+pub use shacl::validator::ShaclValidationEvent;
+#[cfg(not(target_family = "wasm"))]
+pub use shex_validation::ShExValidationEvent;
+
+// Reexport the limits on the number of SHACL validation results
+pub use shacl::validator::ResultLimits;
+
 // Reexport config trait and error
 pub use rudof_config::{ConfigError, TomlConfig};
@@ -1,19 +1,24 @@
 use crate::{
     RudofConfig,
     api::{
-        comparison::builders::ShowSchemaComparisonBuilder,
+        comparison::builders::{
+            CompareValidationResultsBuilder, SerializeValidationDiffBuilder, ShowSchemaComparisonBuilder,
+        },
         conversion::builders::ShowSchemaConversionBuilder,
         core::{
             CoreOperations,
-            builders::{ConfigBuilder, ResetAllBuilder, UpdateConfigBuilder, VersionBuilder},
+            builders::{
+                ConfigBuilder, ResetAllBuilder, SerializeValidationProfileBuilder, UpdateConfigBuilder, VersionBuilder,
+            },
         },
         data::builders::{
-            DereferenceBuilder, ListEndpointsBuilder, LoadDataBuilder, LoadServiceDescriptionBuilder, ResetDataBuilder,
-            ResetServiceDescriptionBuilder, SerializeDataBuilder, SerializeServiceDescriptionBuilder,
-            ShowNodeInfoBuilder,
+            BuildMieBuilder, DereferenceBuilder, DescribeDataBuilder, ListEndpointsBuilder, LoadDataBuilder,
+            LoadServiceDescriptionBuilder, ResetDataBuilder, ResetServiceDescriptionBuilder, SerializeDataBuilder,
+            SerializeMieBuilder, SerializeServiceDescriptionBuilder, ShowNodeInfoBuilder,
         },
         dctap::builders::{LoadDctapBuilder, ResetDctapBuilder, SerializeDctapBuilder},
         generation::builders::GenerateDataBuilder,
+        inference::builders::InferSchemaBuilder,
         map_state::builders::{LoadMapStateBuilder, SerializeMapStateBuilder},
         materialize::builders::MaterializeBuilder,
         pgschema::builders::{
@@ -30,28 +35,33 @@ use crate::{
         },
         rdf_config::builders::{LoadRdfConfigBuilder, ResetRdfConfigBuilder, SerializeRdfConfigBuilder},
         shacl::builders::{
-            LoadShaclShapesBuilder, ResetShaclBuilder, ResetShaclShapesBuilder, SerializeShaclShapesBuilder,
-            SerializeShaclValidationResultsBuilder, ValidateShaclBuilder,
+            CheckShaclValidationPolicyBuilder, LoadShaclShapesBuilder, ResetShaclBuilder, ResetShaclShapesBuilder,
+            SerializeShaclShapesBuilder, SerializeShaclValidationResultsBuilder, ValidateShaclBuilder,
         },
         shex::builders::{
-            AddNodeShapeToShapemapBuilder, CheckShexSchemaBuilder, CompileShexSchemaToFileBuilder, LoadShapemapBuilder,
-            LoadShexSchemaBuilder, LoadShexSchemaPrecompiledBuilder, ResetShapemapBuilder, ResetShexBuilder,
-            ResetShexSchemaBuilder, SerializeShapemapBuilder, SerializeShexSchemaBuilder,
-            SerializeShexValidationResultsBuilder, ValidateShexBuilder,
+            AddNodeShapeToShapemapBuilder, CheckShexSchemaBuilder, CheckShexValidationPolicyBuilder,
+            CompileShexSchemaToFileBuilder, LoadShapemapBuilder, LoadShexSchemaBuilder,
+            LoadShexSchemaPrecompiledBuilder, ResetShapemapBuilder, ResetShexBuilder, ResetShexSchemaBuilder,
+            SerializeShapemapBuilder, SerializeShexSchemaBuilder, SerializeShexValidationResultsBuilder,
+            ValidateShexBuilder,
         },
     },
     errors::{RudofError, ShExError},
     formats::{
         ComparisonFormat, ComparisonMode, ConversionFormat, ConversionMode, GenerationSchemaFormat, InputSpec,
-        ResultConversionFormat, ResultConversionMode,
+        ResultConversionFormat, ResultConversionMode, ValidationMode,
     },
-    types::{Data, QueryResult},
+    types::{Data, QueryResult, ValidationDiff},
 };
 use dctap::DCTap as DCTAP;
+use mie::Mie;
 use pgschema::{pgs::PropertyGraphSchema, type_map::TypeMap, validation_result::ValidationResult};
 use prefixmap::PrefixMap;
 use rdf_config::RdfConfigModel;
+use rudof_iri::IriS;
 use rudof_rdf::rdf_core::query::SparqlQuery;
+use rudof_rdf::rdf_core::utils::Profiler;
+use rudof_rdf::rdf_impl::RecoveredError;
 use shacl::ir::IRSchema;
 use shacl::validator::report::ValidationReport;
 use shex_ast::ir::external_resolver::{
@@ -91,9 +101,15 @@ pub struct Rudof {
     /// Current Data
     pub(crate) data: Option<Data>,
 
+    /// Syntax errors skipped by the last data loading in lax mode
+    pub(crate) data_warnings: Vec<RecoveredError>,
+
     /// Current SHACL Schema Internal Representation
     pub(crate) shacl_shapes: Option<IRSchema>,
 
+    /// Location the current SHACL shapes were read from, when they came from a file or URL
+    pub(crate) shacl_shapes_source: Option<IriS>,
+
     /// Current SHACL validation results
     pub(crate) shacl_validation_results: Option<ValidationReport>,
 
@@ -112,6 +128,15 @@ pub struct Rudof {
     /// Current ShEx validation results
     pub(crate) shex_validation_results: Option<ResultShapeMap>,
 
+    /// Differences between the last two validation results compared
+    pub(crate) validation_diff: Option<ValidationDiff>,
+
+    /// Whether ShEx and SHACL validations record a profile
+    pub(crate) profile_validation: bool,
+
+    /// Profile of the last ShEx or SHACL validation, when profiling is enabled
+    pub(crate) validation_profile: Option<Profiler>,
+
     /// Current PGSchema
     pub(crate) pg_schema: Option<PropertyGraphSchema>,
 
@@ -133,6 +158,9 @@ pub struct Rudof {
     /// Current Service Description
     pub(crate) service_description: Option<ServiceDescription>,
 
+    /// Current MIE, built by introspecting the data
+    pub(crate) mie: Option<Mie>,
+
     /// Current rdf_config model
     pub(crate) rdf_config: Option<RdfConfigModel>,
 
@@ -179,6 +207,14 @@ impl Rudof {
         ResetAllBuilder::new(self)
     }
 
+    /// Returns a `SerializeValidationProfileBuilder` to write the profile of the last validation to `writer`.
+    pub fn serialize_validation_profile<'a, W: io::Write>(
+        &'a self,
+        writer: &'a mut W,
+    ) -> SerializeValidationProfileBuilder<'a, W> {
+        SerializeValidationProfileBuilder::new(self, writer)
+    }
+
     // ========================================================================
     // External-shape resolver management
     // ========================================================================
@@ -216,6 +252,29 @@ impl Rudof {
         self.config = std::mem::take(&mut self.config).with_shex_validator(vc);
     }
 
+    /// Enable or disable the recording of justification trees during ShEx validation.
+    ///
+    /// Like external resolvers, it must be set before loading the schema, since the
+    /// validator takes its configuration when the schema is loaded.
+    pub fn set_shex_justify(&mut self, flag: bool) {
+        let vc = self.config.shex_validator().clone().with_justify(flag);
+        self.config = std::mem::take(&mut self.config).with_shex_validator(vc);
+    }
+
+    /// Enable or disable the profiling of ShEx and SHACL validations.
+    ///
+    /// When enabled, each validation records the time, evaluations, cache hits and
+    /// SPARQL queries of every shape and constraint, available afterwards with
+    /// [`Self::validation_profile`] and [`Self::serialize_validation_profile`].
+    pub fn set_validation_profiling(&mut self, flag: bool) {
+        self.profile_validation = flag;
+    }
+
+    /// Returns the profile of the last validation, if it was profiled.
+    pub fn validation_profile(&self) -> Option<&Profiler> {
+        self.validation_profile.as_ref()
+    }
+
     /// Enumerate the built-in external-shape resolver kinds. Each entry
     /// describes a `name`, a one-line `description`, and the `spec_syntax`
     /// accepted by [`Self::add_external_resolver`].
@@ -261,6 +320,13 @@ impl Rudof {
         MaterializeBuilder::new(self, writer)
     }
 
+    /// Returns an `InferSchemaBuilder` to infer a schema from the currently-loaded data.
+    ///
+    /// The inferred schema replaces the current schema, so it can be serialized or used for validation.
+    pub fn infer_schema<'a>(&'a mut self) -> InferSchemaBuilder<'a> {
+        InferSchemaBuilder::new(self)
+    }
+
     /// Returns a `ResetDataBuilder` to clear loaded data from `Rudof`.
     pub fn reset_data<'a>(&'a mut self) -> ResetDataBuilder<'a> {
         ResetDataBuilder::new(self)
@@ -290,6 +356,26 @@ impl Rudof {
         ResetServiceDescriptionBuilder::new(self)
     }
 
+    /// Returns a `DescribeDataBuilder` to compute a VoID / SPARQL service description
+    /// of the loaded data, which becomes the current service description.
+    pub fn describe_data<'a>(&'a mut self) -> DescribeDataBuilder<'a> {
+        DescribeDataBuilder::new(self)
+    }
+
+    /// Returns a `BuildMieBuilder` to build a MIE file by introspecting the loaded data,
+    /// which becomes the current MIE. The service description of the data is also updated.
+    pub fn build_mie<'a>(&'a mut self) -> BuildMieBuilder<'a> {
+        BuildMieBuilder::new(self)
+    }
+
+    /// Returns a `SerializeMieBuilder` to write the current MIE to `writer` as JSON.
+    ///
+    /// # Parameters
+    /// - `writer`: output target for the serialized MIE.
+    pub fn serialize_mie<'a, W: io::Write>(&'a self, writer: &'a mut W) -> SerializeMieBuilder<'a, W> {
+        SerializeMieBuilder::new(self, writer)
+    }
+
     /// Returns a `ShowNodeInfoBuilder` that writes structural inspection information
     /// about the given `node` (within the loaded data) to `writer`.
     ///
@@ -331,6 +417,13 @@ impl Rudof {
         }
     }
 
+    /// Returns the syntax errors skipped by the last data loading in lax mode.
+    ///
+    /// The statements where they were found were dropped from the data.
+    pub fn data_warnings(&self) -> &[RecoveredError] {
+        &self.data_warnings
+    }
+
     /// Returns whether a SPARQL query is currently loaded, regardless of
     /// whether it has been run yet (e.g. loaded by `sparql`, which only
     /// loads and shows, without running).
@@ -363,6 +456,11 @@ impl Rudof {
         self.service_description.as_ref()
     }
 
+    /// Returns the current MIE, if any.
+    pub fn mie(&self) -> Option<&Mie> {
+        self.mie.as_ref()
+    }
+
     /// Returns a `DereferenceBuilder` to fetch `uri` over HTTP(S) — content-negotiating
     /// for an RDF serialization and following redirects — and merge the result into
     /// `Rudof`'s state.
@@ -480,6 +578,12 @@ impl Rudof {
         SerializeShexValidationResultsBuilder::new(self, writer)
     }
 
+    /// Returns a `CheckShexValidationPolicyBuilder` to check the ShEx validation
+    /// results against the configured validation policy.
+    pub fn check_shex_validation_policy<'a>(&'a self) -> CheckShexValidationPolicyBuilder<'a> {
+        CheckShexValidationPolicyBuilder::new(self)
+    }
+
     /// Returns the result of the most recent `validate_shex()` call, if any.
     pub fn shex_validation_results(&self) -> Option<&ResultShapeMap> {
         self.shex_validation_results.as_ref()
@@ -530,6 +634,12 @@ impl Rudof {
         SerializeShaclValidationResultsBuilder::new(self, writer)
     }
 
+    /// Returns a `CheckShaclValidationPolicyBuilder` to check the SHACL validation
+    /// results against the configured validation policy.
+    pub fn check_shacl_validation_policy<'a>(&'a self) -> CheckShaclValidationPolicyBuilder<'a> {
+        CheckShaclValidationPolicyBuilder::new(self)
+    }
+
     /// Returns a `ResetShaclBuilder` to clear SHACL validation
     /// results from the internal state.
     pub fn reset_shacl<'a>(&'a mut self) -> ResetShaclBuilder<'a> {
@@ -614,6 +724,32 @@ impl Rudof {
         ShowSchemaComparisonBuilder::new(self, schema1, schema2, format1, format2, mode1, mode2, writer)
     }
 
+    /// Returns a `CompareValidationResultsBuilder` to compare the results of two validation runs.
+    ///
+    /// - `old`/`new`: input specifications for the results of the previous and current runs.
+    /// - `mode`: validation language of both results.
+    pub fn compare_validation_results<'a>(
+        &'a mut self,
+        old: &'a InputSpec,
+        new: &'a InputSpec,
+        mode: &'a ValidationMode,
+    ) -> CompareValidationResultsBuilder<'a> {
+        CompareValidationResultsBuilder::new(self, old, new, mode)
+    }
+
+    /// Returns a `SerializeValidationDiffBuilder` to write the last validation diff to `writer`.
+    pub fn serialize_validation_diff<'a, W: io::Write>(
+        &'a self,
+        writer: &'a mut W,
+    ) -> SerializeValidationDiffBuilder<'a, W> {
+        SerializeValidationDiffBuilder::new(self, writer)
+    }
+
+    /// Returns the result of the most recent `compare_validation_results()` call, if any.
+    pub fn validation_diff(&self) -> Option<&ValidationDiff> {
+        self.validation_diff.as_ref()
+    }
+
     // ========================================================================
     // ConversionOperations methods
     // ========================================================================
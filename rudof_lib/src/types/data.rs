@@ -1,4 +1,6 @@
+use crate::errors::DataError;
 use pgschema::pg::PropertyGraph;
+use shapes_converter::{Pg2Rdf, Pg2RdfConfig, Rdf2Pg, Rdf2PgConfig};
 use sparql_service::RdfData;
 
 #[derive(Debug, Clone)]
@@ -34,3 +36,19 @@ impl Data {
         }
     }
 }
+
+impl Data {
+    /// Converts a property graph into RDF data following the given mapping
+    pub fn pg_to_rdf(pg: &PropertyGraph, config: &Pg2RdfConfig) -> Result<RdfData, Box<DataError>> {
+        Pg2Rdf::new(config)
+            .convert(pg)
+            .map_err(|e| Box::new(DataError::FailedConvertingPgToRdf { error: e.to_string() }))
+    }
+
+    /// Converts RDF data into a property graph following the given mapping
+    pub fn rdf_to_pg(rdf: &RdfData, config: &Rdf2PgConfig) -> Result<PropertyGraph, Box<DataError>> {
+        Rdf2Pg::new(config)
+            .convert(rdf)
+            .map_err(|e| Box::new(DataError::FailedConvertingRdfToPg { error: e.to_string() }))
+    }
+}
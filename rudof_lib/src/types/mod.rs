@@ -1,7 +1,14 @@
 mod data;
 mod query;
+mod shacl_focus;
 mod shex_statistics;
+mod validation_diff;
+mod validation_verdict;
 
 pub(crate) use data::Data;
 pub(crate) use query::QueryResult;
+pub use shacl_focus::ShaclFocus;
 pub(crate) use shex_statistics::ShExStatistics;
+pub(crate) use validation_diff::{OutcomeKey, ValidationOutcome, shacl_outcomes};
+pub use validation_diff::{ValidationChange, ValidationChangeKind, ValidationDiff, ValidationDiffCounts};
+pub use validation_verdict::ValidationVerdict;
@@ -0,0 +1,101 @@
+use crate::{Result, errors::ShaclError};
+use prefixmap::PrefixMap;
+use rudof_iri::IriS;
+use rudof_rdf::rdf_core::term::Object;
+use shacl::validator::ValidationFocus;
+use std::str::FromStr;
+
+/// Focus nodes and shapes that a SHACL validation is restricted to.
+///
+/// Nodes and shapes are written as IRIs, between angle brackets or not, or as
+/// prefixed names, resolved with the prefixes of the data and of the shapes
+/// graph. The selected nodes and shapes restrict the targets that are
+/// validated, while the associations validate a node against a shape whatever
+/// the targets of the shape. Only the associations are validated when no nodes
+/// or shapes are selected.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShaclFocus {
+    nodes: Vec<String>,
+    shapes: Vec<String>,
+    associations: Vec<(String, String)>,
+}
+
+impl ShaclFocus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts the validated targets to `nodes`
+    pub fn with_nodes(mut self, nodes: &[String]) -> Self {
+        self.nodes.extend_from_slice(nodes);
+        self
+    }
+
+    /// Restricts the validated targets to the ones of `shapes`
+    pub fn with_shapes(mut self, shapes: &[String]) -> Self {
+        self.shapes.extend_from_slice(shapes);
+        self
+    }
+
+    /// Validates `node` against `shape`
+    pub fn with_association(mut self, node: &str, shape: &str) -> Self {
+        self.associations.push((node.to_string(), shape.to_string()));
+        self
+    }
+
+    /// Adds associations written as `node@shape`, like the ones of a ShapeMap
+    pub fn with_shape_map(mut self, associations: &[String]) -> Result<Self> {
+        for association in associations {
+            let (node, shape) = association
+                .rsplit_once('@')
+                .filter(|(node, shape)| !node.trim().is_empty() && !shape.trim().is_empty())
+                .ok_or_else(|| ShaclError::InvalidFocusAssociation {
+                    association: association.clone(),
+                })?;
+            self = self.with_association(node.trim(), shape.trim());
+        }
+        Ok(self)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty() && self.shapes.is_empty() && self.associations.is_empty()
+    }
+
+    /// Resolves the nodes and shapes with `prefixmap`
+    pub(crate) fn resolve(&self, prefixmap: &PrefixMap) -> Result<ValidationFocus> {
+        let resolve_all = |terms: &[String]| {
+            terms
+                .iter()
+                .map(|term| resolve_term(term, prefixmap))
+                .collect::<Result<Vec<_>>>()
+        };
+        let mut focus = ValidationFocus::new();
+        if !self.nodes.is_empty() {
+            focus = focus.with_nodes(resolve_all(&self.nodes)?);
+        }
+        if !self.shapes.is_empty() {
+            focus = focus.with_shapes(resolve_all(&self.shapes)?);
+        }
+        for (node, shape) in &self.associations {
+            focus = focus.with_association(resolve_term(node, prefixmap)?, resolve_term(shape, prefixmap)?);
+        }
+        Ok(focus)
+    }
+}
+
+fn resolve_term(term: &str, prefixmap: &PrefixMap) -> Result<Object> {
+    let iri = match term.strip_prefix('<').and_then(|term| term.strip_suffix('>')) {
+        Some(iri) => IriS::from_str(iri).map_err(|e| e.to_string()),
+        None => prefixmap
+            .resolve(term)
+            .or_else(|e| IriS::from_str(term).map_err(|_| e))
+            .map_err(|e| e.to_string()),
+    };
+    iri.map(Object::iri).map_err(|error| {
+        ShaclError::InvalidFocusTerm {
+            term: term.to_string(),
+            error,
+        }
+        .into()
+    })
+}
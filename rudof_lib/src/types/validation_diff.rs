@@ -0,0 +1,313 @@
+use serde::Serialize;
+use shacl::validator::report::{ValidationReport, ValidationResult};
+use shex_ast::shapemap::ResultShapeMap;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// What happened to a result between two validation runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ValidationChangeKind {
+    /// Failed in the new run but not in the old one
+    NewlyFailing,
+    /// Failed in the old run but not in the new one
+    NewlyPassing,
+    /// Failed in both runs for different reasons
+    ChangedReason,
+}
+
+/// A result that is different in the new validation run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationChange {
+    pub kind: ValidationChangeKind,
+    pub node: String,
+    pub shape: String,
+    /// Constraint component of SHACL results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constraint: Option<String>,
+    /// Result path of SHACL results
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_reason: Option<String>,
+}
+
+/// Number of changes of each kind for a shape and constraint component.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationDiffCounts {
+    pub shape: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constraint: Option<String>,
+    pub newly_failing: usize,
+    pub newly_passing: usize,
+    pub changed_reasons: usize,
+}
+
+/// Differences between the results of two validation runs.
+///
+/// Results are matched by node and shape, and for SHACL also by constraint
+/// component and path. A result missing from a run counts as not failing, as
+/// SHACL reports only contain the failures. Failures whose node and shape
+/// could not be decided in the new run, because they were pending or timed
+/// out, are not counted as newly passing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct ValidationDiff {
+    changes: Vec<ValidationChange>,
+    summary: Vec<ValidationDiffCounts>,
+}
+
+impl ValidationDiff {
+    /// Compares the results of two ShEx validations
+    pub fn from_shex(old: &ResultShapeMap, new: &ResultShapeMap) -> Self {
+        Self::new(shex_outcomes(old), shex_outcomes(new))
+    }
+
+    /// Compares two SHACL validation reports
+    pub fn from_shacl(old: &ValidationReport, new: &ValidationReport) -> Self {
+        Self::new(shacl_outcomes(old), shacl_outcomes(new))
+    }
+
+    pub(crate) fn new(old: Vec<ValidationOutcome>, new: Vec<ValidationOutcome>) -> Self {
+        let old = merge_outcomes(old);
+        let new = merge_outcomes(new);
+        let keys: BTreeSet<&OutcomeKey> = old.keys().chain(new.keys()).collect();
+        // A failure is not fixed when the new run could not decide about its node and shape
+        let undecided: BTreeSet<(&str, &str)> = new
+            .iter()
+            .filter(|(_, outcome)| **outcome == Outcome::Undecided)
+            .map(|(key, _)| (key.node.as_str(), key.shape.as_str()))
+            .collect();
+
+        let mut changes = Vec::new();
+        for key in keys {
+            let old_reason = old.get(key).and_then(Outcome::failure);
+            let new_reason = new.get(key).and_then(Outcome::failure);
+            let kind = match (old_reason, new_reason) {
+                (None, Some(_)) => ValidationChangeKind::NewlyFailing,
+                (Some(_), None) if undecided.contains(&(key.node.as_str(), key.shape.as_str())) => continue,
+                (Some(_), None) => ValidationChangeKind::NewlyPassing,
+                (Some(old_reason), Some(new_reason)) if old_reason != new_reason => ValidationChangeKind::ChangedReason,
+                _ => continue,
+            };
+            changes.push(ValidationChange {
+                kind,
+                node: key.node.clone(),
+                shape: key.shape.clone(),
+                constraint: key.constraint.clone(),
+                path: key.path.clone(),
+                old_reason: old_reason.map(str::to_string),
+                new_reason: new_reason.map(str::to_string),
+            });
+        }
+        changes.sort_by_key(|change| change.kind);
+
+        let mut counts: BTreeMap<(&str, Option<&str>), ValidationDiffCounts> = BTreeMap::new();
+        for change in changes.iter() {
+            let entry = counts
+                .entry((&change.shape, change.constraint.as_deref()))
+                .or_insert_with(|| ValidationDiffCounts {
+                    shape: change.shape.clone(),
+                    constraint: change.constraint.clone(),
+                    ..Default::default()
+                });
+            match change.kind {
+                ValidationChangeKind::NewlyFailing => entry.newly_failing += 1,
+                ValidationChangeKind::NewlyPassing => entry.newly_passing += 1,
+                ValidationChangeKind::ChangedReason => entry.changed_reasons += 1,
+            }
+        }
+        let summary = counts.into_values().collect();
+
+        ValidationDiff { changes, summary }
+    }
+
+    /// All the changes, the newly failing results first, then the newly passing
+    /// ones and the ones with changed reasons
+    pub fn changes(&self) -> &[ValidationChange] {
+        &self.changes
+    }
+
+    pub fn changes_of(&self, kind: ValidationChangeKind) -> impl Iterator<Item = &ValidationChange> {
+        self.changes.iter().filter(move |change| change.kind == kind)
+    }
+
+    pub fn count_of(&self, kind: ValidationChangeKind) -> usize {
+        self.changes_of(kind).count()
+    }
+
+    /// Counts of changes per shape and constraint component
+    pub fn summary(&self) -> &[ValidationDiffCounts] {
+        &self.summary
+    }
+
+    /// Whether some result fails now that did not fail before
+    pub fn has_regressions(&self) -> bool {
+        self.count_of(ValidationChangeKind::NewlyFailing) > 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// Identifies the same result in both runs
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct OutcomeKey {
+    pub node: String,
+    pub shape: String,
+    pub constraint: Option<String>,
+    pub path: Option<String>,
+}
+
+impl OutcomeKey {
+    /// Key of a SHACL result. Blank node shapes, as property shapes often are, get
+    /// a new label in each run, so they are all named `[]` and told apart by the
+    /// constraint component and path.
+    pub fn shacl(node: String, shape: Option<String>, constraint: String, path: Option<String>) -> Self {
+        let shape = match shape {
+            Some(shape) if shape.starts_with("_:") => "[]".to_string(),
+            shape => shape.unwrap_or_default(),
+        };
+        OutcomeKey {
+            node,
+            shape,
+            constraint: Some(constraint),
+            path,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Outcome {
+    Passed,
+    Failed(String),
+    /// The validation did not decide, because it was pending or timed out
+    Undecided,
+}
+
+impl Outcome {
+    fn failure(&self) -> Option<&str> {
+        match self {
+            Outcome::Failed(reason) => Some(reason),
+            _ => None,
+        }
+    }
+}
+
+/// A result of a validation run, reduced to what is compared between runs
+#[derive(Debug, Clone)]
+pub(crate) struct ValidationOutcome {
+    pub key: OutcomeKey,
+    pub outcome: Outcome,
+}
+
+impl ValidationOutcome {
+    /// Outcome of a ShEx result from its status code and reason
+    pub fn shex(node: String, shape: String, status: &str, reason: String) -> Self {
+        let outcome = match status {
+            "conformant" => Outcome::Passed,
+            "nonconformant" | "inconsistent" => Outcome::Failed(reason),
+            _ => Outcome::Undecided,
+        };
+        ValidationOutcome {
+            key: OutcomeKey {
+                node,
+                shape,
+                constraint: None,
+                path: None,
+            },
+            outcome,
+        }
+    }
+
+    /// Outcome of a SHACL result, which is always a failure
+    pub fn shacl(key: OutcomeKey, severity: &str, message: Option<&str>, value: Option<&str>) -> Self {
+        let mut reason = severity.to_string();
+        if let Some(message) = message {
+            reason.push_str(&format!(": {message}"));
+        }
+        if let Some(value) = value {
+            reason.push_str(&format!(" (value {value})"));
+        }
+        ValidationOutcome {
+            key,
+            outcome: Outcome::Failed(reason),
+        }
+    }
+
+    /// Outcome of a SHACL focus node that timed out
+    pub fn shacl_timeout(key: OutcomeKey) -> Self {
+        ValidationOutcome {
+            key,
+            outcome: Outcome::Undecided,
+        }
+    }
+}
+
+/// Joins the outcomes with the same key. SHACL produces a result per value, so
+/// the failures are joined into one with all their reasons.
+fn merge_outcomes(outcomes: Vec<ValidationOutcome>) -> BTreeMap<OutcomeKey, Outcome> {
+    let mut reasons: BTreeMap<OutcomeKey, BTreeSet<String>> = BTreeMap::new();
+    let mut merged = BTreeMap::new();
+    for ValidationOutcome { key, outcome } in outcomes {
+        match outcome {
+            Outcome::Failed(reason) => {
+                reasons.entry(key).or_default().insert(reason);
+            },
+            outcome => {
+                merged.insert(key, outcome);
+            },
+        }
+    }
+    for (key, reasons) in reasons {
+        let reasons: Vec<_> = reasons.into_iter().collect();
+        merged.insert(key, Outcome::Failed(reasons.join("; ")));
+    }
+    merged
+}
+
+fn shex_outcomes(result_map: &ResultShapeMap) -> Vec<ValidationOutcome> {
+    result_map
+        .iter()
+        .map(|(node, shape, status)| {
+            ValidationOutcome::shex(node.to_string(), shape.to_string(), &status.code(), status.reason())
+        })
+        .collect()
+}
+
+pub(crate) fn shacl_outcomes(report: &ValidationReport) -> Vec<ValidationOutcome> {
+    let results = report.results().iter().map(|result| {
+        let message = result_message(result);
+        let value = result.value().map(|value| value.to_string());
+        ValidationOutcome::shacl(
+            shacl_key(result),
+            &result.severity().to_string(),
+            message.as_deref(),
+            value.as_deref(),
+        )
+    });
+    let timeouts = report
+        .timeouts()
+        .iter()
+        .map(|timeout| ValidationOutcome::shacl_timeout(shacl_key(timeout)));
+    results.chain(timeouts).collect()
+}
+
+fn shacl_key(result: &ValidationResult) -> OutcomeKey {
+    OutcomeKey::shacl(
+        result.focus_node().to_string(),
+        result.source().map(|source| source.to_string()),
+        result.constraint_component().to_string(),
+        result.path().map(|path| path.to_string()),
+    )
+}
+
+/// The message that the JSON serialization of the result contains
+fn result_message(result: &ValidationResult) -> Option<String> {
+    let mut messages: Vec<_> = result.message().iter().collect();
+    messages.sort_by_key(|(lang, _)| lang.as_ref().map(|lang| lang.to_string()));
+    messages.first().map(|(_, message)| message.to_string())
+}
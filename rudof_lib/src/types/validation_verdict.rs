@@ -0,0 +1,30 @@
+use std::fmt::{Display, Formatter};
+
+/// Outcome of checking the results of a validation against a validation policy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationVerdict {
+    /// The results pass the policy
+    Passed,
+    /// There are more violations than the policy allows
+    Violations { violations: usize, allowed: usize },
+    /// There are warnings and the policy fails on warnings
+    Warnings { warnings: usize },
+}
+
+impl ValidationVerdict {
+    pub fn passed(&self) -> bool {
+        matches!(self, ValidationVerdict::Passed)
+    }
+}
+
+impl Display for ValidationVerdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationVerdict::Passed => write!(f, "Validation passed the policy"),
+            ValidationVerdict::Violations { violations, allowed } => {
+                write!(f, "Validation failed: {violations} violation(s), {allowed} allowed")
+            },
+            ValidationVerdict::Warnings { warnings } => write!(f, "Validation failed: {warnings} warning(s)"),
+        }
+    }
+}
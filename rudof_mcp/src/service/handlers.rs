@@ -67,6 +67,7 @@ impl ServerHandler for RudofMcpService {
                 - Explore data: load → node_info or execute_sparql_query\n\
                 - Validate with ShEx or SHACL: load → validate_shex / validate_shacl\n\
                 - Query data: load → execute_sparql_query (SELECT, CONSTRUCT and ASK)\n\
+                - Describe a dataset: load → generate_mie, or generate_mie with an endpoint\n\
                 - Visualize graph: load → export_image (SVG/PNG) or export_plantuml\n\
                 - Check a schema without data: check_shex or show_shex (no load needed)\n\
                 ",
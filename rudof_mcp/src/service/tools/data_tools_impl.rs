@@ -91,6 +91,30 @@ pub struct ExportPlantUmlResponse {
     pub size: usize,
 }
 
+/// Request parameters for generating a MIE file.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GenerateMieRequest {
+    /// Title of the dataset, added to the schema information of the MIE.
+    pub title: Option<String>,
+
+    /// SPARQL endpoint URL to introspect (e.g. "https://dbpedia.org/sparql").
+    /// When omitted, the data already loaded in the server is introspected.
+    pub endpoint: Option<String>,
+}
+
+/// Response containing a generated MIE file.
+#[derive(Debug, Serialize, Deserialize, JsonSchema)]
+pub struct GenerateMieResponse {
+    /// MIE file serialized as JSON
+    pub mie: String,
+    /// Number of classes with an inferred shape expression
+    pub shape_expressions: usize,
+    /// Number of sample RDF entries
+    pub sample_rdf_entries: usize,
+    /// Number of cross references to external namespaces
+    pub cross_references: usize,
+}
+
 /// Empty request for tools that don't require parameters.
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct EmptyRequest {}
@@ -421,3 +445,93 @@ pub async fn export_image_impl(
 
     Ok(result)
 }
+
+/// Generate a MIE file by introspecting the loaded data or a SPARQL endpoint.
+///
+/// The MIE contains the statistics, prefixes, sample RDF, inferred ShEx shapes
+/// and cross references of the classes with more instances.
+///
+/// # Errors
+///
+/// Returns a Protocol Error when no data is loaded, or loading the endpoint
+/// or the introspection fails.
+pub async fn generate_mie_impl(
+    service: &RudofMcpService,
+    params: Parameters<GenerateMieRequest>,
+) -> Result<CallToolResult, McpError> {
+    let Parameters(GenerateMieRequest { title, endpoint }) = params;
+    let mut rudof = service.rudof.lock().await;
+
+    if let Some(endpoint) = endpoint.as_deref() {
+        rudof.load_data().with_endpoint(endpoint).execute().map_err(|e| {
+            internal_error(
+                "Endpoint load error",
+                e.to_string(),
+                Some(json!({"operation":"generate_mie_impl", "phase":"load_endpoint"})),
+            )
+        })?;
+    }
+
+    let mut build_mie = rudof.build_mie();
+    if let Some(title) = title.as_deref() {
+        build_mie = build_mie.with_title(title);
+    }
+    if let Some(endpoint) = endpoint.as_deref() {
+        build_mie = build_mie.with_endpoint(endpoint);
+    }
+    build_mie.execute().map_err(|e| {
+        internal_error(
+            "MIE generation error",
+            e.to_string(),
+            Some(json!({"operation":"generate_mie_impl", "phase":"build_mie"})),
+        )
+    })?;
+
+    let mut v = Vec::new();
+    rudof.serialize_mie(&mut v).execute().map_err(|e| {
+        internal_error(
+            "Serialization error",
+            e.to_string(),
+            Some(json!({"operation":"generate_mie_impl", "phase":"serialize_mie"})),
+        )
+    })?;
+    let mie = String::from_utf8(v).map_err(|e| {
+        internal_error(
+            "Conversion error",
+            e.to_string(),
+            Some(json!({"operation":"generate_mie_impl", "phase":"utf8_conversion"})),
+        )
+    })?;
+
+    let (shape_expressions, sample_rdf_entries, cross_references) = rudof
+        .mie()
+        .map(|mie| {
+            (
+                mie.shape_expressions().len(),
+                mie.sample_rdf_entries().len(),
+                mie.cross_references().len(),
+            )
+        })
+        .unwrap_or_default();
+    let response = GenerateMieResponse {
+        mie: mie.clone(),
+        shape_expressions,
+        sample_rdf_entries,
+        cross_references,
+    };
+
+    let structured = serialize_structured(&response, "generate_mie_impl")?;
+
+    let preview = code_block_preview("json", &mie, DEFAULT_CONTENT_PREVIEW_CHARS);
+    let summary = format!(
+        "MIE generated.\nShape expressions: {}\nSample RDF entries: {}\nCross references: {}",
+        shape_expressions, sample_rdf_entries, cross_references
+    );
+    let mut result = CallToolResult::success(vec![
+        Content::text(summary),
+        Content::text(format!("## MIE Preview\n\n{}", preview)),
+    ]);
+    result.structured_content = Some(structured);
+
+    Ok(result)
+}
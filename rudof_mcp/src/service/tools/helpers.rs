@@ -453,7 +453,8 @@ pub const SPARQL_RESULT_FORMAT_LIST: &[&str] = &[
 /// Supported validation result formats as a slice for completions (shared by ShEx and SHACL).
 /// Includes all values from both validators: `csv` is valid for ShEx, `minimal` for SHACL.
 pub const RESULT_FORMAT_LIST: &[&str] = &[
-    "details", "compact", "json", "csv", "minimal", "turtle", "ntriples", "rdfxml", "trig", "n3", "nquads",
+    "details", "compact", "json", "csv", "ndjson", "sarif", "junit", "html", "minimal", "turtle", "ntriples", "rdfxml",
+    "trig", "n3", "nquads",
 ];
 
 pub const SHEX_VALIDATION_RESULT_FORMAT_ENTRIES: &[FormatEntry] = &[
@@ -485,6 +486,34 @@ pub const SHEX_VALIDATION_RESULT_FORMAT_ENTRIES: &[FormatEntry] = &[
         extensions: &[".csv"],
         description: "Validation results in CSV format",
     },
+    FormatEntry {
+        name: "NDJSON",
+        value: "ndjson",
+        mime_type: Some("application/x-ndjson"),
+        extensions: &[".ndjson"],
+        description: "One JSON object per validation result and line",
+    },
+    FormatEntry {
+        name: "SARIF",
+        value: "sarif",
+        mime_type: Some("application/sarif+json"),
+        extensions: &[".sarif"],
+        description: "SARIF 2.1.0 log for code scanning tools",
+    },
+    FormatEntry {
+        name: "JUnit",
+        value: "junit",
+        mime_type: Some("application/xml"),
+        extensions: &[".xml"],
+        description: "JUnit XML test report for CI pipelines",
+    },
+    FormatEntry {
+        name: "HTML",
+        value: "html",
+        mime_type: Some("text/html"),
+        extensions: &[".html"],
+        description: "Self-contained HTML report with a summary dashboard",
+    },
     FormatEntry {
         name: "Turtle",
         value: "turtle",
@@ -565,6 +594,34 @@ pub const SHACL_VALIDATION_RESULT_FORMAT_ENTRIES: &[FormatEntry] = &[
         extensions: &[".csv"],
         description: "Validation results in CSV format",
     },
+    FormatEntry {
+        name: "NDJSON",
+        value: "ndjson",
+        mime_type: Some("application/x-ndjson"),
+        extensions: &[".ndjson"],
+        description: "One JSON object per validation result and line",
+    },
+    FormatEntry {
+        name: "SARIF",
+        value: "sarif",
+        mime_type: Some("application/sarif+json"),
+        extensions: &[".sarif"],
+        description: "SARIF 2.1.0 log for code scanning tools",
+    },
+    FormatEntry {
+        name: "JUnit",
+        value: "junit",
+        mime_type: Some("application/xml"),
+        extensions: &[".xml"],
+        description: "JUnit XML test report for CI pipelines",
+    },
+    FormatEntry {
+        name: "HTML",
+        value: "html",
+        mime_type: Some("text/html"),
+        extensions: &[".html"],
+        description: "Self-contained HTML report with a summary dashboard",
+    },
     FormatEntry {
         name: "Turtle",
         value: "turtle",
@@ -616,10 +673,11 @@ pub const SHEX_INPUT_FORMATS_SUPPORTED: &str = "shexc, shexj";
 pub const SHEX_OUTPUT_FORMATS_SUPPORTED: &str = "shexc, shexj, json, jsonld, internal";
 
 /// Supported ShEx validation result formats as a constant.
-pub const SHEX_RESULT_FORMATS: &str = "compact, details, json, csv";
+pub const SHEX_RESULT_FORMATS: &str = "compact, details, json, csv, ndjson, sarif, junit, html";
 
 /// Supported SHACL validation result formats as a constant.
-pub const SHACL_RESULT_FORMATS: &str = "compact, details, minimal, csv, turtle, ntriples, rdfxml, trig, n3, nquads";
+pub const SHACL_RESULT_FORMATS: &str =
+    "compact, details, minimal, csv, ndjson, sarif, junit, html, turtle, ntriples, rdfxml, trig, n3, nquads";
 
 /// Supported reader modes as a constant.
 pub const READER_MODES_LIST: &[&str] = &["strict", "lax"];
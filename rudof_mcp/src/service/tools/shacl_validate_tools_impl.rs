@@ -5,14 +5,17 @@ use rmcp::{
     handler::server::wrapper::Parameters,
     model::{CallToolResult, Content},
 };
+use rudof_lib::ResultLimits;
 use rudof_lib::formats::{
     InputSpec, ResultShaclValidationFormat, ShaclFormat, ShaclValidationMode, ShaclValidationSortByMode,
 };
+use rudof_lib::types::ShaclFocus;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::io::Cursor;
 use std::str::FromStr;
+use std::time::Duration;
 
 /// Request parameters for SHACL validation.
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
@@ -31,12 +34,43 @@ pub struct ValidateShaclRequest {
     pub mode: Option<String>,
 
     /// Output format for the validation report.
-    /// One of: details (default), compact, minimal, csv, turtle, ntriples, rdfxml, trig, n3, nquads.
+    /// One of: details (default), compact, minimal, csv, ndjson, sarif, junit, html, turtle, ntriples, rdfxml, trig, n3, nquads.
     /// Note: json is listed but not yet implemented.
     pub result_format: Option<String>,
 
     /// Sort order for results. One of: severity (default), node, component, value, path, sourceshape, details.
     pub sort_by: Option<String>,
+
+    /// Time limit for the whole validation, in milliseconds.
+    /// Focus nodes not validated in time are reported as timeouts and the report does not conform.
+    pub timeout_ms: Option<u64>,
+
+    /// Time limit for the validation of each focus node, in milliseconds.
+    pub node_timeout_ms: Option<u64>,
+
+    /// Focus nodes to validate, as IRIs or prefixed names. Only the targets among them are validated.
+    /// Example: ["ex:alice"]
+    pub focus_nodes: Option<Vec<String>>,
+
+    /// Shapes to validate, as IRIs or prefixed names. Only their targets are validated.
+    /// Example: ["ex:PersonShape"]
+    pub focus_shapes: Option<Vec<String>>,
+
+    /// Nodes to validate against shapes whatever their targets, written as node@shape.
+    /// Example: ["ex:alice@ex:PersonShape"]
+    pub shape_map: Option<Vec<String>>,
+
+    /// Stop at the first result, to know whether the data conforms.
+    pub fail_fast: Option<bool>,
+
+    /// Stop the validation after this number of results.
+    pub max_results: Option<usize>,
+
+    /// Keep this number of results of each source shape and count the rest.
+    pub max_results_per_shape: Option<usize>,
+
+    /// Keep this number of results of each constraint component and count the rest.
+    pub max_results_per_component: Option<usize>,
 }
 
 /// Response containing SHACL validation results.
@@ -63,6 +97,7 @@ pub struct ValidateShaclResponse {
 /// - Validation mode is invalid
 /// - Result format is invalid
 /// - Sort order is invalid
+/// - A shape map entry is not written as node@shape
 ///
 /// Returns a Protocol Error for internal validation failures.
 pub async fn validate_shacl_impl(
@@ -74,6 +109,15 @@ pub async fn validate_shacl_impl(
         mode,
         result_format,
         sort_by,
+        timeout_ms,
+        node_timeout_ms,
+        focus_nodes,
+        focus_shapes,
+        shape_map,
+        fail_fast,
+        max_results,
+        max_results_per_shape,
+        max_results_per_component,
     }): Parameters<ValidateShaclRequest>,
 ) -> Result<CallToolResult, McpError> {
     let mut rudof = service.rudof.lock().await;
@@ -133,6 +177,26 @@ pub async fn validate_shacl_impl(
         Err(e) => return Ok(e.into_call_tool_result()),
     };
 
+    let focus = match ShaclFocus::new()
+        .with_nodes(&focus_nodes.unwrap_or_default())
+        .with_shapes(&focus_shapes.unwrap_or_default())
+        .with_shape_map(&shape_map.unwrap_or_default())
+    {
+        Ok(focus) => focus,
+        Err(e) => {
+            return Ok(ToolExecutionError::with_hint(
+                format!("Invalid shape map: {}", e),
+                "Write each entry as node@shape, like ex:alice@ex:PersonShape",
+            )
+            .into_call_tool_result());
+        },
+    };
+    let limits = ResultLimits::new()
+        .with_fail_fast(fail_fast.unwrap_or(false))
+        .with_max_results(max_results)
+        .with_max_results_per_shape(max_results_per_shape)
+        .with_max_results_per_component(max_results_per_component);
+
     // Guard: JSON result format is not yet implemented for SHACL validation.
     if matches!(parsed_result_format, Some(ResultShaclValidationFormat::Json)) {
         return Ok(
@@ -162,6 +226,18 @@ pub async fn validate_shacl_impl(
     if let Some(mode) = &parsed_mode {
         validation = validation.with_shacl_validation_mode(mode);
     }
+    if let Some(timeout) = timeout_ms {
+        validation = validation.with_timeout(Duration::from_millis(timeout));
+    }
+    if let Some(node_timeout) = node_timeout_ms {
+        validation = validation.with_node_timeout(Duration::from_millis(node_timeout));
+    }
+    if !focus.is_empty() {
+        validation = validation.with_focus(&focus);
+    }
+    if !limits.is_unlimited() {
+        validation = validation.with_result_limits(&limits);
+    }
     if let Err(e) = validation.execute() {
         return Ok(ToolExecutionError::with_hint(
             format!("SHACL validation failed: {}", e),
@@ -10,6 +10,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::io::Cursor;
 use std::str::FromStr;
+use std::time::Duration;
 
 use super::helpers::*;
 
@@ -57,7 +58,7 @@ pub struct ValidateShexRequest {
     /// Format of the ShapeMap. Only "compact" is currently supported.
     pub shapemap_format: Option<String>,
 
-    /// Output format for validation results. Supported: details (default), compact, json, csv.
+    /// Output format for validation results. Supported: details (default), compact, json, csv, ndjson, sarif, junit, html.
     pub result_format: Option<String>,
 
     /// Sort order for results. One of: node (default), shape, status, details.
@@ -74,6 +75,13 @@ pub struct ValidateShexRequest {
     /// (substitutes EXTERNAL declarations from a ShEx file). Read the
     /// resource at `rudof://shex/external-resolvers` to enumerate.
     pub external_resolvers: Option<Vec<String>>,
+
+    /// Time limit for the whole validation, in milliseconds.
+    /// Entries not decided in time get a "timeout" status instead of failing the call.
+    pub timeout_ms: Option<u64>,
+
+    /// Time limit for the validation of each ShapeMap entry, in milliseconds.
+    pub node_timeout_ms: Option<u64>,
 }
 
 /// Response containing ShEx validation results.
@@ -117,6 +125,8 @@ pub async fn validate_shex_impl(
         sort_by,
         strict_iris,
         external_resolvers,
+        timeout_ms,
+        node_timeout_ms,
     }): Parameters<ValidateShexRequest>,
 ) -> Result<CallToolResult, McpError> {
     let mut rudof = service.rudof.lock().await;
@@ -209,7 +219,7 @@ pub async fn validate_shex_impl(
         Err(e) => return Ok(e.into_call_tool_result()),
     };
 
-    // Guard: only compact/details/json/csv are implemented for ShEx validation results.
+    // Guard: only compact/details/json/csv/ndjson/sarif/junit/html are implemented for ShEx validation results.
     if let Some(fmt) = &parsed_result_format
         && !matches!(
             fmt,
@@ -217,6 +227,10 @@ pub async fn validate_shex_impl(
                 | ResultShExValidationFormat::Details
                 | ResultShExValidationFormat::Json
                 | ResultShExValidationFormat::Csv
+                | ResultShExValidationFormat::NdJson
+                | ResultShExValidationFormat::Sarif
+                | ResultShExValidationFormat::JUnit
+                | ResultShExValidationFormat::Html
         )
     {
         return Ok(unsupported_format_error(
@@ -297,7 +311,14 @@ pub async fn validate_shex_impl(
         .into_call_tool_result());
     }
 
-    if let Err(e) = rudof.validate_shex().execute() {
+    let mut validation = rudof.validate_shex();
+    if let Some(timeout) = timeout_ms {
+        validation = validation.with_timeout(Duration::from_millis(timeout));
+    }
+    if let Some(node_timeout) = node_timeout_ms {
+        validation = validation.with_node_timeout(Duration::from_millis(node_timeout));
+    }
+    if let Err(e) = validation.execute() {
         return Ok(ToolExecutionError::with_hint(
             format!("ShEx validation failed: {}", e),
             "Ensure the RDF data is loaded and the schema/shapemap are correct",
@@ -365,11 +386,12 @@ pub async fn validate_shex_impl(
 
     let results_language = match result_format_str.to_lowercase().as_str() {
         "csv" => "csv",
-        "json" | "jsonld" => "json",
+        "json" | "jsonld" | "ndjson" | "sarif" => "json",
         "turtle" | "n3" => "turtle",
         "ntriples" | "nquads" => "ntriples",
-        "rdfxml" => "xml",
+        "rdfxml" | "junit" => "xml",
         "trig" => "trig",
+        "html" => "html",
         _ => "text",
     };
     let results_preview = code_block_preview(results_language, &output_str, DEFAULT_CONTENT_PREVIEW_CHARS);
@@ -99,6 +99,22 @@ impl RudofMcpService {
         export_image_impl(self, params).await
     }
 
+    /// Generate a MIE file describing the loaded data or a SPARQL endpoint.
+    #[tool(
+        name = "generate_mie",
+        description = "Build a MIE (Metadata Interoperable Exchange) file by introspecting the loaded RDF data or the SPARQL endpoint given in `endpoint`. The MIE, returned as JSON, contains class and property statistics, prefixes, sample RDF and an inferred ShEx shape per class, and cross references to external namespaces. Use to get an overview of a dataset before writing queries.",
+        annotations(
+            title = "Generate MIE File",
+            read_only_hint = false,
+            destructive_hint = false,
+            idempotent_hint = true,
+            open_world_hint = true,
+        )
+    )]
+    pub async fn generate_mie(&self, params: Parameters<GenerateMieRequest>) -> Result<CallToolResult, McpError> {
+        generate_mie_impl(self, params).await
+    }
+
     // -------------------------------------------------------------------------
     // Node Inspection Tools
     // -------------------------------------------------------------------------
@@ -197,7 +213,7 @@ impl RudofMcpService {
     /// Validate RDF data against a SHACL schema.
     #[tool(
         name = "validate_shacl",
-        description = "Validate the loaded RDF data against a SHACL shapes graph. Returns a standard SHACL validation report. If `shapes` is omitted, shapes embedded in the loaded data are used. Requires data to be loaded first.",
+        description = "Validate the loaded RDF data against a SHACL shapes graph. Returns a standard SHACL validation report. If `shapes` is omitted, shapes embedded in the loaded data are used. Use `focus_nodes`, `focus_shapes` or `shape_map` (node@shape entries) to validate a single entity instead of the whole graph. Set `fail_fast` or `max_results` to stop at the first results, and `max_results_per_shape` or `max_results_per_component` to summarise repeated results. Requires data to be loaded first.",
         annotations(
             title = "Validate RDF with SHACL",
             read_only_hint = true,
@@ -265,6 +281,10 @@ fn build_annotated_tools() -> Vec<rmcp::model::Tool> {
                 output_schema_for::<ExportImageResponse>("export_image"),
                 rmcp::model::TaskSupport::Forbidden,
             ),
+            "generate_mie" => (
+                output_schema_for::<GenerateMieResponse>("generate_mie"),
+                rmcp::model::TaskSupport::Forbidden,
+            ),
             "node_info" => (
                 output_schema_for::<NodeInfoResponse>("node_info"),
                 rmcp::model::TaskSupport::Forbidden,
@@ -93,7 +93,12 @@ mod tool_router_tests {
         let tool_names: Vec<_> = tools.iter().map(|t| t.name.to_string()).collect();
 
         // Verify some expected tools exist
-        let expected_tools = vec!["load_rdf_data_from_sources", "export_rdf_data", "node_info"];
+        let expected_tools = vec![
+            "load_rdf_data_from_sources",
+            "export_rdf_data",
+            "generate_mie",
+            "node_info",
+        ];
 
         for expected in expected_tools {
             assert!(
@@ -10,6 +10,7 @@ mod rdf;
 mod rdf_data_config;
 mod rdf_format;
 mod shacl_path;
+mod source_span;
 pub mod term;
 pub mod utils;
 pub mod visualizer;
@@ -25,3 +26,4 @@ pub use rdf::Rdf;
 pub use rdf_data_config::{EndpointDescription, RdfDataConfig};
 pub use rdf_format::RDFFormat;
 pub use shacl_path::SHACLPath;
+pub use source_span::SourceSpan;
@@ -1,5 +1,5 @@
 use crate::rdf_core::{
-    Matcher, RDFError,
+    Matcher, RDFError, SourceSpan,
     term::{
         BlankNode, Iri, IriOrBlankNode, Object, Subject, Term, Triple,
         literal::{ConcreteLiteral, Lang, Literal},
@@ -123,6 +123,35 @@ pub trait Rdf: Sized {
     /// Returns `PrefixMapError` if the prefix is not registered in the prefix map.
     fn resolve_prefix_local(&self, prefix: &str, local: &str) -> Result<IriS, PrefixMapError>;
 
+    /// Returns the position in its source of the first triple read with `subject` as subject.
+    ///
+    /// Implementations that don't keep track of where triples were read from return `None`.
+    ///
+    /// # Parameters
+    ///
+    /// * `subject` - The subject to locate
+    fn subject_span(&self, _subject: &Self::Subject) -> Option<SourceSpan> {
+        None
+    }
+
+    /// Returns the position in its source where a triple was read from.
+    ///
+    /// Implementations that don't keep track of where triples were read from return `None`.
+    ///
+    /// # Parameters
+    ///
+    /// * `subject` - The subject of the triple
+    /// * `predicate` - The predicate of the triple
+    /// * `object` - The object of the triple
+    fn triple_span(
+        &self,
+        _subject: &Self::Subject,
+        _predicate: &Self::IRI,
+        _object: &Self::Term,
+    ) -> Option<SourceSpan> {
+        None
+    }
+
     /// Extracts the numeric value from a term, if it represents a number.
     ///
     /// Attempts to convert the term to a literal and extract its numeric value
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+/// Position in a source where an RDF triple or a schema element was read from.
+///
+/// Lines and columns start at 1, and columns count characters, not bytes.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SourceSpan {
+    /// Name of the source, usually a file path or an IRI
+    source: String,
+    line: usize,
+    column: usize,
+}
+
+impl SourceSpan {
+    pub fn new(source: &str, line: usize, column: usize) -> Self {
+        SourceSpan {
+            source: source.to_string(),
+            line,
+            column,
+        }
+    }
+
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+}
+
+impl Display for SourceSpan {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}:{}", self.source, self.line, self.column)
+    }
+}
@@ -386,7 +386,16 @@ impl From<Object> for oxrdf::Term {
             Object::Iri(iri_s) => oxrdf::NamedNode::new_unchecked(iri_s.as_str()).into(),
             Object::BlankNode(bnode) => oxrdf::BlankNode::new_unchecked(bnode).into(),
             Object::Literal(literal) => oxrdf::Term::Literal(literal.into()),
-            Object::Triple { .. } => todo!(),
+            Object::Triple {
+                subject,
+                predicate,
+                object,
+            } => oxrdf::Triple::new(
+                oxrdf::NamedOrBlankNode::from(*subject),
+                oxrdf::NamedNode::new_unchecked(predicate.as_str()),
+                oxrdf::Term::from(*object),
+            )
+            .into(),
         }
     }
 }
@@ -474,13 +483,17 @@ impl Display for Object {
     /// - IRIs: displayed as-is
     /// - Blank nodes: prefixed with "_:"
     /// - Literals: uses the literal's Display implementation
-    /// - Triples: not yet implemented
+    /// - Triples: displayed as triple terms `<<( s p o )>>`
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Object::Iri(iri) => write!(f, "{iri}"),
             Object::BlankNode(bnode) => write!(f, "_:{bnode}"),
             Object::Literal(lit) => write!(f, "{lit}"),
-            Object::Triple { .. } => todo!(),
+            Object::Triple {
+                subject,
+                predicate,
+                object,
+            } => write!(f, "<<( {subject} {predicate} {object} )>>"),
         }
     }
 }
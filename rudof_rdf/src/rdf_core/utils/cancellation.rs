@@ -0,0 +1,216 @@
+use super::Profiler;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Cooperative cancellation for long-running operations.
+///
+/// A token combines a flag that can be raised from another thread with an
+/// optional wall-clock deadline. Clones and tokens derived with
+/// [`CancellationToken::with_timeout`] share the flag, so cancelling any of them
+/// cancels all of them, while each one can have a tighter deadline.
+///
+/// A token made with [`CancellationToken::child`] is also interrupted when the
+/// token it was made from is, but cancelling it leaves that token untouched.
+///
+/// Nothing is interrupted preemptively: the operations that accept a token check
+/// it periodically and stop at the next check.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+    /// Flags of the tokens this one is a child of
+    parents: Vec<Arc<AtomicBool>>,
+    deadline: Option<Instant>,
+}
+
+impl CancellationToken {
+    /// Creates a token that is never interrupted unless it is cancelled
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a token that shares the cancellation flag of this one and whose
+    /// deadline is at most `timeout` from now. `None` keeps the current deadline.
+    pub fn with_timeout(&self, timeout: Option<Duration>) -> Self {
+        let deadline = match (self.deadline, timeout) {
+            (Some(current), Some(timeout)) => Some(current.min(Instant::now() + timeout)),
+            (None, Some(timeout)) => Some(Instant::now() + timeout),
+            (current, None) => current,
+        };
+        Self {
+            cancelled: Arc::clone(&self.cancelled),
+            parents: self.parents.clone(),
+            deadline,
+        }
+    }
+
+    /// Returns a token with the same deadline that is interrupted when this one
+    /// is, and that can be cancelled without cancelling this one
+    pub fn child(&self) -> Self {
+        let mut parents = self.parents.clone();
+        parents.push(Arc::clone(&self.cancelled));
+        Self {
+            cancelled: Arc::new(AtomicBool::new(false)),
+            parents,
+            deadline: self.deadline,
+        }
+    }
+
+    /// Requests the cancellation of every operation that checks this token or one sharing its flag
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether the operation must stop, either because it was cancelled or because the deadline passed
+    pub fn is_interrupted(&self) -> bool {
+        self.interruption().is_some()
+    }
+
+    /// The reason why the operation must stop, if any
+    pub fn interruption(&self) -> Option<Interruption> {
+        let cancelled = |flag: &Arc<AtomicBool>| flag.load(Ordering::Relaxed);
+        if cancelled(&self.cancelled) || self.parents.iter().any(cancelled) {
+            Some(Interruption::Cancelled)
+        } else if self.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            Some(Interruption::TimedOut)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `Err` with the reason to stop, if any, so checks can be chained with `?`
+    pub fn check(&self) -> Result<(), Interruption> {
+        match self.interruption() {
+            Some(interruption) => Err(interruption),
+            None => Ok(()),
+        }
+    }
+
+    /// Time left until the deadline, `None` if the token has no deadline
+    pub fn remaining(&self) -> Option<Duration> {
+        self.deadline
+            .map(|deadline| deadline.saturating_duration_since(Instant::now()))
+    }
+}
+
+/// Why an operation checking a [`CancellationToken`] was stopped
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Interruption {
+    #[error("cancelled")]
+    Cancelled,
+    #[error("timeout")]
+    TimedOut,
+}
+
+/// Time limits of a validation run.
+///
+/// `timeout` bounds the whole run and `node_timeout` the validation of each
+/// node. Both count from the moment they are started, and both are also
+/// interrupted when the cancellation token they derive from is cancelled.
+///
+/// The budget also carries the [`Profiler`] of the run, disabled by default, as
+/// it reaches every engine taking part in the validation.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationBudget {
+    cancellation: CancellationToken,
+    timeout: Option<Duration>,
+    node_timeout: Option<Duration>,
+    profiler: Profiler,
+}
+
+impl ValidationBudget {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = token;
+        self
+    }
+
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn with_node_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.node_timeout = timeout;
+        self
+    }
+
+    pub fn with_profiler(mut self, profiler: Profiler) -> Self {
+        self.profiler = profiler;
+        self
+    }
+
+    pub fn cancellation(&self) -> &CancellationToken {
+        &self.cancellation
+    }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    pub fn node_timeout(&self) -> Option<Duration> {
+        self.node_timeout
+    }
+
+    pub fn profiler(&self) -> &Profiler {
+        &self.profiler
+    }
+
+    /// Token for a run starting now
+    pub fn start_run(&self) -> CancellationToken {
+        self.cancellation.with_timeout(self.timeout)
+    }
+
+    /// Token for a node of the run checked by `run`, starting now
+    pub fn start_node(&self, run: &CancellationToken) -> CancellationToken {
+        run.with_timeout(self.node_timeout)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cancelling_a_derived_token_cancels_the_original() {
+        let token = CancellationToken::new();
+        let node = token.with_timeout(Some(Duration::from_secs(60)));
+        assert_eq!(token.check(), Ok(()));
+        node.cancel();
+        assert_eq!(token.check(), Err(Interruption::Cancelled));
+    }
+
+    #[test]
+    fn cancelling_a_child_leaves_its_parent() {
+        let token = CancellationToken::new();
+        let child = token.child();
+        child.cancel();
+        assert_eq!(token.check(), Ok(()));
+        assert_eq!(child.check(), Err(Interruption::Cancelled));
+
+        let child = token.child().with_timeout(Some(Duration::from_secs(60)));
+        token.cancel();
+        assert_eq!(child.check(), Err(Interruption::Cancelled));
+    }
+
+    #[test]
+    fn derived_tokens_keep_the_tightest_deadline() {
+        let run = CancellationToken::new().with_timeout(Some(Duration::ZERO));
+        let node = run.with_timeout(Some(Duration::from_secs(60)));
+        assert_eq!(node.check(), Err(Interruption::TimedOut));
+        assert_eq!(node.remaining(), Some(Duration::ZERO));
+        assert!(!CancellationToken::new().with_timeout(None).is_interrupted());
+    }
+
+    #[test]
+    fn budget_starts_nodes_from_the_run() {
+        let budget = ValidationBudget::new().with_node_timeout(Some(Duration::ZERO));
+        let run = budget.start_run();
+        assert!(!run.is_interrupted());
+        assert_eq!(budget.start_node(&run).check(), Err(Interruption::TimedOut));
+    }
+}
@@ -1,3 +1,7 @@
+mod cancellation;
+mod profiler;
 mod regex;
 
+pub use cancellation::{CancellationToken, Interruption, ValidationBudget};
+pub use profiler::{ProfileEntry, ProfileSpan, Profiler};
 pub use regex::{RDFRegex, RDFRegexError};
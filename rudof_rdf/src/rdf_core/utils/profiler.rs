@@ -0,0 +1,318 @@
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// Records where the time of a validation goes.
+///
+/// Engines open a [`ProfileSpan`] for each shape they validate and for each
+/// constraint they evaluate, and count the cache hits and SPARQL queries of the
+/// span they are in. A disabled profiler, the default, records nothing.
+///
+/// Clones share what is recorded but keep their own stack of open spans, so
+/// each engine validating in parallel must use its own clone.
+#[derive(Debug, Default)]
+pub struct Profiler {
+    data: Option<Arc<Mutex<ProfileData>>>,
+    stack: Arc<Mutex<Vec<Frame>>>,
+}
+
+/// Shape, and constraint component when the span is the evaluation of a constraint
+type ProfileKey = (String, Option<String>);
+
+#[derive(Debug, Default)]
+struct ProfileData {
+    entries: HashMap<ProfileKey, ProfileEntry>,
+    /// Time spent in each stack of spans, excluding the spans opened inside
+    folded: HashMap<String, Duration>,
+}
+
+#[derive(Debug)]
+struct Frame {
+    key: ProfileKey,
+    start: Instant,
+    /// Time of the spans opened inside this one
+    children: Duration,
+}
+
+/// Labels of the stack of `frames` in a folded stack. The spans of components
+/// are labelled with their component, preceded by their shape when the span
+/// they are opened in is not the one of their shape
+fn folded_path<'a>(frames: impl Iterator<Item = &'a Frame>) -> String {
+    let mut labels = Vec::new();
+    let mut parent: Option<&ProfileKey> = None;
+    for frame in frames {
+        let (shape, component) = &frame.key;
+        match component {
+            Some(component) => {
+                if parent.is_none_or(|parent| &parent.0 != shape) {
+                    labels.push(shape.as_str());
+                }
+                labels.push(component.as_str());
+            },
+            None => labels.push(shape.as_str()),
+        }
+        parent = Some(&frame.key);
+    }
+    labels.join(";")
+}
+
+impl Profiler {
+    /// Creates a profiler that records the spans opened with it
+    pub fn new() -> Self {
+        Self {
+            data: Some(Arc::default()),
+            stack: Arc::default(),
+        }
+    }
+
+    /// Creates a profiler that records nothing
+    pub fn disabled() -> Self {
+        Self::default()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.data.is_some()
+    }
+
+    /// Opens a span for `shape`, or for `component` of `shape`, that is closed when dropped
+    pub fn span(&self, shape: &dyn Display, component: Option<&dyn Display>) -> ProfileSpan {
+        let Some(data) = &self.data else {
+            return ProfileSpan { profiler: None };
+        };
+        lock(&self.stack).push(Frame {
+            key: (shape.to_string(), component.map(|component| component.to_string())),
+            start: Instant::now(),
+            children: Duration::ZERO,
+        });
+        ProfileSpan {
+            profiler: Some((Arc::clone(data), Arc::clone(&self.stack))),
+        }
+    }
+
+    /// Counts a validation of `shape` answered from a cache
+    pub fn record_cache_hit(&self, shape: &dyn Display) {
+        if let Some(data) = &self.data {
+            lock(data).entry((shape.to_string(), None)).cache_hits += 1;
+        }
+    }
+
+    /// Counts a SPARQL query issued in the current span and in the span of its shape
+    pub fn record_query(&self) {
+        let Some(data) = &self.data else {
+            return;
+        };
+        let stack = lock(&self.stack);
+        let Some(frame) = stack.last() else {
+            return;
+        };
+        let mut data = lock(data);
+        data.entry(frame.key.clone()).queries += 1;
+        if frame.key.1.is_some() {
+            data.entry((frame.key.0.clone(), None)).queries += 1;
+        }
+    }
+
+    /// What was recorded for each shape and constraint component, the slowest first
+    pub fn entries(&self) -> Vec<ProfileEntry> {
+        let Some(data) = &self.data else {
+            return Vec::new();
+        };
+        let mut entries: Vec<_> = lock(data).entries.values().cloned().collect();
+        entries.sort_by(|a, b| {
+            b.time
+                .cmp(&a.time)
+                .then_with(|| a.shape.cmp(&b.shape))
+                .then_with(|| a.component.cmp(&b.component))
+        });
+        entries
+    }
+
+    /// Time spent in each stack of spans, excluding the spans opened inside,
+    /// with the spans separated by `;` as in the folded stacks of flame graphs
+    pub fn folded(&self) -> Vec<(String, Duration)> {
+        let Some(data) = &self.data else {
+            return Vec::new();
+        };
+        let mut folded: Vec<_> = lock(data)
+            .folded
+            .iter()
+            .map(|(stack, time)| (stack.clone(), *time))
+            .collect();
+        folded.sort();
+        folded
+    }
+}
+
+impl Clone for Profiler {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+            stack: Arc::default(),
+        }
+    }
+}
+
+impl ProfileData {
+    fn entry(&mut self, key: ProfileKey) -> &mut ProfileEntry {
+        self.entries
+            .entry(key)
+            .or_insert_with_key(|(shape, component)| ProfileEntry {
+                shape: shape.clone(),
+                component: component.clone(),
+                time: Duration::ZERO,
+                evaluations: 0,
+                cache_hits: 0,
+                queries: 0,
+            })
+    }
+}
+
+/// Span of a [`Profiler`], closed when dropped
+#[must_use]
+pub struct ProfileSpan {
+    profiler: Option<(Arc<Mutex<ProfileData>>, Arc<Mutex<Vec<Frame>>>)>,
+}
+
+impl Drop for ProfileSpan {
+    fn drop(&mut self) {
+        let Some((data, stack)) = &self.profiler else {
+            return;
+        };
+        let mut stack = lock(stack);
+        let Some(frame) = stack.pop() else {
+            return;
+        };
+        let elapsed = frame.start.elapsed();
+        let path = folded_path(stack.iter().chain([&frame]));
+        // The time of recursive spans is already counted by the outermost one
+        let recursive = stack.iter().any(|open| open.key == frame.key);
+        if let Some(parent) = stack.last_mut() {
+            parent.children += elapsed;
+        }
+        let mut data = lock(data);
+        *data.folded.entry(path).or_default() += elapsed.saturating_sub(frame.children);
+        let entry = data.entry(frame.key);
+        entry.evaluations += 1;
+        if !recursive {
+            entry.time += elapsed;
+        }
+    }
+}
+
+/// What a [`Profiler`] recorded for a shape, or for a constraint component of a shape
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfileEntry {
+    shape: String,
+    component: Option<String>,
+    time: Duration,
+    evaluations: u64,
+    cache_hits: u64,
+    queries: u64,
+}
+
+impl ProfileEntry {
+    pub fn shape(&self) -> &str {
+        &self.shape
+    }
+
+    /// Constraint component, `None` for the entry of the whole shape
+    pub fn component(&self) -> Option<&str> {
+        self.component.as_deref()
+    }
+
+    /// Time spent in the spans, including the spans opened inside them
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    pub fn evaluations(&self) -> u64 {
+        self.evaluations
+    }
+
+    pub fn cache_hits(&self) -> u64 {
+        self.cache_hits
+    }
+
+    pub fn queries(&self) -> u64 {
+        self.queries
+    }
+}
+
+/// Serialized as a JSON object with the time in milliseconds
+impl Serialize for ProfileEntry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("shape", &self.shape)?;
+        if let Some(component) = &self.component {
+            map.serialize_entry("component", component)?;
+        }
+        map.serialize_entry("timeMs", &(self.time.as_secs_f64() * 1000.0))?;
+        map.serialize_entry("evaluations", &self.evaluations)?;
+        map.serialize_entry("cacheHits", &self.cache_hits)?;
+        map.serialize_entry("queries", &self.queries)?;
+        map.end()
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spans_record_times_counts_and_stacks() {
+        let profiler = Profiler::new();
+        {
+            let _shape = profiler.span(&"Person", None);
+            {
+                let _component = profiler.span(&"Person", Some(&"MinCount"));
+                profiler.record_query();
+            }
+            profiler.record_cache_hit(&"Person");
+        }
+        let entries = profiler.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].component(), None);
+        assert_eq!(entries[0].evaluations(), 1);
+        assert_eq!(entries[0].cache_hits(), 1);
+        assert_eq!(entries[0].queries(), 1);
+        assert!(entries[0].time() >= entries[1].time());
+        assert_eq!(entries[1].queries(), 1);
+
+        let stacks: Vec<_> = profiler.folded().into_iter().map(|(stack, _)| stack).collect();
+        assert_eq!(stacks, vec!["Person", "Person;MinCount"]);
+    }
+
+    #[test]
+    fn clones_share_the_records_but_not_the_spans() {
+        let profiler = Profiler::new();
+        let fork = profiler.clone();
+        let _outer = profiler.span(&"Person", None);
+        drop(fork.span(&"Address", None));
+        assert_eq!(profiler.folded()[0].0, "Address");
+    }
+
+    #[test]
+    fn components_outside_their_shape_are_folded_with_it() {
+        let profiler = Profiler::new();
+        drop(profiler.span(&"Person", Some(&"targets")));
+        assert_eq!(profiler.folded()[0].0, "Person;targets");
+    }
+
+    #[test]
+    fn disabled_profiler_records_nothing() {
+        let profiler = Profiler::disabled();
+        drop(profiler.span(&"Person", None));
+        profiler.record_query();
+        assert!(profiler.entries().is_empty());
+    }
+}
@@ -5,7 +5,6 @@
 //! represent standard properties and datatypes used throughout RDF processing.
 
 use rudof_iri::IriS;
-use std::sync::OnceLock;
 
 mod owl;
 mod rdf;
@@ -51,8 +50,8 @@ macro_rules! vocab_term {
 pub trait RdfVocabulary {
     const BASE: &'static str;
 
-    fn base_iri() -> &'static IriS {
-        static IRI: OnceLock<IriS> = OnceLock::new();
-        IRI.get_or_init(|| IriS::new_unchecked(Self::BASE))
+    // A static declared here would be shared by every vocabulary, so the IRI is built on each call
+    fn base_iri() -> IriS {
+        IriS::new_unchecked(Self::BASE)
     }
 }
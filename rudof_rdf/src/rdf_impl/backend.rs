@@ -16,7 +16,7 @@ use super::QleverGraphContainer;
 use super::{OxigraphInMemory, RdfBackendError};
 #[cfg(feature = "sparql")]
 use crate::rdf_core::query::{QueryRDF, QueryResultFormat, QuerySolution, QuerySolutions};
-use crate::rdf_core::{BuildRDF, FocusRDF, Matcher, NeighsRDF, RDFFormat, Rdf};
+use crate::rdf_core::{BuildRDF, FocusRDF, Matcher, NeighsRDF, RDFFormat, Rdf, SourceSpan};
 
 /// Strategy enum that owns one concrete RDF backend.
 #[derive(Debug, Clone)]
@@ -143,6 +143,26 @@ impl Rdf for RdfBackend {
             RdfBackend::Qlever(b) => b.resolve_prefix_local(prefix, local),
         }
     }
+
+    fn subject_span(&self, subject: &Self::Subject) -> Option<SourceSpan> {
+        match self {
+            RdfBackend::InMemory(b) => b.subject_span(subject),
+            #[cfg(all(not(target_family = "wasm"), feature = "sparql"))]
+            RdfBackend::Endpoint(_) => None,
+            #[cfg(all(not(target_family = "wasm"), feature = "qlever"))]
+            RdfBackend::Qlever(_) => None,
+        }
+    }
+
+    fn triple_span(&self, subject: &Self::Subject, predicate: &Self::IRI, object: &Self::Term) -> Option<SourceSpan> {
+        match self {
+            RdfBackend::InMemory(b) => b.triple_span(subject, predicate, object),
+            #[cfg(all(not(target_family = "wasm"), feature = "sparql"))]
+            RdfBackend::Endpoint(_) => None,
+            #[cfg(all(not(target_family = "wasm"), feature = "qlever"))]
+            RdfBackend::Qlever(_) => None,
+        }
+    }
 }
 
 impl NeighsRDF for RdfBackend {
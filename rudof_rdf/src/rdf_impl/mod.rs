@@ -7,7 +7,7 @@ mod backend_error;
 
 #[cfg(all(not(target_family = "wasm"), feature = "sparql"))]
 pub use oxigraph::{OxigraphEndpoint, OxigraphEndpointError, SparqlVars};
-pub use oxigraph::{OxigraphInMemory, OxigraphInMemoryError, ReaderMode};
+pub use oxigraph::{OxigraphInMemory, OxigraphInMemoryError, ReaderMode, RecoveredError};
 #[cfg(all(not(target_family = "wasm"), feature = "qlever"))]
 pub use qlever::{
     Bzip2Strategy, CliKind, Compression, CompressionStrategy, DecompressorCandidate, DecompressorProbe, GzipStrategy,
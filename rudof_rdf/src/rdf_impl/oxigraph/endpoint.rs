@@ -2,6 +2,7 @@ use crate::{
     rdf_core::{
         Any, AsyncRDF, Matcher, NeighsRDF, Rdf,
         query::{QueryRDF, QueryResultFormat, QuerySolution, QuerySolutions, VarName},
+        utils::CancellationToken,
     },
     rdf_impl::OxigraphEndpointError,
 };
@@ -64,6 +65,10 @@ pub struct OxigraphEndpoint {
     /// (common in recursive ShEx schemas like E10/human) cost one SPARQL
     /// request instead of one per validation pass.
     triple_cache: Arc<std::sync::RwLock<HashMap<OxSubject, HashMap<OxNamedNode, HashSet<OxTerm>>>>>,
+
+    /// Checked while the blocking query methods wait for a response, so that a
+    /// cancelled or timed out validation doesn't stay blocked on a slow endpoint.
+    cancellation: CancellationToken,
 }
 
 impl PartialEq for OxigraphEndpoint {
@@ -137,6 +142,7 @@ impl OxigraphEndpoint {
             construct_clients: Arc::new(RwLock::new(HashMap::new())),
             last_request_at: Arc::new(tokio::sync::Mutex::new(initial)),
             triple_cache: Arc::new(std::sync::RwLock::new(HashMap::new())),
+            cancellation: CancellationToken::new(),
         })
     }
 
@@ -214,6 +220,14 @@ impl OxigraphEndpoint {
         self
     }
 
+    /// Sets the token checked by the blocking query methods.
+    ///
+    /// When it is interrupted, pending and later requests fail with
+    /// [`OxigraphEndpointError::Interrupted`].
+    pub fn set_cancellation(&mut self, token: CancellationToken) {
+        self.cancellation = token;
+    }
+
     /// Formats a blank node with color for display.
     ///
     /// This is an internal helper that applies green coloring to blank nodes.
@@ -427,6 +441,7 @@ impl FromStr for OxigraphEndpoint {
                 construct_clients: Arc::new(RwLock::new(HashMap::new())),
                 last_request_at: Arc::new(tokio::sync::Mutex::new(initial)),
                 triple_cache: Arc::new(std::sync::RwLock::new(HashMap::new())),
+                cancellation: CancellationToken::new(),
             })
         } else {
             // Try to match predefined endpoint names
@@ -762,6 +777,30 @@ static SPARQL_RUNTIME: once_cell::sync::Lazy<tokio::runtime::Runtime> = once_cel
         .expect("failed to build shared tokio runtime for SPARQL queries")
 });
 
+#[cfg(not(target_family = "wasm"))]
+impl OxigraphEndpoint {
+    /// Runs a request on the shared runtime, polling the cancellation token while
+    /// it is in flight and abandoning it as soon as the token is interrupted.
+    fn block_on_cancellable<T>(&self, request: impl std::future::Future<Output = Result<T>>) -> Result<T> {
+        const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(50);
+        self.cancellation.check()?;
+        SPARQL_RUNTIME.block_on(async {
+            let watch = async {
+                loop {
+                    if let Some(interruption) = self.cancellation.interruption() {
+                        return interruption;
+                    }
+                    tokio::time::sleep(POLL_INTERVAL).await;
+                }
+            };
+            tokio::select! {
+                result = request => result,
+                interruption = watch => Err(interruption.into()),
+            }
+        })
+    }
+}
+
 // QueryRDF is only available on non-WASM platforms.
 // On native platforms, these sync methods bridge to the async implementations
 // through a shared tokio runtime (see `SPARQL_RUNTIME`).
@@ -771,21 +810,21 @@ impl QueryRDF for OxigraphEndpoint {
     ///
     /// This is a blocking wrapper around `query_construct_async`.
     fn query_construct(&self, query: &str, format: &QueryResultFormat) -> Result<String> {
-        SPARQL_RUNTIME.block_on(self.query_construct_async(query, format))
+        self.block_on_cancellable(self.query_construct_async(query, format))
     }
 
     /// Executes a SPARQL SELECT query synchronously.
     ///
     /// This is a blocking wrapper around `query_select_async`.
     fn query_select(&self, query: &str) -> Result<QuerySolutions<Self>> {
-        SPARQL_RUNTIME.block_on(self.query_select_async(query))
+        self.block_on_cancellable(self.query_select_async(query))
     }
 
     /// Executes a SPARQL ASK query synchronously.
     ///
     /// This is a blocking wrapper around `query_ask_async`.
     fn query_ask(&self, query: &str) -> Result<bool> {
-        SPARQL_RUNTIME.block_on(self.query_ask_async(query))
+        self.block_on_cancellable(self.query_ask_async(query))
     }
 }
 
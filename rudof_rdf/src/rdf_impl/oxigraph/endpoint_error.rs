@@ -1,3 +1,4 @@
+use crate::rdf_core::utils::Interruption;
 use crate::rdf_impl::SparqlVars;
 use oxiri::IriParseError;
 use oxrdf::Term;
@@ -113,6 +114,16 @@ pub enum OxigraphEndpointError {
         #[from]
         err: IriSError,
     },
+
+    /// Error when a request is abandoned because its cancellation token was interrupted.
+    ///
+    /// # Fields
+    /// - `interruption`: Whether the request was cancelled or timed out
+    #[error("SPARQL request interrupted: {interruption}")]
+    Interrupted {
+        #[from]
+        interruption: Interruption,
+    },
 }
 
 /// Converts a reqwest error into an HTTPRequestError.
@@ -1,7 +1,9 @@
 use super::in_memory_error::OxigraphInMemoryError;
+use super::recovered_error::{RecoveredError, Recovery};
+use super::source_positions::{Located, SourcePositions, parse_located, syntax_error_message};
 #[cfg(feature = "sparql")]
 use crate::rdf_core::query::{QueryRDF, QueryResultFormat, QuerySolution, QuerySolutions, VarName};
-use crate::rdf_core::{AsyncRDF, BuildRDF, FocusRDF, Matcher, NeighsRDF, RDFFormat, Rdf};
+use crate::rdf_core::{AsyncRDF, BuildRDF, FocusRDF, Matcher, NeighsRDF, RDFFormat, Rdf, SourceSpan};
 
 use crate::rdf_core::vocabs::RdfVocab;
 use colored::*;
@@ -20,7 +22,7 @@ use serde::{Serialize, ser::SerializeStruct};
 use std::{
     collections::{HashMap, HashSet},
     fmt::{Debug, Display, Formatter},
-    io::{self, Cursor, Write},
+    io::{self, Cursor, Read, Write},
     str::FromStr,
     sync::Arc,
 };
@@ -57,6 +59,15 @@ pub struct OxigraphInMemory {
     /// Counter used to generate unique blank node identifiers.
     bnode_counter: usize,
 
+    /// Positions in their sources of the triples read from Turtle and N-Triples.
+    positions: Arc<SourcePositions>,
+
+    /// Syntax errors skipped while reading data in lax mode.
+    recovered_errors: Vec<RecoveredError>,
+
+    /// Maximum number of syntax errors skipped in each source before reading it fails.
+    max_errors: Option<usize>,
+
     /// Optional Oxigraph store used for SPARQL evaluation.
     #[cfg(feature = "sparql")]
     store: Option<Store>,
@@ -111,15 +122,27 @@ impl OxigraphInMemory {
         self.base = default_base;
     }
 
+    /// Sets how many syntax errors can be skipped in each source read in lax mode
+    /// before reading it fails. There is no limit by default.
+    pub fn set_max_errors(&mut self, max_errors: Option<usize>) {
+        self.max_errors = max_errors;
+    }
+
+    /// Syntax errors skipped while reading data in lax mode, in the order they were found
+    pub fn recovered_errors(&self) -> &[RecoveredError] {
+        &self.recovered_errors
+    }
+
     /// Merges RDF data from a reader into the current graph.
     ///
     /// The parsing behavior depends on [`RDFFormat`] and [`ReaderMode`].
-    /// Prefixes and base IRI are merged when available.
+    /// Prefixes and base IRI are merged when available. The positions of the
+    /// triples read from Turtle and N-Triples are kept, see [`Rdf::triple_span`].
     ///
     /// # Parameters
     ///
     /// * `reader` - Input stream containing RDF data
-    /// * `source_name` - Name used for error reporting
+    /// * `source_name` - Name used for error reporting and for the positions of the triples
     /// * `format` - RDF serialization format
     /// * `base` - Optional base IRI for resolving relative IRIs
     /// * `reader_mode` - Controls error handling (strict or lax)
@@ -135,15 +158,47 @@ impl OxigraphInMemory {
         base: Option<&str>,
         reader_mode: &ReaderMode,
     ) -> Result<(), OxigraphInMemoryError> {
+        self.merge_from_reader_with_header("", reader, source_name, format, base, reader_mode)
+    }
+
+    /// Merges RDF data from a reader into the current graph, parsing `header` before it.
+    ///
+    /// The header holds declarations that are not part of the source, like default
+    /// prefixes, so lines are counted from the end of the header in the positions of
+    /// the triples and in syntax errors.
+    ///
+    /// # Parameters
+    ///
+    /// * `header` - Text parsed before the content of the reader
+    /// * `reader` - Input stream containing RDF data
+    /// * `source_name` - Name used for error reporting and for the positions of the triples
+    /// * `format` - RDF serialization format
+    /// * `base` - Optional base IRI for resolving relative IRIs
+    /// * `reader_mode` - Controls error handling (strict or lax)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails in strict mode or if I/O errors occur.
+    pub fn merge_from_reader_with_header<R: io::Read>(
+        &mut self,
+        header: &str,
+        reader: &mut R,
+        source_name: &str,
+        format: &RDFFormat,
+        base: Option<&str>,
+        reader_mode: &ReaderMode,
+    ) -> Result<(), OxigraphInMemoryError> {
+        let header_lines = header.matches('\n').count();
+        let mut reader = Cursor::new(header.as_bytes()).chain(reader);
         match format {
             RDFFormat::Turtle => {
-                self.parse_turtle(reader, source_name, base, reader_mode)?;
+                self.parse_turtle(&mut reader, source_name, header_lines, base, reader_mode)?;
             },
             RDFFormat::NTriples => {
-                self.parse_ntriples(reader, reader_mode)?;
+                self.parse_ntriples(&mut reader, source_name, header_lines, reader_mode)?;
             },
             RDFFormat::Rdfxml => {
-                self.parse_rdfxml(reader, reader_mode)?;
+                self.parse_rdfxml(&mut reader, source_name, reader_mode)?;
             },
             RDFFormat::TriG => {
                 todo!();
@@ -152,10 +207,10 @@ impl OxigraphInMemory {
                 todo!();
             },
             RDFFormat::NQuads => {
-                self.parse_nquads(reader, reader_mode)?;
+                self.parse_nquads(&mut reader, source_name, reader_mode)?;
             },
             RDFFormat::JsonLd => {
-                self.parse_jsonld(reader, reader_mode)?;
+                self.parse_jsonld(&mut reader, source_name, reader_mode)?;
             },
         }
         if let Some(base) = base {
@@ -165,12 +220,13 @@ impl OxigraphInMemory {
         Ok(())
     }
 
-    /// Parses Turtle data and merges it into the graph.
+    /// Parses Turtle data and merges it into the graph, recording the position of each triple.
     ///
     /// # Parameters
     ///
     /// * `reader` - Input stream containing Turtle data
-    /// * `source_name` - Name used for error reporting
+    /// * `source_name` - Name used for error reporting and for the positions of the triples
+    /// * `header_lines` - Number of lines at the start of the input that are not part of the source
     /// * `base` - Optional base IRI for resolving relative IRIs
     /// * `reader_mode` - Controls error handling (strict or lax)
     ///
@@ -181,6 +237,7 @@ impl OxigraphInMemory {
         &mut self,
         reader: &mut R,
         source_name: &str,
+        header_lines: usize,
         base: Option<&str>,
         reader_mode: &ReaderMode,
     ) -> Result<(), OxigraphInMemoryError> {
@@ -189,35 +246,45 @@ impl OxigraphInMemory {
             Some(iri) => TurtleParser::new().lenient().with_base_iri(iri)?,
         };
 
-        let mut turtle_reader = turtle_parser.for_reader(reader);
+        let mut turtle_parser = turtle_parser.low_level();
         let graph = Arc::make_mut(&mut self.graph);
-
-        for triple_result in turtle_reader.by_ref() {
-            let triple = match handle_parse_error(triple_result, reader_mode, |e| {
-                OxigraphInMemoryError::TurtleParseError {
-                    source_name: source_name.to_string(),
-                    error: e,
-                }
-            })? {
-                Some(t) => t,
-                None => continue,
+        let positions = Arc::make_mut(&mut self.positions);
+        let source = positions.add_source(source_name);
+
+        let mut recovery = Recovery::new(source_name, reader_mode, self.max_errors, &mut self.recovered_errors);
+
+        parse_located(&mut turtle_parser, reader, |triple_result, located| {
+            let span = source_span(source_name, &located, header_lines);
+            let triple = match triple_result {
+                Ok(triple) => triple,
+                Err(e) => {
+                    let error = RecoveredError::new(source_name, e.message())
+                        .with_span(span)
+                        .with_text(located.text);
+                    return recovery.recover(error, |_| OxigraphInMemoryError::TurtleParseError {
+                        source_name: source_name.to_string(),
+                        error: syntax_error_message(&e, header_lines),
+                    });
+                },
             };
             let triple_ref = triple.as_ref();
             if let Err(e) = validate_triple_iris(triple_ref) {
-                match handle_parse_error(Err::<(), _>(e), reader_mode, |e| {
-                    OxigraphInMemoryError::TurtleParseError {
-                        source_name: source_name.to_string(),
-                        error: format!("Invalid IRI in triple: {e}"),
-                    }
-                })? {
-                    Some(_) => unreachable!(),
-                    None => continue,
-                }
+                let error = RecoveredError::new(source_name, &format!("Invalid IRI in triple: {e}"))
+                    .with_span(span)
+                    .with_text(located.text);
+                return recovery.recover(error, |message| OxigraphInMemoryError::TurtleParseError {
+                    source_name: source_name.to_string(),
+                    error: message,
+                });
             }
             graph.insert(triple_ref);
-        }
+            if let Some(span) = span {
+                positions.record(source, &triple, span.line(), span.column());
+            }
+            Ok(())
+        })?;
 
-        let prefixes: HashMap<&str, &str> = turtle_reader.prefixes().collect();
+        let prefixes: HashMap<&str, &str> = turtle_parser.prefixes().collect();
         self.base = match (&self.base, base) {
             (None, None) => None,
             (Some(b), None) => Some(b.clone()),
@@ -228,11 +295,13 @@ impl OxigraphInMemory {
         Ok(())
     }
 
-    /// Parses N-Triples data and merges it into the graph.
+    /// Parses N-Triples data and merges it into the graph, recording the position of each triple.
     ///
     /// # Parameters
     ///
     /// * `reader` - Input stream containing N-Triples data
+    /// * `source_name` - Name used for the positions of the triples
+    /// * `header_lines` - Number of lines at the start of the input that are not part of the source
     /// * `reader_mode` - Controls error handling (strict or lax)
     ///
     /// # Errors
@@ -241,25 +310,37 @@ impl OxigraphInMemory {
     fn parse_ntriples<R: io::Read>(
         &mut self,
         reader: &mut R,
+        source_name: &str,
+        header_lines: usize,
         reader_mode: &ReaderMode,
     ) -> Result<(), OxigraphInMemoryError> {
-        let parser = NTriplesParser::new();
-        let mut nt_reader = parser.for_reader(reader);
+        let mut nt_parser = NTriplesParser::new().low_level();
         let graph = Arc::make_mut(&mut self.graph);
-
-        for triple_result in nt_reader.by_ref() {
-            let triple =
-                match handle_parse_error(triple_result, reader_mode, |e| OxigraphInMemoryError::NTriplesError {
-                    data: "Reading N-Triples".to_string(),
-                    error: e,
-                })? {
-                    Some(t) => t,
-                    None => continue,
-                };
+        let positions = Arc::make_mut(&mut self.positions);
+        let source = positions.add_source(source_name);
+
+        let mut recovery = Recovery::new(source_name, reader_mode, self.max_errors, &mut self.recovered_errors);
+
+        parse_located(&mut nt_parser, reader, |triple_result, located| {
+            let span = source_span(source_name, &located, header_lines);
+            let triple = match triple_result {
+                Ok(triple) => triple,
+                Err(e) => {
+                    let error = RecoveredError::new(source_name, e.message())
+                        .with_span(span)
+                        .with_text(located.text);
+                    return recovery.recover(error, |_| OxigraphInMemoryError::NTriplesError {
+                        data: "Reading N-Triples".to_string(),
+                        error: syntax_error_message(&e, header_lines),
+                    });
+                },
+            };
             graph.insert(triple.as_ref());
-        }
-
-        Ok(())
+            if let Some(span) = span {
+                positions.record(source, &triple, span.line(), span.column());
+            }
+            Ok(())
+        })
     }
 
     /// Parses RDF/XML data and merges it into the graph.
@@ -267,6 +348,7 @@ impl OxigraphInMemory {
     /// # Parameters
     ///
     /// * `reader` - Input stream containing RDF/XML data
+    /// * `source_name` - Name used for the skipped syntax errors
     /// * `reader_mode` - Controls error handling (strict or lax)
     ///
     /// # Errors
@@ -275,19 +357,25 @@ impl OxigraphInMemory {
     fn parse_rdfxml<R: io::Read>(
         &mut self,
         reader: &mut R,
+        source_name: &str,
         reader_mode: &ReaderMode,
     ) -> Result<(), OxigraphInMemoryError> {
         let parser = RdfXmlParser::new();
         let mut xml_reader = parser.for_reader(reader);
         let graph = Arc::make_mut(&mut self.graph);
 
+        let mut recovery = Recovery::new(source_name, reader_mode, self.max_errors, &mut self.recovered_errors);
+
         for triple_result in xml_reader.by_ref() {
-            let triple = match handle_parse_error(triple_result, reader_mode, |e| OxigraphInMemoryError::RDFXMLError {
-                data: "Reading RDF/XML".to_string(),
-                error: e,
-            })? {
-                Some(t) => t,
-                None => continue,
+            let triple = match triple_result {
+                Ok(triple) => triple,
+                Err(e) => {
+                    recovery.recover_message(e, |error| OxigraphInMemoryError::RDFXMLError {
+                        data: "Reading RDF/XML".to_string(),
+                        error,
+                    })?;
+                    continue;
+                },
             };
             let triple_ref = cnv_triple(&triple);
             graph.insert(triple_ref);
@@ -301,6 +389,7 @@ impl OxigraphInMemory {
     /// # Parameters
     ///
     /// * `reader` - Input stream containing N-Quads data
+    /// * `source_name` - Name used for the skipped syntax errors
     /// * `reader_mode` - Controls error handling (strict or lax)
     ///
     /// # Errors
@@ -309,19 +398,25 @@ impl OxigraphInMemory {
     fn parse_nquads<R: io::Read>(
         &mut self,
         reader: &mut R,
+        source_name: &str,
         reader_mode: &ReaderMode,
     ) -> Result<(), OxigraphInMemoryError> {
         let parser = NQuadsParser::new();
         let mut nq_reader = parser.for_reader(reader);
         let graph = Arc::make_mut(&mut self.graph);
 
+        let mut recovery = Recovery::new(source_name, reader_mode, self.max_errors, &mut self.recovered_errors);
+
         for triple_result in nq_reader.by_ref() {
-            let triple = match handle_parse_error(triple_result, reader_mode, |e| OxigraphInMemoryError::NQuadsError {
-                data: "Reading NQuads".to_string(),
-                error: e,
-            })? {
-                Some(t) => t,
-                None => continue,
+            let triple = match triple_result {
+                Ok(triple) => triple,
+                Err(e) => {
+                    recovery.recover_message(e, |error| OxigraphInMemoryError::NQuadsError {
+                        data: "Reading NQuads".to_string(),
+                        error,
+                    })?;
+                    continue;
+                },
             };
             graph.insert(triple.as_ref());
         }
@@ -334,6 +429,7 @@ impl OxigraphInMemory {
     /// # Parameters
     ///
     /// * `reader` - Input stream containing JSON-LD data
+    /// * `source_name` - Name used for the skipped syntax errors
     /// * `reader_mode` - Controls error handling (strict or lax)
     ///
     /// # Errors
@@ -342,19 +438,25 @@ impl OxigraphInMemory {
     fn parse_jsonld<R: io::Read>(
         &mut self,
         reader: &mut R,
+        source_name: &str,
         reader_mode: &ReaderMode,
     ) -> Result<(), OxigraphInMemoryError> {
         let parser = JsonLdParser::new();
         let mut jsonld_reader = parser.for_reader(reader);
         let graph = Arc::make_mut(&mut self.graph);
 
+        let mut recovery = Recovery::new(source_name, reader_mode, self.max_errors, &mut self.recovered_errors);
+
         for triple_result in jsonld_reader.by_ref() {
-            let triple = match handle_parse_error(triple_result, reader_mode, |e| OxigraphInMemoryError::JsonLDError {
-                data: "Reading JSON-LD".to_string(),
-                error: e,
-            })? {
-                Some(t) => t,
-                None => continue,
+            let triple = match triple_result {
+                Ok(triple) => triple,
+                Err(e) => {
+                    recovery.recover_message(e, |error| OxigraphInMemoryError::JsonLDError {
+                        data: "Reading JSON-LD".to_string(),
+                        error,
+                    })?;
+                    continue;
+                },
             };
             graph.insert(triple.as_ref());
         }
@@ -633,6 +735,15 @@ impl Rdf for OxigraphInMemory {
         Ok(iri)
     }
 
+    fn subject_span(&self, subject: &OxSubject) -> Option<SourceSpan> {
+        self.positions.subject_span(subject)
+    }
+
+    fn triple_span(&self, subject: &OxSubject, predicate: &OxNamedNode, object: &OxTerm) -> Option<SourceSpan> {
+        let triple = OxTriple::new(subject.clone(), predicate.clone(), object.clone());
+        self.positions.triple_span(&triple)
+    }
+
     /// Converts a full IRI to a qualified (prefixed) name if possible.
     ///
     /// If the IRI matches a known namespace prefix, it returns a shortened form
@@ -1045,6 +1156,9 @@ impl BuildRDF for OxigraphInMemory {
             pm: PrefixMap::new(),
             base: None,
             bnode_counter: 0,
+            positions: Default::default(),
+            recovered_errors: Vec::new(),
+            max_errors: None,
             #[cfg(feature = "sparql")]
             store: None,
         }
@@ -1154,21 +1268,9 @@ fn validate_triple_iris(triple: TripleRef) -> Result<(), String> {
     Ok(())
 }
 
-fn handle_parse_error<T, E: std::fmt::Display>(
-    result: Result<T, E>,
-    reader_mode: &ReaderMode,
-    error_constructor: impl FnOnce(String) -> OxigraphInMemoryError,
-) -> Result<Option<T>, OxigraphInMemoryError> {
-    match result {
-        Ok(val) => Ok(Some(val)),
-        Err(e) => {
-            if reader_mode.is_strict() {
-                Err(error_constructor(e.to_string()))
-            } else {
-                Ok(None)
-            }
-        },
-    }
+/// Position in the source of a triple or syntax error, unless it is in the header
+fn source_span(source_name: &str, located: &Located<'_>, header_lines: usize) -> Option<SourceSpan> {
+    (located.line > header_lines).then(|| SourceSpan::new(source_name, located.line - header_lines, located.column))
 }
 
 /// Reader mode when parsing RDF data files.
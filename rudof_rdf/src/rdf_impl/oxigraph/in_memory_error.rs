@@ -47,6 +47,19 @@ pub enum OxigraphInMemoryError {
     #[error("Error parsing Turtle data from {source_name}: {error}")]
     TurtleParseError { source_name: String, error: String },
 
+    /// Too many syntax errors were skipped while reading data in lax mode.
+    ///
+    /// # Fields
+    /// - `source_name`: The name or path of the data source
+    /// - `max_errors`: The number of errors that were allowed
+    /// - `last_error`: Description of the error that went over the limit
+    #[error("Stopped reading {source_name} after more than {max_errors} syntax errors. Last error: {last_error}")]
+    TooManySyntaxErrors {
+        source_name: String,
+        max_errors: usize,
+        last_error: String,
+    },
+
     /// Error parsing a base IRI.
     ///
     /// # Fields
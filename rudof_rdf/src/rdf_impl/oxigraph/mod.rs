@@ -10,6 +10,8 @@ mod endpoint_error;
 mod in_memory;
 mod in_memory_error;
 mod oxrdf_impl;
+mod recovered_error;
+mod source_positions;
 
 #[cfg(all(not(target_family = "wasm"), feature = "sparql"))]
 pub use endpoint::{OxigraphEndpoint, SparqlVars};
@@ -17,3 +19,4 @@ pub use endpoint::{OxigraphEndpoint, SparqlVars};
 pub use endpoint_error::OxigraphEndpointError;
 pub use in_memory::{OxigraphInMemory, ReaderMode};
 pub use in_memory_error::OxigraphInMemoryError;
+pub use recovered_error::RecoveredError;
@@ -0,0 +1,134 @@
+use super::in_memory::ReaderMode;
+use super::in_memory_error::OxigraphInMemoryError;
+use crate::rdf_core::SourceSpan;
+use serde::Serialize;
+use std::fmt::{Display, Formatter};
+
+/// A syntax error that was skipped while reading RDF data in [`ReaderMode::Lax`].
+///
+/// The statement where the error was found is dropped and the parser continues
+/// with the next one.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct RecoveredError {
+    /// Name of the source that was being read
+    source_name: String,
+    /// Position of the error, for the formats where it is known
+    #[serde(rename = "location", skip_serializing_if = "Option::is_none")]
+    span: Option<SourceSpan>,
+    message: String,
+    /// Line of the source that contains the error
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+}
+
+impl RecoveredError {
+    pub fn new(source_name: &str, message: &str) -> Self {
+        RecoveredError {
+            source_name: source_name.to_string(),
+            span: None,
+            message: message.to_string(),
+            text: None,
+        }
+    }
+
+    pub fn with_span(mut self, span: Option<SourceSpan>) -> Self {
+        self.span = span;
+        self
+    }
+
+    pub fn with_text(mut self, text: &str) -> Self {
+        let text = text.trim();
+        self.text = (!text.is_empty()).then(|| text.to_string());
+        self
+    }
+
+    pub fn source_name(&self) -> &str {
+        &self.source_name
+    }
+
+    pub fn span(&self) -> Option<&SourceSpan> {
+        self.span.as_ref()
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn text(&self) -> Option<&str> {
+        self.text.as_deref()
+    }
+}
+
+impl Display for RecoveredError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match &self.span {
+            Some(span) => write!(f, "{span}: {}", self.message)?,
+            None => write!(f, "{}: {}", self.source_name, self.message)?,
+        }
+        if let Some(text) = &self.text {
+            write!(f, " in `{text}`")?;
+        }
+        Ok(())
+    }
+}
+
+/// Decides what to do with the syntax errors found while reading a source.
+///
+/// In strict mode the first error stops the reading. In lax mode the errors are
+/// collected until there are more than `max_errors` of them.
+pub(crate) struct Recovery<'a> {
+    source_name: &'a str,
+    reader_mode: ReaderMode,
+    max_errors: Option<usize>,
+    errors: &'a mut Vec<RecoveredError>,
+    recovered: usize,
+}
+
+impl<'a> Recovery<'a> {
+    pub fn new(
+        source_name: &'a str,
+        reader_mode: &ReaderMode,
+        max_errors: Option<usize>,
+        errors: &'a mut Vec<RecoveredError>,
+    ) -> Self {
+        Recovery {
+            source_name,
+            reader_mode: *reader_mode,
+            max_errors,
+            errors,
+            recovered: 0,
+        }
+    }
+
+    /// Returns the error built by `strict_error` in strict mode, and otherwise keeps
+    /// the error unless it goes over the maximum number of errors
+    pub fn recover(
+        &mut self,
+        error: RecoveredError,
+        strict_error: impl FnOnce(String) -> OxigraphInMemoryError,
+    ) -> Result<(), OxigraphInMemoryError> {
+        if self.reader_mode.is_strict() {
+            return Err(strict_error(error.message));
+        }
+        self.errors.push(error);
+        self.recovered += 1;
+        match self.max_errors {
+            Some(max_errors) if self.recovered > max_errors => Err(OxigraphInMemoryError::TooManySyntaxErrors {
+                source_name: self.source_name.to_string(),
+                max_errors,
+                last_error: self.errors.last().map(|error| error.to_string()).unwrap_or_default(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Recovers from an error that has no known position
+    pub fn recover_message(
+        &mut self,
+        message: impl Display,
+        strict_error: impl FnOnce(String) -> OxigraphInMemoryError,
+    ) -> Result<(), OxigraphInMemoryError> {
+        let error = RecoveredError::new(self.source_name, &message.to_string());
+        self.recover(error, strict_error)
+    }
+}
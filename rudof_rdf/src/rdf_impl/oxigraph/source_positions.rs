@@ -0,0 +1,259 @@
+//! Positions of the triples of an in-memory graph in the sources they were read from.
+//!
+//! The Oxigraph parsers don't report where each triple was found, so the sources are
+//! fed to their low level parsers a token at a time and each triple is located at the
+//! token that completed it, which is usually its object. Literals are only complete
+//! when the parser sees the token that follows them, so the punctuation that ends a
+//! triple is never used as its position.
+
+use super::in_memory_error::OxigraphInMemoryError;
+use crate::rdf_core::SourceSpan;
+use oxrdf::{NamedOrBlankNode as OxSubject, Triple as OxTriple};
+use oxttl::TurtleSyntaxError;
+use oxttl::ntriples::LowLevelNTriplesParser;
+use oxttl::turtle::LowLevelTurtleParser;
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader};
+
+/// Where the triples of a graph were read from.
+///
+/// Only the first position of a triple, or of a subject, is kept.
+#[derive(Default, Clone, Debug)]
+pub(crate) struct SourcePositions {
+    sources: Vec<String>,
+    triples: HashMap<OxTriple, Position>,
+    subjects: HashMap<OxSubject, Position>,
+}
+
+#[derive(Copy, Clone, Debug)]
+struct Position {
+    source: usize,
+    line: usize,
+    column: usize,
+}
+
+impl SourcePositions {
+    /// Registers a source, returning the index used to record its triples
+    pub fn add_source(&mut self, name: &str) -> usize {
+        match self.sources.iter().position(|source| source == name) {
+            Some(idx) => idx,
+            None => {
+                self.sources.push(name.to_string());
+                self.sources.len() - 1
+            },
+        }
+    }
+
+    pub fn record(&mut self, source: usize, triple: &OxTriple, line: usize, column: usize) {
+        let position = Position { source, line, column };
+        self.subjects.entry(triple.subject.clone()).or_insert(position);
+        self.triples.entry(triple.clone()).or_insert(position);
+    }
+
+    pub fn triple_span(&self, triple: &OxTriple) -> Option<SourceSpan> {
+        self.triples.get(triple).map(|position| self.span(position))
+    }
+
+    pub fn subject_span(&self, subject: &OxSubject) -> Option<SourceSpan> {
+        self.subjects.get(subject).map(|position| self.span(position))
+    }
+
+    fn span(&self, position: &Position) -> SourceSpan {
+        SourceSpan::new(&self.sources[position.source], position.line, position.column)
+    }
+}
+
+/// The low level parsers of the line based and Turtle formats
+pub(crate) trait LowLevelParser {
+    fn extend_from_slice(&mut self, bytes: &[u8]);
+    fn end(&mut self);
+    fn parse_next(&mut self) -> Option<Result<OxTriple, TurtleSyntaxError>>;
+}
+
+impl LowLevelParser for LowLevelTurtleParser {
+    fn extend_from_slice(&mut self, bytes: &[u8]) {
+        LowLevelTurtleParser::extend_from_slice(self, bytes)
+    }
+
+    fn end(&mut self) {
+        LowLevelTurtleParser::end(self)
+    }
+
+    fn parse_next(&mut self) -> Option<Result<OxTriple, TurtleSyntaxError>> {
+        LowLevelTurtleParser::parse_next(self)
+    }
+}
+
+impl LowLevelParser for LowLevelNTriplesParser {
+    fn extend_from_slice(&mut self, bytes: &[u8]) {
+        LowLevelNTriplesParser::extend_from_slice(self, bytes)
+    }
+
+    fn end(&mut self) {
+        LowLevelNTriplesParser::end(self)
+    }
+
+    fn parse_next(&mut self) -> Option<Result<OxTriple, TurtleSyntaxError>> {
+        LowLevelNTriplesParser::parse_next(self)
+    }
+}
+
+/// Where a triple, or a syntax error, was found in the parsed input
+pub(crate) struct Located<'a> {
+    /// Line, counting from 1 at the start of the input
+    pub line: usize,
+    /// Column, counting characters from 1
+    pub column: usize,
+    /// Content of the line, when it is one of the last two lines read
+    pub text: &'a str,
+}
+
+/// Parses the content of `reader`, calling `on_triple` with each parsed triple, or
+/// syntax error, and the place where it was found.
+pub(crate) fn parse_located<P: LowLevelParser, R: io::Read>(
+    parser: &mut P,
+    reader: R,
+    mut on_triple: impl FnMut(Result<OxTriple, TurtleSyntaxError>, Located<'_>) -> Result<(), OxigraphInMemoryError>,
+) -> Result<(), OxigraphInMemoryError> {
+    let mut reader = BufReader::new(reader);
+    let mut lines = RecentLines::default();
+    let mut line = Vec::new();
+    let mut position = (1, 1);
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            break;
+        }
+        lines.push(&line);
+        for (column, segment) in segments(&line) {
+            if !matches!(segment.trim_ascii(), b"." | b";" | b",") {
+                position = (lines.count, column);
+            }
+            parser.extend_from_slice(segment);
+            while let Some(result) = parser.parse_next() {
+                let located = lines.locate(&result_position(&result, position));
+                on_triple(result, located)?;
+            }
+        }
+    }
+    parser.end();
+    while let Some(result) = parser.parse_next() {
+        let located = lines.locate(&result_position(&result, position));
+        on_triple(result, located)?;
+    }
+    Ok(())
+}
+
+/// Position of a triple, or the start of a syntax error, as a 1-based line and column
+fn result_position(result: &Result<OxTriple, TurtleSyntaxError>, triple_position: (usize, usize)) -> (usize, usize) {
+    match result {
+        Ok(_) => triple_position,
+        Err(error) => {
+            let start = error.location().start;
+            (start.line as usize + 1, start.column as usize + 1)
+        },
+    }
+}
+
+/// The last two lines read, which are the ones where a triple can be completed
+#[derive(Default)]
+struct RecentLines {
+    count: usize,
+    current: String,
+    previous: String,
+}
+
+impl RecentLines {
+    fn push(&mut self, line: &[u8]) {
+        self.count += 1;
+        self.previous = std::mem::replace(&mut self.current, String::from_utf8_lossy(line).into_owned());
+    }
+
+    fn locate(&self, (line, column): &(usize, usize)) -> Located<'_> {
+        let text = if *line == self.count {
+            &self.current
+        } else if *line + 1 == self.count {
+            &self.previous
+        } else {
+            ""
+        };
+        Located {
+            line: *line,
+            column: *column,
+            text,
+        }
+    }
+}
+
+/// Splits a line in pieces that start with a token and end with the whitespace that
+/// follows it, paired with the column where the token starts.
+///
+/// The whitespace at the start of the line goes with the first token.
+fn segments(line: &[u8]) -> impl Iterator<Item = (usize, &[u8])> {
+    let is_space = |b: &u8| matches!(b, b' ' | b'\t' | b'\r' | b'\n');
+    let mut starts: Vec<usize> = (1..line.len())
+        .filter(|&i| is_space(&line[i - 1]) && !is_space(&line[i]))
+        .collect();
+    match starts.first_mut() {
+        Some(first) if line.first().is_some_and(is_space) => *first = 0,
+        _ => starts.insert(0, 0),
+    }
+    let mut chars_before = 0;
+    (0..starts.len()).map(move |k| {
+        let end = starts.get(k + 1).copied().unwrap_or(line.len());
+        let segment = &line[starts[k]..end];
+        let indent = segment.iter().take_while(|b| is_space(b)).count();
+        let column = chars_before + count_chars(&segment[..indent]) + 1;
+        chars_before += count_chars(segment);
+        (column, segment)
+    })
+}
+
+/// Counts the UTF-8 encoded characters of some bytes
+fn count_chars(bytes: &[u8]) -> usize {
+    bytes.iter().filter(|b| (**b & 0xC0) != 0x80).count()
+}
+
+/// Describes a syntax error with its position in the source, which starts after
+/// `header_lines` lines of the parsed input
+pub(crate) fn syntax_error_message(error: &TurtleSyntaxError, header_lines: usize) -> String {
+    let start = error.location().start;
+    match (start.line as usize + 1).checked_sub(header_lines) {
+        Some(line) if line > 0 => {
+            format!(
+                "Parser error at line {line} column {}: {}",
+                start.column + 1,
+                error.message()
+            )
+        },
+        _ => error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn segments_start_at_tokens() {
+        let found: Vec<_> = segments(b"  :a :b \"x y\" .\n")
+            .map(|(column, segment)| (column, String::from_utf8_lossy(segment).to_string()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                (3, "  :a ".to_string()),
+                (6, ":b ".to_string()),
+                (9, "\"x ".to_string()),
+                (12, "y\" ".to_string()),
+                (15, ".\n".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn segments_count_characters() {
+        let columns: Vec<_> = segments(":á :b".as_bytes()).map(|(column, _)| column).collect();
+        assert_eq!(columns, vec![1, 4]);
+    }
+}
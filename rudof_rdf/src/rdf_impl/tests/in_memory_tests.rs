@@ -1,5 +1,5 @@
 use crate::rdf_core::{
-    Any, BuildRDF, NeighsRDF, RDFError, RDFFormat,
+    Any, BuildRDF, NeighsRDF, RDFError, RDFFormat, Rdf, SourceSpan,
     parser::rdf_node_parser::{
         ParserExt, RDFNodeParse,
         constructors::{
@@ -9,7 +9,7 @@ use crate::rdf_core::{
     },
     term::Triple,
 };
-use crate::rdf_impl::{OxigraphInMemory, ReaderMode};
+use crate::rdf_impl::{OxigraphInMemory, OxigraphInMemoryError, ReaderMode};
 use crate::rdf_parser;
 use oxrdf::Literal as OxLiteral;
 use oxrdf::NamedNode as OxNamedNode;
@@ -349,3 +349,163 @@ fn test_add_triple_ref() {
     graph.add_triple_ref(&s, &p, &o).unwrap();
     assert_eq!(graph.len(), 1);
 }
+
+fn ex(local: &str) -> OxNamedNode {
+    OxNamedNode::new_unchecked(format!("http://example.org/{local}"))
+}
+
+#[test]
+fn test_turtle_triple_spans() {
+    let data = "prefix : <http://example.org/>\n:x :p 1 ;\n   :q \"a b\", :y .\n";
+    let graph = OxigraphInMemory::from_reader(
+        &mut data.as_bytes(),
+        "data.ttl",
+        &RDFFormat::Turtle,
+        None,
+        &ReaderMode::Strict,
+    )
+    .unwrap();
+    let x: OxSubject = ex("x").into();
+    let one: OxTerm = OxLiteral::from(1).into();
+    assert_eq!(
+        graph.triple_span(&x, &ex("p"), &one),
+        Some(SourceSpan::new("data.ttl", 2, 7))
+    );
+    assert_eq!(
+        graph.triple_span(&x, &ex("q"), &ex("y").into()),
+        Some(SourceSpan::new("data.ttl", 3, 14))
+    );
+    assert_eq!(graph.subject_span(&x), Some(SourceSpan::new("data.ttl", 2, 7)));
+    assert_eq!(graph.subject_span(&ex("y").into()), None);
+}
+
+#[test]
+fn test_turtle_triple_spans_skip_header() {
+    let header = "prefix : <http://example.org/>\nprefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>\n";
+    let mut graph = OxigraphInMemory::new();
+    graph
+        .merge_from_reader_with_header(
+            header,
+            &mut ":x :p :y .\n".as_bytes(),
+            "data.ttl",
+            &RDFFormat::Turtle,
+            None,
+            &ReaderMode::Strict,
+        )
+        .unwrap();
+    assert_eq!(
+        graph.triple_span(&ex("x").into(), &ex("p"), &ex("y").into()),
+        Some(SourceSpan::new("data.ttl", 1, 7))
+    );
+}
+
+#[test]
+fn test_turtle_syntax_error_skips_header() {
+    let mut graph = OxigraphInMemory::new();
+    let error = graph
+        .merge_from_reader_with_header(
+            "prefix : <http://example.org/>\n",
+            &mut ":x :p :y .\n:x :p .\n".as_bytes(),
+            "data.ttl",
+            &RDFFormat::Turtle,
+            None,
+            &ReaderMode::Strict,
+        )
+        .unwrap_err();
+    assert!(error.to_string().contains("line 2"), "{error}");
+}
+
+#[test]
+fn test_ntriples_triple_spans() {
+    let data = "<http://example.org/x> <http://example.org/p> <http://example.org/y> .\n\n<http://example.org/y> <http://example.org/p> \"1\" .\n";
+    let graph = OxigraphInMemory::from_reader(
+        &mut data.as_bytes(),
+        "data.nt",
+        &RDFFormat::NTriples,
+        None,
+        &ReaderMode::Strict,
+    )
+    .unwrap();
+    assert_eq!(
+        graph.subject_span(&ex("y").into()),
+        Some(SourceSpan::new("data.nt", 3, 47))
+    );
+}
+
+#[test]
+fn test_lax_turtle_collects_syntax_errors() {
+    let data = "prefix : <http://example.org/>\n:x :p :y .\n:x :p :y :z .\n:y :p :z .\n:z :p :x .\n:z :p \"a .\n";
+    let mut graph = OxigraphInMemory::new();
+    graph
+        .merge_from_reader(
+            &mut data.as_bytes(),
+            "data.ttl",
+            &RDFFormat::Turtle,
+            None,
+            &ReaderMode::Lax,
+        )
+        .unwrap();
+    assert!(graph.triples().unwrap().any(|triple| triple.subject == ex("z").into()));
+    let errors = graph.recovered_errors();
+    let first = errors.first().unwrap();
+    assert_eq!(first.span().map(|span| span.line()), Some(3));
+    assert_eq!(first.text(), Some(":x :p :y :z ."));
+    assert_eq!(first.source_name(), "data.ttl");
+    let last = errors.last().unwrap();
+    assert_eq!(last.span().map(|span| span.line()), Some(6));
+    assert_eq!(last.text(), Some(":z :p \"a ."));
+}
+
+#[test]
+fn test_lax_ntriples_collects_syntax_errors() {
+    let data = "<http://example.org/x> <http://example.org/p> .\n<http://example.org/x> <http://example.org/p> <http://example.org/y> .\n";
+    let mut graph = OxigraphInMemory::new();
+    graph
+        .merge_from_reader(
+            &mut data.as_bytes(),
+            "data.nt",
+            &RDFFormat::NTriples,
+            None,
+            &ReaderMode::Lax,
+        )
+        .unwrap();
+    assert_eq!(graph.len(), 1);
+    let errors = graph.recovered_errors();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].span().map(|span| span.line()), Some(1));
+}
+
+#[test]
+fn test_lax_turtle_stops_after_max_errors() {
+    let data = "prefix : <http://example.org/>\n:x :p :y :z .\n:y :p :x :z .\n:z :p :x .\n";
+    let mut graph = OxigraphInMemory::new();
+    graph.set_max_errors(Some(1));
+    let error = graph
+        .merge_from_reader(
+            &mut data.as_bytes(),
+            "data.ttl",
+            &RDFFormat::Turtle,
+            None,
+            &ReaderMode::Lax,
+        )
+        .unwrap_err();
+    assert!(
+        matches!(error, OxigraphInMemoryError::TooManySyntaxErrors { max_errors: 1, .. }),
+        "{error}"
+    );
+    assert_eq!(graph.recovered_errors().len(), 2);
+}
+
+#[test]
+fn test_strict_turtle_keeps_no_recovered_errors() {
+    let mut graph = OxigraphInMemory::new();
+    let result = graph.merge_from_reader(
+        &mut "prefix : <http://example.org/>\n:x :p .\n".as_bytes(),
+        "data.ttl",
+        &RDFFormat::Turtle,
+        None,
+        &ReaderMode::Strict,
+    );
+    assert!(result.is_err());
+    assert!(graph.recovered_errors().is_empty());
+}
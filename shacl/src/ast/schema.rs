@@ -1,6 +1,7 @@
 use crate::ast::shape::ASTShape;
 use prefixmap::PrefixMap;
 use rudof_iri::IriS;
+use rudof_rdf::rdf_core::SourceSpan;
 use rudof_rdf::rdf_core::term::Object;
 use std::collections::HashMap;
 use std::collections::hash_map::IntoIter;
@@ -13,6 +14,8 @@ pub struct ASTSchema {
     shapes: HashMap<Object, ASTShape>,
     prefixmap: PrefixMap,
     base: Option<IriS>,
+    /// Positions of the shapes in the shapes graph, when it was read from a source
+    spans: HashMap<Object, SourceSpan>,
 }
 
 impl ASTSchema {
@@ -21,6 +24,7 @@ impl ASTSchema {
             shapes: HashMap::new(),
             prefixmap: PrefixMap::new(),
             base: None,
+            spans: HashMap::new(),
         }
     }
 
@@ -38,6 +42,11 @@ impl ASTSchema {
         self
     }
 
+    pub fn with_spans(mut self, spans: HashMap<Object, SourceSpan>) -> Self {
+        self.spans = spans;
+        self
+    }
+
     pub fn prefixmap(&self) -> &PrefixMap {
         &self.prefixmap
     }
@@ -59,6 +68,11 @@ impl ASTSchema {
     pub fn get_shape(&self, sref: &Object) -> Option<&ASTShape> {
         self.shapes.get(sref)
     }
+
+    /// Position in the shapes graph of the first triple about the shape `sref`
+    pub fn span(&self, sref: &Object) -> Option<&SourceSpan> {
+        self.spans.get(sref)
+    }
 }
 
 impl IntoIterator for ASTSchema {
@@ -16,7 +16,7 @@ use rudof_iri::IriS;
 use rudof_rdf::rdf_core::BuildRDF;
 use rudof_rdf::rdf_core::term::Object;
 use rudof_rdf::rdf_core::term::literal::ConcreteLiteral;
-use rudof_rdf::rdf_core::vocabs::ShaclVocab;
+use rudof_rdf::rdf_core::vocabs::{RdfVocab, ShaclVocab};
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
 
@@ -255,12 +255,17 @@ impl IRComponent {
                 _ => unreachable!(),
             },
             IRComponent::In(i) => {
-                // TODO - Review this code
-                i.values().iter().try_for_each(|v| match v {
-                    Object::Iri(iri) => register_iri(iri, ShaclVocab::sh_in(), id, graph),
-                    Object::Literal(lit) => register_literal(lit, ShaclVocab::sh_in(), id, graph),
-                    _ => unreachable!(),
-                })
+                let values = i
+                    .values()
+                    .iter()
+                    .map(|v| match v {
+                        Object::Iri(iri) => iri.clone().into(),
+                        Object::Literal(lit) => RDF::Literal::from(lit.clone()).into(),
+                        _ => unreachable!(),
+                    })
+                    .collect();
+                let list = register_list(values, graph)?;
+                register_term(&list, ShaclVocab::sh_in(), id, graph)
             },
             IRComponent::QualifiedValueShape(qvs) => {
                 if let Some(value) = qvs.qualified_min_count() {
@@ -463,10 +468,29 @@ fn register_literal<RDF: BuildRDF>(
     node: &Object,
     graph: &mut RDF,
 ) -> Result<(), IRError> {
-    let literal: RDF::Literal = value.lexical_form().into();
+    let literal: RDF::Literal = value.clone().into();
     register_term(&literal.into(), predicate, node, graph)
 }
 
+/// Adds an RDF list with the given values and returns its head
+fn register_list<RDF: BuildRDF>(values: Vec<RDF::Term>, graph: &mut RDF) -> Result<RDF::Term, IRError> {
+    let mut list: RDF::Term = RdfVocab::rdf_nil().into();
+    for value in values.into_iter().rev() {
+        let bnode = graph
+            .add_bnode()
+            .map_err(|e| IRError::from_rdf_err::<RDF>("add blank node", e))?;
+        let node: RDF::Subject = bnode.into();
+        graph
+            .add_triple(node.clone(), RdfVocab::rdf_first(), value)
+            .map_err(|e| IRError::from_rdf_err::<RDF>("add triple", e))?;
+        graph
+            .add_triple(node.clone(), RdfVocab::rdf_rest(), list)
+            .map_err(|e| IRError::from_rdf_err::<RDF>("add triple", e))?;
+        list = node.into();
+    }
+    Ok(list)
+}
+
 fn register_iri<RDF: BuildRDF>(value: &IriS, predicate: IriS, node: &Object, graph: &mut RDF) -> Result<(), IRError> {
     register_term(&value.clone().into(), predicate, node, graph)
 }
@@ -0,0 +1,206 @@
+use crate::ir::{IRComponent, IRSchema, ShapeLabelIdx};
+use crate::types::{MessageMap, message_term};
+use rudof_iri::IriS;
+use rudof_rdf::rdf_core::term::Object;
+use rudof_rdf::rdf_core::term::literal::Lang;
+
+/// Languages of the built-in messages
+const LANGS: [&str; 2] = ["en", "es"];
+
+impl IRComponent {
+    /// Messages used for the results of the component when its shape has no
+    /// `sh:message`, in English and Spanish. They are templates whose placeholders
+    /// are the parameters of the component, like `{$minCount}`, and `{$this}`,
+    /// `{$value}` and `{$path}`.
+    pub fn default_message(&self) -> MessageMap {
+        let [en, es] = match self {
+            IRComponent::Class(_) => [
+                "Value {$value} does not have class {$class}",
+                "El valor {$value} no tiene la clase {$class}",
+            ],
+            IRComponent::Datatype(_) => [
+                "Value {$value} does not have datatype {$datatype}",
+                "El valor {$value} no tiene el tipo de datos {$datatype}",
+            ],
+            IRComponent::NodeKind(_) => [
+                "Value {$value} does not have node kind {$nodeKind}",
+                "El valor {$value} no es de tipo de nodo {$nodeKind}",
+            ],
+            IRComponent::MinCount(_) => [
+                "{$this} has less than {$minCount} values for {$path}",
+                "{$this} tiene menos de {$minCount} valores para {$path}",
+            ],
+            IRComponent::MaxCount(_) => [
+                "{$this} has more than {$maxCount} values for {$path}",
+                "{$this} tiene más de {$maxCount} valores para {$path}",
+            ],
+            IRComponent::MinExclusive(_) => [
+                "Value {$value} is not greater than {$minExclusive}",
+                "El valor {$value} no es mayor que {$minExclusive}",
+            ],
+            IRComponent::MaxExclusive(_) => [
+                "Value {$value} is not less than {$maxExclusive}",
+                "El valor {$value} no es menor que {$maxExclusive}",
+            ],
+            IRComponent::MinInclusive(_) => [
+                "Value {$value} is less than {$minInclusive}",
+                "El valor {$value} es menor que {$minInclusive}",
+            ],
+            IRComponent::MaxInclusive(_) => [
+                "Value {$value} is greater than {$maxInclusive}",
+                "El valor {$value} es mayor que {$maxInclusive}",
+            ],
+            IRComponent::MinLength(_) => [
+                "Value {$value} has less than {$minLength} characters",
+                "El valor {$value} tiene menos de {$minLength} caracteres",
+            ],
+            IRComponent::MaxLength(_) => [
+                "Value {$value} has more than {$maxLength} characters",
+                "El valor {$value} tiene más de {$maxLength} caracteres",
+            ],
+            IRComponent::Pattern(_) => [
+                "Value {$value} does not match the pattern \"{$pattern}\"",
+                "El valor {$value} no encaja con el patrón \"{$pattern}\"",
+            ],
+            IRComponent::UniqueLang(_) => [
+                "{$this} has more than one value for {$path} with the same language",
+                "{$this} tiene más de un valor para {$path} con el mismo idioma",
+            ],
+            IRComponent::LanguageIn(_) => [
+                "Language of {$value} is not one of {$languageIn}",
+                "El idioma de {$value} no es ninguno de {$languageIn}",
+            ],
+            IRComponent::Equals(_) => [
+                "Values of {$this} for {$path} are not equal to its values for {$equals}",
+                "Los valores de {$this} para {$path} no son iguales a sus valores para {$equals}",
+            ],
+            IRComponent::Disjoint(_) => [
+                "Value {$value} is also a value of {$this} for {$disjoint}",
+                "El valor {$value} también es un valor de {$this} para {$disjoint}",
+            ],
+            IRComponent::LessThan(_) => [
+                "Value {$value} is not less than the values of {$this} for {$lessThan}",
+                "El valor {$value} no es menor que los valores de {$this} para {$lessThan}",
+            ],
+            IRComponent::LessThanOrEquals(_) => [
+                "Value {$value} is greater than the values of {$this} for {$lessThanOrEquals}",
+                "El valor {$value} es mayor que los valores de {$this} para {$lessThanOrEquals}",
+            ],
+            IRComponent::Or(_) => [
+                "Value {$value} does not conform to any of the shapes {$or}",
+                "El valor {$value} no cumple ninguna de las formas {$or}",
+            ],
+            IRComponent::And(_) => [
+                "Value {$value} does not conform to all the shapes {$and}",
+                "El valor {$value} no cumple todas las formas {$and}",
+            ],
+            IRComponent::Not(_) => [
+                "Value {$value} conforms to the shape {$not}",
+                "El valor {$value} cumple la forma {$not}",
+            ],
+            IRComponent::Xone(_) => [
+                "Value {$value} does not conform to exactly one of the shapes {$xone}",
+                "El valor {$value} no cumple exactamente una de las formas {$xone}",
+            ],
+            IRComponent::Node(_) => [
+                "Value {$value} does not conform to the shape {$node}",
+                "El valor {$value} no cumple la forma {$node}",
+            ],
+            IRComponent::HasValue(_) => [
+                "{$this} does not have the value {$hasValue} for {$path}",
+                "{$this} no tiene el valor {$hasValue} para {$path}",
+            ],
+            IRComponent::In(_) => [
+                "Value {$value} is not one of {$in}",
+                "El valor {$value} no es ninguno de {$in}",
+            ],
+            IRComponent::QualifiedValueShape(_) => [
+                "{$this} has a wrong number of values for {$path} that conform to the shape {$qualifiedValueShape}",
+                "{$this} tiene un número incorrecto de valores para {$path} que cumplen la forma {$qualifiedValueShape}",
+            ],
+            IRComponent::Closed(_) => [
+                "{$this} has the value {$value} for {$path}, which the closed shape does not allow",
+                "{$this} tiene el valor {$value} para {$path}, que la forma cerrada no permite",
+            ],
+            IRComponent::Deactivated(_) => ["The shape is deactivated", "La forma está desactivada"],
+            IRComponent::BasicSparql(_) => [
+                "{$this} does not satisfy the SPARQL constraint",
+                "{$this} no cumple la restricción SPARQL",
+            ],
+        };
+        LANGS.iter().zip([en, es]).fold(MessageMap::new(), |msg, (lang, text)| {
+            msg.with_message(Lang::new(*lang).ok(), text.to_string())
+        })
+    }
+
+    /// Values of the parameters of the component that can be used in message
+    /// templates, named as the SHACL properties that set them. Lists of values
+    /// are separated by commas, shapes are written by their identifier and IRIs
+    /// with the prefixes of the shapes graph.
+    pub fn message_parameters(&self, shapes_graph: &IRSchema) -> Vec<(&'static str, String)> {
+        let iri = |iri: &IriS| shapes_graph.prefix_map().qualify(iri);
+        let term = |term: &Object| message_term(term, shapes_graph.prefix_map());
+        let shape = |idx: &ShapeLabelIdx| {
+            shapes_graph
+                .get_shape_from_idx(idx)
+                .map(|shape| term(shape.id()))
+                .unwrap_or_else(|| idx.to_string())
+        };
+        let shapes = |idxs: &[ShapeLabelIdx]| idxs.iter().map(shape).collect::<Vec<_>>().join(", ");
+        match self {
+            IRComponent::Class(c) => vec![("class", term(c.class_rule()))],
+            IRComponent::Datatype(dt) => vec![("datatype", iri(dt.datatype()))],
+            IRComponent::NodeKind(nk) => vec![("nodeKind", nk.node_kind().to_string())],
+            IRComponent::MinCount(mc) => vec![("minCount", mc.min_count().to_string())],
+            IRComponent::MaxCount(mc) => vec![("maxCount", mc.max_count().to_string())],
+            IRComponent::MinExclusive(me) => vec![("minExclusive", me.min_exclusive().lexical_form())],
+            IRComponent::MaxExclusive(me) => vec![("maxExclusive", me.max_exclusive().lexical_form())],
+            IRComponent::MinInclusive(mi) => vec![("minInclusive", mi.min_inclusive().lexical_form())],
+            IRComponent::MaxInclusive(mi) => vec![("maxInclusive", mi.max_inclusive().lexical_form())],
+            IRComponent::MinLength(ml) => vec![("minLength", ml.min_length().to_string())],
+            IRComponent::MaxLength(ml) => vec![("maxLength", ml.max_length().to_string())],
+            IRComponent::Pattern(p) => vec![
+                ("pattern", p.pattern().to_string()),
+                ("flags", p.flags().cloned().unwrap_or_default()),
+            ],
+            IRComponent::UniqueLang(ul) => vec![("uniqueLang", ul.unique_lang().to_string())],
+            IRComponent::LanguageIn(li) => vec![(
+                "languageIn",
+                li.langs().iter().map(|l| l.to_string()).collect::<Vec<_>>().join(", "),
+            )],
+            IRComponent::Equals(eq) => vec![("equals", iri(eq.iri()))],
+            IRComponent::Disjoint(d) => vec![("disjoint", iri(d.iri()))],
+            IRComponent::LessThan(lt) => vec![("lessThan", iri(lt.iri()))],
+            IRComponent::LessThanOrEquals(lte) => vec![("lessThanOrEquals", iri(lte.iri()))],
+            IRComponent::Or(or) => vec![("or", shapes(or.shapes()))],
+            IRComponent::And(and) => vec![("and", shapes(and.shapes()))],
+            IRComponent::Not(not) => vec![("not", shape(not.shape()))],
+            IRComponent::Xone(xone) => vec![("xone", shapes(xone.shapes()))],
+            IRComponent::Node(node) => vec![("node", shape(node.shape()))],
+            IRComponent::HasValue(hv) => vec![("hasValue", term(hv.value()))],
+            IRComponent::In(i) => vec![("in", i.values().iter().map(term).collect::<Vec<_>>().join(", "))],
+            IRComponent::QualifiedValueShape(qvs) => {
+                let count = |count: Option<isize>| count.map(|c| c.to_string()).unwrap_or_default();
+                vec![
+                    ("qualifiedValueShape", shape(qvs.shape())),
+                    ("qualifiedMinCount", count(qvs.qualified_min_count())),
+                    ("qualifiedMaxCount", count(qvs.qualified_max_count())),
+                ]
+            },
+            IRComponent::Closed(closed) => vec![
+                ("closed", closed.is_closed().to_string()),
+                (
+                    "ignoredProperties",
+                    closed
+                        .ignored_properties()
+                        .iter()
+                        .map(iri)
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+            ],
+            IRComponent::Deactivated(d) => vec![("deactivated", d.is_deactivated().to_string())],
+            IRComponent::BasicSparql(_) => Vec::new(),
+        }
+    }
+}
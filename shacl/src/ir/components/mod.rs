@@ -15,6 +15,7 @@ mod max_count;
 mod max_exclusive;
 mod max_inclusive;
 mod max_length;
+mod messages;
 mod min_count;
 mod min_exclusive;
 mod min_inclusive;
@@ -9,7 +9,7 @@ use prefixmap::PrefixMap;
 use rudof_iri::IriS;
 use rudof_rdf::rdf_core::term::Object;
 use rudof_rdf::rdf_core::vocabs::{RdfVocab, RdfVocabulary, ShaclVocab, XsdVocab};
-use rudof_rdf::rdf_core::{BuildRDF, RDFFormat};
+use rudof_rdf::rdf_core::{BuildRDF, RDFFormat, SourceSpan};
 use rudof_rdf::rdf_impl::{OxigraphInMemory, ReaderMode};
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
@@ -28,6 +28,8 @@ pub struct IRSchema {
     base: Option<IriS>,
     dependency_graph: DependencyGraph,
     shape_label_counter: usize,
+    /// Positions of the shapes in the shapes graph, when it was read from a source
+    spans: HashMap<Object, SourceSpan>,
 }
 
 impl IRSchema {
@@ -40,6 +42,7 @@ impl IRSchema {
             base: None,
             dependency_graph: DependencyGraph::new(),
             shape_label_counter: 0,
+            spans: HashMap::new(),
         }
     }
 
@@ -93,6 +96,11 @@ impl IRSchema {
         self.shapes.get(idx)
     }
 
+    /// Position in the shapes graph of the first triple about the shape `sref`
+    pub fn span(&self, sref: &Object) -> Option<&SourceSpan> {
+        self.spans.get(sref)
+    }
+
     /// Returns the `ShapeLabelIdx` for the given shape reference `Object`, if it exists.
     pub fn get_idx(&self, sref: &Object) -> Option<&ShapeLabelIdx> {
         self.labels_idx_map.get(sref)
@@ -126,7 +134,14 @@ impl IRSchema {
     /// Shapes that do not appear in the dependency graph at all (isolated shapes
     /// with no `sh:node`/`sh:and`/etc. references) are treated as level-0 shapes.
     pub(crate) fn shapes_with_targets_by_level(&self) -> Vec<Vec<ShapeLabelIdx>> {
+        self.shapes_by_level(|shape| !shape.targets().is_empty())
+    }
+
+    /// Same as [`IRSchema::shapes_with_targets_by_level`], but with the shapes
+    /// for which `include` returns `true`.
+    pub(crate) fn shapes_by_level(&self, include: impl Fn(&IRShape) -> bool) -> Vec<Vec<ShapeLabelIdx>> {
         let graph_levels = self.dependency_graph.topological_levels();
+        let included = |idx: &ShapeLabelIdx| self.shapes.get(idx).is_some_and(&include);
 
         // Track which indices appear in the dependency graph
         let in_graph: HashSet<ShapeLabelIdx> = graph_levels.iter().flatten().copied().collect();
@@ -137,18 +152,13 @@ impl IRSchema {
             .values()
             .copied()
             .filter(|idx| !in_graph.contains(idx))
-            .filter(|idx| self.shapes.get(idx).is_some_and(|s| !s.targets().is_empty()))
+            .filter(included)
             .collect();
         level0.sort_unstable();
 
-        // Add graph level-0 shapes that have targets
+        // Add graph level-0 shapes that are included
         if let Some(graph_l0) = graph_levels.first() {
-            level0.extend(
-                graph_l0
-                    .iter()
-                    .copied()
-                    .filter(|idx| self.shapes.get(idx).is_some_and(|s| !s.targets().is_empty())),
-            );
+            level0.extend(graph_l0.iter().copied().filter(included));
         }
 
         let mut result: Vec<Vec<ShapeLabelIdx>> = Vec::new();
@@ -158,13 +168,9 @@ impl IRSchema {
 
         // Remaining levels (skip index 0, already handled above)
         for graph_level in graph_levels.iter().skip(1) {
-            let level_with_targets: Vec<ShapeLabelIdx> = graph_level
-                .iter()
-                .copied()
-                .filter(|idx| self.shapes.get(idx).is_some_and(|s| !s.targets().is_empty()))
-                .collect();
-            if !level_with_targets.is_empty() {
-                result.push(level_with_targets);
+            let level_included: Vec<ShapeLabelIdx> = graph_level.iter().copied().filter(included).collect();
+            if !level_included.is_empty() {
+                result.push(level_included);
             }
         }
 
@@ -197,6 +203,9 @@ impl IRSchema {
                 self.idx_labels_map.insert(label_idx, id.clone());
                 let compiled = IRShape::compile(shape, ast, self)?;
                 self.shapes.insert(label_idx, compiled);
+                if let Some(span) = ast.span(id) {
+                    self.spans.insert(id.clone(), span.clone());
+                }
                 Ok(label_idx)
             },
             Some(idx) => Ok(*idx),
@@ -297,9 +306,9 @@ impl IRSchema {
         let mut graph = RDF::empty();
 
         graph.set_prefix_map(self.prefixmap.clone());
-        graph.add_prefix("rdf", RdfVocab::base_iri());
-        graph.add_prefix("xsd", XsdVocab::base_iri());
-        graph.add_prefix("sh", ShaclVocab::base_iri());
+        graph.add_prefix("rdf", &RdfVocab::base_iri());
+        graph.add_prefix("xsd", &XsdVocab::base_iri());
+        graph.add_prefix("sh", &ShaclVocab::base_iri());
 
         graph.add_base(&self.base().cloned());
 
@@ -9,7 +9,7 @@ use rudof_rdf::rdf_core::parser::rdf_node_parser::{ParserExt, RDFNodeParse};
 use rudof_rdf::rdf_core::term::Object;
 use rudof_rdf::rdf_core::term::Triple;
 use rudof_rdf::rdf_core::vocabs::{RdfVocab, ShaclVocab};
-use rudof_rdf::rdf_core::{Any, FocusRDF, Matcher};
+use rudof_rdf::rdf_core::{Any, FocusRDF, Matcher, SourceSpan};
 use std::collections::{HashMap, HashSet};
 
 pub struct ShaclParser<RDF: FocusRDF> {
@@ -30,17 +30,28 @@ impl<RDF: FocusRDF> ShaclParser<RDF> {
 
         let mut state: State = self.shapes_candidates()?.into();
 
+        let mut spans = HashMap::new();
         while let Some(node) = state.pop_pending() {
             if !self.shapes.contains_key(&node) {
                 self.rdf_parser.rdf_mut().set_focus(&node.clone().into());
                 let shape = shape().parse_focused(self.rdf_parser.rdf_mut())?;
+                if let Some(span) = self.span(&node) {
+                    spans.insert(node.clone(), span);
+                }
                 self.shapes.insert(node, shape);
             }
         }
 
-        Ok(
-            ASTSchema::new().with_prefixmap(pm).with_shapes(self.shapes.clone()), // TODO - Maybe avoid the shapes clone
-        )
+        Ok(ASTSchema::new()
+            .with_prefixmap(pm)
+            .with_shapes(self.shapes.clone()) // TODO - Maybe avoid the shapes clone
+            .with_spans(spans))
+    }
+
+    /// Position of the first triple about a shape in the shapes graph, when known
+    fn span(&self, node: &Object) -> Option<SourceSpan> {
+        let subject = RDF::Subject::try_from(node.clone()).ok()?;
+        self.rdf_parser.rdf().subject_span(&subject)
     }
 
     /// Shapes candidates are defined in Appendix A of SHACL spec (Syntax rules)
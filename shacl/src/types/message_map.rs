@@ -1,3 +1,6 @@
+use prefixmap::PrefixMap;
+use rudof_rdf::rdf_core::SHACLPath;
+use rudof_rdf::rdf_core::term::Object;
 use rudof_rdf::rdf_core::term::literal::{ConcreteLiteral, Lang};
 use std::collections::HashMap;
 use std::collections::hash_map::IntoIter;
@@ -45,6 +48,96 @@ impl MessageMap {
         });
         self
     }
+
+    /// Fills the `{$name}` and `{?name}` placeholders of every message with the
+    /// values of the variables. Placeholders of unknown variables are kept as they are
+    pub fn render(&self, vars: &[(&str, String)]) -> Self {
+        let messages = self
+            .messages
+            .iter()
+            .map(|(lang, msg)| (lang.clone(), render_template(msg, vars)))
+            .collect();
+        Self { messages }
+    }
+
+    /// Keeps only the message in the first of `langs` that has one, where a
+    /// language also matches its subtags, so `en` matches `en-GB`. If none of them
+    /// has a message, the untagged one is kept, or else the first by language.
+    /// An empty list keeps all the messages
+    pub fn preferred(self, langs: &[Lang]) -> Self {
+        if langs.is_empty() || self.messages.len() <= 1 {
+            return self;
+        }
+        let matches = |wanted: &Lang, lang: &Lang| {
+            let (wanted, lang) = (wanted.as_str(), lang.as_str());
+            lang.eq_ignore_ascii_case(wanted)
+                || (lang.len() > wanted.len()
+                    && lang[..wanted.len()].eq_ignore_ascii_case(wanted)
+                    && lang.as_bytes()[wanted.len()] == b'-')
+        };
+        let mut entries: Vec<_> = self.messages.into_iter().collect();
+        entries.sort_by(|(lang1, _), (lang2, _)| lang1.cmp(lang2));
+        let best = langs
+            .iter()
+            .find_map(|wanted| {
+                entries
+                    .iter()
+                    .position(|(lang, _)| lang.as_ref().is_some_and(|lang| matches(wanted, lang)))
+            })
+            .unwrap_or(0);
+        let (lang, msg) = entries.swap_remove(best);
+        Self {
+            messages: HashMap::from([(lang, msg)]),
+        }
+    }
+}
+
+/// Text of a term in a message: IRIs are qualified with the prefix map, literals
+/// are written by their lexical form and other terms as usual
+pub(crate) fn message_term(object: &Object, prefixmap: &PrefixMap) -> String {
+    match object {
+        Object::Iri(iri) => prefixmap.qualify(iri),
+        Object::Literal(literal) => literal.lexical_form(),
+        other => other.to_string(),
+    }
+}
+
+/// Text of a path in a message, qualified with the prefix map when it is a predicate
+pub(crate) fn message_path(path: &SHACLPath, prefixmap: &PrefixMap) -> String {
+    match path.pred() {
+        Some(pred) => prefixmap.qualify(pred),
+        None => path.to_string(),
+    }
+}
+
+fn render_template(template: &str, vars: &[(&str, String)]) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let placeholder = &rest[start..];
+        let value = placeholder
+            .strip_prefix("{$")
+            .or_else(|| placeholder.strip_prefix("{?"))
+            .and_then(|name| name.split_once('}'))
+            .and_then(|(name, after)| {
+                vars.iter()
+                    .find(|(var, _)| *var == name)
+                    .map(|(_, value)| (value, after))
+            });
+        match value {
+            Some((value, after)) => {
+                rendered.push_str(value);
+                rest = after;
+            },
+            None => {
+                rendered.push('{');
+                rest = &placeholder[1..];
+            },
+        }
+    }
+    rendered.push_str(rest);
+    rendered
 }
 
 impl IntoIterator for MessageMap {
@@ -98,3 +191,52 @@ impl Display for MessageMap {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::MessageMap;
+    use rudof_rdf::rdf_core::term::literal::Lang;
+
+    fn lang(tag: &str) -> Lang {
+        Lang::new(tag).unwrap()
+    }
+
+    #[test]
+    fn render_fills_placeholders() {
+        let msg = MessageMap::from("{$this} has {?value} for {$path}, expected {$minCount} {$unknown}");
+        let rendered = msg.render(&[
+            ("this", "ex:alice".to_string()),
+            ("value", "42".to_string()),
+            ("path", "ex:age".to_string()),
+            ("minCount", "1".to_string()),
+        ]);
+        assert_eq!(
+            rendered.get(None).unwrap(),
+            "ex:alice has 42 for ex:age, expected 1 {$unknown}"
+        );
+    }
+
+    #[test]
+    fn render_keeps_every_language() {
+        let msg = MessageMap::new()
+            .with_message(Some(lang("en")), "Bad {$value}".to_string())
+            .with_message(Some(lang("es")), "Valor {$value} incorrecto".to_string());
+        let rendered = msg.render(&[("value", "3".to_string())]);
+        assert_eq!(rendered.get(Some(&lang("en"))).unwrap(), "Bad 3");
+        assert_eq!(rendered.get(Some(&lang("es"))).unwrap(), "Valor 3 incorrecto");
+    }
+
+    #[test]
+    fn preferred_picks_first_language_with_a_message() {
+        let msg = MessageMap::new()
+            .with_message(Some(lang("en-GB")), "colour".to_string())
+            .with_message(Some(lang("es")), "color".to_string())
+            .with_message(None, "untagged".to_string());
+        let preferred = msg.clone().preferred(&[lang("fr"), lang("en")]);
+        assert_eq!(preferred.messages().len(), 1);
+        assert_eq!(preferred.get(Some(&lang("en-GB"))).unwrap(), "colour");
+        let fallback = msg.clone().preferred(&[lang("fr")]);
+        assert_eq!(fallback.get(None).unwrap(), "untagged");
+        assert_eq!(msg.clone().preferred(&[]), msg);
+    }
+}
@@ -8,6 +8,7 @@ mod value;
 
 pub use closed_info::ClosedInfo;
 pub use message_map::MessageMap;
+pub(crate) use message_map::{message_path, message_term};
 pub use node_kind::NodeKind;
 pub use severity::Severity;
 pub use shacl_format::ShaclFormat;
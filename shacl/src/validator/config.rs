@@ -1,6 +1,10 @@
+use crate::validator::limits::DEFAULT_DETAIL_DEPTH;
 use rudof_config::TomlConfig;
 use rudof_rdf::rdf_core::RdfDataConfig;
+use rudof_rdf::rdf_core::term::literal::Lang;
+use rudof_rdf::rdf_core::utils::ValidationBudget;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 
 /// This struct can be used to define the configuration of SHACL
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
@@ -8,12 +12,35 @@ use serde::{Deserialize, Serialize};
 pub struct ShaclConfig {
     #[serde(rename = "rdf", skip_serializing)]
     pub(crate) data: RdfDataConfig,
+
+    /// Wall-clock budget for a whole validation run, in milliseconds.
+    /// Focus nodes not validated when it runs out are reported as timeouts.
+    #[serde(rename = "timeout_ms", skip_serializing_if = "Option::is_none")]
+    pub(crate) timeout_ms: Option<u64>,
+
+    /// Wall-clock budget for the validation of each focus node, in milliseconds
+    #[serde(rename = "node_timeout_ms", skip_serializing_if = "Option::is_none")]
+    pub(crate) node_timeout_ms: Option<u64>,
+
+    /// Languages of the result messages, in order of preference. Each result
+    /// keeps only its message in the first of them it has. Empty keeps them all
+    #[serde(rename = "message_languages")]
+    pub(crate) message_languages: Vec<Lang>,
+
+    /// Levels of nested results kept as `sh:detail` of the results of logical
+    /// and shape-based constraints. 0 leaves them out
+    #[serde(rename = "detail_depth")]
+    pub(crate) detail_depth: usize,
 }
 
 impl ShaclConfig {
     pub fn new() -> Self {
         Self {
             data: Self::default_data_config(),
+            timeout_ms: Self::default_timeout_ms(),
+            node_timeout_ms: Self::default_timeout_ms(),
+            message_languages: Self::default_message_languages(),
+            detail_depth: Self::default_detail_depth(),
         }
     }
 
@@ -21,12 +48,55 @@ impl ShaclConfig {
         self.data = data;
         self
     }
+
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout_ms = timeout.map(as_millis);
+        self
+    }
+
+    pub fn with_node_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.node_timeout_ms = timeout.map(as_millis);
+        self
+    }
+
+    pub fn with_message_languages(mut self, langs: Vec<Lang>) -> Self {
+        self.message_languages = langs;
+        self
+    }
+
+    pub fn with_detail_depth(mut self, depth: usize) -> Self {
+        self.detail_depth = depth;
+        self
+    }
 }
 
 impl ShaclConfig {
     pub fn rdf_data(&self) -> &RdfDataConfig {
         &self.data
     }
+
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout_ms.map(Duration::from_millis)
+    }
+
+    pub fn node_timeout(&self) -> Option<Duration> {
+        self.node_timeout_ms.map(Duration::from_millis)
+    }
+
+    pub fn message_languages(&self) -> &[Lang] {
+        &self.message_languages
+    }
+
+    pub fn detail_depth(&self) -> usize {
+        self.detail_depth
+    }
+
+    /// Time limits configured for validation runs, without a cancellation token
+    pub fn budget(&self) -> ValidationBudget {
+        ValidationBudget::new()
+            .with_timeout(self.timeout())
+            .with_node_timeout(self.node_timeout())
+    }
 }
 
 /// Serde stuff
@@ -34,6 +104,13 @@ impl ShaclConfig {
 #[rustfmt::skip]
 impl ShaclConfig {
     #[inline] fn default_data_config() -> RdfDataConfig { RdfDataConfig::default() }
+    #[inline] fn default_timeout_ms() -> Option<u64> { None }
+    #[inline] fn default_message_languages() -> Vec<Lang> { Lang::new("en").into_iter().collect() }
+    #[inline] fn default_detail_depth() -> usize { DEFAULT_DETAIL_DEPTH }
+}
+
+fn as_millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
 }
 
 impl Default for ShaclConfig {
@@ -52,6 +129,23 @@ mod tests {
     #[test]
     fn defaults() {
         assert_eq!(ShaclConfig::default().rdf_data(), &ShaclConfig::default_data_config());
+        assert_eq!(ShaclConfig::default().timeout(), None);
+        assert_eq!(ShaclConfig::default().node_timeout(), None);
+        assert_eq!(
+            ShaclConfig::default().message_languages(),
+            &ShaclConfig::default_message_languages()
+        );
+        assert_eq!(
+            ShaclConfig::default().detail_depth(),
+            ShaclConfig::default_detail_depth()
+        );
+    }
+
+    #[test]
+    fn message_languages_are_read_in_order() {
+        let c = ShaclConfig::from_toml_str(r#"message_languages = ["es", "en"]"#).unwrap();
+        let langs: Vec<_> = c.message_languages().iter().map(|l| l.as_str()).collect();
+        assert_eq!(langs, vec!["es", "en"]);
     }
 
     #[test]
@@ -68,7 +162,7 @@ mod tests {
 
     #[test]
     fn toml_round_trip() {
-        let c = ShaclConfig::default();
+        let c = ShaclConfig::default().with_node_timeout(Some(std::time::Duration::from_millis(250)));
         let s = c.to_toml_string().unwrap();
         let d = ShaclConfig::from_toml_str(&s).unwrap();
         assert_eq!(c, d);
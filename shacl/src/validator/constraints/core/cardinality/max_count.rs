@@ -27,7 +27,6 @@ impl<S: NeighsRDF + Debug> Validator<S> for MaxCount {
             value_nodes,
             FocusNodeIteration,
             |t| t.len() > self.max_count(),
-            &format!("MaxCount({}) not satisfied", self.max_count()),
             maybe_path,
         )
     }
@@ -31,7 +31,6 @@ impl<S: NeighsRDF + Debug> Validator<S> for MinCount {
             value_nodes,
             FocusNodeIteration,
             |t| t.len() < self.min_count(),
-            &format!("MinCount({}) not satisfied", self.min_count()),
             maybe_path,
         )
     }
@@ -1,7 +1,6 @@
 use crate::error::ValidationError;
 use crate::ir::components::Not;
 use crate::ir::{IRComponent, IRSchema, IRShape};
-use crate::types::MessageMap;
 use crate::validator::constraints::Validator;
 use crate::validator::engine::{Engine, Validate};
 use crate::validator::nodes::{FocusNodes, ValueNodes};
@@ -35,15 +34,9 @@ impl<S: NeighsRDF + Debug> Validator<S> for Not {
                     Err(_) => false, // TODO - Should we fail instead of considering it valid?
                 };
                 if is_valid_inside {
-                    let msg = format!(
-                        "Shape: {}. NOT constraint not satisfied for focus node {fnode} and internal shape {}",
-                        shape.id(),
-                        not_shape.id()
-                    );
                     let component = Object::iri(component.into());
                     let node_object = S::term_as_object(node).ok();
                     let vr = ValidationResult::new(fnode_obj.clone(), component.clone(), shape.severity().clone())
-                        .with_message(MessageMap::from(msg))
                         .with_path(maybe_path.cloned())
                         .with_source(Some(shape.id().clone()))
                         .with_value(node_object);
@@ -1,7 +1,6 @@
 use crate::error::ValidationError;
 use crate::ir::components::Or;
 use crate::ir::{IRComponent, IRSchema, IRShape};
-use crate::types::MessageMap;
 use crate::validator::constraints::Validator;
 use crate::validator::engine::{Engine, Validate};
 use crate::validator::nodes::FocusNodes;
@@ -31,22 +30,25 @@ impl<S: NeighsRDF + Debug> Validator<S> for Or {
             for node in nodes.iter() {
                 let focus_nodes = FocusNodes::single(node.clone());
                 let mut conforms = false;
+                let mut details = Vec::new();
                 for idx in self.shapes().iter() {
                     let or_shape = shapes_graph.get_shape_from_idx_e(idx)?;
-                    let inner_results = or_shape.validate(store, engine, Some(&focus_nodes), Some(shape), shapes_graph);
-                    if inner_results?.is_empty() {
+                    let inner_results =
+                        or_shape.validate(store, engine, Some(&focus_nodes), Some(shape), shapes_graph)?;
+                    if inner_results.is_empty() {
                         conforms = true;
                         break;
                     }
+                    details.extend(inner_results);
                 }
                 if !conforms {
                     let node_obj = S::term_as_object(node).ok();
-                    let msg = "OR not satisfied".to_string();
                     let vr = ValidationResult::new(fnode_obj.clone(), component.clone(), shape.severity().clone())
-                        .with_message(MessageMap::from(msg))
                         .with_path(maybe_path.cloned())
                         .with_value(node_obj)
-                        .with_source(Some(shape.id().clone()));
+                        .with_source(Some(shape.id().clone()))
+                        .with_details(details)
+                        .with_detail_depth(engine.detail_depth());
                     validation_results.push(vr);
                 }
             }
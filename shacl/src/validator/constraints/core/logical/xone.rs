@@ -1,7 +1,6 @@
 use crate::error::ValidationError;
 use crate::ir::components::Xone;
 use crate::ir::{IRComponent, IRSchema, IRShape};
-use crate::types::MessageMap;
 use crate::validator::constraints::Validator;
 use crate::validator::engine::{Engine, Validate};
 use crate::validator::nodes::{FocusNodes, ValueNodes};
@@ -30,27 +29,25 @@ impl<S: NeighsRDF + Debug> Validator<S> for Xone {
             for node in nodes.iter() {
                 let focus_nodes = FocusNodes::single(node.clone());
                 let mut conforming_shapes = 0;
+                let mut details = Vec::new();
                 for idx in self.shapes().iter() {
                     let internal_shape = shapes_graph.get_shape_from_idx_e(idx)?;
                     let inner_results =
                         internal_shape.validate(store, engine, Some(&focus_nodes), Some(shape), shapes_graph);
-                    if let Ok(results) = inner_results
-                        && results.is_empty()
-                    {
-                        conforming_shapes += 1;
+                    match inner_results {
+                        Ok(results) if results.is_empty() => conforming_shapes += 1,
+                        Ok(results) => details.extend(results),
+                        Err(_) => {},
                     }
                 }
                 if conforming_shapes != 1 {
                     let node_obj = S::term_as_object(node).ok();
-                    let msg = format!(
-                        "Shape {}: Xone constraint not satisfied for node {node}. Number of conforming shapes: {conforming_shapes}",
-                        shape.id()
-                    );
                     let vr = ValidationResult::new(fnode_obj.clone(), component.clone(), shape.severity().clone())
-                        .with_message(MessageMap::from(msg))
                         .with_path(maybe_path.cloned())
                         .with_value(node_obj)
-                        .with_source(Some(shape.id().clone()));
+                        .with_source(Some(shape.id().clone()))
+                        .with_details(details)
+                        .with_detail_depth(engine.detail_depth());
                     validation_results.push(vr);
                 }
             }
@@ -75,7 +75,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for Clos
         component: &IRComponent,
         shape: &IRShape,
         store: &S,
-        _: &mut dyn Engine<S>,
+        engine: &mut dyn Engine<S>,
         value_nodes: &ValueNodes<S>,
         _: Option<&IRShape>,
         _: Option<&SHACLPath>,
@@ -125,6 +125,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for Clos
                     SELECT ?p ?o WHERE {{ {} ?p ?o .{} }}
                 ", fnode, not_in_clause};
 
+                engine.profiler().record_query();
                 let solutions = store
                     .query_select(&query)
                     .map_err(ValidationError::select_query_error::<S>)?;
@@ -30,7 +30,6 @@ impl<S: NeighsRDF + Debug> Validator<S> for HasValue {
                 let value_term = &S::object_as_term(self.value());
                 !t.iter().any(|v| v == value_term)
             },
-            &format!("HasValue({}) not satisfied", self.value()),
             maybe_path,
         )
     }
@@ -30,7 +30,6 @@ impl<S: NeighsRDF + Debug> Validator<S> for In {
                 let values = self.values().iter().map(S::object_as_term).collect::<Vec<_>>();
                 !values.contains(vn)
             },
-            &format!("In constraint not satisfied. Expected one of {:?}", self.values()),
             maybe_path,
         )
     }
@@ -49,15 +49,7 @@ impl<S: NeighsRDF + Debug + 'static> NativeValidator<S> for Disjoint {
             false
         };
 
-        validate_with_focus(
-            component,
-            shape,
-            value_nodes,
-            ValueNodeIteration,
-            check_fn,
-            &format!("Disjoint failed. Property {}", self.iri()),
-            maybe_path,
-        )
+        validate_with_focus(component, shape, value_nodes, ValueNodeIteration, check_fn, maybe_path)
     }
 }
 
@@ -68,7 +60,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for Disj
         component: &IRComponent,
         shape: &IRShape,
         store: &S,
-        _: &mut dyn Engine<S>,
+        engine: &mut dyn Engine<S>,
         value_nodes: &ValueNodes<S>,
         _: Option<&IRShape>,
         maybe_path: Option<&SHACLPath>,
@@ -85,6 +77,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for Disj
                     ASK {{ {} <{}> {} }}
                 ", fnode, self.iri(), vn};
 
+                engine.profiler().record_query();
                 let ask = store.query_ask(&query).map_err(ValidationError::ask_query_error::<S>)?;
 
                 if ask {
@@ -83,7 +83,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for Equa
         component: &IRComponent,
         shape: &IRShape,
         store: &S,
-        _: &mut dyn Engine<S>,
+        engine: &mut dyn Engine<S>,
         value_nodes: &ValueNodes<S>,
         _: Option<&IRShape>,
         maybe_path: Option<&SHACLPath>,
@@ -97,6 +97,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for Equa
                 SELECT ?o WHERE {{ {} <{}> ?o }}
             ", fnode, self.iri()};
 
+            engine.profiler().record_query();
             let solutions = store
                 .query_select(&query)
                 .map_err(ValidationError::select_query_error::<S>)?;
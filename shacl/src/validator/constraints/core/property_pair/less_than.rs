@@ -43,24 +43,15 @@ impl<S: NeighsRDF + Debug + 'static> NativeValidator<S> for LessThan {
                         let node1 = S::term_as_object(triple.obj())?;
                         for value in nodes.iter() {
                             let node2 = S::term_as_object(value)?;
-                            let msg = match node2.partial_cmp(&node1) {
-                                None => Some(format!(
-                                    "LessThan constraint violated: {node1} is not comparable to {node2}"
-                                )),
-                                Some(ord) if ord.is_ge() => Some(format!(
-                                    "LessThan constraint violated: {node1} is not less than {node2}"
-                                )),
-                                _ => None,
-                            };
-
-                            if let Some(msg) = msg {
+                            // Incomparable values are violations too
+                            let violated = node2.partial_cmp(&node1).is_none_or(|ord| ord.is_ge());
+                            if violated {
                                 let node_obj = S::term_as_object(value).ok();
                                 let vr = ValidationResult::new(
                                     fnode_obj.clone(),
                                     component.clone(),
                                     shape.severity().clone(),
                                 )
-                                .with_message(MessageMap::from(msg))
                                 .with_path(maybe_path.cloned())
                                 .with_source(Some(shape.id().clone()))
                                 .with_value(node_obj);
@@ -94,7 +85,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for Less
         component: &IRComponent,
         shape: &IRShape,
         store: &S,
-        _: &mut dyn Engine<S>,
+        engine: &mut dyn Engine<S>,
         value_nodes: &ValueNodes<S>,
         _: Option<&IRShape>,
         maybe_path: Option<&SHACLPath>,
@@ -118,21 +109,17 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for Less
                     }}
                 ", fnode, self.iri(), vn};
 
+                engine.profiler().record_query();
                 let solutions = store
                     .query_select(&query)
                     .map_err(ValidationError::select_query_error::<S>)?;
 
                 let value = S::term_as_object(vn).ok();
                 for _ in solutions.iter() {
-                    let msg = format!(
-                        "LessThan constraint violated for property {}: value is not strictly less than every comparator",
-                        self.iri()
-                    );
                     let vr = ValidationResult::new(fnode_obj.clone(), component_obj.clone(), shape.severity().clone())
                         .with_source(Some(shape.id().clone()))
                         .with_path(maybe_path.cloned())
-                        .with_value(value.clone())
-                        .with_message(MessageMap::from(msg));
+                        .with_value(value.clone());
                     results.push(vr);
                 }
             }
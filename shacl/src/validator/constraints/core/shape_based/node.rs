@@ -1,9 +1,8 @@
 use crate::error::ValidationError;
 use crate::ir::components::Node;
 use crate::ir::{IRComponent, IRSchema, IRShape};
-use crate::types::MessageMap;
 use crate::validator::constraints::Validator;
-use crate::validator::engine::{Engine, Validate};
+use crate::validator::engine::{Engine, ProfileLabel, Validate};
 use crate::validator::nodes::{FocusNodes, ValueNodes};
 use crate::validator::report::ValidationResult;
 use rudof_rdf::rdf_core::term::Object;
@@ -33,27 +32,22 @@ impl<S: NeighsRDF + Debug> Validator<S> for Node {
                 let node_object = S::term_as_object(node)?;
                 let focus_nodes = FocusNodes::single(node.clone());
 
-                let had_violations = if engine.has_validated(&node_object, *shape_idx) {
+                let inner_results = if engine.has_validated(&node_object, *shape_idx) {
                     engine
-                        .get_cached_results(&node_object, *shape_idx)
-                        .map(|r| !r.is_empty())
-                        .unwrap_or(false)
+                        .profiler()
+                        .record_cache_hit(&ProfileLabel::Shape(node_shape.id(), shapes_graph.prefix_map()));
+                    engine.get_cached_results(&node_object, *shape_idx).unwrap_or_default()
                 } else {
-                    let inner_results =
-                        node_shape.validate(store, engine, Some(&focus_nodes), Some(shape), shapes_graph);
-                    !inner_results?.is_empty()
+                    node_shape.validate(store, engine, Some(&focus_nodes), Some(shape), shapes_graph)?
                 };
 
-                if had_violations {
-                    let msg = format!(
-                        "Shape {}: Node({node_shape}) constraint not satisfied for {node}",
-                        shape.id()
-                    );
+                if !inner_results.is_empty() {
                     let vr = ValidationResult::new(fnode_obj.clone(), component_obj.clone(), shape.severity().clone())
                         .with_path(maybe_path.cloned())
-                        .with_message(MessageMap::from(msg))
                         .with_value(Some(node_object.clone()))
-                        .with_source(Some(shape.id().clone()));
+                        .with_source(Some(shape.id().clone()))
+                        .with_details(inner_results)
+                        .with_detail_depth(engine.detail_depth());
                     validation_results.push(vr);
                 }
             }
@@ -1,7 +1,6 @@
 use crate::error::ValidationError;
 use crate::ir::components::QualifiedValueShape;
 use crate::ir::{IRComponent, IRSchema, IRShape};
-use crate::types::MessageMap;
 use crate::validator::constraints::Validator;
 use crate::validator::engine::{Engine, Validate};
 use crate::validator::nodes::{FocusNodes, ValueNodes};
@@ -30,6 +29,8 @@ impl<S: NeighsRDF + Debug> Validator<S> for QualifiedValueShape {
 
         for (fnode, nodes) in value_nodes.iter() {
             let mut valid_counter = 0;
+            // Results of the values that don't conform to the qualified shape
+            let mut details = Vec::new();
             let fnode_obj = S::term_as_object(fnode)?;
             // Count how many nodes conform to the shape
             for node in nodes.iter() {
@@ -37,7 +38,11 @@ impl<S: NeighsRDF + Debug> Validator<S> for QualifiedValueShape {
                 let qv_shape = shapes_graph.get_shape_from_idx_e(self.shape())?;
                 let inner_results = qv_shape.validate(store, engine, Some(&focus_nodes), Some(shape), shapes_graph);
                 let mut is_valid = match inner_results {
-                    Ok(results) => results.is_empty(),
+                    Ok(results) if results.is_empty() => true,
+                    Ok(results) => {
+                        details.extend(results);
+                        false
+                    },
                     Err(_) => false,
                 };
 
@@ -64,14 +69,11 @@ impl<S: NeighsRDF + Debug> Validator<S> for QualifiedValueShape {
                 && valid_counter < min_count
             {
                 let component = Object::iri(ShaclVocab::sh_qualified_min_count_constraint_component());
-                let msg = format!(
-                    "QualifiedValueShape: only {valid_counter} nodes conform to shape {}, which is less than minCount: {min_count}. Focus node: {fnode}",
-                    shape.id()
-                );
                 let vr = ValidationResult::new(fnode_obj.clone(), component, shape.severity().clone())
-                    .with_message(MessageMap::from(msg))
                     .with_path(maybe_path.cloned())
-                    .with_source(Some(shape.id().clone()));
+                    .with_source(Some(shape.id().clone()))
+                    .with_details(details)
+                    .with_detail_depth(engine.detail_depth());
                 validation_results.insert(vr);
             }
 
@@ -79,13 +81,8 @@ impl<S: NeighsRDF + Debug> Validator<S> for QualifiedValueShape {
                 && valid_counter > max_count
             {
                 let component = Object::iri(ShaclVocab::sh_qualified_max_count_constraint_component());
-                let msg = format!(
-                    "QualifiedValueShape: {valid_counter} nodes conform to shape {}, which is grater than maxCount: {max_count}. Focus node: {fnode}",
-                    shape.id()
-                );
                 let vr = ValidationResult::new(fnode_obj, component, shape.severity().clone())
                     .with_path(maybe_path.cloned())
-                    .with_message(MessageMap::from(msg))
                     .with_source(Some(shape.id().clone()));
                 validation_results.insert(vr);
             }
@@ -42,14 +42,6 @@ impl<S: NeighsRDF + Debug> Validator<S> for LanguageIn {
                 }
                 true
             },
-            &format!(
-                "LanguageIn constraint not satisfied. Expected one of {}",
-                self.langs()
-                    .iter()
-                    .map(|l| l.to_string())
-                    .collect::<Vec<_>>()
-                    .join(", ")
-            ),
             maybe_path,
         )
     }
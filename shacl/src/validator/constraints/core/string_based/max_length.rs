@@ -49,7 +49,6 @@ impl<S: NeighsRDF + Debug + 'static> NativeValidator<S> for MaxLength {
             value_nodes,
             ValueNodeIteration,
             max_length_fn,
-            &format!("MaxLength({}) not satisfied", self.max_length()),
             maybe_path,
         )
     }
@@ -62,7 +61,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for MaxL
         component: &IRComponent,
         shape: &IRShape,
         store: &S,
-        _: &mut dyn Engine<S>,
+        engine: &mut dyn Engine<S>,
         value_nodes: &ValueNodes<S>,
         _: Option<&IRShape>,
         maybe_path: Option<&SHACLPath>,
@@ -84,9 +83,9 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for MaxL
             component,
             shape,
             store,
+            engine.profiler(),
             value_nodes,
             query_fn,
-            &format!("MaxLength({max}) not satisfied"),
             maybe_path,
         )
     }
@@ -49,7 +49,6 @@ impl<S: NeighsRDF + Debug + 'static> NativeValidator<S> for MinLength {
             value_nodes,
             ValueNodeIteration,
             min_length_fn,
-            &format!("MinLength({}) not satisfied", self.min_length()),
             maybe_path,
         )
     }
@@ -62,7 +61,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for MinL
         component: &IRComponent,
         shape: &IRShape,
         store: &S,
-        _: &mut dyn Engine<S>,
+        engine: &mut dyn Engine<S>,
         value_nodes: &ValueNodes<S>,
         _: Option<&IRShape>,
         maybe_path: Option<&SHACLPath>,
@@ -84,9 +83,9 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for MinL
             component,
             shape,
             store,
+            engine.profiler(),
             value_nodes,
             query_fn,
-            &format!("MinLength({min}) not satisfied"),
             maybe_path,
         )
     }
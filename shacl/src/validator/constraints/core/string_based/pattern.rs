@@ -43,7 +43,6 @@ impl<S: NeighsRDF + Debug + 'static> NativeValidator<S> for Pattern {
                     !self.match_str(vn.lexical_form().as_str())
                 }
             },
-            &format!("Pattern({}) not satisfied", self.pattern()),
             maybe_path,
         )
     }
@@ -56,7 +55,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for Patt
         component: &IRComponent,
         shape: &IRShape,
         store: &S,
-        _: &mut dyn Engine<S>,
+        engine: &mut dyn Engine<S>,
         value_nodes: &ValueNodes<S>,
         _: Option<&IRShape>,
         maybe_path: Option<&SHACLPath>,
@@ -82,9 +81,9 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for Patt
             component,
             shape,
             store,
+            engine.profiler(),
             value_nodes,
             query_fn,
-            &format!("Pattern({}) not satisfied", self.pattern()),
             maybe_path,
         )
     }
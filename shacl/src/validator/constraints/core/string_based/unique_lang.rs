@@ -1,7 +1,6 @@
 use crate::error::ValidationError;
 use crate::ir::components::UniqueLang;
 use crate::ir::{IRComponent, IRSchema, IRShape};
-use crate::types::MessageMap;
 use crate::validator::constraints::Validator;
 use crate::validator::engine::Engine;
 use crate::validator::nodes::ValueNodes;
@@ -44,16 +43,11 @@ impl<S: NeighsRDF + Debug> Validator<S> for UniqueLang {
                 }
             }
 
-            for (k, v) in langs_map {
+            for v in langs_map.values() {
                 if v.len() > 1 {
                     // If there are multiple nodes with the same language, report a violation
-                    let msg = format!(
-                        "Unique lang failed for lang {k} with values: {}",
-                        v.iter().map(|n| n.to_string()).collect::<Vec<_>>().join(", ")
-                    );
                     let vr = ValidationResult::new(fnode_obj.clone(), component.clone(), shape.severity().clone())
                         .with_path(maybe_path.cloned())
-                        .with_message(MessageMap::from(msg))
                         .with_source(Some(shape.id().clone()));
                     validation_results.push(vr);
                 }
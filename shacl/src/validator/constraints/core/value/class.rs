@@ -2,7 +2,6 @@ use crate::error::ValidationError;
 use crate::ir::components::Class;
 use crate::ir::{IRComponent, IRSchema, IRShape};
 #[cfg(feature = "sparql")]
-use crate::types::MessageMap;
 use crate::validator::constraints::{NativeValidator, validate_with};
 use crate::validator::engine::Engine;
 use crate::validator::iteration::ValueNodeIteration;
@@ -54,15 +53,7 @@ impl<S: NeighsRDF + 'static> NativeValidator<S> for Class {
                 })
         };
 
-        validate_with(
-            component,
-            shape,
-            value_nodes,
-            ValueNodeIteration,
-            class_fn,
-            &format!("Class constraint not satisfied for class {}", self.class_rule()),
-            maybe_path,
-        )
+        validate_with(component, shape, value_nodes, ValueNodeIteration, class_fn, maybe_path)
     }
 }
 
@@ -73,7 +64,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for Clas
         component: &IRComponent,
         shape: &IRShape,
         store: &S,
-        _: &mut dyn Engine<S>,
+        engine: &mut dyn Engine<S>,
         value_nodes: &ValueNodes<S>,
         _: Option<&IRShape>,
         maybe_path: Option<&SHACLPath>,
@@ -85,7 +76,6 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for Clas
         };
         let class_term: S::Term = self.class_rule().clone().into();
         let component_obj = Object::iri(component.into());
-        let msg = format!("Class constraint not satisfied for class {}", self.class_rule());
         let mut results = Vec::new();
 
         for (focus, vns) in value_nodes.iter() {
@@ -102,6 +92,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for Clas
                         PREFIX rdfs: <http://www.w3.org/2000/01/rdf-schema#>
                         ASK {{ {vn_sparql} rdf:type/rdfs:subClassOf* {class_sparql} }}
                     "};
+                    engine.profiler().record_query();
                     store.query_ask(&query).map_err(ValidationError::ask_query_error::<S>)?
                 };
 
@@ -109,7 +100,6 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for Clas
                     let value = S::term_as_object(vn).ok();
                     let vr = ValidationResult::new(focus_obj.clone(), component_obj.clone(), shape.severity().clone())
                         .with_source(Some(shape.id().clone()))
-                        .with_message(MessageMap::from(msg.as_str()))
                         .with_path(maybe_path.cloned())
                         .with_value(value);
                     results.push(vr);
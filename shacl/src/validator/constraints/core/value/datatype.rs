@@ -20,7 +20,7 @@ impl<S: NeighsRDF + Debug> Validator<S> for Datatype {
         value_nodes: &ValueNodes<S>,
         _: Option<&IRShape>,
         maybe_path: Option<&SHACLPath>,
-        shapes_graph: &IRSchema,
+        _: &IRSchema,
     ) -> Result<Vec<ValidationResult>, ValidationError> {
         validate_with(
             component,
@@ -38,10 +38,6 @@ impl<S: NeighsRDF + Debug> Validator<S> for Datatype {
                     true
                 }
             },
-            &format!(
-                "Expected Datatype: {}",
-                shapes_graph.prefix_map().qualify(self.datatype())
-            ),
             maybe_path,
         )
     }
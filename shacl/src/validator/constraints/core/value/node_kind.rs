@@ -50,18 +50,7 @@ impl<S: NeighsRDF + Debug + 'static> NativeValidator<S> for Nodekind {
             .not()
         };
 
-        validate_with(
-            component,
-            shape,
-            value_nodes,
-            ValueNodeIteration,
-            nk_fn,
-            &format!(
-                "NodeKind constraint not satisfied. Expected node kind: {}",
-                self.node_kind()
-            ),
-            maybe_path,
-        )
+        validate_with(component, shape, value_nodes, ValueNodeIteration, nk_fn, maybe_path)
     }
 }
 
@@ -72,7 +61,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for Node
         component: &IRComponent,
         shape: &IRShape,
         store: &S,
-        _: &mut dyn Engine<S>,
+        engine: &mut dyn Engine<S>,
         value_nodes: &ValueNodes<S>,
         _: Option<&IRShape>,
         maybe_path: Option<&SHACLPath>,
@@ -121,12 +110,9 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for Node
             component,
             shape,
             store,
+            engine.profiler(),
             value_nodes,
             query_fn,
-            &format!(
-                "NodeKind constraint not satisfied. Expected node kind: {}",
-                self.node_kind()
-            ),
             maybe_path,
         )
     }
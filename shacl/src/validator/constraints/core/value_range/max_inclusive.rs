@@ -39,7 +39,6 @@ impl<S: NeighsRDF + Debug + 'static> NativeValidator<S> for MaxInclusive {
                 Ok(lit) => lit.partial_cmp(self.max_inclusive()).map(|o| o.is_gt()).unwrap_or(true),
                 Err(_) => true,
             },
-            &format!("MaxInclusive({}) not satisfied", self.max_inclusive()),
             maybe_path,
         )
     }
@@ -52,7 +51,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for MaxI
         component: &IRComponent,
         shape: &IRShape,
         store: &S,
-        _: &mut dyn Engine<S>,
+        engine: &mut dyn Engine<S>,
         value_nodes: &ValueNodes<S>,
         _: Option<&IRShape>,
         maybe_path: Option<&SHACLPath>,
@@ -77,9 +76,9 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for MaxI
             component,
             shape,
             store,
+            engine.profiler(),
             value_nodes,
             query_fn,
-            &format!("MaxInclusive({}) not satisfied", self.max_inclusive()),
             maybe_path,
         )
     }
@@ -39,7 +39,6 @@ impl<S: NeighsRDF + Debug + 'static> NativeValidator<S> for MinInclusive {
                 Ok(lit) => lit.partial_cmp(self.min_inclusive()).map(|o| o.is_lt()).unwrap_or(true),
                 Err(_) => true,
             },
-            &format!("MinInclusive({}) not satisfied", self.min_inclusive()),
             maybe_path,
         )
     }
@@ -52,7 +51,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for MinI
         component: &IRComponent,
         shape: &IRShape,
         store: &S,
-        _: &mut dyn Engine<S>,
+        engine: &mut dyn Engine<S>,
         value_nodes: &ValueNodes<S>,
         _: Option<&IRShape>,
         maybe_path: Option<&SHACLPath>,
@@ -77,9 +76,9 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<S> for MinI
             component,
             shape,
             store,
+            engine.profiler(),
             value_nodes,
             query_fn,
-            &format!("MinInclusive({}) not satisfied", self.min_inclusive()),
             maybe_path,
         )
     }
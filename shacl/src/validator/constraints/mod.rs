@@ -9,7 +9,6 @@ use crate::ir::components::{
     UniqueLang, Xone,
 };
 use crate::ir::{IRComponent, IRSchema, IRShape};
-use crate::types::MessageMap;
 use crate::validator::engine::Engine;
 use crate::validator::iteration::IterationStrategy;
 #[cfg(feature = "sparql")]
@@ -19,6 +18,8 @@ use crate::validator::report::ValidationResult;
 #[cfg(feature = "sparql")]
 use rudof_rdf::rdf_core::query::QueryRDF;
 use rudof_rdf::rdf_core::term::Object;
+#[cfg(feature = "sparql")]
+use rudof_rdf::rdf_core::utils::Profiler;
 use rudof_rdf::rdf_core::{NeighsRDF, Rdf, SHACLPath};
 use std::fmt::Debug;
 use std::marker::PhantomData;
@@ -251,7 +252,6 @@ fn apply<S: Rdf, I: IterationStrategy<S>>(
     value_nodes: &ValueNodes<S>,
     strategy: I,
     evaluator: impl Fn(&I::Item) -> Result<bool, ValidationError>,
-    msg: &str,
     maybe_path: Option<&SHACLPath>,
 ) -> Result<Vec<ValidationResult>, ValidationError> {
     let results = strategy
@@ -262,17 +262,12 @@ fn apply<S: Rdf, I: IterationStrategy<S>>(
             let shape_id = shape.id();
             let source = Some(shape_id);
             let value = strategy.to_object(item);
-            let mut msg = MessageMap::from(msg);
-            if let Some(m) = shape.message() {
-                msg = msg.merge(m.to_owned(), true);
-            }
             if let Ok(condition) = evaluator(item)
                 && condition
             {
                 return Some(
                     ValidationResult::new(focus, component, shape.severity().clone())
                         .with_source(source.cloned())
-                        .with_message(msg)
                         .with_path(maybe_path.cloned())
                         .with_value(value),
                 );
@@ -290,7 +285,6 @@ fn apply_with_focus<S: Rdf, I: IterationStrategy<S>>(
     value_nodes: &ValueNodes<S>,
     strategy: I,
     evaluator: impl Fn(&S::Term, &I::Item) -> Result<bool, ValidationError>,
-    msg: &str,
     maybe_path: Option<&SHACLPath>,
 ) -> Result<Vec<ValidationResult>, ValidationError> {
     let results = strategy
@@ -305,7 +299,6 @@ fn apply_with_focus<S: Rdf, I: IterationStrategy<S>>(
                 Ok(true) => Some(
                     ValidationResult::new(focus, component, shape.severity().clone())
                         .with_source(source.cloned())
-                        .with_message(MessageMap::from(msg))
                         .with_path(maybe_path.cloned())
                         .with_value(value),
                 ),
@@ -325,7 +318,6 @@ fn validate_with<S: Rdf, I: IterationStrategy<S>>(
     value_nodes: &ValueNodes<S>,
     strategy: I,
     evaluator: impl Fn(&I::Item) -> bool,
-    msg: &str,
     maybe_path: Option<&SHACLPath>,
 ) -> Result<Vec<ValidationResult>, ValidationError> {
     apply(
@@ -334,7 +326,6 @@ fn validate_with<S: Rdf, I: IterationStrategy<S>>(
         value_nodes,
         strategy,
         |item| Ok(evaluator(item)),
-        msg,
         maybe_path,
     )
 }
@@ -346,7 +337,6 @@ fn validate_with_focus<S: Rdf, I: IterationStrategy<S>>(
     value_nodes: &ValueNodes<S>,
     strategy: I,
     evaluator: impl Fn(&S::Term, &I::Item) -> bool,
-    msg: &str,
     maybe_path: Option<&SHACLPath>,
 ) -> Result<Vec<ValidationResult>, ValidationError> {
     apply_with_focus(
@@ -355,7 +345,6 @@ fn validate_with_focus<S: Rdf, I: IterationStrategy<S>>(
         value_nodes,
         strategy,
         |f, i| Ok(evaluator(f, i)),
-        msg,
         maybe_path,
     )
 }
@@ -370,9 +359,9 @@ fn validate_ask_with_opt<S: QueryRDF>(
     component: &IRComponent,
     shape: &IRShape,
     store: &S,
+    profiler: &Profiler,
     value_nodes: &ValueNodes<S>,
     eval_query: impl Fn(&S::Term) -> Option<String>,
-    msg: &str,
     maybe_path: Option<&SHACLPath>,
 ) -> Result<Vec<ValidationResult>, ValidationError> {
     apply(
@@ -382,12 +371,14 @@ fn validate_ask_with_opt<S: QueryRDF>(
         ValueNodeIteration,
         |vn| match eval_query(vn) {
             None => Ok(false),
-            Some(query) => match store.query_ask(&query) {
-                Ok(ask) => Ok(!ask),
-                Err(err) => Err(ValidationError::ask_query_error::<S>(err)),
+            Some(query) => {
+                profiler.record_query();
+                match store.query_ask(&query) {
+                    Ok(ask) => Ok(!ask),
+                    Err(err) => Err(ValidationError::ask_query_error::<S>(err)),
+                }
             },
         },
-        msg,
         maybe_path,
     )
 }
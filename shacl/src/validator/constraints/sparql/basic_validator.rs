@@ -2,7 +2,7 @@ use crate::error::ValidationError;
 use crate::ir::components::BasicSparql;
 use crate::ir::{IRComponent, IRSchema, IRShape};
 #[cfg(feature = "sparql")]
-use crate::types::MessageMap;
+use crate::types::{MessageMap, message_term};
 #[cfg(feature = "sparql")]
 use crate::validator::constraints::BasicSparqlValidator;
 use crate::validator::constraints::NativeValidator;
@@ -44,11 +44,11 @@ impl<RDF: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<RDF> for
         component: &IRComponent,
         shape: &IRShape,
         store: &RDF,
-        _: &mut dyn Engine<RDF>,
+        engine: &mut dyn Engine<RDF>,
         value_nodes: &ValueNodes<RDF>,
         _: Option<&IRShape>,
         maybe_path: Option<&SHACLPath>,
-        _: &IRSchema,
+        shapes_graph: &IRSchema,
     ) -> Result<Vec<ValidationResult>, ValidationError> {
         if self.deactivated() == Some(true) {
             return Ok(Vec::new());
@@ -76,6 +76,7 @@ impl<RDF: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<RDF> for
             let query_body = inject_values_into_where(&select_with_path, &values_clause);
             let full_query = format!("{}{}", prefix_header, query_body);
 
+            engine.profiler().record_query();
             let solutions = store
                 .query_select(&full_query)
                 .map_err(ValidationError::select_query_error::<RDF>)?;
@@ -116,20 +117,32 @@ impl<RDF: QueryRDF + NeighsRDF + Debug + 'static> BasicSparqlValidator<RDF> for
                     .and_then(|t| RDF::term_as_object(t).ok())
                     .or_else(|| RDF::term_as_object(focus_node).ok());
 
-                // sh:resultMessage: prefer ?message binding, then self.message
-                let message = if let Some(msg_term) = sol.find_solution("message") {
-                    MessageMap::from(format!("{msg_term}"))
-                } else {
-                    self.message().cloned().unwrap_or_default()
-                };
-
-                results.push(
+                let result =
                     ValidationResult::new(result_focus, constraint_component.clone(), shape.severity().clone())
                         .with_source(Some(shape.id().clone()))
                         .with_path(result_path)
-                        .with_value(value)
-                        .with_message(message),
-                );
+                        .with_value(value);
+
+                // sh:resultMessage: prefer ?message binding, then the sh:message of the constraint,
+                // of the shape or the default one, filled with the bindings of the solution
+                let result = if let Some(msg_term) = sol.find_solution("message") {
+                    result.with_message(MessageMap::from(format!("{msg_term}")))
+                } else {
+                    let template = self
+                        .message()
+                        .or(shape.message())
+                        .cloned()
+                        .unwrap_or_else(|| component.default_message());
+                    let bindings: Vec<_> = sol
+                        .variables_iter()
+                        .filter_map(|var| {
+                            let term = RDF::term_as_object(sol.find_solution(var)?).ok()?;
+                            Some((var.as_str(), message_term(&term, shapes_graph.prefix_map())))
+                        })
+                        .collect();
+                    result.with_message_template(&template, &bindings, shapes_graph.prefix_map())
+                };
+                results.push(result);
             }
         }
 
@@ -7,11 +7,14 @@ mod value_nodes_ops;
 
 use crate::ir::{IRComponent, IRPropertyShape, IRSchema, IRShape, ShapeLabelIdx};
 use crate::types::Target;
+use prefixmap::PrefixMap;
 use rudof_iri::IriS;
 use rudof_rdf::rdf_core::term::Object;
+use rudof_rdf::rdf_core::utils::{CancellationToken, Profiler};
 use rudof_rdf::rdf_core::{NeighsRDF, SHACLPath};
 #[cfg(feature = "sparql")]
 use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
 
 use crate::error::ValidationError;
 use crate::validator::nodes::{FocusNodes, ValueNodes};
@@ -37,6 +40,26 @@ pub trait Engine<S: NeighsRDF>: Send {
     /// engines without rebuilding expensive indexes for every thread.
     fn fork(&self) -> Box<dyn Engine<S>>;
 
+    /// Token checked between the steps of a validation, so that long
+    /// validations can be stopped when they run out of time or are cancelled.
+    fn cancellation(&self) -> &CancellationToken;
+
+    /// Replaces the token checked by this engine. Forks inherit it.
+    fn set_cancellation(&mut self, token: CancellationToken);
+
+    /// Levels of nested results kept as `sh:detail` by the logical and
+    /// shape-based constraints
+    fn detail_depth(&self) -> usize;
+
+    /// Replaces the levels of nested results kept. Forks inherit it.
+    fn set_detail_depth(&mut self, depth: usize);
+
+    /// Profiler recording the shapes and constraints evaluated by this engine
+    fn profiler(&self) -> &Profiler;
+
+    /// Replaces the profiler of this engine. Forks record to the same profile.
+    fn set_profiler(&mut self, profiler: Profiler);
+
     fn evaluate(
         &mut self,
         store: &S,
@@ -99,6 +122,24 @@ pub trait Engine<S: NeighsRDF>: Send {
     fn get_cached_results(&self, node: &Object, shape_idx: ShapeLabelIdx) -> Option<Vec<ValidationResult>>;
 }
 
+/// Shape or constraint component shown with the prefixes of the shapes graph
+/// in profiles. It is only rendered when the profiler is enabled.
+pub(crate) enum ProfileLabel<'a> {
+    Shape(&'a Object, &'a PrefixMap),
+    Component(&'a IRComponent, &'a PrefixMap),
+}
+
+impl Display for ProfileLabel<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProfileLabel::Shape(shape, prefix_map) => write!(f, "{}", prefix_map.show(*shape)),
+            ProfileLabel::Component(component, prefix_map) => {
+                write!(f, "{}", prefix_map.show(&Object::iri((*component).into())))
+            },
+        }
+    }
+}
+
 #[cfg(feature = "sparql")]
 fn select<S: QueryRDF>(store: &S, query: &str, index: &str) -> Result<HashSet<S::Term>, ValidationError> {
     let mut out = HashSet::new();
@@ -4,10 +4,12 @@ use crate::validator::cache::{SharedValidationCache, ValidationCache};
 use crate::validator::constraints::{NativeValidator, ShaclComponent, ValidatorDeref};
 use crate::validator::engine::Engine;
 use crate::validator::index::ClassIndex;
+use crate::validator::limits::DEFAULT_DETAIL_DEPTH;
 use crate::validator::nodes::{FocusNodes, ValueNodes};
 use crate::validator::report::ValidationResult;
 use rudof_iri::IriS;
 use rudof_rdf::rdf_core::term::{Object, Term, Triple};
+use rudof_rdf::rdf_core::utils::{CancellationToken, Profiler};
 use rudof_rdf::rdf_core::vocabs::{RdfVocab, RdfsVocab};
 use rudof_rdf::rdf_core::{NeighsRDF, SHACLPath};
 use std::fmt::Debug;
@@ -23,6 +25,12 @@ pub struct NativeEngine {
     cache: SharedValidationCache,
     /// Pre-built inverted index mapping classes to their instances and subclasses.
     class_index: Option<Arc<ClassIndex>>,
+    /// Token checked between the steps of a validation.
+    cancellation: CancellationToken,
+    /// Levels of nested results kept as `sh:detail`.
+    detail_depth: usize,
+    /// Profiler recording the shapes and constraints evaluated.
+    profiler: Profiler,
 }
 
 impl NativeEngine {
@@ -30,6 +38,9 @@ impl NativeEngine {
         Self {
             cache: SharedValidationCache::new(),
             class_index: None,
+            cancellation: CancellationToken::new(),
+            detail_depth: DEFAULT_DETAIL_DEPTH,
+            profiler: Profiler::disabled(),
         }
     }
 
@@ -134,9 +145,36 @@ impl<RDF: NeighsRDF + Debug + 'static> Engine<RDF> for NativeEngine {
         Box::new(NativeEngine {
             cache: self.cache.clone(),
             class_index: self.class_index.clone(),
+            cancellation: self.cancellation.clone(),
+            detail_depth: self.detail_depth,
+            profiler: self.profiler.clone(),
         })
     }
 
+    fn cancellation(&self) -> &CancellationToken {
+        &self.cancellation
+    }
+
+    fn set_cancellation(&mut self, token: CancellationToken) {
+        self.cancellation = token;
+    }
+
+    fn detail_depth(&self) -> usize {
+        self.detail_depth
+    }
+
+    fn set_detail_depth(&mut self, depth: usize) {
+        self.detail_depth = depth;
+    }
+
+    fn profiler(&self) -> &Profiler {
+        &self.profiler
+    }
+
+    fn set_profiler(&mut self, profiler: Profiler) {
+        self.profiler = profiler;
+    }
+
     fn evaluate(
         &mut self,
         store: &RDF,
@@ -204,9 +242,36 @@ impl<RDF: NeighsRDF + QueryRDF + Debug + 'static> Engine<RDF> for NativeEngine {
         Box::new(NativeEngine {
             cache: self.cache.clone(),
             class_index: self.class_index.clone(),
+            cancellation: self.cancellation.clone(),
+            detail_depth: self.detail_depth,
+            profiler: self.profiler.clone(),
         })
     }
 
+    fn cancellation(&self) -> &CancellationToken {
+        &self.cancellation
+    }
+
+    fn set_cancellation(&mut self, token: CancellationToken) {
+        self.cancellation = token;
+    }
+
+    fn detail_depth(&self) -> usize {
+        self.detail_depth
+    }
+
+    fn set_detail_depth(&mut self, depth: usize) {
+        self.detail_depth = depth;
+    }
+
+    fn profiler(&self) -> &Profiler {
+        &self.profiler
+    }
+
+    fn set_profiler(&mut self, profiler: Profiler) {
+        self.profiler = profiler;
+    }
+
     fn evaluate(
         &mut self,
         store: &RDF,
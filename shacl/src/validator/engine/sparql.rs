@@ -3,23 +3,31 @@ use crate::ir::{IRComponent, IRSchema, IRShape, ShapeLabelIdx};
 use crate::validator::cache::{SharedValidationCache, ValidationCache};
 use crate::validator::constraints::{BasicSparqlValidator, ShaclComponent, ValidatorDeref, object_as_sparql};
 use crate::validator::engine::{Engine, select};
+use crate::validator::limits::DEFAULT_DETAIL_DEPTH;
 use crate::validator::nodes::{FocusNodes, ValueNodes};
 use crate::validator::report::ValidationResult;
 use indoc::formatdoc;
 use rudof_iri::IriS;
 use rudof_rdf::rdf_core::query::QueryRDF;
 use rudof_rdf::rdf_core::term::{Object, Term};
+use rudof_rdf::rdf_core::utils::{CancellationToken, Profiler};
 use rudof_rdf::rdf_core::{NeighsRDF, SHACLPath};
 use std::fmt::Debug;
 
 pub struct SparqlEngine {
     cache: SharedValidationCache,
+    cancellation: CancellationToken,
+    detail_depth: usize,
+    profiler: Profiler,
 }
 
 impl SparqlEngine {
     pub fn new() -> Self {
         Self {
             cache: SharedValidationCache::new(),
+            cancellation: CancellationToken::new(),
+            detail_depth: DEFAULT_DETAIL_DEPTH,
+            profiler: Profiler::disabled(),
         }
     }
 }
@@ -78,6 +86,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> Engine<S> for SparqlEngine {
             }}
         "};
 
+        self.profiler.record_query();
         let results = select(store, &query, "this")?;
         Ok(FocusNodes::new(results))
     }
@@ -90,6 +99,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> Engine<S> for SparqlEngine {
             }}
         ", predicate};
 
+        self.profiler.record_query();
         let results = select(store, &query, "this")?;
         Ok(FocusNodes::new(results))
     }
@@ -102,6 +112,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> Engine<S> for SparqlEngine {
             }}
         ", predicate};
 
+        self.profiler.record_query();
         let results = select(store, &query, "this")?;
         Ok(FocusNodes::new(results))
     }
@@ -119,6 +130,7 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> Engine<S> for SparqlEngine {
             }}
         "};
 
+        self.profiler.record_query();
         let results = select(store, &query, "this")?;
         Ok(FocusNodes::new(results))
     }
@@ -138,8 +150,35 @@ impl<S: QueryRDF + NeighsRDF + Debug + 'static> Engine<S> for SparqlEngine {
     fn fork(&self) -> Box<dyn Engine<S>> {
         Box::new(SparqlEngine {
             cache: self.cache.clone(),
+            cancellation: self.cancellation.clone(),
+            detail_depth: self.detail_depth,
+            profiler: self.profiler.clone(),
         })
     }
+
+    fn cancellation(&self) -> &CancellationToken {
+        &self.cancellation
+    }
+
+    fn set_cancellation(&mut self, token: CancellationToken) {
+        self.cancellation = token;
+    }
+
+    fn detail_depth(&self) -> usize {
+        self.detail_depth
+    }
+
+    fn set_detail_depth(&mut self, depth: usize) {
+        self.detail_depth = depth;
+    }
+
+    fn profiler(&self) -> &Profiler {
+        &self.profiler
+    }
+
+    fn set_profiler(&mut self, profiler: Profiler) {
+        self.profiler = profiler;
+    }
 }
 
 impl Default for SparqlEngine {
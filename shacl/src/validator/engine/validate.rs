@@ -1,9 +1,9 @@
 use crate::error::ValidationError;
-use crate::ir::{IRSchema, IRShape, ReifierInfo};
+use crate::ir::{IRComponent, IRSchema, IRShape, ReifierInfo};
 use crate::types::MessageMap;
-use crate::validator::engine::Engine;
 use crate::validator::engine::focus_nodes_ops::FocusNodesOps;
 use crate::validator::engine::value_nodes_ops::ValueNodesOps;
+use crate::validator::engine::{Engine, ProfileLabel};
 use crate::validator::nodes::FocusNodes;
 use crate::validator::report::ValidationResult;
 use rudof_rdf::rdf_core::term::Object;
@@ -38,6 +38,11 @@ impl<RDF: NeighsRDF + Debug> Validate<RDF> for IRShape {
             return Ok(Vec::new());
         }
 
+        let prefix_map = shapes_graph.prefix_map();
+        let _shape_span = runner
+            .profiler()
+            .span(&ProfileLabel::Shape(self.id(), prefix_map), None);
+
         // Get focus nodes
         let focus_nodes = match targets {
             None => &self.focus_nodes(store, runner),
@@ -57,6 +62,9 @@ impl<RDF: NeighsRDF + Debug> Validate<RDF> for IRShape {
                 if let Ok(ref obj) = node_object
                     && let Some(results) = runner.get_cached_results(obj, *idx)
                 {
+                    runner
+                        .profiler()
+                        .record_cache_hit(&ProfileLabel::Shape(self.id(), prefix_map));
                     cached_results.extend(results);
                     continue;
                 }
@@ -81,6 +89,11 @@ impl<RDF: NeighsRDF + Debug> Validate<RDF> for IRShape {
         // 3. Check each of the components
         let mut component_validation_results = Vec::new();
         for component in components.iter() {
+            runner.cancellation().check()?;
+            let _component_span = runner.profiler().span(
+                &ProfileLabel::Shape(self.id(), prefix_map),
+                Some(&ProfileLabel::Component(component, prefix_map)),
+            );
             let results = runner.evaluate(
                 store,
                 self,
@@ -90,7 +103,7 @@ impl<RDF: NeighsRDF + Debug> Validate<RDF> for IRShape {
                 self.path(),
                 shapes_graph,
             )?;
-            component_validation_results.extend(results);
+            component_validation_results.extend(render_messages(results, self, component, shapes_graph));
         }
 
         // After validating the constraints of the current shape, validate any nested
@@ -112,6 +125,7 @@ impl<RDF: NeighsRDF + Debug> Validate<RDF> for IRShape {
         for ps in self.property_shapes().iter() {
             let shape = shapes_graph.get_shape_from_idx_e(ps)?;
             for (_, vn) in value_nodes.iter() {
+                runner.cancellation().check()?;
                 let results = shape.validate(store, runner, Some(vn), Some(self), shapes_graph)?;
                 property_shapes_validation_results.extend(results);
             }
@@ -138,6 +152,10 @@ impl<RDF: NeighsRDF + Debug> Validate<RDF> for IRShape {
             .chain(reification_results)
             .collect();
 
+        // Some constraints (e.g. sh:not) treat errors of nested shapes as
+        // results, so an interruption must be detected before caching anything.
+        runner.cancellation().check()?;
+
         // Record new results in the cache per focus node
         if let Some(idx) = idx {
             // Group results by focus node in O(M), then record each in O(1)
@@ -163,6 +181,29 @@ impl<RDF: NeighsRDF + Debug> Validate<RDF> for IRShape {
     }
 }
 
+/// Gives the results of a component the `sh:message` of the shape, or else the
+/// default message of the component, with its placeholders filled. Results that
+/// already have a message, like those of SPARQL constraints, keep it
+fn render_messages(
+    results: Vec<ValidationResult>,
+    shape: &IRShape,
+    component: &IRComponent,
+    shapes_graph: &IRSchema,
+) -> Vec<ValidationResult> {
+    let template = shape.message().cloned().unwrap_or_else(|| component.default_message());
+    let parameters = component.message_parameters(shapes_graph);
+    results
+        .into_iter()
+        .map(|result| {
+            if result.message().messages().is_empty() {
+                result.with_message_template(&template, &parameters, shapes_graph.prefix_map())
+            } else {
+                result
+            }
+        })
+        .collect()
+}
+
 fn validate_reifiers<RDF: NeighsRDF + Debug>(
     shape: &IRShape,
     store: &RDF,
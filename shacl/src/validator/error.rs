@@ -1,4 +1,5 @@
 use crate::error::{IRError, ShaclParserError};
+use rudof_rdf::rdf_core::utils::Interruption;
 use rudof_rdf::rdf_core::{RDFError, Rdf};
 #[cfg(feature = "sparql")]
 use rudof_rdf::rdf_impl::OxigraphEndpointError;
@@ -56,6 +57,15 @@ pub enum ValidationError {
 
     #[error("TargetClass should be an IRI")]
     TargetClassNotIri,
+
+    #[error("Shape {shape} to validate not found in the shapes graph")]
+    FocusShapeNotFound { shape: String },
+
+    #[error("Validation interrupted: {interruption}")]
+    Interrupted {
+        #[from]
+        interruption: Interruption,
+    },
 }
 
 impl ValidationError {
@@ -0,0 +1,140 @@
+use crate::validator::report::ValidationResult;
+use rudof_rdf::rdf_core::term::Object;
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Events emitted while a shapes graph is being validated, so that callers can
+/// show the results as they are produced instead of waiting for the whole
+/// [`ValidationReport`].
+///
+/// Shapes of the same dependency level are validated in parallel, so the events
+/// of different shapes can be interleaved.
+///
+/// [`ValidationReport`]: crate::validator::report::ValidationReport
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShaclValidationEvent {
+    /// The focus nodes of `shape` were resolved and its validation started
+    ShapeStarted { shape: Object, focus_nodes: usize },
+
+    /// A result of the validation report was produced
+    Result(ValidationResult),
+
+    /// The validation of a focus node was interrupted before it finished
+    Timeout(ValidationResult),
+
+    /// All the focus nodes of `shape` were validated
+    ShapeCompleted { shape: Object },
+
+    /// Progress of the whole validation so far. The number of focus nodes found
+    /// grows as the shapes start, so it is only final once all of them did.
+    Progress {
+        shapes_done: usize,
+        shapes_total: usize,
+        focus_nodes_done: usize,
+        focus_nodes_found: usize,
+    },
+}
+
+/// Serialized as a JSON object with a `type` field, one per line in NDJSON output
+impl Serialize for ShaclValidationEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        match self {
+            ShaclValidationEvent::ShapeStarted { shape, focus_nodes } => {
+                map.serialize_entry("type", "shapeStarted")?;
+                map.serialize_entry("shape", &shape.to_string())?;
+                map.serialize_entry("focusNodes", focus_nodes)?;
+            },
+            ShaclValidationEvent::Result(result) => {
+                map.serialize_entry("type", "result")?;
+                map.serialize_entry("result", result)?;
+            },
+            ShaclValidationEvent::Timeout(result) => {
+                map.serialize_entry("type", "timeout")?;
+                map.serialize_entry("result", result)?;
+            },
+            ShaclValidationEvent::ShapeCompleted { shape } => {
+                map.serialize_entry("type", "shapeCompleted")?;
+                map.serialize_entry("shape", &shape.to_string())?;
+            },
+            ShaclValidationEvent::Progress {
+                shapes_done,
+                shapes_total,
+                focus_nodes_done,
+                focus_nodes_found,
+            } => {
+                map.serialize_entry("type", "progress")?;
+                map.serialize_entry("shapesDone", shapes_done)?;
+                map.serialize_entry("shapesTotal", shapes_total)?;
+                map.serialize_entry("focusNodesDone", focus_nodes_done)?;
+                map.serialize_entry("focusNodesFound", focus_nodes_found)?;
+            },
+        }
+        map.end()
+    }
+}
+
+/// Keeps the progress counters of a validation and sends its events
+pub(crate) struct EventReporter<'a> {
+    on_event: &'a (dyn Fn(ShaclValidationEvent) + Sync),
+    shapes_total: usize,
+    shapes_done: AtomicUsize,
+    focus_nodes_done: AtomicUsize,
+    focus_nodes_found: AtomicUsize,
+}
+
+impl<'a> EventReporter<'a> {
+    pub(crate) fn new(on_event: &'a (dyn Fn(ShaclValidationEvent) + Sync), shapes_total: usize) -> Self {
+        Self {
+            on_event,
+            shapes_total,
+            shapes_done: AtomicUsize::new(0),
+            focus_nodes_done: AtomicUsize::new(0),
+            focus_nodes_found: AtomicUsize::new(0),
+        }
+    }
+
+    pub(crate) fn shape_started(&self, shape: &Object, focus_nodes: usize) {
+        self.focus_nodes_found.fetch_add(focus_nodes, Ordering::Relaxed);
+        (self.on_event)(ShaclValidationEvent::ShapeStarted {
+            shape: shape.clone(),
+            focus_nodes,
+        });
+    }
+
+    /// Reports the results and timeouts of `focus_nodes` focus nodes whose validation finished
+    pub(crate) fn focus_nodes_done(
+        &self,
+        focus_nodes: usize,
+        results: &[ValidationResult],
+        timeouts: &[ValidationResult],
+    ) {
+        for result in results {
+            (self.on_event)(ShaclValidationEvent::Result(result.clone()));
+        }
+        for timeout in timeouts {
+            (self.on_event)(ShaclValidationEvent::Timeout(timeout.clone()));
+        }
+        self.focus_nodes_done.fetch_add(focus_nodes, Ordering::Relaxed);
+        self.progress();
+    }
+
+    pub(crate) fn shape_completed(&self, shape: &Object) {
+        self.shapes_done.fetch_add(1, Ordering::Relaxed);
+        (self.on_event)(ShaclValidationEvent::ShapeCompleted { shape: shape.clone() });
+        self.progress();
+    }
+
+    fn progress(&self) {
+        (self.on_event)(ShaclValidationEvent::Progress {
+            shapes_done: self.shapes_done.load(Ordering::Relaxed),
+            shapes_total: self.shapes_total,
+            focus_nodes_done: self.focus_nodes_done.load(Ordering::Relaxed),
+            focus_nodes_found: self.focus_nodes_found.load(Ordering::Relaxed),
+        });
+    }
+}
@@ -0,0 +1,99 @@
+use rudof_rdf::rdf_core::term::Object;
+use std::collections::HashSet;
+
+/// Part of the data graph and of the shapes graph checked by a validation.
+///
+/// By default every shape with targets is validated against all its target
+/// nodes. Selecting focus nodes or shapes restricts the targets that are
+/// validated to those nodes or to the targets of those shapes. Associations
+/// validate a node against a shape, whether the node is a target of the shape
+/// or not, like the entries of a ShapeMap. When there are associations but no
+/// selected nodes or shapes, only the associations are validated.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ValidationFocus {
+    nodes: Option<HashSet<Object>>,
+    shapes: Option<HashSet<Object>>,
+    associations: Vec<(Object, Object)>,
+}
+
+impl ValidationFocus {
+    /// Creates a focus on the whole data graph and shapes graph
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts the validated targets to `nodes`
+    pub fn with_nodes(mut self, nodes: impl IntoIterator<Item = Object>) -> Self {
+        self.nodes.get_or_insert_with(HashSet::new).extend(nodes);
+        self
+    }
+
+    /// Restricts the validated targets to the ones of `shapes`
+    pub fn with_shapes(mut self, shapes: impl IntoIterator<Item = Object>) -> Self {
+        self.shapes.get_or_insert_with(HashSet::new).extend(shapes);
+        self
+    }
+
+    /// Validates `node` against `shape`
+    pub fn with_association(mut self, node: Object, shape: Object) -> Self {
+        self.associations.push((node, shape));
+        self
+    }
+
+    /// Whether the focus covers the whole data graph and shapes graph
+    pub fn is_everything(&self) -> bool {
+        self.nodes.is_none() && self.shapes.is_none() && self.associations.is_empty()
+    }
+
+    /// Shapes selected or associated with a node
+    pub fn shapes(&self) -> impl Iterator<Item = &Object> {
+        self.shapes
+            .iter()
+            .flatten()
+            .chain(self.associations.iter().map(|(_, shape)| shape))
+    }
+
+    /// Whether the targets of `shape` are validated
+    pub(crate) fn validates_targets_of(&self, shape: &Object) -> bool {
+        if self.nodes.is_none() && self.shapes.is_none() && !self.associations.is_empty() {
+            return false;
+        }
+        self.shapes.as_ref().is_none_or(|shapes| shapes.contains(shape))
+    }
+
+    /// Whether `node` is validated when it is a target
+    pub(crate) fn selects_node(&self, node: &Object) -> bool {
+        self.nodes.as_ref().is_none_or(|nodes| nodes.contains(node))
+    }
+
+    /// Nodes associated with `shape`
+    pub(crate) fn associated_nodes<'a>(&'a self, shape: &'a Object) -> impl Iterator<Item = &'a Object> {
+        self.associations
+            .iter()
+            .filter(move |(_, associated)| associated == shape)
+            .map(|(node, _)| node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rudof_iri::IriS;
+
+    fn iri(local: &str) -> Object {
+        Object::iri(IriS::new_unchecked(&format!("http://example.org/{local}")))
+    }
+
+    #[test]
+    fn associations_alone_skip_the_targets() {
+        let shape = iri("Shape");
+        let node = iri("node");
+        let focus = ValidationFocus::new().with_association(node.clone(), shape.clone());
+        assert!(!focus.validates_targets_of(&shape));
+        assert_eq!(focus.associated_nodes(&shape).collect::<Vec<_>>(), vec![&node]);
+
+        let focus = focus.with_shapes([shape.clone()]);
+        assert!(focus.validates_targets_of(&shape));
+        assert!(focus.selects_node(&iri("other")));
+    }
+}
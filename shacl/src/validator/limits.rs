@@ -0,0 +1,195 @@
+use crate::validator::report::{OmittedResults, ValidationResult};
+use rudof_rdf::rdf_core::term::Object;
+use rudof_rdf::rdf_core::utils::CancellationToken;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Number of focus nodes of a shape validated together when the validation can
+/// stop before the end, so that it stops soon after reaching the limit
+const STOPPABLE_BATCH_SIZE: usize = 64;
+
+/// Levels of nested results kept as `sh:detail` when no other depth is set
+pub(crate) const DEFAULT_DETAIL_DEPTH: usize = 2;
+
+/// Limits on the results of a validation.
+///
+/// `max_results` stops the validation once that many results are found, and
+/// fail-fast stops it at the first one, which is enough to know whether the
+/// data conforms. The caps per shape and per component don't stop the
+/// validation: they keep that many results of each source shape or constraint
+/// component and count the rest in the report. The detail depth bounds the
+/// nested results kept as `sh:detail` of each result.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ResultLimits {
+    fail_fast: bool,
+    max_results: Option<usize>,
+    max_results_per_shape: Option<usize>,
+    max_results_per_component: Option<usize>,
+    detail_depth: Option<usize>,
+}
+
+impl ResultLimits {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_fail_fast(mut self, fail_fast: bool) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    pub fn with_max_results(mut self, max_results: Option<usize>) -> Self {
+        self.max_results = max_results;
+        self
+    }
+
+    pub fn with_max_results_per_shape(mut self, max_results: Option<usize>) -> Self {
+        self.max_results_per_shape = max_results;
+        self
+    }
+
+    pub fn with_max_results_per_component(mut self, max_results: Option<usize>) -> Self {
+        self.max_results_per_component = max_results;
+        self
+    }
+
+    /// Keeps `depth` levels of nested results as `sh:detail`, 2 if `None`
+    pub fn with_detail_depth(mut self, depth: Option<usize>) -> Self {
+        self.detail_depth = depth;
+        self
+    }
+
+    pub fn fail_fast(&self) -> bool {
+        self.fail_fast
+    }
+
+    pub fn max_results(&self) -> Option<usize> {
+        self.max_results
+    }
+
+    pub fn max_results_per_shape(&self) -> Option<usize> {
+        self.max_results_per_shape
+    }
+
+    pub fn max_results_per_component(&self) -> Option<usize> {
+        self.max_results_per_component
+    }
+
+    pub fn detail_depth(&self) -> Option<usize> {
+        self.detail_depth
+    }
+
+    /// Whether every result is kept
+    pub fn is_unlimited(&self) -> bool {
+        !self.fail_fast
+            && self.max_results.is_none()
+            && self.max_results_per_shape.is_none()
+            && self.max_results_per_component.is_none()
+    }
+
+    /// Number of results after which the validation stops
+    pub fn stop_after(&self) -> Option<usize> {
+        match (self.fail_fast, self.max_results) {
+            (true, max_results) => Some(max_results.map_or(1, |max| max.min(1))),
+            (false, max_results) => max_results,
+        }
+    }
+}
+
+/// Applies the [`ResultLimits`] to the results of the shapes validated in
+/// parallel, and cancels `stop` when the validation must stop.
+pub(crate) struct ResultLimiter {
+    limits: ResultLimits,
+    stop: CancellationToken,
+    state: Mutex<LimiterState>,
+}
+
+#[derive(Default)]
+struct LimiterState {
+    kept: usize,
+    kept_by_shape: HashMap<Option<Object>, usize>,
+    kept_by_component: HashMap<Object, usize>,
+    omitted: HashMap<(Option<Object>, Object), usize>,
+    stopped: bool,
+}
+
+impl ResultLimiter {
+    pub(crate) fn new(limits: ResultLimits, stop: CancellationToken) -> Self {
+        Self {
+            limits,
+            stop,
+            state: Mutex::new(LimiterState::default()),
+        }
+    }
+
+    /// Returns the results that are kept and counts the ones that are left out
+    pub(crate) fn admit(&self, results: Vec<ValidationResult>) -> Vec<ValidationResult> {
+        if self.limits.is_unlimited() {
+            return results;
+        }
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let mut kept = Vec::new();
+        for result in results {
+            if state.stopped {
+                break;
+            }
+            let shape = result.source().cloned();
+            let component = result.constraint_component().clone();
+            let by_shape = state.kept_by_shape.get(&shape).copied().unwrap_or(0);
+            let by_component = state.kept_by_component.get(&component).copied().unwrap_or(0);
+            if self.limits.max_results_per_shape.is_some_and(|max| by_shape >= max)
+                || self
+                    .limits
+                    .max_results_per_component
+                    .is_some_and(|max| by_component >= max)
+            {
+                *state.omitted.entry((shape, component)).or_insert(0) += 1;
+                continue;
+            }
+            if self.limits.stop_after().is_some_and(|max| state.kept >= max) {
+                self.stop_validation(&mut state);
+                break;
+            }
+            state.kept_by_shape.insert(shape, by_shape + 1);
+            state.kept_by_component.insert(component, by_component + 1);
+            state.kept += 1;
+            kept.push(result);
+            if self.limits.stop_after().is_some_and(|max| state.kept >= max) {
+                self.stop_validation(&mut state);
+            }
+        }
+        kept
+    }
+
+    fn stop_validation(&self, state: &mut LimiterState) {
+        state.stopped = true;
+        self.stop.cancel();
+    }
+
+    /// Whether the validation stopped because it reached the maximum number of results
+    pub(crate) fn is_stopped(&self) -> bool {
+        self.state.lock().unwrap_or_else(|e| e.into_inner()).stopped
+    }
+
+    /// Number of focus nodes of a shape to validate together, `None` for all of them
+    pub(crate) fn batch_size(&self) -> Option<usize> {
+        self.limits.stop_after().map(|_| STOPPABLE_BATCH_SIZE)
+    }
+
+    /// Counts of the results left out, by source shape and component
+    pub(crate) fn omitted(&self) -> Vec<OmittedResults> {
+        let state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let mut omitted: Vec<_> = state
+            .omitted
+            .iter()
+            .map(|((shape, component), count)| OmittedResults::new(shape.clone(), component.clone(), *count))
+            .collect();
+        omitted.sort_by_key(|omitted| {
+            (
+                omitted.source().map(|shape| shape.to_string()),
+                omitted.constraint_component().to_string(),
+            )
+        });
+        omitted
+    }
+}
@@ -6,8 +6,11 @@ mod config;
 pub mod constraints;
 pub mod engine;
 pub(crate) mod error;
+mod event;
+mod focus;
 mod index;
 mod iteration;
+mod limits;
 mod mode;
 pub mod nodes;
 pub mod processor;
@@ -15,4 +18,7 @@ pub mod report;
 pub mod store;
 
 pub use config::ShaclConfig;
+pub use event::ShaclValidationEvent;
+pub use focus::ValidationFocus;
+pub use limits::ResultLimits;
 pub use mode::ShaclValidationMode;
@@ -5,17 +5,21 @@ mod graph;
 mod rdf_data;
 
 use crate::error::ValidationError;
-use crate::ir::IRSchema;
-use crate::validator::ShaclValidationMode;
-use crate::validator::engine::{Engine, Validate};
-use crate::validator::report::ValidationReport;
+use crate::ir::{IRSchema, IRShape};
+use crate::validator::engine::{Engine, ProfileLabel, Validate};
+use crate::validator::event::{EventReporter, ShaclValidationEvent};
+use crate::validator::limits::{DEFAULT_DETAIL_DEPTH, ResultLimiter};
+use crate::validator::nodes::FocusNodes;
+use crate::validator::report::{ValidationReport, ValidationResult};
+use crate::validator::{ResultLimits, ShaclValidationMode, ValidationFocus};
 #[cfg(feature = "sparql")]
 pub use endpoint::EndpointValidation;
 pub use graph::GraphValidation;
 use rayon::prelude::*;
 #[cfg(feature = "sparql")]
 pub use rdf_data::DataValidation;
-use rudof_rdf::rdf_core::NeighsRDF;
+use rudof_rdf::rdf_core::utils::{CancellationToken, Interruption, ValidationBudget};
+use rudof_rdf::rdf_core::{NeighsRDF, SHACLPath, SourceSpan};
 use std::fmt::Debug;
 
 /// The basic operations of the SHACL Processor.
@@ -55,19 +59,111 @@ pub trait ShaclProcessor<S: NeighsRDF + Debug + Send + Sync> {
         shapes_graph: &IRSchema,
         mode: &ShaclValidationMode,
     ) -> Result<ValidationReport, ValidationError> {
+        self.validate_with_budget(shapes_graph, mode, &ValidationBudget::default())
+    }
+
+    /// Same as [`ShaclProcessor::validate`], but stops when the time limits of
+    /// `budget` are exceeded or its cancellation token is cancelled.
+    ///
+    /// The focus nodes that could not be validated are returned as the
+    /// timeouts of the report, next to the results of the nodes that were.
+    /// When the budget has a per-node timeout, the focus nodes of each shape
+    /// are validated one by one so that each gets its own deadline.
+    fn validate_with_budget(
+        &mut self,
+        shapes_graph: &IRSchema,
+        mode: &ShaclValidationMode,
+        budget: &ValidationBudget,
+    ) -> Result<ValidationReport, ValidationError> {
+        self.validate_with_events(shapes_graph, mode, budget, &|_| {})
+    }
+
+    /// Same as [`ShaclProcessor::validate_with_budget`], but calls `on_event`
+    /// with each result as soon as it is produced and with the progress of the
+    /// validation.
+    ///
+    /// Results are produced when a shape has validated all its focus nodes, or
+    /// each focus node when the budget has a per-node timeout. As shapes are
+    /// validated in parallel, `on_event` is called from several threads.
+    fn validate_with_events(
+        &mut self,
+        shapes_graph: &IRSchema,
+        mode: &ShaclValidationMode,
+        budget: &ValidationBudget,
+        on_event: &(dyn Fn(ShaclValidationEvent) + Sync),
+    ) -> Result<ValidationReport, ValidationError> {
+        self.validate_focused(
+            shapes_graph,
+            mode,
+            &ValidationFocus::default(),
+            &ResultLimits::default(),
+            budget,
+            on_event,
+        )
+    }
+
+    /// Same as [`ShaclProcessor::validate_with_events`], but only validates the
+    /// focus nodes and shapes selected by `focus` and applies `limits` to the
+    /// results.
+    ///
+    /// Only the shapes whose targets or associated nodes are validated are
+    /// scheduled. The shapes they depend on are validated on demand, for the
+    /// value nodes that reach them.
+    ///
+    /// When the maximum number of results of `limits` is reached, the engines
+    /// are stopped at their next check, as when the validation is cancelled,
+    /// and the focus nodes not validated yet are left out of the report
+    /// instead of being reported as timeouts.
+    fn validate_focused(
+        &mut self,
+        shapes_graph: &IRSchema,
+        mode: &ShaclValidationMode,
+        focus: &ValidationFocus,
+        limits: &ResultLimits,
+        budget: &ValidationBudget,
+        on_event: &(dyn Fn(ShaclValidationEvent) + Sync),
+    ) -> Result<ValidationReport, ValidationError> {
+        if let Some(shape) = focus.shapes().find(|shape| shapes_graph.get_idx(shape).is_none()) {
+            return Err(ValidationError::FocusShapeNotFound {
+                shape: shape.to_string(),
+            });
+        }
         self.prepare_store()?;
         let store = self.store();
+        // The run can be stopped by the limits without cancelling the token of the budget
+        let run = budget.start_run().child();
+        let limiter = ResultLimiter::new(*limits, run.clone());
 
         // Build shared indexes once. Forked engines share
         // the data, avoiding redundant scans.
         let mut master_runner = Self::runner(mode);
+        master_runner.set_cancellation(run.clone());
+        master_runner.set_detail_depth(limits.detail_depth().unwrap_or(DEFAULT_DETAIL_DEPTH));
+        master_runner.set_profiler(budget.profiler().clone());
         master_runner.build_indexes(store)?;
 
-        // Group shapes-with-targets by topological level so that dependencies
+        // Group the shapes to validate by topological level so that dependencies
         // are always validated before the shapes that reference them.
-        let levels = shapes_graph.shapes_with_targets_by_level();
+        let levels = if focus.is_everything() {
+            shapes_graph.shapes_with_targets_by_level()
+        } else {
+            shapes_graph.shapes_by_level(|shape| {
+                (focus.validates_targets_of(shape.id()) && !shape.targets().is_empty())
+                    || focus.associated_nodes(shape.id()).next().is_some()
+            })
+        };
+        let reporter = EventReporter::new(on_event, levels.iter().map(Vec::len).sum());
+        let ctx = RunContext {
+            shapes_graph,
+            focus,
+            budget,
+            run: &run,
+            reporter: &reporter,
+            limiter: &limiter,
+        };
 
         let mut all_results = Vec::new();
+        let mut all_timeouts = Vec::new();
 
         for level in levels {
             // Fork one engine per shape in this level. Each fork shares the
@@ -75,17 +171,22 @@ pub trait ShaclProcessor<S: NeighsRDF + Debug + Send + Sync> {
             let mut forked_runners: Vec<Box<dyn Engine<S>>> = level.iter().map(|_| master_runner.fork()).collect();
 
             // Validate all shapes in the level in parallel.
-            let level_results: Vec<Result<Vec<_>, ValidationError>> = forked_runners
+            let level_results: Vec<Result<ShapeOutcome, ValidationError>> = forked_runners
                 .par_iter_mut()
                 .zip(level.par_iter())
                 .map(|(runner, idx)| {
                     let shape = shapes_graph.get_shape_from_idx_e(idx)?;
-                    shape.validate(store, runner.as_mut(), None, Some(shape), shapes_graph)
+                    validate_shape(store, runner.as_mut(), shape, &ctx)
                 })
                 .collect();
 
             for result in level_results {
-                all_results.extend(result?);
+                let (results, timeouts) = result?;
+                all_results.extend(results);
+                all_timeouts.extend(timeouts);
+            }
+            if limiter.is_stopped() {
+                break;
             }
         }
 
@@ -94,6 +195,523 @@ pub trait ShaclProcessor<S: NeighsRDF + Debug + Send + Sync> {
             pm.merge(store_pm);
         }
 
-        Ok(ValidationReport::new().with_results(all_results).with_prefixmap(pm))
+        let locate_all = |results: Vec<ValidationResult>| {
+            results
+                .into_iter()
+                .map(|result| locate(store, shapes_graph, result))
+                .collect()
+        };
+        Ok(ValidationReport::new()
+            .with_results(locate_all(all_results))
+            .with_timeouts(locate_all(all_timeouts))
+            .with_omitted(limiter.omitted())
+            .with_stopped_early(limiter.is_stopped())
+            .with_prefixmap(pm))
+    }
+}
+
+/// Adds to a result the positions of the data that failed and of its source shape
+fn locate<S: NeighsRDF>(store: &S, shapes_graph: &IRSchema, result: ValidationResult) -> ValidationResult {
+    let span = data_span(store, &result);
+    let shape_span = result.source().and_then(|shape| shapes_graph.span(shape)).cloned();
+    result.with_span(span).with_shape_span(shape_span)
+}
+
+/// Position of the triple that links the focus node with the value of a result
+/// through a predicate path, or else of the focus node
+fn data_span<S: NeighsRDF>(store: &S, result: &ValidationResult) -> Option<SourceSpan> {
+    let focus = S::Subject::try_from(result.focus_node().clone()).ok();
+    let triple_span = match (result.path(), result.value()) {
+        (Some(SHACLPath::Predicate { pred }), Some(value)) => focus
+            .as_ref()
+            .and_then(|focus| store.triple_span(focus, &pred.clone().into(), &value.clone().into())),
+        (Some(SHACLPath::Inverse { path }), Some(value)) => match path.as_ref() {
+            SHACLPath::Predicate { pred } => S::Subject::try_from(value.clone()).ok().and_then(|subject| {
+                store.triple_span(&subject, &pred.clone().into(), &result.focus_node().clone().into())
+            }),
+            _ => None,
+        },
+        _ => None,
+    };
+    triple_span.or_else(|| focus.and_then(|focus| store.subject_span(&focus)))
+}
+
+/// Results and timeouts of the validation of a shape
+type ShapeOutcome = (Vec<ValidationResult>, Vec<ValidationResult>);
+
+/// What the validations of the shapes of a run share
+struct RunContext<'a> {
+    shapes_graph: &'a IRSchema,
+    focus: &'a ValidationFocus,
+    budget: &'a ValidationBudget,
+    run: &'a CancellationToken,
+    reporter: &'a EventReporter<'a>,
+    limiter: &'a ResultLimiter,
+}
+
+fn validate_shape<S: NeighsRDF + Debug>(
+    store: &S,
+    runner: &mut dyn Engine<S>,
+    shape: &IRShape,
+    ctx: &RunContext<'_>,
+) -> Result<ShapeOutcome, ValidationError> {
+    let outcome = validate_focus_nodes(store, runner, shape, ctx)?;
+    ctx.reporter.shape_completed(shape.id());
+    Ok(outcome)
+}
+
+fn validate_focus_nodes<S: NeighsRDF + Debug>(
+    store: &S,
+    runner: &mut dyn Engine<S>,
+    shape: &IRShape,
+    ctx: &RunContext<'_>,
+) -> Result<ShapeOutcome, ValidationError> {
+    if shape.deactivated() {
+        return Ok((Vec::new(), Vec::new()));
+    }
+
+    let focus_nodes = match interruptible(ctx.run, || focus_nodes(store, runner, shape, ctx))? {
+        Ok(focus_nodes) => focus_nodes,
+        Err(interruption) => {
+            let (results, timeouts) = interrupted_shape(store, runner, shape, ctx, interruption)?;
+            ctx.reporter.focus_nodes_done(0, &results, &timeouts);
+            return Ok((results, timeouts));
+        },
+    };
+    ctx.reporter.shape_started(shape.id(), focus_nodes.len());
+
+    if ctx.budget.node_timeout().is_none() {
+        // When the validation can stop early, the focus nodes are validated in
+        // batches so that it stops soon after reaching the limit
+        let batches: Vec<FocusNodes<S>> = match ctx.limiter.batch_size() {
+            Some(size) => focus_nodes
+                .iter()
+                .cloned()
+                .collect::<Vec<_>>()
+                .chunks(size)
+                .map(|batch| batch.iter().cloned().collect())
+                .collect(),
+            None => vec![focus_nodes],
+        };
+        let mut results = Vec::new();
+        for (i, batch) in batches.iter().enumerate() {
+            match interruptible(ctx.run, || {
+                shape.validate(store, runner, Some(batch), Some(shape), ctx.shapes_graph)
+            })? {
+                Ok(batch_results) => {
+                    let kept = ctx.limiter.admit(batch_results);
+                    ctx.reporter.focus_nodes_done(batch.len(), &kept, &[]);
+                    results.extend(kept);
+                },
+                Err(interruption) => {
+                    let pending: FocusNodes<S> = batches[i..].iter().flat_map(|batch| batch.iter().cloned()).collect();
+                    let (interrupted, timeouts) = interrupted_nodes(runner, shape, ctx, &pending, interruption);
+                    ctx.reporter.focus_nodes_done(pending.len(), &interrupted, &timeouts);
+                    results.extend(interrupted);
+                    return Ok((results, timeouts));
+                },
+            }
+        }
+        return Ok((results, Vec::new()));
+    }
+
+    let mut results = Vec::new();
+    let mut timeouts = Vec::new();
+    for node in focus_nodes.iter() {
+        if ctx.limiter.is_stopped() {
+            break;
+        }
+        let token = ctx.budget.start_node(ctx.run);
+        runner.set_cancellation(token.clone());
+        let targets = FocusNodes::single(node.clone());
+        match interruptible(&token, || {
+            shape.validate(store, runner, Some(&targets), Some(shape), ctx.shapes_graph)
+        })? {
+            Ok(node_results) => {
+                let kept = ctx.limiter.admit(node_results);
+                ctx.reporter.focus_nodes_done(1, &kept, &[]);
+                results.extend(kept)
+            },
+            // Nodes left when the validation stops early did not time out
+            Err(_) if ctx.limiter.is_stopped() => break,
+            Err(interruption) => {
+                let node_timeouts: Vec<_> = S::term_as_object(node)
+                    .map(|node| ValidationResult::timeout(node, shape.id().clone(), interruption))
+                    .into_iter()
+                    .collect();
+                ctx.reporter.focus_nodes_done(1, &[], &node_timeouts);
+                timeouts.extend(node_timeouts);
+            },
+        }
+    }
+    runner.set_cancellation(ctx.run.clone());
+    Ok((results, timeouts))
+}
+
+/// Target nodes of a shape that are in the focus of the validation, and the
+/// nodes associated with the shape
+fn focus_nodes<S: NeighsRDF + Debug>(
+    store: &S,
+    runner: &mut dyn Engine<S>,
+    shape: &IRShape,
+    ctx: &RunContext<'_>,
+) -> Result<FocusNodes<S>, ValidationError> {
+    let focus = ctx.focus;
+    let targets = if focus.validates_targets_of(shape.id()) {
+        // Finding the targets is profiled apart, as it is done before validating the shape
+        let _span = runner.profiler().span(
+            &ProfileLabel::Shape(shape.id(), ctx.shapes_graph.prefix_map()),
+            Some(&"targets"),
+        );
+        runner.focus_nodes(store, shape.targets())?
+    } else {
+        FocusNodes::default()
+    };
+    if focus.is_everything() {
+        return Ok(targets);
+    }
+    Ok(targets
+        .into_iter()
+        .filter(|node| S::term_as_object(node).is_ok_and(|node| focus.selects_node(&node)))
+        .chain(focus.associated_nodes(shape.id()).map(S::object_as_term))
+        .collect())
+}
+
+/// Runs `validation` unless `token` is already interrupted. Errors raised while
+/// the token is interrupted are attributed to the interruption, as they come
+/// from the checks of the engine or from aborted SPARQL requests.
+fn interruptible<T>(
+    token: &CancellationToken,
+    validation: impl FnOnce() -> Result<T, ValidationError>,
+) -> Result<Result<T, Interruption>, ValidationError> {
+    if let Some(interruption) = token.interruption() {
+        return Ok(Err(interruption));
+    }
+    match validation() {
+        Ok(value) => Ok(Ok(value)),
+        Err(err) => token.interruption().map(Err).ok_or(err),
+    }
+}
+
+/// Outcome of a shape whose validation was interrupted: the focus nodes that
+/// were already validated (and cached) keep their results, the rest time out.
+fn interrupted_shape<S: NeighsRDF + Debug>(
+    store: &S,
+    runner: &mut dyn Engine<S>,
+    shape: &IRShape,
+    ctx: &RunContext<'_>,
+    interruption: Interruption,
+) -> Result<ShapeOutcome, ValidationError> {
+    if ctx.limiter.is_stopped() {
+        return Ok((Vec::new(), Vec::new()));
+    }
+    // Finding the focus nodes must not be stopped by the token that interrupted the shape
+    runner.set_cancellation(CancellationToken::new());
+    let Ok(focus_nodes) = focus_nodes(store, runner, shape, ctx) else {
+        return Ok((Vec::new(), vec![shape_timeout(shape, interruption)]));
+    };
+    Ok(interrupted_nodes(runner, shape, ctx, &focus_nodes, interruption))
+}
+
+/// Outcome of the focus nodes of a shape whose validation was interrupted. When
+/// the validation stopped early, the nodes are neither reported nor timed out.
+fn interrupted_nodes<S: NeighsRDF + Debug>(
+    runner: &mut dyn Engine<S>,
+    shape: &IRShape,
+    ctx: &RunContext<'_>,
+    focus_nodes: &FocusNodes<S>,
+    interruption: Interruption,
+) -> ShapeOutcome {
+    if ctx.limiter.is_stopped() {
+        return (Vec::new(), Vec::new());
+    }
+    let idx = ctx.shapes_graph.get_idx(shape.id());
+    let mut results = Vec::new();
+    let mut timeouts = Vec::new();
+    for node in focus_nodes.iter() {
+        let Ok(node) = S::term_as_object(node) else {
+            continue;
+        };
+        match idx.and_then(|idx| runner.get_cached_results(&node, *idx)) {
+            Some(cached) => results.extend(cached),
+            None => timeouts.push(ValidationResult::timeout(node, shape.id().clone(), interruption)),
+        }
+    }
+    (ctx.limiter.admit(results), timeouts)
+}
+
+/// Timeout of a shape whose focus nodes could not even be found
+fn shape_timeout(shape: &IRShape, interruption: Interruption) -> ValidationResult {
+    ValidationResult::timeout(shape.id().clone(), shape.id().clone(), interruption)
+}
+
+#[cfg(all(not(target_family = "wasm"), feature = "sparql", test))]
+mod tests {
+    use crate::ir::IRSchema;
+    use crate::rdf::ShaclParser;
+    use crate::validator::processor::{DataValidation, ShaclProcessor};
+    use crate::validator::{ResultLimits, ShaclValidationEvent, ShaclValidationMode, ValidationFocus};
+    use rudof_iri::IriS;
+    use rudof_rdf::rdf_core::RDFFormat;
+    use rudof_rdf::rdf_core::term::Object;
+    use rudof_rdf::rdf_core::utils::{CancellationToken, Profiler, ValidationBudget};
+    use rudof_rdf::rdf_impl::ReaderMode;
+    use sparql_service::RdfData;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    const GRAPH: &str = r#"
+prefix sh: <http://www.w3.org/ns/shacl#>
+prefix : <http://example.org/>
+
+:PersonShape a sh:NodeShape ;
+  sh:targetClass :Person ;
+  sh:property [ sh:path :name ; sh:minCount 1 ] .
+
+:alice a :Person ; :name "Alice" .
+:bob a :Person .
+"#;
+
+    fn validate(budget: &ValidationBudget) -> crate::validator::report::ValidationReport {
+        validate_with_events(budget).0
+    }
+
+    fn validate_focused(
+        focus: &ValidationFocus,
+    ) -> Result<crate::validator::report::ValidationReport, crate::error::ValidationError> {
+        let rdf = RdfData::from_str(GRAPH, &RDFFormat::Turtle, None, &ReaderMode::Strict).unwrap();
+        let mut validator: DataValidation = rdf.clone().into();
+        let schema = ShaclParser::new(rdf).parse().unwrap();
+        let schema_ir: IRSchema = schema.try_into().unwrap();
+        validator.validate_focused(
+            &schema_ir,
+            &ShaclValidationMode::Native,
+            focus,
+            &ResultLimits::default(),
+            &ValidationBudget::default(),
+            &|_| {},
+        )
+    }
+
+    const PEOPLE: &str = r#"
+prefix sh: <http://www.w3.org/ns/shacl#>
+prefix xsd: <http://www.w3.org/2001/XMLSchema#>
+prefix : <http://example.org/>
+
+:PersonShape a sh:NodeShape ;
+  sh:targetClass :Person ;
+  sh:property [ sh:path :name ; sh:minCount 1 ] ;
+  sh:property [ sh:path :age ; sh:datatype xsd:integer ] .
+
+:alice a :Person ; :age "old" .
+:bob a :Person ; :age "young" .
+:carol a :Person ; :age "unknown" .
+"#;
+
+    fn validate_limited(limits: &ResultLimits) -> crate::validator::report::ValidationReport {
+        validate_graph(PEOPLE, limits)
+    }
+
+    fn validate_graph(graph: &str, limits: &ResultLimits) -> crate::validator::report::ValidationReport {
+        let rdf = RdfData::from_str(graph, &RDFFormat::Turtle, None, &ReaderMode::Strict).unwrap();
+        let mut validator: DataValidation = rdf.clone().into();
+        let schema = ShaclParser::new(rdf).parse().unwrap();
+        let schema_ir: IRSchema = schema.try_into().unwrap();
+        validator
+            .validate_focused(
+                &schema_ir,
+                &ShaclValidationMode::Native,
+                &ValidationFocus::default(),
+                limits,
+                &ValidationBudget::default(),
+                &|_| {},
+            )
+            .unwrap()
+    }
+
+    fn iri(local: &str) -> Object {
+        Object::iri(IriS::new_unchecked(&format!("http://example.org/{local}")))
+    }
+
+    fn validate_with_events(
+        budget: &ValidationBudget,
+    ) -> (crate::validator::report::ValidationReport, Vec<ShaclValidationEvent>) {
+        let rdf = RdfData::from_str(GRAPH, &RDFFormat::Turtle, None, &ReaderMode::Strict).unwrap();
+        let mut validator: DataValidation = rdf.clone().into();
+        let schema = ShaclParser::new(rdf).parse().unwrap();
+        let schema_ir: IRSchema = schema.try_into().unwrap();
+        let events = Mutex::new(Vec::new());
+        let report = validator
+            .validate_with_events(&schema_ir, &ShaclValidationMode::Native, budget, &|event| {
+                events.lock().unwrap().push(event)
+            })
+            .unwrap();
+        (report, events.into_inner().unwrap())
+    }
+
+    #[test]
+    fn unlimited_budget_validates_every_node() {
+        let report = validate(&ValidationBudget::default());
+        assert_eq!(report.results().len(), 1);
+        assert!(report.is_complete());
+    }
+
+    #[test]
+    fn profiler_records_shapes_and_components() {
+        let profiler = Profiler::new();
+        validate(&ValidationBudget::new().with_profiler(profiler.clone()));
+        let entries = profiler.entries();
+        let person = entries
+            .iter()
+            .find(|entry| entry.shape() == ":PersonShape" && entry.component().is_none())
+            .unwrap();
+        assert_eq!(person.evaluations(), 1);
+        assert!(
+            entries
+                .iter()
+                .any(|entry| entry.component() == Some("sh:MinCountConstraintComponent") && entry.evaluations() == 2)
+        );
+        assert!(
+            profiler
+                .folded()
+                .iter()
+                .any(|(stack, _)| stack.starts_with(":PersonShape;")
+                    && stack.ends_with(";sh:MinCountConstraintComponent"))
+        );
+    }
+
+    #[test]
+    fn focus_restricts_the_targets() {
+        let report = validate_focused(&ValidationFocus::new().with_nodes([iri("alice")])).unwrap();
+        assert!(report.results().is_empty());
+        let report = validate_focused(&ValidationFocus::new().with_nodes([iri("bob")])).unwrap();
+        assert_eq!(report.results().len(), 1);
+        assert!(validate_focused(&ValidationFocus::new().with_shapes([iri("OtherShape")])).is_err());
+    }
+
+    #[test]
+    fn focus_validates_associated_nodes() {
+        let focus = ValidationFocus::new().with_association(iri("carol"), iri("PersonShape"));
+        let report = validate_focused(&focus).unwrap();
+        assert_eq!(report.results().len(), 1);
+        assert_eq!(report.results()[0].focus_node(), &iri("carol"));
+    }
+
+    #[test]
+    fn fail_fast_stops_at_the_first_result() {
+        assert_eq!(validate_limited(&ResultLimits::default()).results().len(), 6);
+        let report = validate_limited(&ResultLimits::new().with_fail_fast(true));
+        assert_eq!(report.results().len(), 1);
+        assert!(report.stopped_early());
+        assert!(report.timeouts().is_empty());
+        assert!(!report.conforms());
+        assert!(!report.is_complete());
+    }
+
+    #[test]
+    fn max_results_keeps_that_many_results() {
+        let report = validate_limited(&ResultLimits::new().with_max_results(Some(4)));
+        assert_eq!(report.results().len(), 4);
+        assert!(report.stopped_early());
+    }
+
+    #[test]
+    fn component_cap_counts_the_omitted_results() {
+        let report = validate_limited(&ResultLimits::new().with_max_results_per_component(Some(1)));
+        assert_eq!(report.results().len(), 2);
+        assert_eq!(report.omitted_count(), 4);
+        assert_eq!(report.omitted().len(), 2);
+        assert!(!report.stopped_early());
+        assert!(!report.conforms());
+    }
+
+    #[test]
+    fn or_results_detail_the_failed_shapes() {
+        let graph = r#"
+prefix sh: <http://www.w3.org/ns/shacl#>
+prefix : <http://example.org/>
+
+:ContactShape a sh:NodeShape ;
+  sh:targetClass :Person ;
+  sh:or ( [ sh:path :email ; sh:minCount 1 ] [ sh:path :address ; sh:node :AddressShape ; sh:minCount 1 ] ) .
+
+:AddressShape a sh:NodeShape ;
+  sh:property [ sh:path :street ; sh:minCount 1 ] .
+
+:alice a :Person ; :address [ :city "Oviedo" ] .
+"#;
+        let report = validate_graph(graph, &ResultLimits::default());
+        let details = report.results()[0].details();
+        assert_eq!(details.len(), 2);
+        let node = details.iter().find(|detail| !detail.details().is_empty()).unwrap();
+        assert_eq!(
+            node.details()[0].path().map(|path| path.to_string()).as_deref(),
+            Some("http://example.org/street")
+        );
+
+        let report = validate_graph(graph, &ResultLimits::new().with_detail_depth(Some(1)));
+        assert!(
+            report.results()[0]
+                .details()
+                .iter()
+                .all(|detail| detail.details().is_empty())
+        );
+        let report = validate_graph(graph, &ResultLimits::new().with_detail_depth(Some(0)));
+        assert!(report.results()[0].details().is_empty());
+    }
+
+    #[test]
+    fn exhausted_node_budget_reports_timeouts() {
+        let report = validate(&ValidationBudget::new().with_node_timeout(Some(Duration::ZERO)));
+        assert!(report.results().is_empty());
+        assert_eq!(report.timeouts().len(), 2);
+        assert!(!report.conforms());
+    }
+
+    #[test]
+    fn cancelled_run_reports_timeouts() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let report = validate(&ValidationBudget::new().with_cancellation(token));
+        assert_eq!(report.timeouts().len(), 2);
+        assert!(report.timeouts()[0].message().to_string().contains("cancelled"));
+    }
+
+    #[test]
+    fn events_stream_every_result_and_end_with_full_progress() {
+        let (report, events) = validate_with_events(&ValidationBudget::default());
+        let streamed: Vec<_> = events
+            .iter()
+            .filter_map(|event| match event {
+                ShaclValidationEvent::Result(result) => Some(result.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(&streamed, report.results());
+        assert!(
+            events
+                .iter()
+                .any(|event| matches!(event, ShaclValidationEvent::ShapeStarted { focus_nodes: 2, .. }))
+        );
+        assert!(matches!(
+            events.last(),
+            Some(ShaclValidationEvent::Progress {
+                shapes_done: 1,
+                shapes_total: 1,
+                focus_nodes_done: 2,
+                focus_nodes_found: 2,
+            })
+        ));
+    }
+
+    #[test]
+    fn events_stream_timeouts_of_each_node() {
+        let budget = ValidationBudget::new().with_node_timeout(Some(Duration::ZERO));
+        let (_, events) = validate_with_events(&budget);
+        let timeouts = events
+            .iter()
+            .filter(|event| matches!(event, ShaclValidationEvent::Timeout(_)))
+            .count();
+        assert_eq!(timeouts, 2);
     }
 }
@@ -1,13 +1,18 @@
 use crate::types::Severity;
 use prefixmap::PrefixMap;
+use rudof_rdf::rdf_core::term::literal::Lang;
 use rudof_rdf::rdf_core::vocabs::ShaclVocab;
 use rudof_rdf::rdf_core::{BuildRDF, FocusRDF, Rdf};
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
 use std::fmt::{Display, Formatter};
 
+mod omitted;
 mod result;
 mod sorting;
 
 use crate::error::ValidationError;
+pub use omitted::OmittedResults;
 pub use result::ValidationResult;
 use rudof_rdf::rdf_core::term::Object;
 pub use sorting::ValidationReportSorting;
@@ -15,6 +20,12 @@ pub use sorting::ValidationReportSorting;
 #[derive(Debug, Clone)]
 pub struct ValidationReport {
     results: Vec<ValidationResult>,
+    /// Focus nodes whose validation was interrupted before it finished
+    timeouts: Vec<ValidationResult>,
+    /// Results left out because of the caps per shape or per component
+    omitted: Vec<OmittedResults>,
+    /// Whether the validation stopped when it reached the maximum number of results
+    stopped_early: bool,
     nodes_pm: PrefixMap,
     shapes_pm: PrefixMap,
 }
@@ -23,6 +34,9 @@ impl ValidationReport {
     pub fn new() -> Self {
         Self {
             results: Vec::new(),
+            timeouts: Vec::new(),
+            omitted: Vec::new(),
+            stopped_early: false,
             nodes_pm: PrefixMap::new(),
             shapes_pm: PrefixMap::new(),
         }
@@ -33,6 +47,21 @@ impl ValidationReport {
         self
     }
 
+    pub fn with_timeouts(mut self, timeouts: Vec<ValidationResult>) -> Self {
+        self.timeouts = timeouts;
+        self
+    }
+
+    pub fn with_omitted(mut self, omitted: Vec<OmittedResults>) -> Self {
+        self.omitted = omitted;
+        self
+    }
+
+    pub fn with_stopped_early(mut self, stopped_early: bool) -> Self {
+        self.stopped_early = stopped_early;
+        self
+    }
+
     /// Sets the same prefixmap for nodes and shapes
     pub fn with_prefixmap(mut self, pm: PrefixMap) -> Self {
         self.shapes_pm = pm.clone();
@@ -52,10 +81,61 @@ impl ValidationReport {
         self
     }
 
+    /// Keeps only the message in the preferred language of each result, as
+    /// [`MessageMap::preferred`] does
+    ///
+    /// [`MessageMap::preferred`]: crate::types::MessageMap::preferred
+    pub fn with_message_languages(mut self, langs: &[Lang]) -> Self {
+        self.results = self
+            .results
+            .into_iter()
+            .map(|result| result.with_message_languages(langs))
+            .collect();
+        self
+    }
+
+    /// Keeps the `sh:detail` results of each result down to `depth` levels
+    pub fn with_detail_depth(mut self, depth: usize) -> Self {
+        self.results = self
+            .results
+            .into_iter()
+            .map(|result| result.with_detail_depth(depth))
+            .collect();
+        self
+    }
+
     pub fn results(&self) -> &Vec<ValidationResult> {
         &self.results
     }
 
+    /// Focus nodes that could not be validated within the time budget or before
+    /// the validation was cancelled, as results of severity `sh:Info`.
+    pub fn timeouts(&self) -> &Vec<ValidationResult> {
+        &self.timeouts
+    }
+
+    /// Results left out because of the caps per shape or per component, counted
+    /// by source shape and constraint component
+    pub fn omitted(&self) -> &Vec<OmittedResults> {
+        &self.omitted
+    }
+
+    /// Number of results left out because of the caps per shape or per component
+    pub fn omitted_count(&self) -> usize {
+        self.omitted.iter().map(OmittedResults::count).sum()
+    }
+
+    /// Whether the validation stopped when it reached the maximum number of
+    /// results, so that other focus nodes may have results too
+    pub fn stopped_early(&self) -> bool {
+        self.stopped_early
+    }
+
+    /// Whether every focus node was validated
+    pub fn is_complete(&self) -> bool {
+        self.timeouts.is_empty() && !self.stopped_early
+    }
+
     pub fn nodes_prefixmap(&self) -> &PrefixMap {
         &self.nodes_pm
     }
@@ -64,8 +144,10 @@ impl ValidationReport {
         &self.shapes_pm
     }
 
+    /// Whether the data conforms. A report with timeouts does not conform
+    /// because some nodes could not be checked.
     pub fn conforms(&self) -> bool {
-        self.results.is_empty()
+        self.results.is_empty() && self.timeouts.is_empty() && self.omitted.is_empty()
     }
 
     pub fn get_count_of(&self, severity: &Severity) -> usize {
@@ -76,12 +158,20 @@ impl ValidationReport {
 impl ValidationReport {
     pub fn parse<S: FocusRDF>(store: &mut S, subject: S::Term) -> Result<Self, ValidationError> {
         let mut results = Vec::new();
+        let mut timeouts = Vec::new();
+        let timeout_component = Object::iri(ShaclVocab::sh_constraint_component());
 
         for result in store.objects_for(&subject, &ShaclVocab::sh_result().into())? {
-            results.push(ValidationResult::parse(store, &result)?);
+            let result = ValidationResult::parse(store, &result)?;
+            // Timeouts are written as results whose component is sh:ConstraintComponent itself
+            if result.constraint_component() == &timeout_component {
+                timeouts.push(result);
+            } else {
+                results.push(result);
+            }
         }
 
-        let mut report = Self::new().with_results(results);
+        let mut report = Self::new().with_results(results).with_timeouts(timeouts);
 
         if let Some(pm) = store.prefixmap() {
             report = report.with_prefixmap(pm);
@@ -104,7 +194,7 @@ impl ValidationReport {
         let conforms: RDF::IRI = ShaclVocab::sh_conforms().into();
         let result: RDF::IRI = ShaclVocab::sh_result().into();
 
-        if self.results.is_empty() {
+        if self.conforms() {
             let true_term: RDF::Term = Object::boolean(true).into();
             writer
                 .add_triple(report_node.clone(), conforms, true_term)
@@ -115,7 +205,7 @@ impl ValidationReport {
                 .add_triple(report_node.clone(), conforms, false_term)
                 .map_err(error_mapper::<RDF>("Error adding conforms to bnode"))?;
 
-            for vr in self.results.iter() {
+            for vr in self.results.iter().chain(self.timeouts.iter()) {
                 let result_node = writer
                     .add_bnode()
                     .map_err(error_mapper::<RDF>("Error creating bnode"))?;
@@ -142,13 +232,38 @@ impl Default for ValidationReport {
 
 impl PartialEq for ValidationReport {
     fn eq(&self, other: &Self) -> bool {
-        self.results.len() == other.results.len() && self.results.iter().all(|r| other.results.contains(r))
+        self.results.len() == other.results.len()
+            && self.results.iter().all(|r| other.results.contains(r))
+            && self.timeouts.len() == other.timeouts.len()
+            && self.timeouts.iter().all(|r| other.timeouts.contains(r))
+    }
+}
+
+/// Serialized as a JSON object with whether the data conforms and the lists of
+/// results and timed out focus nodes, followed by the counts of the omitted
+/// results and whether the validation stopped early, when there are any.
+impl Serialize for ValidationReport {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("conforms", &self.conforms())?;
+        map.serialize_entry("results", &self.results)?;
+        map.serialize_entry("timeouts", &self.timeouts)?;
+        if !self.omitted.is_empty() {
+            map.serialize_entry("omitted", &self.omitted)?;
+        }
+        if self.stopped_early {
+            map.serialize_entry("stoppedEarly", &true)?;
+        }
+        map.end()
     }
 }
 
 impl Display for ValidationReport {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        if self.results.is_empty() {
+        if self.conforms() {
             write!(f, "No Errors found")
         } else {
             writeln!(f, "{} errors found", self.results.len())?;
@@ -168,6 +283,33 @@ impl Display for ValidationReport {
                 )?;
             }
 
+            for omitted in self.omitted.iter() {
+                writeln!(
+                    f,
+                    "{} more results of {} {}",
+                    omitted.count(),
+                    self.shapes_pm.show(&omitted.source()),
+                    self.shapes_pm.show(omitted.constraint_component()),
+                )?;
+            }
+
+            if self.stopped_early {
+                writeln!(f, "Validation stopped after {} results", self.results.len())?;
+            }
+
+            if !self.timeouts.is_empty() {
+                writeln!(f, "{} nodes timed out", self.timeouts.len())?;
+                for timeout in self.timeouts.iter() {
+                    writeln!(
+                        f,
+                        " node: {} {}\n{}",
+                        self.nodes_pm.show(timeout.focus_node()),
+                        self.shapes_pm.show(&timeout.source()),
+                        timeout.message(),
+                    )?;
+                }
+            }
+
             Ok(())
         }
     }
@@ -0,0 +1,50 @@
+use rudof_rdf::rdf_core::term::Object;
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+
+/// Number of results of a source shape and constraint component that were left
+/// out of a report because of the caps per shape or per component.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OmittedResults {
+    source: Option<Object>,
+    constraint_component: Object,
+    count: usize,
+}
+
+impl OmittedResults {
+    pub fn new(source: Option<Object>, constraint_component: Object, count: usize) -> Self {
+        Self {
+            source,
+            constraint_component,
+            count,
+        }
+    }
+
+    pub fn source(&self) -> Option<&Object> {
+        self.source.as_ref()
+    }
+
+    pub fn constraint_component(&self) -> &Object {
+        &self.constraint_component
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+}
+
+/// Serialized as a JSON object with the names of the fields of an `sh:ValidationResult`
+impl Serialize for OmittedResults {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        if let Some(source) = &self.source {
+            map.serialize_entry("sourceShape", &source.to_string())?;
+        }
+        map.serialize_entry("sourceConstraintComponent", &self.constraint_component.to_string())?;
+        map.serialize_entry("count", &self.count)?;
+        map.end()
+    }
+}
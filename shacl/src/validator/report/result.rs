@@ -1,10 +1,15 @@
 use crate::error::ValidationError;
-use crate::types::{MessageMap, Severity};
+use crate::types::{MessageMap, Severity, message_path, message_term};
 use crate::validator::report::error_mapper;
+use prefixmap::PrefixMap;
 use rudof_iri::IriS;
 use rudof_rdf::rdf_core::term::Object;
+use rudof_rdf::rdf_core::term::literal::Lang;
+use rudof_rdf::rdf_core::utils::Interruption;
 use rudof_rdf::rdf_core::vocabs::{RdfVocab, ShaclVocab};
-use rudof_rdf::rdf_core::{BuildRDF, FocusRDF, SHACLPath};
+use rudof_rdf::rdf_core::{BuildRDF, FocusRDF, SHACLPath, SourceSpan};
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
 use std::fmt::{Display, Formatter};
 use std::hash::{Hash, Hasher};
 
@@ -19,8 +24,12 @@ pub struct ValidationResult {
     path: Option<SHACLPath>,
     value: Option<Object>,
     source: Option<Object>,
-    details: Option<Vec<Object>>,
+    details: Vec<ValidationResult>,
     message: MessageMap,
+
+    // Positions of the data that failed and of the source shape in the files they were read from
+    span: Option<SourceSpan>,
+    shape_span: Option<SourceSpan>,
 }
 
 impl ValidationResult {
@@ -33,11 +42,29 @@ impl ValidationResult {
             path: None,
             value: None,
             source: None,
-            details: None,
+            details: Vec::new(),
             message: Default::default(),
+            span: None,
+            shape_span: None,
         }
     }
 
+    /// Creates the result recorded for a focus node whose validation against
+    /// the shape `source` was interrupted before it finished
+    pub fn timeout(focus_node: Object, source: Object, interruption: Interruption) -> Self {
+        let message = match interruption {
+            Interruption::TimedOut => "Validation ran out of time before deciding",
+            Interruption::Cancelled => "Validation was cancelled before deciding",
+        };
+        Self::new(
+            focus_node,
+            Object::iri(ShaclVocab::sh_constraint_component()),
+            Severity::Info,
+        )
+        .with_source(Some(source))
+        .with_message(MessageMap::from(message))
+    }
+
     pub fn with_path(mut self, path: Option<SHACLPath>) -> Self {
         self.path = path;
         self
@@ -51,17 +78,77 @@ impl ValidationResult {
         self.source = source;
         self
     }
-    pub fn with_details(mut self, details: Option<Vec<Object>>) -> Self {
+    /// Sets the results that explain this one, as the results of the shapes
+    /// of an `sh:or` that the value did not conform to
+    pub fn with_details(mut self, details: Vec<ValidationResult>) -> Self {
         self.details = details;
         self
     }
+
+    /// Keeps the nested details down to `depth` levels, none with 0
+    pub fn with_detail_depth(mut self, depth: usize) -> Self {
+        self.details = match depth {
+            0 => Vec::new(),
+            depth => self
+                .details
+                .into_iter()
+                .map(|detail| detail.with_detail_depth(depth - 1))
+                .collect(),
+        };
+        self
+    }
     pub fn with_message(mut self, message: MessageMap) -> Self {
         self.message = message;
         self
     }
 
-    pub fn details(&self) -> Option<&Vec<Object>> {
-        self.details.as_ref()
+    /// Sets the message from a template, whose `{$this}`, `{$value}` and `{$path}`
+    /// placeholders are filled with the focus node, value and path of the result,
+    /// with their IRIs qualified by `prefixmap`, and the other ones with `parameters`
+    pub fn with_message_template(
+        mut self,
+        template: &MessageMap,
+        parameters: &[(&str, String)],
+        prefixmap: &PrefixMap,
+    ) -> Self {
+        let mut vars = vec![("this", message_term(&self.focus_node, prefixmap))];
+        if let Some(value) = &self.value {
+            vars.push(("value", message_term(value, prefixmap)));
+        }
+        if let Some(path) = &self.path {
+            vars.push(("path", message_path(path, prefixmap)));
+        }
+        vars.extend(parameters.iter().cloned());
+        self.message = template.render(&vars);
+        self
+    }
+
+    /// Keeps only the message in the preferred language, as [`MessageMap::preferred`] does
+    pub fn with_message_languages(mut self, langs: &[Lang]) -> Self {
+        self.message = self.message.preferred(langs);
+        self.details = self
+            .details
+            .into_iter()
+            .map(|detail| detail.with_message_languages(langs))
+            .collect();
+        self
+    }
+
+    /// Sets where the data that failed was read from, which is the triple with
+    /// the value when the path is a predicate, or else the focus node
+    pub fn with_span(mut self, span: Option<SourceSpan>) -> Self {
+        self.span = span;
+        self
+    }
+
+    /// Sets where the source shape was read from
+    pub fn with_shape_span(mut self, shape_span: Option<SourceSpan>) -> Self {
+        self.shape_span = shape_span;
+        self
+    }
+
+    pub fn details(&self) -> &[ValidationResult] {
+        &self.details
     }
 
     pub fn constraint_component(&self) -> &Object {
@@ -91,6 +178,14 @@ impl ValidationResult {
     pub fn severity(&self) -> &Severity {
         &self.severity
     }
+
+    pub fn span(&self) -> Option<&SourceSpan> {
+        self.span.as_ref()
+    }
+
+    pub fn shape_span(&self) -> Option<&SourceSpan> {
+        self.shape_span.as_ref()
+    }
 }
 
 impl ValidationResult {
@@ -130,10 +225,24 @@ impl ValidationResult {
 
         let value = store.object_for(validation_result, &ShaclVocab::sh_value().into())?;
 
+        let mut message = MessageMap::new();
+        for term in store.objects_for(validation_result, &ShaclVocab::sh_result_message().into())? {
+            if let Object::Literal(literal) = S::term_as_object(&term)? {
+                message = message.with_message(literal.lang(), literal.lexical_form());
+            }
+        }
+
+        let mut details = Vec::new();
+        for detail in store.objects_for(validation_result, &ShaclVocab::sh_detail().into())? {
+            details.push(ValidationResult::parse(store, &detail)?);
+        }
+
         Ok(ValidationResult::new(focus_node, constraint_component, severity)
             .with_path(path)
             .with_source(source)
-            .with_value(value))
+            .with_value(value)
+            .with_message(message)
+            .with_details(details))
     }
 
     pub fn to_rdf<RDF: BuildRDF + Sized>(
@@ -193,6 +302,18 @@ impl ValidationResult {
                 .map_err(error_mapper::<RDF>("Error adding value to validation result"))?;
         }
 
+        for detail in &self.details {
+            let detail_node: RDF::Subject = writer
+                .add_bnode()
+                .map_err(error_mapper::<RDF>("Error creating bnode for detail"))?
+                .into();
+            let term: RDF::Term = detail_node.clone().into();
+            writer
+                .add_triple(report_node.clone(), ShaclVocab::sh_detail(), term)
+                .map_err(error_mapper::<RDF>("Error adding detail to validation result"))?;
+            detail.to_rdf(writer, detail_node)?;
+        }
+
         Ok(())
     }
 }
@@ -280,6 +401,46 @@ impl Display for ValidationResult {
     }
 }
 
+/// Serialized as a JSON object whose keys are the local names of the
+/// `sh:ValidationResult` properties, with the terms written as strings.
+impl Serialize for ValidationResult {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        map.serialize_entry("focusNode", &self.focus_node.to_string())?;
+        map.serialize_entry("resultSeverity", &self.severity.to_string())?;
+        map.serialize_entry("sourceConstraintComponent", &self.constraint_component.to_string())?;
+        if let Some(path) = &self.path {
+            map.serialize_entry("resultPath", &path.to_string())?;
+        }
+        if let Some(value) = &self.value {
+            map.serialize_entry("value", &value.to_string())?;
+        }
+        if let Some(source) = &self.source {
+            map.serialize_entry("sourceShape", &source.to_string())?;
+        }
+        if let Some(span) = &self.span {
+            map.serialize_entry("location", span)?;
+        }
+        if let Some(shape_span) = &self.shape_span {
+            map.serialize_entry("shapeLocation", shape_span)?;
+        }
+        let mut messages: Vec<_> = self.message.iter().collect();
+        messages.sort_by_key(|(lang, _)| lang.as_ref().map(|lang| lang.to_string()));
+        if let Some((_, message)) = messages.first() {
+            map.serialize_entry("resultMessage", message)?;
+        }
+        if !self.details.is_empty() {
+            map.serialize_entry("detail", &self.details)?;
+        }
+        map.end()
+    }
+}
+
+/// Results are compared without their messages and `sh:detail` results, which
+/// conformance tests don't check
 impl PartialEq for ValidationResult {
     fn eq(&self, other: &Self) -> bool {
         self.focus_node == other.focus_node
@@ -288,7 +449,6 @@ impl PartialEq for ValidationResult {
             && self.path == other.path
             && self.value == other.value
             && self.source == other.source
-            && self.details == other.details
     }
 }
 
@@ -300,7 +460,6 @@ impl Hash for ValidationResult {
         self.path.hash(state);
         self.value.hash(state);
         self.source.hash(state);
-        self.details.hash(state);
     }
 }
 
@@ -328,6 +487,31 @@ mod tests {
         SHACLPath::iri(IriS::new_unchecked(&format!("http://example.org/{local}")))
     }
 
+    #[test]
+    fn details_round_trip_through_rdf() {
+        let iri = |local: &str| Object::iri(IriS::new_unchecked(&format!("http://example.org/{local}")));
+        let detail = ValidationResult::new(
+            iri("alice"),
+            Object::iri(ShaclVocab::sh_min_count_constraint_component()),
+            Severity::Violation,
+        )
+        .with_path(Some(pred("name")));
+        let result = ValidationResult::new(
+            iri("alice"),
+            Object::iri(ShaclVocab::sh_or_constraint_component()),
+            Severity::Violation,
+        )
+        .with_details(vec![detail.clone()]);
+
+        let mut graph = OxigraphInMemory::empty();
+        let node = graph.add_bnode().unwrap();
+        result.to_rdf(&mut graph, node.clone().into()).unwrap();
+        let subject: <OxigraphInMemory as Rdf>::Term = node.into();
+        let parsed = ValidationResult::parse(&mut graph, &subject).unwrap();
+        assert_eq!(parsed.details(), &[detail]);
+        assert!(result.with_detail_depth(0).details().is_empty());
+    }
+
     #[test]
     fn path_to_rdf_predicate() {
         assert_path_round_trip(pred("knows"));
@@ -0,0 +1,574 @@
+//! Statistics about how groups of nodes use their predicates.
+//!
+//! A [`DataProfile`] is the input of the schema inference converters: it
+//! records, for every group of nodes, which predicates appear, how many
+//! values each node has and which kinds of values they take.
+use rudof_iri::IriS;
+use rudof_rdf::rdf_core::{
+    NeighsRDF,
+    term::{IriOrBlankNode, Object, Triple, literal::ConcreteLiteral},
+    vocabs::{RdfVocab, XsdVocab},
+};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum DataProfileError {
+    #[error("Error obtaining triples from RDF graph: {error}")]
+    Triples { error: String },
+
+    #[error("Unexpected RDF term in {position} position")]
+    UnexpectedTerm { position: String },
+}
+
+/// A set of nodes that will be described by the same inferred shape
+#[derive(Debug, Clone, PartialEq)]
+pub struct NodeGroup {
+    label: IriS,
+    class: Option<IriS>,
+    nodes: Vec<Object>,
+}
+
+impl NodeGroup {
+    pub fn new(label: IriS, nodes: Vec<Object>) -> Self {
+        NodeGroup {
+            label,
+            class: None,
+            nodes,
+        }
+    }
+
+    /// Sets the class shared by the nodes of the group
+    pub fn with_class(mut self, class: Option<IriS>) -> Self {
+        self.class = class;
+        self
+    }
+
+    pub fn label(&self) -> &IriS {
+        &self.label
+    }
+
+    pub fn class(&self) -> Option<&IriS> {
+        self.class.as_ref()
+    }
+
+    pub fn nodes(&self) -> &[Object] {
+        &self.nodes
+    }
+}
+
+/// Kind of the values of a predicate
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValueKind {
+    /// Literal with the given datatype (other than `rdf:langString`)
+    Datatype(IriS),
+    /// Language-tagged string
+    LangString,
+    /// IRI or blank node belonging to the group with the given label
+    Shape(IriS),
+    /// IRI that does not belong to any group
+    Iri,
+    /// Blank node that does not belong to any group
+    BNode,
+    /// Triple term
+    Triple,
+}
+
+impl ValueKind {
+    pub fn is_literal(&self) -> bool {
+        matches!(self, ValueKind::Datatype(_) | ValueKind::LangString)
+    }
+
+    pub fn is_iri(&self) -> bool {
+        matches!(self, ValueKind::Iri) || matches!(self, ValueKind::Shape(_))
+    }
+}
+
+/// Usage of a predicate by the nodes of a group
+#[derive(Debug, Clone, PartialEq)]
+pub struct PredicateProfile {
+    predicate: IriS,
+    subjects: usize,
+    min_count: usize,
+    max_count: usize,
+    triples: usize,
+    kinds: BTreeMap<ValueKind, usize>,
+    values: HashMap<Object, usize>,
+    values_overflow: bool,
+    strings: usize,
+    patterns: HashMap<String, usize>,
+    patterns_overflow: bool,
+}
+
+impl PredicateProfile {
+    fn new(predicate: &IriS) -> Self {
+        PredicateProfile {
+            predicate: predicate.clone(),
+            subjects: 0,
+            min_count: usize::MAX,
+            max_count: 0,
+            triples: 0,
+            kinds: BTreeMap::new(),
+            values: HashMap::new(),
+            values_overflow: false,
+            strings: 0,
+            patterns: HashMap::new(),
+            patterns_overflow: false,
+        }
+    }
+
+    pub fn predicate(&self) -> &IriS {
+        &self.predicate
+    }
+
+    /// Number of nodes of the group that have at least one value
+    pub fn subjects(&self) -> usize {
+        self.subjects
+    }
+
+    /// Minimum number of values among the nodes that have the predicate
+    pub fn min_count(&self) -> usize {
+        self.min_count
+    }
+
+    /// Maximum number of values of a node
+    pub fn max_count(&self) -> usize {
+        self.max_count
+    }
+
+    /// Total number of triples with this predicate
+    pub fn triples(&self) -> usize {
+        self.triples
+    }
+
+    /// Number of values of each kind
+    pub fn kinds(&self) -> &BTreeMap<ValueKind, usize> {
+        &self.kinds
+    }
+
+    /// Most frequent kind of value with its number of occurrences
+    pub fn dominant_kind(&self) -> Option<(&ValueKind, usize)> {
+        // max_by_key returns the last maximum, so ties are resolved in favour of the first kind
+        self.kinds
+            .iter()
+            .rev()
+            .max_by_key(|(_, n)| **n)
+            .map(|(kind, n)| (kind, *n))
+    }
+
+    /// Distinct values with their number of occurrences.
+    ///
+    /// Returns `None` when there were more distinct values than the limit used to build the profile.
+    pub fn values(&self) -> Option<&HashMap<Object, usize>> {
+        if self.values_overflow { None } else { Some(&self.values) }
+    }
+
+    /// Number of `xsd:string` values
+    pub fn strings(&self) -> usize {
+        self.strings
+    }
+
+    /// Most frequent regular expression among the ones that describe the
+    /// `xsd:string` values, with the number of values it describes.
+    ///
+    /// The expression of a value replaces each run of ASCII digits, uppercase
+    /// letters, lowercase letters or whitespace by a repeated character class,
+    /// so both `"AB-12"` and `"X-3"` give `^[A-Z]+-[0-9]+$`.
+    /// Returns `None` when the values follow too many different expressions.
+    pub fn dominant_pattern(&self) -> Option<(&str, usize)> {
+        if self.patterns_overflow {
+            return None;
+        }
+        self.patterns
+            .iter()
+            .max_by(|(p1, n1), (p2, n2)| n1.cmp(n2).then_with(|| p2.cmp(p1)))
+            .map(|(pattern, n)| (pattern.as_str(), *n))
+    }
+
+    fn add_node_values(&mut self, values: &[&Object], groups: &HashMap<&Object, &IriS>, value_limit: usize) {
+        self.subjects += 1;
+        self.triples += values.len();
+        self.min_count = self.min_count.min(values.len());
+        self.max_count = self.max_count.max(values.len());
+        for value in values {
+            let kind = value_kind(value, groups);
+            if let (ValueKind::Datatype(dt), Object::Literal(lit)) = (&kind, value)
+                && dt == XsdVocab::xsd_string_ref()
+            {
+                self.add_string(&lit.lexical_form(), value_limit);
+            }
+            *self.kinds.entry(kind).or_default() += 1;
+            if !self.values_overflow {
+                *self.values.entry((*value).clone()).or_default() += 1;
+                if self.values.len() > value_limit {
+                    self.values_overflow = true;
+                    self.values.clear();
+                }
+            }
+        }
+    }
+}
+
+impl PredicateProfile {
+    fn add_string(&mut self, str: &str, value_limit: usize) {
+        self.strings += 1;
+        if self.patterns_overflow {
+            return;
+        }
+        *self.patterns.entry(string_pattern(str)).or_default() += 1;
+        if self.patterns.len() > value_limit.max(MIN_PATTERNS) {
+            self.patterns_overflow = true;
+            self.patterns.clear();
+        }
+    }
+}
+
+/// Usage of predicates by the nodes of a group
+#[derive(Debug, Clone, PartialEq)]
+pub struct GroupProfile {
+    label: IriS,
+    class: Option<IriS>,
+    nodes: usize,
+    predicates: Vec<PredicateProfile>,
+}
+
+impl GroupProfile {
+    pub fn label(&self) -> &IriS {
+        &self.label
+    }
+
+    pub fn class(&self) -> Option<&IriS> {
+        self.class.as_ref()
+    }
+
+    /// Number of nodes in the group
+    pub fn nodes(&self) -> usize {
+        self.nodes
+    }
+
+    /// Predicates used by the nodes of the group, sorted by IRI
+    pub fn predicates(&self) -> &[PredicateProfile] {
+        &self.predicates
+    }
+}
+
+/// Usage of predicates by several groups of nodes of an RDF graph
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct DataProfile {
+    groups: Vec<GroupProfile>,
+}
+
+impl DataProfile {
+    /// Builds the profile of the given groups.
+    ///
+    /// At most `value_limit` distinct values are kept for each predicate. When
+    /// `sample_size` is given, only that many nodes of each group, evenly spaced,
+    /// are profiled, while all nodes are still used to recognize references to a group.
+    pub fn new<RDF: NeighsRDF>(
+        rdf: &RDF,
+        groups: &[NodeGroup],
+        value_limit: usize,
+        sample_size: Option<usize>,
+    ) -> Result<Self, DataProfileError> {
+        // A node that belongs to several groups is referenced through the first one
+        let mut node_groups: HashMap<&Object, &IriS> = HashMap::new();
+        for group in groups {
+            for node in group.nodes() {
+                node_groups.entry(node).or_insert(group.label());
+            }
+        }
+
+        let mut profiles = Vec::new();
+        for group in groups {
+            let nodes = sample(group.nodes(), sample_size);
+            let mut predicates: BTreeMap<IriS, PredicateProfile> = BTreeMap::new();
+            for node in nodes.iter() {
+                for (p, values) in outgoing_arcs(rdf, node)? {
+                    let values: Vec<&Object> = values.iter().collect();
+                    predicates
+                        .entry(p)
+                        .or_insert_with_key(PredicateProfile::new)
+                        .add_node_values(&values, &node_groups, value_limit);
+                }
+            }
+            profiles.push(GroupProfile {
+                label: group.label().clone(),
+                class: group.class().cloned(),
+                nodes: nodes.len(),
+                predicates: predicates.into_values().collect(),
+            });
+        }
+        Ok(DataProfile { groups: profiles })
+    }
+
+    /// Groups the nodes of an RDF graph by the IRIs they are related to through `type_predicate`.
+    ///
+    /// The label of each group is obtained from its class with `label_for`.
+    /// Groups are sorted by class and nodes by their string representation.
+    pub fn groups_by_type<RDF, F>(
+        rdf: &RDF,
+        type_predicate: &IriS,
+        label_for: F,
+    ) -> Result<Vec<NodeGroup>, DataProfileError>
+    where
+        RDF: NeighsRDF,
+        F: FnMut(&IriS) -> IriS,
+    {
+        let mut label_for = label_for;
+        let mut classes: BTreeMap<IriS, Vec<Object>> = BTreeMap::new();
+        let predicate: RDF::IRI = type_predicate.clone().into();
+        for triple in rdf
+            .triples_with_predicate(&predicate)
+            .map_err(|e| DataProfileError::Triples { error: e.to_string() })?
+        {
+            let (s, _, o) = triple.into_components();
+            if let Ok(Object::Iri(class)) = o.try_into() {
+                let nodes = classes.entry(class).or_default();
+                let subject = subject2object::<RDF>(s)?;
+                if !nodes.contains(&subject) {
+                    nodes.push(subject);
+                }
+            }
+        }
+        Ok(classes
+            .into_iter()
+            .map(|(class, mut nodes)| {
+                // Sorting the nodes makes the groups independent of the order of the graph
+                nodes.sort_by_cached_key(|n| n.to_string());
+                NodeGroup::new(label_for(&class), nodes).with_class(Some(class))
+            })
+            .collect())
+    }
+
+    pub fn groups(&self) -> &[GroupProfile] {
+        &self.groups
+    }
+}
+
+/// IRI in the `base` namespace with the local name of `iri`.
+///
+/// A numeric suffix is appended when the IRI is already in `used`, to which the result is added.
+pub fn local_label(base: &IriS, iri: &IriS, used: &mut HashSet<IriS>) -> IriS {
+    let str = iri.as_str();
+    let local = str.rsplit(['/', '#']).next().unwrap_or(str);
+    let local = if local.is_empty() { "Shape" } else { local };
+    let base = base.as_str();
+    let mut label = IriS::new_unchecked(format!("{base}{local}").as_str());
+    let mut n = 2;
+    while used.contains(&label) {
+        label = IriS::new_unchecked(format!("{base}{local}_{n}").as_str());
+        n += 1;
+    }
+    used.insert(label.clone());
+    label
+}
+
+/// Least number of distinct patterns kept for each predicate, whatever the value limit
+const MIN_PATTERNS: usize = 5;
+
+/// Evenly spaced sample of at most `size` nodes
+fn sample(nodes: &[Object], size: Option<usize>) -> Vec<&Object> {
+    match size {
+        Some(size) if size < nodes.len() => (0..size).map(|i| &nodes[i * nodes.len() / size]).collect(),
+        _ => nodes.iter().collect(),
+    }
+}
+
+/// Regular expression that describes a string, see [`PredicateProfile::dominant_pattern`]
+fn string_pattern(str: &str) -> String {
+    let mut pattern = String::from("^");
+    let mut last_class = None;
+    for c in str.chars() {
+        let class = if c.is_ascii_digit() {
+            Some("[0-9]+")
+        } else if c.is_ascii_uppercase() {
+            Some("[A-Z]+")
+        } else if c.is_ascii_lowercase() {
+            Some("[a-z]+")
+        } else if c.is_whitespace() {
+            Some("\\s+")
+        } else {
+            None
+        };
+        match class {
+            Some(class) if last_class != Some(class) => pattern.push_str(class),
+            Some(_) => {},
+            None => {
+                if "\\.^$|?*+()[]{}".contains(c) {
+                    pattern.push('\\');
+                }
+                pattern.push(c);
+            },
+        }
+        last_class = class;
+    }
+    pattern.push('$');
+    pattern
+}
+
+fn value_kind(value: &Object, groups: &HashMap<&Object, &IriS>) -> ValueKind {
+    if let Some(label) = groups.get(value) {
+        return ValueKind::Shape((*label).clone());
+    }
+    match value {
+        Object::Iri(_) => ValueKind::Iri,
+        Object::BlankNode(_) => ValueKind::BNode,
+        Object::Literal(ConcreteLiteral::StringLiteral { lang: Some(_), .. }) => ValueKind::LangString,
+        Object::Literal(lit) => {
+            let datatype = lit.datatype();
+            match datatype.get_iri() {
+                Ok(iri) if iri == RdfVocab::rdf_lang_string_ref() => ValueKind::LangString,
+                Ok(iri) => ValueKind::Datatype(iri.clone()),
+                Err(_) => ValueKind::Datatype(IriS::new_unchecked(datatype.to_string().as_str())),
+            }
+        },
+        Object::Triple { .. } => ValueKind::Triple,
+    }
+}
+
+/// Values of each predicate of a node, sorted to make the profile independent of the order of the graph
+fn outgoing_arcs<RDF: NeighsRDF>(rdf: &RDF, node: &Object) -> Result<BTreeMap<IriS, Vec<Object>>, DataProfileError> {
+    let mut arcs: BTreeMap<IriS, Vec<Object>> = BTreeMap::new();
+    // Literals and triple terms have no outgoing arcs
+    let Ok(subject) = RDF::Subject::try_from(node.clone()) else {
+        return Ok(arcs);
+    };
+    for triple in rdf
+        .triples_with_subject(&subject)
+        .map_err(|e| DataProfileError::Triples { error: e.to_string() })?
+    {
+        let (_, p, o) = triple.into_components();
+        let object: Object = o.try_into().map_err(|_| DataProfileError::UnexpectedTerm {
+            position: "object".to_string(),
+        })?;
+        arcs.entry(p.into()).or_default().push(object);
+    }
+    for values in arcs.values_mut() {
+        values.sort_by_cached_key(|v| v.to_string());
+    }
+    Ok(arcs)
+}
+
+fn subject2object<RDF: NeighsRDF>(subject: RDF::Subject) -> Result<Object, DataProfileError> {
+    match subject.try_into() {
+        Ok(IriOrBlankNode::Iri(iri)) => Ok(Object::Iri(iri)),
+        Ok(IriOrBlankNode::BlankNode(b)) => Ok(Object::BlankNode(b)),
+        Err(_) => Err(DataProfileError::UnexpectedTerm {
+            position: "subject".to_string(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rudof_rdf::rdf_core::RDFFormat;
+    use sparql_service::RdfData;
+
+    fn graph(data: &str) -> RdfData {
+        RdfData::from_str(data, &RDFFormat::Turtle, None, &Default::default()).unwrap()
+    }
+
+    fn iri(s: &str) -> IriS {
+        IriS::new_unchecked(s)
+    }
+
+    #[test]
+    fn profile_counts_values_per_group() {
+        let rdf = graph(
+            r#"prefix : <http://example.org/>
+               :alice a :Person ; :name "Alice" ; :knows :bob, :carol .
+               :bob a :Person ; :name "Bob" .
+               :carol a :Person ; :name "Carol"@en ; :age 23 ."#,
+        );
+        let groups = DataProfile::groups_by_type(&rdf, &RdfVocab::rdf_type(), |c| c.clone()).unwrap();
+        assert_eq!(groups.len(), 1);
+        let profile = DataProfile::new(&rdf, &groups, 10, None).unwrap();
+        let person = &profile.groups()[0];
+        assert_eq!(person.nodes(), 3);
+
+        let name = person
+            .predicates()
+            .iter()
+            .find(|p| p.predicate() == &iri("http://example.org/name"))
+            .unwrap();
+        assert_eq!(name.subjects(), 3);
+        assert_eq!(name.kinds()[&ValueKind::LangString], 1);
+        assert_eq!(
+            name.dominant_kind(),
+            Some((&ValueKind::Datatype(iri("http://www.w3.org/2001/XMLSchema#string")), 2))
+        );
+
+        let knows = person
+            .predicates()
+            .iter()
+            .find(|p| p.predicate() == &iri("http://example.org/knows"))
+            .unwrap();
+        assert_eq!((knows.subjects(), knows.min_count(), knows.max_count()), (1, 2, 2));
+        assert_eq!(
+            knows.dominant_kind(),
+            Some((&ValueKind::Shape(iri("http://example.org/Person")), 2))
+        );
+    }
+
+    #[test]
+    fn values_are_dropped_over_the_limit() {
+        let rdf = graph(
+            r#"prefix : <http://example.org/>
+               :a a :T ; :p 1 . :b a :T ; :p 2 . :c a :T ; :p 2 ."#,
+        );
+        let groups = DataProfile::groups_by_type(&rdf, &RdfVocab::rdf_type(), |c| c.clone()).unwrap();
+        let p = iri("http://example.org/p");
+        let profile = DataProfile::new(&rdf, &groups, 2, None).unwrap();
+        let pp = profile.groups()[0]
+            .predicates()
+            .iter()
+            .find(|pp| pp.predicate() == &p)
+            .unwrap();
+        assert_eq!(pp.values().map(|v| v.len()), Some(2));
+        let profile = DataProfile::new(&rdf, &groups, 1, None).unwrap();
+        let pp = profile.groups()[0]
+            .predicates()
+            .iter()
+            .find(|pp| pp.predicate() == &p)
+            .unwrap();
+        assert!(pp.values().is_none());
+    }
+
+    #[test]
+    fn strings_give_patterns() {
+        let rdf = graph(
+            r#"prefix : <http://example.org/>
+               :a a :T ; :code "AB-12" . :b a :T ; :code "X-3" . :c a :T ; :code "q 4.5" ."#,
+        );
+        let groups = DataProfile::groups_by_type(&rdf, &RdfVocab::rdf_type(), |c| c.clone()).unwrap();
+        let profile = DataProfile::new(&rdf, &groups, 10, None).unwrap();
+        let code = &profile.groups()[0].predicates()[0];
+        assert_eq!(code.strings(), 3);
+        assert_eq!(code.dominant_pattern(), Some(("^[A-Z]+-[0-9]+$", 2)));
+        assert_eq!(string_pattern("q  4.5"), "^[a-z]+\\s+[0-9]+\\.[0-9]+$");
+    }
+
+    #[test]
+    fn sampling_profiles_a_subset_of_nodes() {
+        let rdf = graph(
+            r#"prefix : <http://example.org/>
+               :a a :T ; :q :b . :b a :T ; :p 1 . :c a :T ; :q :d . :d a :T ; :p 2 ."#,
+        );
+        let groups = DataProfile::groups_by_type(&rdf, &RdfVocab::rdf_type(), |c| c.clone()).unwrap();
+        let profile = DataProfile::new(&rdf, &groups, 10, Some(2)).unwrap();
+        let group = &profile.groups()[0];
+        assert_eq!(group.nodes(), 2);
+        // The sample is :a and :c, whose values are out of the sample but still members of the group
+        assert_eq!(group.predicates().len(), 2);
+        let q = group
+            .predicates()
+            .iter()
+            .find(|pp| pp.predicate() == &iri("http://example.org/q"))
+            .unwrap();
+        assert_eq!(
+            q.dominant_kind(),
+            Some((&ValueKind::Shape(iri("http://example.org/T")), 2))
+        );
+    }
+}
@@ -2,7 +2,13 @@
 //!
 //!
 pub mod converter_config;
+pub mod data_profile;
 pub mod landing_html_template;
+pub mod pg_to_rdf;
+pub mod rdf_to_mie;
+pub mod rdf_to_pg;
+pub mod rdf_to_shacl;
+pub mod rdf_to_shex;
 pub mod service_to_mie;
 pub mod shacl_to_shex;
 pub mod shex_to_html;
@@ -17,6 +23,22 @@ use shex_ast::Annotation;
 use shex_ast::ObjectValue;
 
 pub use crate::converter_config::*;
+pub use crate::data_profile::*;
+pub use crate::pg_to_rdf::pg2rdf::*;
+pub use crate::pg_to_rdf::pg2rdf_config::*;
+pub use crate::pg_to_rdf::pg2rdf_error::*;
+pub use crate::rdf_to_mie::rdf2mie::*;
+pub use crate::rdf_to_mie::rdf2mie_config::*;
+pub use crate::rdf_to_mie::rdf2mie_error::*;
+pub use crate::rdf_to_pg::rdf2pg::*;
+pub use crate::rdf_to_pg::rdf2pg_config::*;
+pub use crate::rdf_to_pg::rdf2pg_error::*;
+pub use crate::rdf_to_shacl::rdf2shacl::*;
+pub use crate::rdf_to_shacl::rdf2shacl_config::*;
+pub use crate::rdf_to_shacl::rdf2shacl_error::*;
+pub use crate::rdf_to_shex::rdf2shex::*;
+pub use crate::rdf_to_shex::rdf2shex_config::*;
+pub use crate::rdf_to_shex::rdf2shex_error::*;
 pub use crate::service_to_mie::service2mie::*;
 pub use crate::shacl_to_shex::shacl2shex::*;
 pub use crate::shacl_to_shex::shacl2shex_config::*;
@@ -0,0 +1,10 @@
+//! Property graphs to RDF
+//!
+//!
+pub mod pg2rdf;
+pub mod pg2rdf_config;
+pub mod pg2rdf_error;
+
+pub use pg2rdf::*;
+pub use pg2rdf_config::*;
+pub use pg2rdf_error::*;
@@ -0,0 +1,210 @@
+use super::{Pg2RdfConfig, Pg2RdfError};
+use pgschema::{edge::Edge, node_id::NodeId, pg::PropertyGraph, record::Record, value::Value};
+use prefixmap::IriRef;
+use rudof_iri::IriS;
+use rudof_rdf::rdf_core::{
+    BuildRDF,
+    term::{BlankNode, IriOrBlankNode, Object, literal::ConcreteLiteral},
+    vocabs::{RdfVocab, XsdVocab},
+};
+
+/// Converts property graphs into RDF graphs.
+///
+/// Nodes become IRIs minted from their names, node labels become classes,
+/// properties become triples with literal objects and edges become triples
+/// between the source and target nodes. Edge properties are attached to an
+/// RDF 1.2 reifier of the edge triple.
+pub struct Pg2Rdf {
+    config: Pg2RdfConfig,
+}
+
+impl Pg2Rdf {
+    pub fn new(config: &Pg2RdfConfig) -> Pg2Rdf {
+        Pg2Rdf { config: config.clone() }
+    }
+
+    /// Converts a property graph into a new RDF graph
+    pub fn convert<RDF: BuildRDF>(&self, pg: &PropertyGraph) -> Result<RDF, Pg2RdfError> {
+        let mut rdf = RDF::empty();
+        self.add_to(pg, &mut rdf)?;
+        Ok(rdf)
+    }
+
+    /// Adds the triples that represent a property graph to an existing RDF graph
+    pub fn add_to<RDF: BuildRDF>(&self, pg: &PropertyGraph, rdf: &mut RDF) -> Result<(), Pg2RdfError> {
+        rdf.merge_prefixes(self.config.prefixmap());
+        for (name, node) in pg.nodes() {
+            let subject = self.node_iri(name);
+            if self.config.labels_as_classes() {
+                for label in node.labels().iter() {
+                    let class = mint(self.config.label_base_iri(), label);
+                    rdf.add_type(subject.clone(), class).map_err(add_error)?;
+                }
+            }
+            self.add_record(rdf, &IriOrBlankNode::Iri(subject), node.content())?;
+        }
+        for (name, edge) in pg.edges() {
+            self.add_edge(rdf, pg, name, edge)?;
+        }
+        Ok(())
+    }
+
+    fn add_edge<RDF: BuildRDF>(
+        &self,
+        rdf: &mut RDF,
+        pg: &PropertyGraph,
+        name: Option<&str>,
+        edge: &Edge,
+    ) -> Result<(), Pg2RdfError> {
+        let source = self.node_iri(node_name(pg, edge.source())?);
+        let target = self.node_iri(node_name(pg, edge.target())?);
+        let predicates: Vec<IriS> = if edge.labels().is_empty() {
+            vec![mint(self.config.property_base_iri(), self.config.default_edge_label())]
+        } else {
+            edge.labels()
+                .iter()
+                .map(|label| mint(self.config.property_base_iri(), label))
+                .collect()
+        };
+        for predicate in predicates.iter() {
+            rdf.add_triple(source.clone(), predicate.clone(), target.clone())
+                .map_err(add_error)?;
+        }
+        let has_properties = edge.content().iter().next().is_some();
+        if !self.config.edge_properties_as_reifiers() || (!has_properties && name.is_none()) {
+            return Ok(());
+        }
+        let reifier = match name {
+            Some(name) => IriOrBlankNode::Iri(mint(self.config.edge_base_iri(), name)),
+            None => IriOrBlankNode::BlankNode(rdf.add_bnode().map_err(add_error)?.id().to_string()),
+        };
+        for predicate in predicates {
+            let triple = Object::Triple {
+                subject: Box::new(IriOrBlankNode::Iri(source.clone())),
+                predicate,
+                object: Box::new(Object::Iri(target.clone())),
+            };
+            rdf.add_triple(reifier.clone(), RdfVocab::rdf_reifies(), triple)
+                .map_err(add_error)?;
+        }
+        self.add_record(rdf, &reifier, edge.content())
+    }
+
+    fn add_record<RDF: BuildRDF>(
+        &self,
+        rdf: &mut RDF,
+        subject: &IriOrBlankNode,
+        record: &Record,
+    ) -> Result<(), Pg2RdfError> {
+        for (key, values) in record.iter() {
+            let predicate = mint(self.config.property_base_iri(), key.str());
+            for value in values {
+                rdf.add_triple(
+                    subject.clone(),
+                    predicate.clone(),
+                    Object::Literal(value2literal(value)),
+                )
+                .map_err(add_error)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn node_iri(&self, name: &str) -> IriS {
+        mint(self.config.node_base_iri(), name)
+    }
+}
+
+fn node_name<'a>(pg: &'a PropertyGraph, id: &NodeId) -> Result<&'a str, Pg2RdfError> {
+    pg.node_name(id)
+        .ok_or_else(|| Pg2RdfError::MissingNodeName { node: id.to_string() })
+}
+
+fn value2literal(value: &Value) -> ConcreteLiteral {
+    match value {
+        Value::String(s) => ConcreteLiteral::str(s),
+        Value::Integer(n) => ConcreteLiteral::integer(*n as i128),
+        Value::Bool(b) => ConcreteLiteral::boolean(*b),
+        Value::Date(d) => ConcreteLiteral::lit_datatype(&d.to_string(), &IriRef::iri(XsdVocab::xsd_date())),
+    }
+}
+
+/// Mints an IRI appending the percent-encoded name to a namespace
+fn mint(base: &IriS, name: &str) -> IriS {
+    base.extend_unchecked(&encode_local_name(name))
+}
+
+fn encode_local_name(name: &str) -> String {
+    let mut result = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_alphanumeric() || matches!(c, '-' | '_' | '.' | '~') {
+            result.push(c)
+        } else {
+            let mut buf = [0; 4];
+            for b in c.encode_utf8(&mut buf).bytes() {
+                result.push_str(&format!("%{b:02X}"))
+            }
+        }
+    }
+    result
+}
+
+fn add_error<E: std::fmt::Display>(e: E) -> Pg2RdfError {
+    Pg2RdfError::AddingTriple { error: e.to_string() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pgschema::parser::pg_builder::PgBuilder;
+    use rudof_rdf::rdf_core::{NeighsRDF, RDFFormat};
+    use sparql_service::RdfData;
+
+    fn convert(str: &str, config: &Pg2RdfConfig) -> RdfData {
+        let pg = PgBuilder::new().parse_pg(str).unwrap();
+        Pg2Rdf::new(config).convert(&pg).unwrap()
+    }
+
+    #[test]
+    fn test_nodes_labels_and_properties() {
+        let rdf = convert(
+            r#"(alice {Person} [ name: "Alice", age: 23, birth: DATE "2000-01-02", active: TRUE ])"#,
+            &Pg2RdfConfig::default(),
+        );
+        let mut out = Vec::new();
+        rdf.serialize(&RDFFormat::NTriples, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains(
+            "<http://example.org/pg/node/alice> <http://www.w3.org/1999/02/22-rdf-syntax-ns#type> <http://example.org/pg/label/Person>"
+        ));
+        assert!(out.contains("<http://example.org/pg/property/name> \"Alice\""));
+        assert!(out.contains("\"23\"^^<http://www.w3.org/2001/XMLSchema#integer>"));
+        assert!(out.contains("\"2000-01-02\"^^<http://www.w3.org/2001/XMLSchema#date>"));
+        assert!(out.contains("\"true\"^^<http://www.w3.org/2001/XMLSchema#boolean>"));
+        assert_eq!(rdf.triples().unwrap().count(), 5);
+    }
+
+    #[test]
+    fn test_edge_properties_as_reifiers() {
+        let str = r#"(alice {Person}) (bob {Person}) (alice) -(e1 {knows} [ since: 2020 ])-> (bob)"#;
+        let rdf = convert(str, &Pg2RdfConfig::default());
+        let mut out = Vec::new();
+        rdf.serialize(&RDFFormat::NTriples, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains(
+            "<http://example.org/pg/node/alice> <http://example.org/pg/property/knows> <http://example.org/pg/node/bob>"
+        ));
+        assert!(out.contains("<http://example.org/pg/edge/e1> <http://www.w3.org/1999/02/22-rdf-syntax-ns#reifies>"));
+        assert!(out.contains("<http://example.org/pg/edge/e1> <http://example.org/pg/property/since> \"2020\""));
+
+        let config = Pg2RdfConfig::default().with_edge_properties_as_reifiers(false);
+        let rdf = convert(str, &config);
+        assert_eq!(rdf.triples().unwrap().count(), 3);
+    }
+
+    #[test]
+    fn test_local_names_are_encoded() {
+        assert_eq!(encode_local_name("has name"), "has%20name");
+        assert_eq!(encode_local_name("año"), "año");
+    }
+}
@@ -0,0 +1,181 @@
+use prefixmap::PrefixMap;
+use rudof_config::TomlConfig;
+use rudof_iri::IriS;
+use rudof_rdf::rdf_core::vocabs::{RdfVocabulary, XsdVocab};
+use serde::{Deserialize, Serialize};
+
+/// Defines how property graphs are mapped to RDF
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(default)]
+pub struct Pg2RdfConfig {
+    /// Namespace used to mint node IRIs from node identifiers
+    #[serde(rename = "node_base_iri")]
+    pub(crate) node_base_iri: IriS,
+
+    /// Namespace used to mint class IRIs from node labels
+    #[serde(rename = "label_base_iri")]
+    pub(crate) label_base_iri: IriS,
+
+    /// Namespace used to mint predicates from property keys and edge labels
+    #[serde(rename = "property_base_iri")]
+    pub(crate) property_base_iri: IriS,
+
+    /// Namespace used to mint reifier IRIs from edge identifiers.
+    /// Edges without identifier are reified with blank nodes
+    #[serde(rename = "edge_base_iri")]
+    pub(crate) edge_base_iri: IriS,
+
+    /// If true, node labels are emitted as `rdf:type` declarations
+    #[serde(rename = "labels_as_classes")]
+    pub(crate) labels_as_classes: bool,
+
+    /// If true, edge properties are attached to an RDF 1.2 reifier of the edge triple
+    #[serde(rename = "edge_properties_as_reifiers")]
+    pub(crate) edge_properties_as_reifiers: bool,
+
+    /// Label used as predicate for edges without labels
+    #[serde(rename = "default_edge_label")]
+    pub(crate) default_edge_label: String,
+}
+
+impl Pg2RdfConfig {
+    pub fn new() -> Self {
+        Self {
+            node_base_iri: Self::default_node_base_iri(),
+            label_base_iri: Self::default_label_base_iri(),
+            property_base_iri: Self::default_property_base_iri(),
+            edge_base_iri: Self::default_edge_base_iri(),
+            labels_as_classes: Self::default_labels_as_classes(),
+            edge_properties_as_reifiers: Self::default_edge_properties_as_reifiers(),
+            default_edge_label: Self::default_default_edge_label(),
+        }
+    }
+
+    pub fn with_node_base_iri(mut self, iri: IriS) -> Self {
+        self.node_base_iri = iri;
+        self
+    }
+
+    pub fn with_label_base_iri(mut self, iri: IriS) -> Self {
+        self.label_base_iri = iri;
+        self
+    }
+
+    pub fn with_property_base_iri(mut self, iri: IriS) -> Self {
+        self.property_base_iri = iri;
+        self
+    }
+
+    pub fn with_edge_base_iri(mut self, iri: IriS) -> Self {
+        self.edge_base_iri = iri;
+        self
+    }
+
+    pub fn with_labels_as_classes(mut self, flag: bool) -> Self {
+        self.labels_as_classes = flag;
+        self
+    }
+
+    pub fn with_edge_properties_as_reifiers(mut self, flag: bool) -> Self {
+        self.edge_properties_as_reifiers = flag;
+        self
+    }
+
+    pub fn with_default_edge_label(mut self, label: String) -> Self {
+        self.default_edge_label = label;
+        self
+    }
+}
+
+impl Pg2RdfConfig {
+    pub fn node_base_iri(&self) -> &IriS {
+        &self.node_base_iri
+    }
+
+    pub fn label_base_iri(&self) -> &IriS {
+        &self.label_base_iri
+    }
+
+    pub fn property_base_iri(&self) -> &IriS {
+        &self.property_base_iri
+    }
+
+    pub fn edge_base_iri(&self) -> &IriS {
+        &self.edge_base_iri
+    }
+
+    pub fn labels_as_classes(&self) -> bool {
+        self.labels_as_classes
+    }
+
+    pub fn edge_properties_as_reifiers(&self) -> bool {
+        self.edge_properties_as_reifiers
+    }
+
+    pub fn default_edge_label(&self) -> &str {
+        &self.default_edge_label
+    }
+
+    /// Prefix declarations for the namespaces used by the generated RDF
+    pub fn prefixmap(&self) -> PrefixMap {
+        let mut prefixmap = PrefixMap::new();
+        prefixmap.add_prefix("node", self.node_base_iri.clone());
+        prefixmap.add_prefix("label", self.label_base_iri.clone());
+        prefixmap.add_prefix("pg", self.property_base_iri.clone());
+        prefixmap.add_prefix("edge", self.edge_base_iri.clone());
+        prefixmap.add_prefix("xsd", IriS::new_unchecked(XsdVocab::BASE));
+        prefixmap
+    }
+}
+
+/// Serde stuff
+#[allow(dead_code)]
+#[rustfmt::skip]
+impl Pg2RdfConfig {
+    #[inline] fn default_node_base_iri() -> IriS { IriS::new_unchecked("http://example.org/pg/node/") }
+    #[inline] fn default_label_base_iri() -> IriS { IriS::new_unchecked("http://example.org/pg/label/") }
+    #[inline] fn default_property_base_iri() -> IriS { IriS::new_unchecked("http://example.org/pg/property/") }
+    #[inline] fn default_edge_base_iri() -> IriS { IriS::new_unchecked("http://example.org/pg/edge/") }
+    #[inline] fn default_labels_as_classes() -> bool { true }
+    #[inline] fn default_edge_properties_as_reifiers() -> bool { true }
+    #[inline] fn default_default_edge_label() -> String { "edge".to_string() }
+}
+
+impl Default for Pg2RdfConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TomlConfig for Pg2RdfConfig {}
+
+#[cfg(test)]
+mod tests {
+    use super::Pg2RdfConfig;
+    use rudof_config::TomlConfig;
+
+    #[test]
+    fn defaults() {
+        let c = Pg2RdfConfig::default();
+        assert_eq!(c.node_base_iri(), &Pg2RdfConfig::default_node_base_iri());
+        assert_eq!(c.labels_as_classes(), Pg2RdfConfig::default_labels_as_classes());
+    }
+
+    #[test]
+    fn partial_toml_fills_remaining_defaults() {
+        let c = Pg2RdfConfig::from_toml_str(r#"node_base_iri = "http://ex/node/""#).unwrap();
+        assert_eq!(c.node_base_iri().as_str(), "http://ex/node/");
+        assert_eq!(
+            c.edge_properties_as_reifiers(),
+            Pg2RdfConfig::default_edge_properties_as_reifiers()
+        );
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let c = Pg2RdfConfig::default().with_labels_as_classes(false);
+        let s = c.to_toml_string().unwrap();
+        let d = Pg2RdfConfig::from_toml_str(&s).unwrap();
+        assert_eq!(c, d);
+    }
+}
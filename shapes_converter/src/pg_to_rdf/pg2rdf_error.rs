@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Pg2RdfError {
+    #[error("Edge refers to node {node} which has no name in the property graph")]
+    MissingNodeName { node: String },
+
+    #[error("Error adding triple to RDF graph: {error}")]
+    AddingTriple { error: String },
+}
@@ -0,0 +1,10 @@
+//! Generation of MIE files by introspecting RDF data
+//!
+//!
+pub mod rdf2mie;
+pub mod rdf2mie_config;
+pub mod rdf2mie_error;
+
+pub use rdf2mie::*;
+pub use rdf2mie_config::*;
+pub use rdf2mie_error::*;
@@ -0,0 +1,371 @@
+use super::{Rdf2MieConfig, Rdf2MieError};
+use crate::data_profile::{NodeGroup, local_label};
+use crate::rdf_to_shex::{Rdf2ShEx, Rdf2ShExConfig};
+use mie::{CrossReference, Mie, RdfExample, ShapeExpression};
+use prefixmap::PrefixMap;
+use rudof_iri::{IriS, iri};
+use rudof_rdf::rdf_core::{
+    BuildRDF, NeighsRDF, RDFFormat,
+    query::{QueryRDF, QueryResultFormat},
+    term::{Object, literal::Literal},
+};
+use rudof_rdf::rdf_impl::{OxigraphInMemory, ReaderMode};
+use shex_ast::{Schema, ShapeExprLabel, compact::ShExFormatter};
+use sparql_service::ServiceDescription;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+/// Name of the statistics of the default graph in the MIE files obtained from service descriptions
+const DEFAULT_GRAPH: &str = "default";
+
+/// Number of rows returned by the queries of the cross references
+const CROSS_REFERENCE_LIMIT: usize = 10;
+
+/// Generates MIE files by introspecting RDF data.
+///
+/// The MIE starts from the statistics of a VoID description of the data (see
+/// [`ServiceDescription::service2mie`]) and is completed with introspection
+/// queries about the classes with more instances:
+///
+/// - the RDF of some of their instances, as sample RDF entries,
+/// - a ShEx shape inferred from a sample of their instances,
+/// - the links from that sample to IRIs of other hosts, as cross references
+///   with a query that retrieves them.
+///
+/// Only the queries are sent to the data, so it can be in memory or behind a SPARQL endpoint.
+pub struct Rdf2Mie {
+    config: Rdf2MieConfig,
+    shex_config: Rdf2ShExConfig,
+}
+
+impl Rdf2Mie {
+    /// Creates a generator. Shapes are inferred following `shex_config`, without annotations
+    pub fn new(config: &Rdf2MieConfig, shex_config: &Rdf2ShExConfig) -> Rdf2Mie {
+        Rdf2Mie {
+            config: config.clone(),
+            shex_config: shex_config.clone().with_annotations(false),
+        }
+    }
+
+    /// Builds the MIE of some RDF data from a service description of it, like the ones
+    /// computed by the [`VoidProfiler`](sparql_service::VoidProfiler)
+    pub fn introspect<RDF>(&self, rdf: &RDF, service: &ServiceDescription) -> Result<Mie, Rdf2MieError>
+    where
+        RDF: QueryRDF + NeighsRDF,
+    {
+        let mut mie = service.service2mie();
+        let prefixmap = rdf.prefixmap().unwrap_or_default().without_rich_qualifying();
+
+        let mut samples = Vec::new();
+        for class in self.main_classes(&mie) {
+            let nodes = self.sample(rdf, &class)?;
+            if !nodes.is_empty() {
+                samples.push((class, nodes));
+            }
+        }
+        for (class, nodes) in samples.iter() {
+            let examples = &nodes[..nodes.len().min(self.config.examples_per_class())];
+            if !examples.is_empty() {
+                let name = prefixmap.qualify(class);
+                let example = RdfExample::new()
+                    .with_description(Some(format!("Instances of {name}")))
+                    .with_rdf(&sample_rdf(rdf, &name, examples, &prefixmap)?);
+                mie.add_sample_rdf_entry(&name, example);
+            }
+        }
+        if self.config.shapes() {
+            self.add_shapes(rdf, &samples, &prefixmap, &mut mie)?;
+        }
+        self.add_cross_references(rdf, &samples, &prefixmap, &mut mie)?;
+        Ok(mie)
+    }
+
+    /// Classes of the default graph with more instances
+    fn main_classes(&self, mie: &Mie) -> Vec<IriS> {
+        let Some(statistics) = mie.data_statistics(DEFAULT_GRAPH) else {
+            return Vec::new();
+        };
+        let mut classes: Vec<_> = statistics.class_partitions().iter().collect();
+        classes.sort_by(|(c1, n1), (c2, n2)| n2.cmp(n1).then(c1.cmp(c2)));
+        classes
+            .into_iter()
+            .take(self.config.max_classes())
+            .map(|(class, _)| IriS::new_unchecked(class))
+            .collect()
+    }
+
+    /// Some instances of a class that are IRIs, sorted
+    fn sample<RDF: QueryRDF>(&self, rdf: &RDF, class: &IriS) -> Result<Vec<IriS>, Rdf2MieError> {
+        let limit = self.config.sample_size().max(self.config.examples_per_class());
+        let query = format!(
+            "SELECT DISTINCT ?node WHERE {{ ?node <{}> <{}> FILTER(isIRI(?node)) }} LIMIT {limit}",
+            self.shex_config.type_predicate().as_str(),
+            class.as_str()
+        );
+        let solutions = rdf.query_select(&query).map_err(|e| query_error(&query, e))?;
+        let nodes: BTreeSet<IriS> = solutions
+            .iter()
+            .filter_map(|solution| solution.find_solution("node"))
+            .filter_map(|term| RDF::term_as_iris(term).ok())
+            .collect();
+        Ok(nodes.into_iter().collect())
+    }
+
+    /// Adds a shape inferred from the sample of each class
+    fn add_shapes<RDF: NeighsRDF>(
+        &self,
+        rdf: &RDF,
+        samples: &[(IriS, Vec<IriS>)],
+        prefixmap: &PrefixMap,
+        mie: &mut Mie,
+    ) -> Result<(), Rdf2MieError> {
+        let mut used = HashSet::new();
+        let groups: Vec<NodeGroup> = samples
+            .iter()
+            .map(|(class, nodes)| {
+                let label = local_label(self.shex_config.shapes_base_iri(), class, &mut used);
+                NodeGroup::new(label, nodes.iter().cloned().map(Object::Iri).collect()).with_class(Some(class.clone()))
+            })
+            .collect();
+        let schema = Rdf2ShEx::new(&self.shex_config)
+            .infer_groups(rdf, &groups)
+            .map_err(|e| Rdf2MieError::Shapes { error: e.to_string() })?;
+        let schema_prefixmap = schema.prefixmap().unwrap_or_default();
+        for (alias, iri) in schema_prefixmap.iter() {
+            mie.add_prefix(alias, iri);
+        }
+
+        let shapes = schema.shapes().unwrap_or_default();
+        for (group, (class, nodes)) in groups.iter().zip(samples) {
+            let Some(shape) = shapes.iter().find(|shape| match &shape.id {
+                ShapeExprLabel::IriRef { value } => value.get_iri().ok() == Some(group.label()),
+                _ => false,
+            }) else {
+                continue;
+            };
+            let mut single = Schema::new(&iri!("http://default/")).with_prefixmap(Some(schema_prefixmap.clone()));
+            single.add_shape_decl(shape);
+            let shape_expr = without_prefixes(&ShExFormatter::default().without_colors().format_schema(&single));
+            let name = prefixmap.qualify(class);
+            let description = format!("Shape inferred from {} instances of {name}", nodes.len());
+            mie.add_shape_expression(
+                &name,
+                ShapeExpression::new(&shape_expr).with_description(Some(description)),
+            );
+        }
+        Ok(())
+    }
+
+    /// Adds the namespaces of other hosts linked from the samples as cross references
+    fn add_cross_references<RDF: QueryRDF>(
+        &self,
+        rdf: &RDF,
+        samples: &[(IriS, Vec<IriS>)],
+        prefixmap: &PrefixMap,
+        mie: &mut Mie,
+    ) -> Result<(), Rdf2MieError> {
+        let hosts: HashSet<&str> = samples
+            .iter()
+            .flat_map(|(_, nodes)| nodes.iter())
+            .filter_map(|node| host(node.as_str()))
+            .collect();
+        let mut links: BTreeMap<String, BTreeSet<IriS>> = BTreeMap::new();
+        for (_, nodes) in samples {
+            let query = format!(
+                "SELECT DISTINCT ?property ?target WHERE {{ VALUES ?node {{ {} }} ?node ?property ?target FILTER(isIRI(?target)) }}",
+                iri_values(nodes.iter())
+            );
+            let solutions = rdf.query_select(&query).map_err(|e| query_error(&query, e))?;
+            for solution in solutions.iter() {
+                let property = solution
+                    .find_solution("property")
+                    .and_then(|t| RDF::term_as_iris(t).ok());
+                let target = solution.find_solution("target").and_then(|t| RDF::term_as_iris(t).ok());
+                let (Some(property), Some(target)) = (property, target) else {
+                    continue;
+                };
+                let is_external = host(target.as_str()).is_some_and(|host| !hosts.contains(host));
+                let is_ignored = self
+                    .config
+                    .ignored_namespaces()
+                    .iter()
+                    .any(|ns| target.as_str().starts_with(ns.as_str()));
+                if &property != self.shex_config.type_predicate() && is_external && !is_ignored {
+                    links.entry(namespace(&target, prefixmap)).or_default().insert(property);
+                }
+            }
+        }
+
+        for (namespace, properties) in links {
+            let pattern = format!(
+                "VALUES ?property {{ {} }}\n  ?node ?property ?target .\n  FILTER(STRSTARTS(STR(?target), \"{namespace}\"))",
+                iri_values(properties.iter())
+            );
+            let count_query = format!("SELECT (COUNT(*) AS ?links) WHERE {{\n  {pattern}\n}}");
+            let solutions = rdf
+                .query_select(&count_query)
+                .map_err(|e| query_error(&count_query, e))?;
+            let count = solutions
+                .iter()
+                .filter_map(|solution| solution.find_solution("links"))
+                .filter_map(|term| RDF::term_as_literal(term).ok())
+                .filter_map(|literal| literal.lexical_form().parse::<isize>().ok())
+                .reduce(|n, m| n + m);
+
+            let name = prefixmap
+                .iter()
+                .find(|(_, iri)| iri.as_str() == namespace)
+                .map(|(alias, _)| alias.clone())
+                .unwrap_or_else(|| namespace.clone());
+            let description = format!(
+                "Links to {namespace} through {}",
+                properties
+                    .iter()
+                    .map(|p| prefixmap.qualify(p))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            let query =
+                format!("SELECT ?node ?property ?target WHERE {{\n  {pattern}\n}}\nLIMIT {CROSS_REFERENCE_LIMIT}");
+            mie.add_cross_reference(&name, CrossReference::new(&query).with_description(Some(description)));
+            if let Some(statistics) = mie.data_statistics_mut(DEFAULT_GRAPH) {
+                statistics.add_cross_reference(&namespace, count);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Turtle of the triples whose subjects are `nodes`, without the prefix declarations
+fn sample_rdf<RDF: QueryRDF>(
+    rdf: &RDF,
+    class: &str,
+    nodes: &[IriS],
+    prefixmap: &PrefixMap,
+) -> Result<String, Rdf2MieError> {
+    let query = format!(
+        "CONSTRUCT {{ ?node ?p ?o }} WHERE {{ VALUES ?node {{ {} }} ?node ?p ?o }}",
+        iri_values(nodes.iter())
+    );
+    let turtle = rdf
+        .query_construct(&query, &QueryResultFormat::Turtle)
+        .map_err(|e| query_error(&query, e))?;
+    // The triples are serialized again to abbreviate their IRIs with the prefixes of the data
+    let sample_error = |error: String| Rdf2MieError::SampleRdf {
+        class: class.to_string(),
+        error,
+    };
+    let mut graph = OxigraphInMemory::from_str(&turtle, &RDFFormat::Turtle, None, &ReaderMode::Lax)
+        .map_err(|e| sample_error(e.to_string()))?;
+    graph.merge_prefixes(prefixmap.clone());
+    let mut buffer = Vec::new();
+    graph
+        .serialize(&RDFFormat::Turtle, &mut buffer)
+        .map_err(|e| sample_error(e.to_string()))?;
+    Ok(without_prefixes(&String::from_utf8_lossy(&buffer)))
+}
+
+/// Removes the prefix declarations, which are listed in the prefixes of the MIE
+fn without_prefixes(str: &str) -> String {
+    str.lines()
+        .filter(|line| {
+            let line = line.trim_start().to_lowercase();
+            !line.starts_with("prefix ") && !line.starts_with("@prefix ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+fn iri_values<'a>(iris: impl Iterator<Item = &'a IriS>) -> String {
+    iris.map(|iri| format!("<{}>", iri.as_str()))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Namespace of an IRI: the longest prefix that declares it or the IRI up to its last `/` or `#`
+fn namespace(iri: &IriS, prefixmap: &PrefixMap) -> String {
+    let str = iri.as_str();
+    prefixmap
+        .iter()
+        .map(|(_, ns)| ns.as_str())
+        .filter(|ns| str.starts_with(ns))
+        .max_by_key(|ns| ns.len())
+        .map(|ns| ns.to_string())
+        .unwrap_or_else(|| match str.rfind(['/', '#']) {
+            Some(end) => str[..=end].to_string(),
+            None => str.to_string(),
+        })
+}
+
+/// Host of an IRI with an authority, like `http://example.org/`
+fn host(iri: &str) -> Option<&str> {
+    let (_, rest) = iri.split_once("://")?;
+    let host = rest.split(['/', '#', '?']).next()?;
+    (!host.is_empty()).then_some(host)
+}
+
+fn query_error(query: &str, error: impl ToString) -> Rdf2MieError {
+    Rdf2MieError::Query {
+        query: query.to_string(),
+        error: error.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sparql_service::VoidProfiler;
+
+    const PEOPLE: &str = r#"prefix : <http://example.org/>
+        prefix wd: <http://www.wikidata.org/entity/>
+        :alice a :Person ; :name "Alice" ; :knows :bob ; :sameAs wd:Q1 .
+        :bob a :Person ; :name "Bob" ; :sameAs wd:Q2 .
+        :carol a :Person ; :name "Carol" .
+        :acme a :Company ; :name "ACME" ."#;
+
+    fn introspect(config: &Rdf2MieConfig) -> Mie {
+        let mut rdf = OxigraphInMemory::from_str(PEOPLE, &RDFFormat::Turtle, None, &ReaderMode::Strict).unwrap();
+        rdf.ensure_store().unwrap();
+        let service = VoidProfiler::new().profile(&rdf).unwrap();
+        Rdf2Mie::new(config, &Rdf2ShExConfig::default())
+            .introspect(&rdf, &service)
+            .unwrap()
+    }
+
+    #[test]
+    fn samples_and_shapes_per_class() {
+        let mie = introspect(&Rdf2MieConfig::default());
+        let person = mie.sample_rdf_entries().get(":Person").unwrap();
+        assert!(person.rdf().contains(":alice"));
+        assert!(!person.rdf().contains("\"Bob\""));
+        assert!(!person.rdf().contains("prefix"));
+        assert!(mie.sample_rdf_entries().contains_key(":Company"));
+
+        let shape = mie.shape_expressions().get(":Person").unwrap();
+        assert!(shape.shape_expr().contains(":name"));
+        assert_eq!(shape.description(), Some("Shape inferred from 3 instances of :Person"));
+        assert!(mie.prefixes().contains_key("shapes"));
+    }
+
+    #[test]
+    fn detects_cross_references() {
+        let mie = introspect(&Rdf2MieConfig::default());
+        let wikidata = mie.cross_references().get("wd").unwrap();
+        assert!(wikidata.sparql().contains("<http://example.org/sameAs>"));
+        assert!(!mie.cross_references().contains_key(""));
+        let statistics = mie.data_statistics("default").unwrap();
+        assert_eq!(
+            statistics.cross_references().get("http://www.wikidata.org/entity/"),
+            Some(&Some(2))
+        );
+    }
+
+    #[test]
+    fn limits_classes_and_skips_shapes() {
+        let mie = introspect(&Rdf2MieConfig::default().with_max_classes(1).with_shapes(false));
+        assert_eq!(mie.sample_rdf_entries().len(), 1);
+        assert!(mie.sample_rdf_entries().contains_key(":Person"));
+        assert!(mie.shape_expressions().is_empty());
+    }
+}
@@ -0,0 +1,139 @@
+use rudof_config::TomlConfig;
+use rudof_iri::IriS;
+use serde::{Deserialize, Serialize};
+
+/// Defines how MIE files are generated by introspecting RDF data
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(default)]
+pub struct Rdf2MieConfig {
+    /// Maximum number of classes with sample entities and shapes, starting with the ones with more instances
+    #[serde(rename = "max_classes")]
+    pub(crate) max_classes: usize,
+
+    /// Number of instances of each class shown as sample RDF
+    #[serde(rename = "examples_per_class")]
+    pub(crate) examples_per_class: usize,
+
+    /// Maximum number of instances of each class profiled to infer its shape and cross references
+    #[serde(rename = "sample_size")]
+    pub(crate) sample_size: usize,
+
+    /// If true, a ShEx shape is inferred for each class
+    #[serde(rename = "shapes")]
+    pub(crate) shapes: bool,
+
+    /// Namespaces of vocabularies whose IRIs are not reported as cross references
+    #[serde(rename = "ignored_namespaces")]
+    pub(crate) ignored_namespaces: Vec<IriS>,
+}
+
+impl Rdf2MieConfig {
+    pub fn new() -> Self {
+        Self {
+            max_classes: Self::default_max_classes(),
+            examples_per_class: Self::default_examples_per_class(),
+            sample_size: Self::default_sample_size(),
+            shapes: Self::default_shapes(),
+            ignored_namespaces: Self::default_ignored_namespaces(),
+        }
+    }
+
+    pub fn with_max_classes(mut self, max_classes: usize) -> Self {
+        self.max_classes = max_classes;
+        self
+    }
+
+    pub fn with_examples_per_class(mut self, examples: usize) -> Self {
+        self.examples_per_class = examples;
+        self
+    }
+
+    pub fn with_sample_size(mut self, size: usize) -> Self {
+        self.sample_size = size;
+        self
+    }
+
+    pub fn with_shapes(mut self, flag: bool) -> Self {
+        self.shapes = flag;
+        self
+    }
+
+    pub fn with_ignored_namespaces(mut self, namespaces: Vec<IriS>) -> Self {
+        self.ignored_namespaces = namespaces;
+        self
+    }
+}
+
+impl Rdf2MieConfig {
+    pub fn max_classes(&self) -> usize {
+        self.max_classes
+    }
+
+    pub fn examples_per_class(&self) -> usize {
+        self.examples_per_class
+    }
+
+    pub fn sample_size(&self) -> usize {
+        self.sample_size
+    }
+
+    pub fn shapes(&self) -> bool {
+        self.shapes
+    }
+
+    pub fn ignored_namespaces(&self) -> &[IriS] {
+        &self.ignored_namespaces
+    }
+}
+
+/// Serde stuff
+#[allow(dead_code)]
+#[rustfmt::skip]
+impl Rdf2MieConfig {
+    #[inline] fn default_max_classes() -> usize { 20 }
+    #[inline] fn default_examples_per_class() -> usize { 1 }
+    #[inline] fn default_sample_size() -> usize { 50 }
+    #[inline] fn default_shapes() -> bool { true }
+    #[inline] fn default_ignored_namespaces() -> Vec<IriS> {
+        [
+            "http://www.w3.org/1999/02/22-rdf-syntax-ns#",
+            "http://www.w3.org/2000/01/rdf-schema#",
+            "http://www.w3.org/2001/XMLSchema#",
+            "http://www.w3.org/2002/07/owl#",
+            "http://www.w3.org/2004/02/skos/core#",
+        ]
+        .into_iter()
+        .map(IriS::new_unchecked)
+        .collect()
+    }
+}
+
+impl Default for Rdf2MieConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TomlConfig for Rdf2MieConfig {}
+
+#[cfg(test)]
+mod tests {
+    use super::Rdf2MieConfig;
+    use rudof_config::TomlConfig;
+
+    #[test]
+    fn partial_toml_fills_remaining_defaults() {
+        let c = Rdf2MieConfig::from_toml_str("max_classes = 5").unwrap();
+        assert_eq!(c.max_classes(), 5);
+        assert_eq!(c.sample_size(), Rdf2MieConfig::default_sample_size());
+        assert_eq!(c.ignored_namespaces().len(), 5);
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let c = Rdf2MieConfig::default().with_shapes(false).with_examples_per_class(3);
+        let s = c.to_toml_string().unwrap();
+        let d = Rdf2MieConfig::from_toml_str(&s).unwrap();
+        assert_eq!(c, d);
+    }
+}
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Rdf2MieError {
+    #[error("Error running introspection query {query}: {error}")]
+    Query { query: String, error: String },
+
+    #[error("Error inferring the shapes of the classes: {error}")]
+    Shapes { error: String },
+
+    #[error("Error serializing the sample RDF of {class}: {error}")]
+    SampleRdf { class: String, error: String },
+}
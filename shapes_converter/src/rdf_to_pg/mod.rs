@@ -0,0 +1,10 @@
+//! RDF to property graphs
+//!
+//!
+pub mod rdf2pg;
+pub mod rdf2pg_config;
+pub mod rdf2pg_error;
+
+pub use rdf2pg::*;
+pub use rdf2pg_config::*;
+pub use rdf2pg_error::*;
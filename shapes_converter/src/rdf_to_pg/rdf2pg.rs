@@ -0,0 +1,372 @@
+use super::{Rdf2PgConfig, Rdf2PgError};
+use pgschema::{key::Key, pg::PropertyGraph, record::Record, value::Value};
+use prefixmap::{IriRef, PrefixMap};
+use rudof_iri::IriS;
+use rudof_rdf::rdf_core::{
+    NeighsRDF,
+    term::{IriOrBlankNode, Object, Triple, literal::ConcreteLiteral},
+    vocabs::{RdfVocab, XsdVocab},
+};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+/// Converts RDF graphs into property graphs.
+///
+/// Every IRI or blank node in subject or object position becomes a node,
+/// objects of the type predicates become node labels, literal objects become
+/// node properties and the remaining triples become edges. RDF 1.2 reifiers
+/// whose properties are all literals are attached to the edges they reify,
+/// while reifiers linked to other nodes are kept as nodes.
+pub struct Rdf2Pg {
+    config: Rdf2PgConfig,
+}
+
+type RdfTriple = (Object, IriS, Object);
+
+impl Rdf2Pg {
+    pub fn new(config: &Rdf2PgConfig) -> Rdf2Pg {
+        Rdf2Pg { config: config.clone() }
+    }
+
+    pub fn convert<RDF: NeighsRDF>(&self, rdf: &RDF) -> Result<PropertyGraph, Rdf2PgError> {
+        let prefixmap = rdf.prefixmap().unwrap_or_default().without_rich_qualifying();
+        let triples = get_triples(rdf)?;
+
+        // Reifiers are not converted to nodes when their properties are moved to edges,
+        // which is only possible when all their properties are literals
+        let mut reified: HashMap<&Object, Vec<&Object>> = HashMap::new();
+        if self.config.reifiers_as_edge_properties() {
+            let with_arcs: HashSet<&Object> = triples
+                .iter()
+                .filter(|(_, p, o)| !is_reification(p, o) && !matches!(o, Object::Literal(_)))
+                .map(|(s, _, _)| s)
+                .collect();
+            for (s, p, o) in triples.iter() {
+                if is_reification(p, o) && !with_arcs.contains(s) {
+                    reified.entry(o).or_default().push(s);
+                }
+            }
+        }
+        let reifiers: HashSet<&Object> = reified.values().flatten().copied().collect();
+
+        let mut nodes = Nodes::default();
+        let mut edges = Vec::new();
+        let mut reifier_records: HashMap<&Object, Record> = HashMap::new();
+        for triple in triples.iter() {
+            let (s, p, o) = triple;
+            if reifiers.contains(s) {
+                if let Object::Literal(lit) = o {
+                    reifier_records
+                        .entry(s)
+                        .or_default()
+                        .insert(Key::new(&self.name(p, &prefixmap)), literal2value(lit));
+                }
+                continue;
+            }
+            let node = nodes.get_or_insert(s, self.config.iri_key());
+            match o {
+                Object::Iri(iri) if self.config.type_predicates().contains(p) => {
+                    node.labels.insert(self.name(iri, &prefixmap));
+                },
+                Object::Literal(lit) => {
+                    node.record
+                        .insert(Key::new(&self.name(p, &prefixmap)), literal2value(lit));
+                },
+                Object::Iri(_) | Object::BlankNode(_) => {
+                    nodes.get_or_insert(o, self.config.iri_key());
+                    edges.push(triple);
+                },
+                Object::Triple { .. } => {},
+            }
+        }
+
+        let mut pg = PropertyGraph::new();
+        let mut names = HashMap::new();
+        for (node, data) in nodes.into_iter() {
+            names.insert(node, data.name.clone());
+            pg.add_node(data.name, data.labels, data.record);
+        }
+        let mut edge_names = NameAllocator::default();
+        for (s, p, o) in edges {
+            let triple_term = Object::Triple {
+                subject: Box::new(object2subject(s)?),
+                predicate: p.clone(),
+                object: Box::new(o.clone()),
+            };
+            let mut record = Record::new();
+            let mut name = None;
+            if let Some(rs) = reified.get(&triple_term) {
+                for r in rs {
+                    if let Some(r_record) = reifier_records.get(r) {
+                        record = record.union(r_record);
+                    }
+                }
+                if let [Object::Iri(iri)] = rs.as_slice() {
+                    name = Some(edge_names.allocate(&local_name(iri)));
+                }
+            }
+            pg.add_edge(
+                name,
+                names[s].clone(),
+                [self.name(p, &prefixmap)],
+                record,
+                names[o].clone(),
+            )
+            .map_err(|e| Rdf2PgError::AddingEdge { error: e.to_string() })?;
+        }
+        Ok(pg)
+    }
+
+    /// Name used for labels and keys
+    fn name(&self, iri: &IriS, prefixmap: &PrefixMap) -> String {
+        if self.config.use_prefixes()
+            && let Some(name) = prefixmap.qualify_optional(iri)
+        {
+            return name;
+        }
+        let local = local_name(iri);
+        if local.is_empty() { iri.to_string() } else { local }
+    }
+}
+
+struct NodeData {
+    name: String,
+    labels: BTreeSet<String>,
+    record: Record,
+}
+
+/// Nodes collected in the order in which they appear
+#[derive(Default)]
+struct Nodes<'a> {
+    order: Vec<&'a Object>,
+    data: HashMap<&'a Object, NodeData>,
+    names: NameAllocator,
+}
+
+impl<'a> Nodes<'a> {
+    fn get_or_insert(&mut self, node: &'a Object, iri_key: Option<&str>) -> &mut NodeData {
+        if !self.data.contains_key(node) {
+            let name = self.names.allocate(&node_name(node));
+            let mut record = Record::new();
+            if let (Some(key), Object::Iri(iri)) = (iri_key, node) {
+                record.insert(Key::new(key), Value::str(iri.as_str()));
+            }
+            self.order.push(node);
+            self.data.insert(
+                node,
+                NodeData {
+                    name,
+                    labels: BTreeSet::new(),
+                    record,
+                },
+            );
+        }
+        self.data.get_mut(node).unwrap()
+    }
+
+    fn into_iter(mut self) -> impl Iterator<Item = (&'a Object, NodeData)> {
+        self.order.into_iter().map(move |node| {
+            let data = self.data.remove(node).unwrap();
+            (node, data)
+        })
+    }
+}
+
+/// Generates unique identifiers which can be used in the property graph syntax
+#[derive(Default)]
+struct NameAllocator {
+    used: HashSet<String>,
+}
+
+impl NameAllocator {
+    fn allocate(&mut self, name: &str) -> String {
+        let mut base: String = name
+            .chars()
+            .map(|c| if c.is_alphanumeric() || c == '_' { c } else { '_' })
+            .collect();
+        if base.is_empty() {
+            base = "node".to_string();
+        }
+        let mut candidate = base.clone();
+        let mut counter = 1;
+        while self.used.contains(&candidate) {
+            counter += 1;
+            candidate = format!("{base}_{counter}");
+        }
+        self.used.insert(candidate.clone());
+        candidate
+    }
+}
+
+fn get_triples<RDF: NeighsRDF>(rdf: &RDF) -> Result<Vec<RdfTriple>, Rdf2PgError> {
+    let mut triples = Vec::new();
+    for triple in rdf
+        .triples()
+        .map_err(|e| Rdf2PgError::Triples { error: e.to_string() })?
+    {
+        let (s, p, o) = triple.into_components();
+        let subject = match s.try_into() {
+            Ok(IriOrBlankNode::Iri(iri)) => Object::Iri(iri),
+            Ok(IriOrBlankNode::BlankNode(b)) => Object::BlankNode(b),
+            Err(_) => {
+                return Err(Rdf2PgError::UnexpectedTerm {
+                    term: "subject".to_string(),
+                });
+            },
+        };
+        let object: Object = o.try_into().map_err(|_| Rdf2PgError::UnexpectedTerm {
+            term: "object".to_string(),
+        })?;
+        triples.push((subject, p.into(), object));
+    }
+    // Sorting the triples makes the generated names deterministic
+    triples.sort_by_cached_key(|(s, p, o)| (s.to_string(), p.to_string(), o.to_string()));
+    Ok(triples)
+}
+
+fn object2subject(node: &Object) -> Result<IriOrBlankNode, Rdf2PgError> {
+    match node {
+        Object::Iri(iri) => Ok(IriOrBlankNode::Iri(iri.clone())),
+        Object::BlankNode(b) => Ok(IriOrBlankNode::BlankNode(b.clone())),
+        _ => Err(Rdf2PgError::UnexpectedTerm { term: node.to_string() }),
+    }
+}
+
+fn node_name(node: &Object) -> String {
+    match node {
+        Object::Iri(iri) => local_name(iri),
+        Object::BlankNode(b) => format!("b{b}"),
+        _ => node.to_string(),
+    }
+}
+
+fn local_name(iri: &IriS) -> String {
+    let str = iri.as_str();
+    let local = str.rsplit(['/', '#']).next().unwrap_or(str);
+    local.to_string()
+}
+
+fn is_reification(predicate: &IriS, object: &Object) -> bool {
+    predicate == RdfVocab::rdf_reifies_ref() && matches!(object, Object::Triple { .. })
+}
+
+/// Property value of a literal.
+///
+/// Property graph integers are 32 bits, so `xsd:integer` literals out of
+/// that range are kept as strings, like literals of other datatypes.
+fn literal2value(lit: &ConcreteLiteral) -> Value {
+    let lexical_form = lit.lexical_form();
+    let datatype = lit.datatype();
+    match lit {
+        ConcreteLiteral::BooleanLiteral(b) => Value::Bool(*b),
+        _ if datatype == IriRef::iri(XsdVocab::xsd_integer()) => lexical_form
+            .parse::<i32>()
+            .map(Value::int)
+            .unwrap_or_else(|_| Value::str(&lexical_form)),
+        _ if datatype == IriRef::iri(XsdVocab::xsd_date()) => {
+            Value::date(&lexical_form).unwrap_or_else(|_| Value::str(&lexical_form))
+        },
+        _ => Value::str(&lexical_form),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rudof_rdf::rdf_core::RDFFormat;
+    use sparql_service::RdfData;
+
+    fn convert(str: &str, config: &Rdf2PgConfig) -> PropertyGraph {
+        let rdf = RdfData::from_str(str, &RDFFormat::Turtle, None, &Default::default()).unwrap();
+        Rdf2Pg::new(config).convert(&rdf).unwrap()
+    }
+
+    #[test]
+    fn test_nodes_labels_and_properties() {
+        let pg = convert(
+            r#"prefix : <http://example.org/>
+               :alice a :Person ; :name "Alice" ; :age 23 ; :knows :bob ."#,
+            &Rdf2PgConfig::default(),
+        );
+        assert_eq!(pg.node_count(), 2);
+        assert_eq!(pg.edge_count(), 1);
+        let alice = pg.get_node_by_label("alice").unwrap();
+        assert!(alice.labels().contains(&":Person".to_string()));
+        assert!(
+            alice
+                .content()
+                .get(&Key::new(":age"))
+                .unwrap()
+                .contains(&Value::int(23))
+        );
+        assert!(
+            alice
+                .content()
+                .get(&Key::new("iri"))
+                .unwrap()
+                .contains(&Value::str("http://example.org/alice"))
+        );
+    }
+
+    #[test]
+    fn test_reifiers_as_edge_properties() {
+        let str = r#"prefix : <http://example.org/>
+                     prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>
+                     :alice :knows :bob .
+                     :e1 rdf:reifies <<( :alice :knows :bob )>> ; :since 2020 ."#;
+        let pg = convert(str, &Rdf2PgConfig::default().with_use_prefixes(false));
+        assert_eq!(pg.node_count(), 2);
+        let edge = pg.get_edge_by_label("e1").unwrap();
+        assert!(edge.labels().contains(&"knows".to_string()));
+        assert!(
+            edge.content()
+                .get(&Key::new("since"))
+                .unwrap()
+                .contains(&Value::int(2020))
+        );
+
+        let pg = convert(str, &Rdf2PgConfig::default().with_reifiers_as_edge_properties(false));
+        assert_eq!(pg.node_count(), 3);
+    }
+
+    #[test]
+    fn test_reifiers_with_links_are_nodes() {
+        let pg = convert(
+            r#"prefix : <http://example.org/>
+               prefix rdf: <http://www.w3.org/1999/02/22-rdf-syntax-ns#>
+               :alice :knows :bob .
+               :e1 rdf:reifies <<( :alice :knows :bob )>> ; :since 2020 ; :source :wiki ."#,
+            &Rdf2PgConfig::default().with_use_prefixes(false),
+        );
+        assert_eq!(pg.node_count(), 4);
+        let e1 = pg.get_node_by_label("e1").unwrap();
+        assert!(
+            e1.content()
+                .get(&Key::new("since"))
+                .unwrap()
+                .contains(&Value::int(2020))
+        );
+        assert_eq!(pg.edge_count(), 2);
+    }
+
+    #[test]
+    fn test_large_integers_are_strings() {
+        let pg = convert(
+            r#"prefix : <http://example.org/> :a :n 99999999999 ."#,
+            &Rdf2PgConfig::default().with_use_prefixes(false),
+        );
+        let a = pg.get_node_by_label("a").unwrap();
+        assert!(
+            a.content()
+                .get(&Key::new("n"))
+                .unwrap()
+                .contains(&Value::str("99999999999"))
+        );
+    }
+
+    #[test]
+    fn test_names_are_unique() {
+        let mut names = NameAllocator::default();
+        assert_eq!(names.allocate("a-b"), "a_b");
+        assert_eq!(names.allocate("a_b"), "a_b_2");
+    }
+}
@@ -0,0 +1,122 @@
+use rudof_config::TomlConfig;
+use rudof_iri::IriS;
+use rudof_rdf::rdf_core::vocabs::RdfVocab;
+use serde::{Deserialize, Serialize};
+
+/// Defines how RDF graphs are mapped to property graphs
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(default)]
+pub struct Rdf2PgConfig {
+    /// Predicates whose IRI objects are mapped to node labels
+    #[serde(rename = "type_predicates")]
+    pub(crate) type_predicates: Vec<IriS>,
+
+    /// Key of the property that keeps the original IRI of each node.
+    /// If not set, the IRIs are not kept
+    #[serde(rename = "iri_key")]
+    pub(crate) iri_key: Option<String>,
+
+    /// If true, labels and keys are written as prefixed names when a prefix declaration matches
+    #[serde(rename = "use_prefixes")]
+    pub(crate) use_prefixes: bool,
+
+    /// If true, literal properties of reifiers are mapped to properties of the reified edge.
+    /// Reifiers with IRI or blank node values are kept as nodes.
+    #[serde(rename = "reifiers_as_edge_properties")]
+    pub(crate) reifiers_as_edge_properties: bool,
+}
+
+impl Rdf2PgConfig {
+    pub fn new() -> Self {
+        Self {
+            type_predicates: Self::default_type_predicates(),
+            iri_key: Self::default_iri_key(),
+            use_prefixes: Self::default_use_prefixes(),
+            reifiers_as_edge_properties: Self::default_reifiers_as_edge_properties(),
+        }
+    }
+
+    pub fn with_type_predicates(mut self, predicates: Vec<IriS>) -> Self {
+        self.type_predicates = predicates;
+        self
+    }
+
+    pub fn with_iri_key(mut self, key: Option<String>) -> Self {
+        self.iri_key = key;
+        self
+    }
+
+    pub fn with_use_prefixes(mut self, flag: bool) -> Self {
+        self.use_prefixes = flag;
+        self
+    }
+
+    pub fn with_reifiers_as_edge_properties(mut self, flag: bool) -> Self {
+        self.reifiers_as_edge_properties = flag;
+        self
+    }
+}
+
+impl Rdf2PgConfig {
+    pub fn type_predicates(&self) -> &[IriS] {
+        &self.type_predicates
+    }
+
+    pub fn iri_key(&self) -> Option<&str> {
+        self.iri_key.as_deref()
+    }
+
+    pub fn use_prefixes(&self) -> bool {
+        self.use_prefixes
+    }
+
+    pub fn reifiers_as_edge_properties(&self) -> bool {
+        self.reifiers_as_edge_properties
+    }
+}
+
+/// Serde stuff
+#[allow(dead_code)]
+#[rustfmt::skip]
+impl Rdf2PgConfig {
+    #[inline] fn default_type_predicates() -> Vec<IriS> { vec![RdfVocab::rdf_type()] }
+    #[inline] fn default_iri_key() -> Option<String> { Some("iri".to_string()) }
+    #[inline] fn default_use_prefixes() -> bool { true }
+    #[inline] fn default_reifiers_as_edge_properties() -> bool { true }
+}
+
+impl Default for Rdf2PgConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TomlConfig for Rdf2PgConfig {}
+
+#[cfg(test)]
+mod tests {
+    use super::Rdf2PgConfig;
+    use rudof_config::TomlConfig;
+
+    #[test]
+    fn defaults() {
+        let c = Rdf2PgConfig::default();
+        assert_eq!(c.type_predicates(), Rdf2PgConfig::default_type_predicates().as_slice());
+        assert_eq!(c.iri_key(), Some("iri"));
+    }
+
+    #[test]
+    fn partial_toml_fills_remaining_defaults() {
+        let c = Rdf2PgConfig::from_toml_str("use_prefixes = false").unwrap();
+        assert!(!c.use_prefixes());
+        assert_eq!(c.iri_key(), Rdf2PgConfig::default_iri_key().as_deref());
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let c = Rdf2PgConfig::default().with_reifiers_as_edge_properties(false);
+        let s = c.to_toml_string().unwrap();
+        let d = Rdf2PgConfig::from_toml_str(&s).unwrap();
+        assert_eq!(c, d);
+    }
+}
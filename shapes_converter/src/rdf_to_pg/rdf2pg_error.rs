@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Rdf2PgError {
+    #[error("Error obtaining triples from RDF graph: {error}")]
+    Triples { error: String },
+
+    #[error("Error converting RDF term {term} to property graph")]
+    UnexpectedTerm { term: String },
+
+    #[error("Error adding edge to property graph: {error}")]
+    AddingEdge { error: String },
+}
@@ -0,0 +1,10 @@
+//! Inference of SHACL shapes graphs from RDF data
+//!
+//!
+pub mod rdf2shacl;
+pub mod rdf2shacl_config;
+pub mod rdf2shacl_error;
+
+pub use rdf2shacl::*;
+pub use rdf2shacl_config::*;
+pub use rdf2shacl_error::*;
@@ -0,0 +1,342 @@
+use super::{Rdf2ShaclConfig, Rdf2ShaclError};
+use crate::data_profile::{DataProfile, GroupProfile, NodeGroup, PredicateProfile, ValueKind, local_label};
+use prefixmap::{IriRef, PrefixMap};
+use rudof_iri::IriS;
+use rudof_rdf::rdf_core::{
+    NeighsRDF, SHACLPath,
+    term::Object,
+    vocabs::{RdfVocab, XsdVocab},
+};
+use shacl::ast::{ASTComponent, ASTNodeShape, ASTPropertyShape, ASTSchema, ASTShape};
+use shacl::types::{NodeKind, Target, Value};
+use std::collections::{HashMap, HashSet};
+
+/// Infers SHACL shapes graphs from RDF data.
+///
+/// Nodes are grouped by the objects of the type predicate (or by explicit
+/// [`NodeGroup`]s) and every group becomes a node shape, targeting its class
+/// or its nodes. The property shapes of each node shape are derived from the
+/// [`DataProfile`] of its nodes, following the thresholds of the [`Rdf2ShaclConfig`].
+///
+/// Values that belong to another group are constrained with `sh:class` rather
+/// than `sh:node`, so the resulting shapes graph is never recursive.
+pub struct Rdf2Shacl {
+    config: Rdf2ShaclConfig,
+}
+
+impl Rdf2Shacl {
+    pub fn new(config: &Rdf2ShaclConfig) -> Rdf2Shacl {
+        Rdf2Shacl { config: config.clone() }
+    }
+
+    /// Infers a shapes graph with one node shape for each class of the RDF graph
+    pub fn infer<RDF: NeighsRDF>(&self, rdf: &RDF) -> Result<ASTSchema, Rdf2ShaclError> {
+        let groups = self.groups_by_type(rdf)?;
+        self.infer_groups(rdf, &groups)
+    }
+
+    /// Infers a shapes graph with one node shape for each group of nodes.
+    ///
+    /// Groups with a class target it with `sh:targetClass` and the other ones
+    /// target their nodes with `sh:targetNode`.
+    pub fn infer_groups<RDF: NeighsRDF>(&self, rdf: &RDF, groups: &[NodeGroup]) -> Result<ASTSchema, Rdf2ShaclError> {
+        self.check_thresholds()?;
+        let profile = DataProfile::new(rdf, groups, self.config.max_value_set_size(), self.config.sample_size())
+            .map_err(|e| Rdf2ShaclError::Profile { error: e.to_string() })?;
+
+        let classes: HashMap<&IriS, &IriS> = groups
+            .iter()
+            .filter_map(|g| g.class().map(|class| (g.label(), class)))
+            .collect();
+        let mut shapes = HashMap::new();
+        for (group, group_profile) in groups.iter().zip(profile.groups()) {
+            let targets = match group.class() {
+                Some(class) => vec![Target::Class(Object::Iri(class.clone()))],
+                None => group.nodes().iter().cloned().map(Target::Node).collect(),
+            };
+            self.add_node_shape(group_profile, targets, &classes, &mut shapes);
+        }
+        Ok(ASTSchema::new()
+            .with_prefixmap(self.prefixmap(rdf.prefixmap()))
+            .with_shapes(shapes))
+    }
+
+    /// Groups the nodes of the RDF graph by the objects of the type predicate.
+    ///
+    /// The label of each group is the local name of its class in the shapes namespace.
+    pub fn groups_by_type<RDF: NeighsRDF>(&self, rdf: &RDF) -> Result<Vec<NodeGroup>, Rdf2ShaclError> {
+        let mut used = HashSet::new();
+        DataProfile::groups_by_type(rdf, self.config.type_predicate(), |class| {
+            local_label(self.config.shapes_base_iri(), class, &mut used)
+        })
+        .map_err(|e| Rdf2ShaclError::Profile { error: e.to_string() })
+    }
+
+    fn check_thresholds(&self) -> Result<(), Rdf2ShaclError> {
+        for (name, value) in [
+            ("min_support", self.config.min_support()),
+            ("min_confidence", self.config.min_confidence()),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(Rdf2ShaclError::InvalidThreshold {
+                    name: name.to_string(),
+                    value,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn prefixmap(&self, data_prefixmap: Option<PrefixMap>) -> PrefixMap {
+        let mut prefixmap = data_prefixmap.unwrap_or_default();
+        if prefixmap.find("shapes").is_none() {
+            prefixmap.add_prefix("shapes", self.config.shapes_base_iri().clone());
+        }
+        prefixmap
+    }
+
+    fn add_node_shape(
+        &self,
+        group: &GroupProfile,
+        targets: Vec<Target>,
+        classes: &HashMap<&IriS, &IriS>,
+        shapes: &mut HashMap<Object, ASTShape>,
+    ) {
+        let mut used = HashSet::new();
+        let property_base = IriS::new_unchecked(format!("{}-", group.label()).as_str());
+        let mut property_shapes = Vec::new();
+        for predicate in group.predicates() {
+            // The type of the nodes is already checked by the target
+            if group.class().is_some() && predicate.predicate() == self.config.type_predicate() {
+                continue;
+            }
+            let support = ratio(predicate.subjects(), group.nodes());
+            if support < self.config.min_support() {
+                continue;
+            }
+            let id = Object::Iri(local_label(&property_base, predicate.predicate(), &mut used));
+            let mut components = Vec::new();
+            if predicate.subjects() == group.nodes() {
+                components.push(ASTComponent::MinCount(1));
+            }
+            if predicate.max_count() <= 1 {
+                components.push(ASTComponent::MaxCount(1));
+            }
+            components.extend(self.value_components(predicate, classes));
+            let shape = ASTPropertyShape::new(id.clone(), SHACLPath::iri(predicate.predicate().clone()))
+                .with_components(components);
+            shapes.insert(id.clone(), ASTShape::property_shape(shape));
+            property_shapes.push(id);
+        }
+        let id = Object::Iri(group.label().clone());
+        let shape = ASTNodeShape::new(id.clone())
+            .with_targets(targets)
+            .with_property_shapes(property_shapes);
+        shapes.insert(id, ASTShape::node_shape(shape));
+    }
+
+    /// Components that constrain the values of a predicate
+    fn value_components(&self, predicate: &PredicateProfile, classes: &HashMap<&IriS, &IriS>) -> Vec<ASTComponent> {
+        if let Some(values) = self.value_set(predicate) {
+            return vec![ASTComponent::In(values)];
+        }
+        let Some((kind, count)) = predicate.dominant_kind() else {
+            return Vec::new();
+        };
+        if ratio(count, predicate.triples()) >= self.config.min_confidence() {
+            let mut components: Vec<ASTComponent> = kind2component(kind, classes).into_iter().collect();
+            if let ValueKind::Datatype(dt) = kind
+                && dt == XsdVocab::xsd_string_ref()
+                && let Some(pattern) = self.pattern(predicate)
+            {
+                components.push(pattern);
+            }
+            return components;
+        }
+        // No kind is dominant enough, so fall back to the most specific node kind shared by all values
+        let kinds = predicate.kinds().keys();
+        let node_kind = if kinds.clone().all(ValueKind::is_literal) {
+            Some(NodeKind::Lit)
+        } else if kinds.clone().all(ValueKind::is_iri) {
+            Some(NodeKind::Iri)
+        } else if kinds.clone().all(|k| !k.is_literal() && *k != ValueKind::Triple) {
+            Some(NodeKind::BNodeOrIri)
+        } else {
+            None
+        };
+        node_kind.map(ASTComponent::NodeKind).into_iter().collect()
+    }
+
+    fn value_set(&self, predicate: &PredicateProfile) -> Option<Vec<Value>> {
+        let values = predicate.values()?;
+        if values.is_empty()
+            || values.len() > self.config.max_value_set_size()
+            || (predicate.triples() as f64) < self.config.min_value_set_repetition() * values.len() as f64
+            || !predicate
+                .kinds()
+                .keys()
+                .all(|k| matches!(k, ValueKind::Iri | ValueKind::Datatype(_) | ValueKind::LangString))
+        {
+            return None;
+        }
+        let mut values: Vec<&Object> = values.keys().collect();
+        values.sort_by_cached_key(|v| v.to_string());
+        values
+            .into_iter()
+            .map(|v| match v {
+                Object::Iri(iri) => Some(Value::Iri(IriRef::iri(iri.clone()))),
+                Object::Literal(lit) => Some(Value::Literal(lit.clone())),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// `sh:pattern` candidate for the string values of a predicate
+    fn pattern(&self, predicate: &PredicateProfile) -> Option<ASTComponent> {
+        if !self.config.patterns() {
+            return None;
+        }
+        let (pattern, count) = predicate.dominant_pattern()?;
+        // A single value always has a pattern, which says nothing about the others
+        if predicate.strings() < 2 || ratio(count, predicate.strings()) < self.config.min_confidence() {
+            return None;
+        }
+        Some(ASTComponent::Pattern {
+            pattern: pattern.to_string(),
+            flags: None,
+        })
+    }
+}
+
+fn ratio(n: usize, total: usize) -> f64 {
+    if total == 0 { 0.0 } else { n as f64 / total as f64 }
+}
+
+fn kind2component(kind: &ValueKind, classes: &HashMap<&IriS, &IriS>) -> Option<ASTComponent> {
+    match kind {
+        ValueKind::Datatype(dt) => Some(ASTComponent::Datatype(IriRef::iri(dt.clone()))),
+        ValueKind::LangString => Some(ASTComponent::Datatype(IriRef::iri(RdfVocab::rdf_lang_string()))),
+        ValueKind::Shape(label) => match classes.get(label) {
+            Some(class) => Some(ASTComponent::Class(Object::Iri((*class).clone()))),
+            None => Some(ASTComponent::NodeKind(NodeKind::BNodeOrIri)),
+        },
+        ValueKind::Iri => Some(ASTComponent::NodeKind(NodeKind::Iri)),
+        ValueKind::BNode => Some(ASTComponent::NodeKind(NodeKind::BNode)),
+        ValueKind::Triple => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rudof_rdf::rdf_core::RDFFormat;
+    use rudof_rdf::rdf_impl::OxigraphInMemory;
+    use shacl::ir::IRSchema;
+    use shacl::rdf::ShaclWriter;
+    use sparql_service::RdfData;
+
+    fn graph(data: &str) -> RdfData {
+        RdfData::from_str(data, &RDFFormat::Turtle, None, &Default::default()).unwrap()
+    }
+
+    fn property_components(schema: &ASTSchema, id: &str) -> Vec<ASTComponent> {
+        match schema.get_shape(&Object::Iri(IriS::new_unchecked(id))) {
+            Some(ASTShape::PropertyShape(ps)) => ps.components().clone(),
+            other => panic!("Expected property shape {id}, found {other:?}"),
+        }
+    }
+
+    const PEOPLE: &str = r#"prefix : <http://example.org/>
+        prefix xsd: <http://www.w3.org/2001/XMLSchema#>
+        :alice a :Person ; :name "Alice" ; :status :Active ; :knows :bob, :carol ; :code "AB-12" .
+        :bob a :Person ; :name "Bob" ; :status :Active ; :code "X-3" .
+        :carol a :Person ; :name "Carol" ; :status :Inactive ; :code "CD-4" .
+        :dave a :Person ; :name "Dave" ; :status :Active ; :code "E-567" ."#;
+
+    #[test]
+    fn infers_node_and_property_shapes() {
+        let schema = Rdf2Shacl::new(&Rdf2ShaclConfig::default())
+            .infer(&graph(PEOPLE))
+            .unwrap();
+        let Some(ASTShape::NodeShape(person)) =
+            schema.get_shape(&Object::Iri(IriS::new_unchecked("http://example.org/shapes/Person")))
+        else {
+            panic!("Expected node shape for Person")
+        };
+        assert_eq!(
+            person.targets(),
+            &vec![Target::Class(Object::Iri(IriS::new_unchecked(
+                "http://example.org/Person"
+            )))]
+        );
+        assert_eq!(person.property_shapes().len(), 4);
+
+        let knows = property_components(&schema, "http://example.org/shapes/Person-knows");
+        assert_eq!(
+            knows,
+            vec![ASTComponent::Class(Object::Iri(IriS::new_unchecked(
+                "http://example.org/Person"
+            )))]
+        );
+        let status = property_components(&schema, "http://example.org/shapes/Person-status");
+        assert!(status.contains(&ASTComponent::MinCount(1)));
+        assert!(status.contains(&ASTComponent::MaxCount(1)));
+        assert!(matches!(status.last(), Some(ASTComponent::In(values)) if values.len() == 2));
+        let code = property_components(&schema, "http://example.org/shapes/Person-code");
+        assert!(code.contains(&ASTComponent::Pattern {
+            pattern: "^[A-Z]+-[0-9]+$".to_string(),
+            flags: None
+        }));
+        let name = property_components(&schema, "http://example.org/shapes/Person-name");
+        assert!(name.contains(&ASTComponent::Datatype(IriRef::iri(XsdVocab::xsd_string()))));
+    }
+
+    #[test]
+    fn patterns_can_be_disabled() {
+        let config = Rdf2ShaclConfig::default().with_patterns(false);
+        let schema = Rdf2Shacl::new(&config).infer(&graph(PEOPLE)).unwrap();
+        let code = property_components(&schema, "http://example.org/shapes/Person-code");
+        assert!(!code.iter().any(|c| matches!(c, ASTComponent::Pattern { .. })));
+    }
+
+    #[test]
+    fn groups_without_class_target_their_nodes() {
+        let rdf = graph(r#"prefix : <http://example.org/> :a :p 1 . :b :p 2 ."#);
+        let nodes = vec![
+            Object::Iri(IriS::new_unchecked("http://example.org/a")),
+            Object::Iri(IriS::new_unchecked("http://example.org/b")),
+        ];
+        let group = NodeGroup::new(IriS::new_unchecked("http://example.org/shapes/S"), nodes.clone());
+        let schema = Rdf2Shacl::new(&Rdf2ShaclConfig::default())
+            .infer_groups(&rdf, &[group])
+            .unwrap();
+        let Some(ASTShape::NodeShape(s)) =
+            schema.get_shape(&Object::Iri(IriS::new_unchecked("http://example.org/shapes/S")))
+        else {
+            panic!("Expected node shape S")
+        };
+        assert_eq!(s.targets(), &nodes.into_iter().map(Target::Node).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn inferred_shapes_are_serialized() {
+        let schema = Rdf2Shacl::new(&Rdf2ShaclConfig::default())
+            .infer(&graph(PEOPLE))
+            .unwrap();
+        let ir = IRSchema::compile(&schema).unwrap();
+        let mut writer: ShaclWriter<OxigraphInMemory> = ShaclWriter::new();
+        writer.register(&ir).unwrap();
+        let mut buffer = Vec::new();
+        writer.serialize(&RDFFormat::Turtle, &mut buffer).unwrap();
+        let turtle = String::from_utf8(buffer).unwrap();
+        assert!(turtle.contains("sh:targetClass"), "{turtle}");
+        assert!(turtle.contains("sh:pattern"), "{turtle}");
+        assert!(turtle.contains("rdf:first :Inactive"), "{turtle}");
+    }
+
+    #[test]
+    fn invalid_thresholds_are_rejected() {
+        let result = Rdf2Shacl::new(&Rdf2ShaclConfig::default().with_min_confidence(-1.0)).infer(&graph(""));
+        assert!(matches!(result, Err(Rdf2ShaclError::InvalidThreshold { .. })));
+    }
+}
@@ -0,0 +1,185 @@
+use rudof_config::TomlConfig;
+use rudof_iri::IriS;
+use rudof_rdf::rdf_core::vocabs::RdfVocab;
+use serde::{Deserialize, Serialize};
+
+/// Defines how SHACL shapes graphs are inferred from RDF data
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(default)]
+pub struct Rdf2ShaclConfig {
+    /// Predicate whose objects are used to group nodes into node shapes
+    #[serde(rename = "type_predicate")]
+    pub(crate) type_predicate: IriS,
+
+    /// Namespace of the inferred node and property shapes
+    #[serde(rename = "shapes_base_iri")]
+    pub(crate) shapes_base_iri: IriS,
+
+    /// Minimum ratio of nodes of a shape that must use a predicate to emit a property shape for it
+    #[serde(rename = "min_support")]
+    pub(crate) min_support: f64,
+
+    /// Minimum ratio of values that must share a datatype, class, node kind or pattern
+    /// to emit the corresponding constraint
+    #[serde(rename = "min_confidence")]
+    pub(crate) min_confidence: f64,
+
+    /// Maximum number of distinct values of a predicate that are emitted with `sh:in`.
+    /// `sh:in` is not emitted when it is 0
+    #[serde(rename = "max_value_set_size")]
+    pub(crate) max_value_set_size: usize,
+
+    /// Minimum average number of occurrences of each value to emit `sh:in`
+    #[serde(rename = "min_value_set_repetition")]
+    pub(crate) min_value_set_repetition: f64,
+
+    /// If true, `sh:pattern` is emitted for string values that follow a common pattern
+    #[serde(rename = "patterns")]
+    pub(crate) patterns: bool,
+
+    /// Maximum number of nodes of each shape that are profiled. All nodes are profiled if it is not set
+    #[serde(rename = "sample_size", skip_serializing_if = "Option::is_none")]
+    pub(crate) sample_size: Option<usize>,
+}
+
+impl Rdf2ShaclConfig {
+    pub fn new() -> Self {
+        Self {
+            type_predicate: Self::default_type_predicate(),
+            shapes_base_iri: Self::default_shapes_base_iri(),
+            min_support: Self::default_min_support(),
+            min_confidence: Self::default_min_confidence(),
+            max_value_set_size: Self::default_max_value_set_size(),
+            min_value_set_repetition: Self::default_min_value_set_repetition(),
+            patterns: Self::default_patterns(),
+            sample_size: Self::default_sample_size(),
+        }
+    }
+
+    pub fn with_type_predicate(mut self, predicate: IriS) -> Self {
+        self.type_predicate = predicate;
+        self
+    }
+
+    pub fn with_shapes_base_iri(mut self, iri: IriS) -> Self {
+        self.shapes_base_iri = iri;
+        self
+    }
+
+    pub fn with_min_support(mut self, ratio: f64) -> Self {
+        self.min_support = ratio;
+        self
+    }
+
+    pub fn with_min_confidence(mut self, ratio: f64) -> Self {
+        self.min_confidence = ratio;
+        self
+    }
+
+    pub fn with_max_value_set_size(mut self, size: usize) -> Self {
+        self.max_value_set_size = size;
+        self
+    }
+
+    pub fn with_min_value_set_repetition(mut self, repetition: f64) -> Self {
+        self.min_value_set_repetition = repetition;
+        self
+    }
+
+    pub fn with_patterns(mut self, flag: bool) -> Self {
+        self.patterns = flag;
+        self
+    }
+
+    pub fn with_sample_size(mut self, size: Option<usize>) -> Self {
+        self.sample_size = size;
+        self
+    }
+}
+
+impl Rdf2ShaclConfig {
+    pub fn type_predicate(&self) -> &IriS {
+        &self.type_predicate
+    }
+
+    pub fn shapes_base_iri(&self) -> &IriS {
+        &self.shapes_base_iri
+    }
+
+    pub fn min_support(&self) -> f64 {
+        self.min_support
+    }
+
+    pub fn min_confidence(&self) -> f64 {
+        self.min_confidence
+    }
+
+    pub fn max_value_set_size(&self) -> usize {
+        self.max_value_set_size
+    }
+
+    pub fn min_value_set_repetition(&self) -> f64 {
+        self.min_value_set_repetition
+    }
+
+    pub fn patterns(&self) -> bool {
+        self.patterns
+    }
+
+    pub fn sample_size(&self) -> Option<usize> {
+        self.sample_size
+    }
+}
+
+/// Serde stuff
+#[allow(dead_code)]
+#[rustfmt::skip]
+impl Rdf2ShaclConfig {
+    #[inline] fn default_type_predicate() -> IriS { RdfVocab::rdf_type() }
+    #[inline] fn default_shapes_base_iri() -> IriS { IriS::new_unchecked("http://example.org/shapes/") }
+    #[inline] fn default_min_support() -> f64 { 0.1 }
+    #[inline] fn default_min_confidence() -> f64 { 0.9 }
+    #[inline] fn default_max_value_set_size() -> usize { 5 }
+    #[inline] fn default_min_value_set_repetition() -> f64 { 2.0 }
+    #[inline] fn default_patterns() -> bool { true }
+    #[inline] fn default_sample_size() -> Option<usize> { None }
+}
+
+impl Default for Rdf2ShaclConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TomlConfig for Rdf2ShaclConfig {}
+
+#[cfg(test)]
+mod tests {
+    use super::Rdf2ShaclConfig;
+    use rudof_config::TomlConfig;
+
+    #[test]
+    fn defaults() {
+        let c = Rdf2ShaclConfig::default();
+        assert_eq!(c.type_predicate(), &Rdf2ShaclConfig::default_type_predicate());
+        assert!(c.patterns());
+        assert_eq!(c.sample_size(), None);
+    }
+
+    #[test]
+    fn partial_toml_fills_remaining_defaults() {
+        let c = Rdf2ShaclConfig::from_toml_str("sample_size = 1000").unwrap();
+        assert_eq!(c.sample_size(), Some(1000));
+        assert_eq!(c.min_support(), Rdf2ShaclConfig::default_min_support());
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        let c = Rdf2ShaclConfig::default()
+            .with_patterns(false)
+            .with_sample_size(Some(10));
+        let s = c.to_toml_string().unwrap();
+        let d = Rdf2ShaclConfig::from_toml_str(&s).unwrap();
+        assert_eq!(c, d);
+    }
+}
@@ -0,0 +1,10 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum Rdf2ShaclError {
+    #[error("Error profiling RDF data: {error}")]
+    Profile { error: String },
+
+    #[error("Invalid value {value} for {name}: it must be between 0 and 1")]
+    InvalidThreshold { name: String, value: f64 },
+}
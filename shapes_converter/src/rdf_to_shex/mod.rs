@@ -0,0 +1,10 @@
+//! Inference of ShEx schemas from RDF data
+//!
+//!
+pub mod rdf2shex;
+pub mod rdf2shex_config;
+pub mod rdf2shex_error;
+
+pub use rdf2shex::*;
+pub use rdf2shex_config::*;
+pub use rdf2shex_error::*;
@@ -0,0 +1,317 @@
+use super::{Rdf2ShExConfig, Rdf2ShExError};
+use crate::data_profile::{DataProfile, GroupProfile, NodeGroup, PredicateProfile, ValueKind, local_label};
+use prefixmap::{IriRef, PrefixMap};
+use rudof_iri::{IriS, iri};
+use rudof_rdf::rdf_core::{
+    NeighsRDF,
+    term::Object,
+    vocabs::{RdfVocab, RdfVocabulary, XsdVocab},
+};
+use shex_ast::{
+    Annotation, NodeConstraint, NodeKind, ObjectValue, Schema, Shape, ShapeExpr, ShapeExprLabel, TripleExpr,
+    ValueSetValue,
+};
+use std::collections::HashSet;
+
+/// Infers ShEx schemas from RDF data.
+///
+/// Nodes are grouped by the objects of the type predicate (or by explicit
+/// [`NodeGroup`]s) and every group becomes a shape. The triple constraints of
+/// each shape are derived from the [`DataProfile`] of its nodes, following the
+/// thresholds of the [`Rdf2ShExConfig`].
+pub struct Rdf2ShEx {
+    config: Rdf2ShExConfig,
+}
+
+impl Rdf2ShEx {
+    pub fn new(config: &Rdf2ShExConfig) -> Rdf2ShEx {
+        Rdf2ShEx { config: config.clone() }
+    }
+
+    /// Infers a schema with one shape for each class of the RDF graph
+    pub fn infer<RDF: NeighsRDF>(&self, rdf: &RDF) -> Result<Schema, Rdf2ShExError> {
+        let groups = self.groups_by_type(rdf)?;
+        self.infer_groups(rdf, &groups)
+    }
+
+    /// Infers a schema with one shape for each group of nodes
+    pub fn infer_groups<RDF: NeighsRDF>(&self, rdf: &RDF, groups: &[NodeGroup]) -> Result<Schema, Rdf2ShExError> {
+        self.check_thresholds()?;
+        let profile = DataProfile::new(rdf, groups, self.config.max_value_set_size(), self.config.sample_size())
+            .map_err(|e| Rdf2ShExError::Profile { error: e.to_string() })?;
+        Ok(self.profile2schema(&profile, rdf.prefixmap()))
+    }
+
+    /// Groups the nodes of the RDF graph by the objects of the type predicate.
+    ///
+    /// The label of each group is the local name of its class in the shapes namespace.
+    pub fn groups_by_type<RDF: NeighsRDF>(&self, rdf: &RDF) -> Result<Vec<NodeGroup>, Rdf2ShExError> {
+        let mut used = HashSet::new();
+        DataProfile::groups_by_type(rdf, self.config.type_predicate(), |class| {
+            local_label(self.config.shapes_base_iri(), class, &mut used)
+        })
+        .map_err(|e| Rdf2ShExError::Profile { error: e.to_string() })
+    }
+
+    /// Builds a schema from a data profile
+    pub fn profile2schema(&self, profile: &DataProfile, prefixmap: Option<PrefixMap>) -> Schema {
+        let mut schema = Schema::new(&iri!("http://default/")).with_prefixmap(Some(self.prefixmap(prefixmap)));
+        for group in profile.groups() {
+            schema.add_shape(
+                ShapeExprLabel::iri(group.label().clone()),
+                self.group2shape(group),
+                false,
+            );
+        }
+        schema
+    }
+
+    fn check_thresholds(&self) -> Result<(), Rdf2ShExError> {
+        for (name, value) in [
+            ("min_support", self.config.min_support()),
+            ("min_confidence", self.config.min_confidence()),
+        ] {
+            if !(0.0..=1.0).contains(&value) {
+                return Err(Rdf2ShExError::InvalidThreshold {
+                    name: name.to_string(),
+                    value,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn prefixmap(&self, data_prefixmap: Option<PrefixMap>) -> PrefixMap {
+        let mut prefixmap = data_prefixmap.unwrap_or_default();
+        let mut add = |alias: &str, iri: IriS| {
+            if prefixmap.find(alias).is_none() {
+                prefixmap.add_prefix(alias, iri);
+            }
+        };
+        add("shapes", self.config.shapes_base_iri().clone());
+        add("xsd", IriS::new_unchecked(XsdVocab::BASE));
+        add("rdf", IriS::new_unchecked(RdfVocab::BASE));
+        if self.config.annotations() {
+            add("infer", self.config.annotation_base_iri().clone());
+        }
+        prefixmap
+    }
+
+    fn group2shape(&self, group: &GroupProfile) -> ShapeExpr {
+        let mut tcs = Vec::new();
+        let mut extra = None;
+        for predicate in group.predicates() {
+            if let Some(class) = group.class()
+                && predicate.predicate() == self.config.type_predicate()
+            {
+                // Nodes may have other types, which are allowed with EXTRA
+                let values = vec![ValueSetValue::iri(IriRef::iri(class.clone()))];
+                tcs.push(TripleExpr::triple_constraint(
+                    None,
+                    None,
+                    IriRef::iri(predicate.predicate().clone()),
+                    Some(ShapeExpr::node_constraint(NodeConstraint::new().with_values(values))),
+                    None,
+                    None,
+                ));
+                extra = Some(vec![IriRef::iri(predicate.predicate().clone())]);
+                continue;
+            }
+            if let Some(tc) = self.predicate2triple_constraint(predicate, group.nodes()) {
+                tcs.push(tc);
+            }
+        }
+        let expression = match tcs.len() {
+            0 => None,
+            1 => tcs.pop(),
+            _ => Some(TripleExpr::each_of(tcs)),
+        };
+        ShapeExpr::shape(Shape::new(None, extra, expression))
+    }
+
+    fn predicate2triple_constraint(&self, predicate: &PredicateProfile, nodes: usize) -> Option<TripleExpr> {
+        let support = ratio(predicate.subjects(), nodes);
+        if support < self.config.min_support() {
+            return None;
+        }
+        let (value_expr, confidence) = self.value_expr(predicate);
+        let min = if predicate.subjects() == nodes { 1 } else { 0 };
+        let max = if predicate.max_count() <= 1 { 1 } else { -1 };
+        let mut tc = TripleExpr::triple_constraint(
+            None,
+            None,
+            IriRef::iri(predicate.predicate().clone()),
+            value_expr,
+            if min == 1 && max == 1 { None } else { Some(min) },
+            if min == 1 && max == 1 { None } else { Some(max) },
+        );
+        if self.config.annotations() {
+            tc.add_annotation(self.ratio_annotation("support", support));
+            tc.add_annotation(self.ratio_annotation("confidence", confidence));
+        }
+        Some(tc)
+    }
+
+    /// Value expression of a predicate with the ratio of values that conform to it
+    fn value_expr(&self, predicate: &PredicateProfile) -> (Option<ShapeExpr>, f64) {
+        if let Some(values) = self.value_set(predicate) {
+            return (
+                Some(ShapeExpr::node_constraint(NodeConstraint::new().with_values(values))),
+                1.0,
+            );
+        }
+        let Some((kind, count)) = predicate.dominant_kind() else {
+            return (None, 1.0);
+        };
+        let confidence = ratio(count, predicate.triples());
+        if confidence >= self.config.min_confidence() {
+            return (kind2shape_expr(kind), confidence);
+        }
+        // No kind is dominant enough, so fall back to the most specific node kind shared by all values
+        let kinds = predicate.kinds().keys();
+        let node_kind = if kinds.clone().all(ValueKind::is_literal) {
+            Some(NodeKind::Literal)
+        } else if kinds.clone().all(ValueKind::is_iri) {
+            Some(NodeKind::Iri)
+        } else if kinds.clone().all(|k| !k.is_literal() && *k != ValueKind::Triple) {
+            Some(NodeKind::NonLiteral)
+        } else {
+            None
+        };
+        (
+            node_kind.map(|nk| ShapeExpr::node_constraint(NodeConstraint::new().with_node_kind(nk))),
+            1.0,
+        )
+    }
+
+    fn value_set(&self, predicate: &PredicateProfile) -> Option<Vec<ValueSetValue>> {
+        let values = predicate.values()?;
+        if values.is_empty()
+            || values.len() > self.config.max_value_set_size()
+            || (predicate.triples() as f64) < self.config.min_value_set_repetition() * values.len() as f64
+            || !predicate
+                .kinds()
+                .keys()
+                .all(|k| matches!(k, ValueKind::Iri | ValueKind::Datatype(_) | ValueKind::LangString))
+        {
+            return None;
+        }
+        let mut values: Vec<&Object> = values.keys().collect();
+        values.sort_by_cached_key(|v| v.to_string());
+        values
+            .into_iter()
+            .map(|v| match v {
+                Object::Iri(iri) => Some(ValueSetValue::iri(IriRef::iri(iri.clone()))),
+                Object::Literal(lit) => Some(ValueSetValue::object_value(ObjectValue::literal(lit.clone()))),
+                _ => None,
+            })
+            .collect()
+    }
+
+    fn ratio_annotation(&self, name: &str, value: f64) -> Annotation {
+        let predicate = IriS::new_unchecked(format!("{}{name}", self.config.annotation_base_iri()).as_str());
+        Annotation::new(
+            IriRef::iri(predicate),
+            ObjectValue::datatype_literal(&format!("{value:.2}"), &IriRef::iri(XsdVocab::xsd_decimal())),
+        )
+    }
+}
+
+fn ratio(n: usize, total: usize) -> f64 {
+    if total == 0 { 0.0 } else { n as f64 / total as f64 }
+}
+
+fn kind2shape_expr(kind: &ValueKind) -> Option<ShapeExpr> {
+    let nc = match kind {
+        ValueKind::Datatype(dt) => NodeConstraint::new().with_datatype(IriRef::iri(dt.clone())),
+        ValueKind::LangString => NodeConstraint::new().with_datatype(IriRef::iri(RdfVocab::rdf_lang_string())),
+        ValueKind::Shape(label) => return Some(ShapeExpr::shape_ref(ShapeExprLabel::iri(label.clone()))),
+        ValueKind::Iri => NodeConstraint::new().with_node_kind(NodeKind::Iri),
+        ValueKind::BNode => NodeConstraint::new().with_node_kind(NodeKind::BNode),
+        ValueKind::Triple => return None,
+    };
+    Some(ShapeExpr::node_constraint(nc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rudof_rdf::rdf_core::RDFFormat;
+    use shex_ast::compact::ShExFormatter;
+    use sparql_service::RdfData;
+
+    fn infer(data: &str, config: &Rdf2ShExConfig) -> String {
+        let rdf = RdfData::from_str(data, &RDFFormat::Turtle, None, &Default::default()).unwrap();
+        let schema = Rdf2ShEx::new(config).infer(&rdf).unwrap();
+        ShExFormatter::default().without_colors().format_schema(&schema)
+    }
+
+    const PEOPLE: &str = r#"prefix : <http://example.org/>
+        prefix xsd: <http://www.w3.org/2001/XMLSchema#>
+        :alice a :Person ; :name "Alice" ; :status :Active ; :knows :bob, :carol ; :worksFor :acme .
+        :bob a :Person ; :name "Bob" ; :status :Active .
+        :carol a :Person ; :name "Carol" ; :status :Inactive ; :birthDate "2000-01-01"^^xsd:date .
+        :dave a :Person ; :name "Dave" ; :status :Active .
+        :acme a :Company ; :name "ACME" ."#;
+
+    #[test]
+    fn infers_shapes_per_class() {
+        let shex = infer(PEOPLE, &Rdf2ShExConfig::default().with_annotations(false));
+        assert!(shex.contains("shapes:Person"), "{shex}");
+        assert!(shex.contains("shapes:Company"), "{shex}");
+        assert!(shex.contains(":name xsd:string"), "{shex}");
+        assert!(shex.contains(":knows @shapes:Person *"), "{shex}");
+        assert!(shex.contains(":worksFor @shapes:Company ?"), "{shex}");
+        assert!(shex.contains(":status  [ :Active :Inactive ]"), "{shex}");
+        assert!(shex.contains(":birthDate xsd:date ?"), "{shex}");
+        assert!(shex.contains("EXTRA rdf:type"), "{shex}");
+    }
+
+    #[test]
+    fn repeated_literals_give_value_sets() {
+        let shex = infer(
+            r#"prefix : <http://example.org/>
+               :a a :T ; :g "M" ; :active true . :b a :T ; :g "F" ; :active false .
+               :c a :T ; :g "M" ; :active true . :d a :T ; :g "F" ; :active true ."#,
+            &Rdf2ShExConfig::default().with_annotations(false),
+        );
+        assert!(shex.contains(r#":g  [ "F" "M" ]"#), "{shex}");
+        assert!(shex.contains(":active  [ false true ]"), "{shex}");
+    }
+
+    #[test]
+    fn support_threshold_removes_rare_predicates() {
+        let shex = infer(
+            PEOPLE,
+            &Rdf2ShExConfig::default().with_annotations(false).with_min_support(0.5),
+        );
+        assert!(!shex.contains(":birthDate"), "{shex}");
+        assert!(shex.contains(":name"), "{shex}");
+    }
+
+    #[test]
+    fn low_confidence_falls_back_to_node_kind() {
+        let shex = infer(
+            r#"prefix : <http://example.org/>
+               :a a :T ; :p 1 . :b a :T ; :p "x" . :c a :T ; :p true ."#,
+            &Rdf2ShExConfig::default()
+                .with_annotations(false)
+                .with_max_value_set_size(0),
+        );
+        assert!(shex.contains(":p LITERAL"), "{shex}");
+    }
+
+    #[test]
+    fn annotations_give_support_and_confidence() {
+        let shex = infer(PEOPLE, &Rdf2ShExConfig::default());
+        assert!(shex.contains("infer:support \"0.25\"^^xsd:decimal"), "{shex}");
+        assert!(shex.contains("infer:confidence \"1.00\"^^xsd:decimal"), "{shex}");
+    }
+
+    #[test]
+    fn invalid_thresholds_are_rejected() {
+        let rdf = RdfData::from_str("", &RDFFormat::Turtle, None, &Default::default()).unwrap();
+        let result = Rdf2ShEx::new(&Rdf2ShExConfig::default().with_min_support(2.0)).infer(&rdf);
+        assert!(matches!(result, Err(Rdf2ShExError::InvalidThreshold { .. })));
+    }
+}
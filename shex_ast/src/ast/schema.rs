@@ -3,7 +3,9 @@ use crate::{BNode, IriOrStr, ShapeExprLabel};
 use prefixmap::{IriRef, PrefixMap};
 use rudof_iri::error::IriSError;
 use rudof_iri::{IriS, iri};
+use rudof_rdf::rdf_core::SourceSpan;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::path::{Path, PathBuf};
 use std::{fs, io};
@@ -11,7 +13,7 @@ use tracing::{debug, trace};
 
 use super::{SemAct, ShapeDecl, ShapeExpr};
 
-#[derive(Deserialize, Serialize, Debug, PartialEq, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Schema {
     #[serde(rename = "@context")]
     context: String,
@@ -45,6 +47,26 @@ pub struct Schema {
     /// Source IRI from which this Schema has been obtained
     #[serde(skip)]
     source_iri: IriS,
+
+    /// Positions of the shape declarations in the source, when it was ShExC
+    #[serde(skip)]
+    shape_spans: HashMap<ShapeExprLabel, SourceSpan>,
+}
+
+/// Positions of shape declarations are not compared, so a schema read from
+/// ShExC equals the same schema read from ShExJ
+impl PartialEq for Schema {
+    fn eq(&self, other: &Self) -> bool {
+        self.context == other.context
+            && self.type_ == other.type_
+            && self.imports == other.imports
+            && self.start == other.start
+            && self.start_acts == other.start_acts
+            && self.shapes == other.shapes
+            && self.prefixmap == other.prefixmap
+            && self.base == other.base
+            && self.source_iri == other.source_iri
+    }
 }
 
 impl Schema {
@@ -59,6 +81,7 @@ impl Schema {
             prefixmap: None,
             base: None,
             source_iri: source_iri.clone(),
+            shape_spans: HashMap::new(),
         }
     }
 
@@ -171,6 +194,16 @@ impl Schema {
         }
     }
 
+    /// Records where the declaration of a shape starts in the source
+    pub fn add_shape_span(&mut self, shape_label: ShapeExprLabel, span: SourceSpan) {
+        self.shape_spans.insert(shape_label, span);
+    }
+
+    /// Position in the source of the declaration of a shape, when known
+    pub fn shape_span(&self, shape_label: &ShapeExprLabel) -> Option<&SourceSpan> {
+        self.shape_spans.get(shape_label)
+    }
+
     pub fn add_shape_decl(&mut self, shape_decl: &ShapeDecl) {
         match self.shapes {
             None => self.shapes = Some(vec![shape_decl.clone()]),
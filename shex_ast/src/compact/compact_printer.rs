@@ -13,15 +13,50 @@ pub(crate) fn pp_object_value<'a, A>(
 ) -> DocBuilder<'a, Arena<'a, A>, A> {
     match v {
         ObjectValue::IriRef(i) => pp_iri_ref(i, doc, prefixmap),
-        ObjectValue::Literal(ConcreteLiteral::BooleanLiteral(_value)) => {
-            todo!()
-        },
+        ObjectValue::Literal(ConcreteLiteral::BooleanLiteral(value)) => doc.text(value.to_string()),
         ObjectValue::Literal(ConcreteLiteral::NumericLiteral(num)) => pp_numeric_literal(num, doc),
-        ObjectValue::Literal(ConcreteLiteral::DatatypeLiteral { .. }) => todo!(),
-        ObjectValue::Literal(ConcreteLiteral::WrongDatatypeLiteral { .. }) => todo!(),
-        ObjectValue::Literal(ConcreteLiteral::DatetimeLiteral { .. }) => todo!(),
-        ObjectValue::Literal(ConcreteLiteral::StringLiteral { .. }) => todo!(),
+        ObjectValue::Literal(
+            ConcreteLiteral::DatatypeLiteral { lexical_form, datatype }
+            | ConcreteLiteral::WrongDatatypeLiteral {
+                lexical_form, datatype, ..
+            },
+        ) => pp_datatype_literal(lexical_form, datatype, doc, prefixmap),
+        ObjectValue::Literal(lit @ ConcreteLiteral::DatetimeLiteral(_)) => {
+            pp_datatype_literal(&lit.lexical_form(), &lit.datatype(), doc, prefixmap)
+        },
+        ObjectValue::Literal(ConcreteLiteral::StringLiteral { lexical_form, lang }) => match lang {
+            Some(lang) => pp_string(lexical_form, doc).append(doc.text(format!("@{lang}"))),
+            None => pp_string(lexical_form, doc),
+        },
+    }
+}
+
+fn pp_datatype_literal<'a, A>(
+    lexical_form: &str,
+    datatype: &IriRef,
+    doc: &'a Arena<'a, A>,
+    prefixmap: &PrefixMap,
+) -> DocBuilder<'a, Arena<'a, A>, A> {
+    pp_string(lexical_form, doc)
+        .append(doc.text("^^"))
+        .append(pp_iri_ref(datatype, doc, prefixmap))
+}
+
+fn pp_string<'a, A>(str: &str, doc: &'a Arena<'a, A>) -> DocBuilder<'a, Arena<'a, A>, A> {
+    let mut escaped = String::with_capacity(str.len() + 2);
+    escaped.push('"');
+    for c in str.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c => escaped.push(c),
+        }
     }
+    escaped.push('"');
+    doc.text(escaped)
 }
 
 pub(crate) fn pp_label<'a, A>(
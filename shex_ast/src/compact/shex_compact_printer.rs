@@ -495,28 +495,31 @@ where
     fn pp_literal(&self, literal: &ConcreteLiteral) -> DocBuilder<'a, Arena<'a, A>, A> {
         match literal {
             ConcreteLiteral::StringLiteral { lexical_form, lang } => self.pp_string_literal(lexical_form, lang),
-            ConcreteLiteral::DatatypeLiteral {
-                lexical_form: _,
-                datatype: _,
-            } => todo!(),
-            ConcreteLiteral::WrongDatatypeLiteral {
-                lexical_form: _,
-                datatype: _,
-                error: _,
-            } => todo!(),
+            ConcreteLiteral::DatatypeLiteral { lexical_form, datatype }
+            | ConcreteLiteral::WrongDatatypeLiteral {
+                lexical_form, datatype, ..
+            } => self.pp_datatype_literal(lexical_form, datatype),
             ConcreteLiteral::NumericLiteral(lit) => self.pp_numeric_literal(lit),
-            ConcreteLiteral::BooleanLiteral(_) => todo!(),
-            ConcreteLiteral::DatetimeLiteral(_xsd_date_time) => todo!(),
+            ConcreteLiteral::BooleanLiteral(b) => self.doc.text(b.to_string()),
+            ConcreteLiteral::DatetimeLiteral(_) => {
+                self.pp_datatype_literal(&literal.lexical_form(), &literal.datatype())
+            },
         }
     }
 
     fn pp_string_literal(&self, lexical_form: &str, lang: &Option<Lang>) -> DocBuilder<'a, Arena<'a, A>, A> {
         match lang {
-            Some(_) => todo!(),
+            Some(lang) => self.pp_string(lexical_form).append(self.doc.text(format!("@{lang}"))),
             None => self.pp_string(lexical_form),
         }
     }
 
+    fn pp_datatype_literal(&self, lexical_form: &str, datatype: &IriRef) -> DocBuilder<'a, Arena<'a, A>, A> {
+        self.pp_string(lexical_form)
+            .append(self.doc.text("^^"))
+            .append(self.pp_iri_ref(datatype))
+    }
+
     fn pp_string(&self, str: &str) -> DocBuilder<'a, Arena<'a, A>, A> {
         let s = format!("\"{str}\"");
         if let Some(color) = self.string_color {
@@ -7,6 +7,7 @@ use crate::tws0;
 use nom::Err;
 use prefixmap::DerefIri;
 use rudof_iri::IriS;
+use rudof_rdf::rdf_core::SourceSpan;
 use std::fs;
 use std::io;
 use std::path::Path;
@@ -28,13 +29,34 @@ impl ShExParser<'_> {
     ///
     /// `base` is an optional IRI that acts as the base for relative IRIs
     pub fn parse(src: &str, base: Option<IriS>, source_iri: &IriS) -> Result<Schema> {
-        let mut schema = Schema::new(source_iri).with_base(base);
+        Self::parse_with_header("", src, base, source_iri, source_iri.as_str())
+    }
+
+    /// Parse a ShEx schema in compact syntax preceded by a `header` with declarations,
+    /// usually prefixes, that are not part of the source.
+    ///
+    /// The positions of the shape declarations are recorded with `source_name` and
+    /// refer to `src`, so the lines of the header are not counted.
+    pub fn parse_with_header(
+        header: &str,
+        src: &str,
+        base: Option<IriS>,
+        source_iri: &IriS,
+        source_name: &str,
+    ) -> Result<Schema> {
+        let schema = Schema::new(source_iri).with_base(base);
+        let schema = Self::parse_statements(schema, header, None)?;
+        Self::parse_statements(schema, src, Some(source_name))
+    }
+
+    fn parse_statements(mut schema: Schema, src: &str, source_name: Option<&str>) -> Result<Schema> {
         let mut parser = ShExParser {
             shex_statement_iterator: StatementIterator::new(Span::new(src))?,
         };
         // let mut shapes_counter = 0;
         for s in parser.shex_statement_iterator.by_ref() {
-            match s? {
+            let (s, line, column) = s?;
+            match s {
                 ShExStatement::BaseDecl { iri } => {
                     schema = schema.with_base(Some(iri));
                 },
@@ -55,6 +77,9 @@ impl ShExParser<'_> {
                 } => {
                     let shape_label = shape_label.deref_iri(schema.base().as_ref(), schema.prefixmap().as_ref())?;
                     let shape_expr = shape_expr.deref_iri(schema.base().as_ref(), schema.prefixmap().as_ref())?;
+                    if let Some(source_name) = source_name {
+                        schema.add_shape_span(shape_label.clone(), SourceSpan::new(source_name, line, column));
+                    }
                     schema.add_shape(shape_label, shape_expr, is_abstract);
                 },
                 ShExStatement::StartActions { actions } => {
@@ -99,8 +124,9 @@ impl StatementIterator<'_> {
     }
 }
 
+/// Yields each statement with the line and column where it starts
 impl<'a> Iterator for StatementIterator<'a> {
-    type Item = Result<ShExStatement<'a>>;
+    type Item = Result<(ShExStatement<'a>, usize, usize)>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if self.done {
@@ -111,9 +137,10 @@ impl<'a> Iterator for StatementIterator<'a> {
             self.done = true;
             return None;
         }
+        let (line, column) = (self.src.location_line() as usize, self.src.get_utf8_column());
         match shex_statement()(self.src) {
             Ok((left, s)) => {
-                r = Some(Ok(s));
+                r = Some(Ok((s, line, column)));
                 self.src = left;
             },
             Err(Err::Incomplete(needed)) => {
@@ -170,4 +197,18 @@ mod tests {
         );
         assert_eq!(schema, expected)
     }
+
+    #[test]
+    fn test_shape_spans_skip_header() {
+        let header = "prefix e: <http://example.org/>\n";
+        let str = "\ne:S {}\n  e:T { e:p . }\n";
+        let schema = ShExParser::parse_with_header(header, str, None, &iri!("http://default/"), "schema.shex").unwrap();
+        let span = |label| {
+            schema
+                .shape_span(&ShapeExprLabel::iri_unchecked(label))
+                .map(|span| span.to_string())
+        };
+        assert_eq!(span("http://example.org/S"), Some("schema.shex:2:1".to_string()));
+        assert_eq!(span("http://example.org/T"), Some("schema.shex:3:3".to_string()));
+    }
 }
@@ -206,6 +206,9 @@ impl AST2IR {
             for shape_decl in shape_decls {
                 let label = self.shape_expr_label_to_shape_label(&shape_decl.id)?;
                 let idx = compiled_schema.add_shape(label, ShapeExpr::Empty, source_iri);
+                if let Some(span) = schema_ast.shape_span(&shape_decl.id) {
+                    compiled_schema.set_shape_span(&idx, span.clone());
+                }
                 if shape_decl.is_abstract {
                     compiled_schema.add_abstract_shape(idx);
                 }
@@ -240,6 +243,9 @@ impl AST2IR {
                     let label = self.shape_expr_label_to_shape_label(&shape_decl.id)?;
                     // trace!("Collecting shape label for {label}");
                     let idx = compiled_schema.add_shape(label, ShapeExpr::Empty, source_iri);
+                    if let Some(span) = schema_ast.shape_span(&shape_decl.id) {
+                        compiled_schema.set_shape_span(&idx, span.clone());
+                    }
                     if shape_decl.is_abstract {
                         compiled_schema.add_abstract_shape(idx);
                     }
@@ -16,6 +16,7 @@ use crate::{CResult, SchemaIRError, ShapeExprLabel, ShapeLabelIdx, ast::Schema a
 use crate::{Expr, Node, Pred, ResolveMethod};
 use prefixmap::{IriRef, PrefixMap};
 use rudof_iri::IriS;
+use rudof_rdf::rdf_core::SourceSpan;
 use serde::{Deserialize, Serialize};
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
@@ -430,6 +431,18 @@ impl SchemaIR {
         self.labels_idx_map.get(label)
     }
 
+    /// Records where the declaration of a shape starts in its source
+    pub fn set_shape_span(&mut self, idx: &ShapeLabelIdx, span: SourceSpan) {
+        if let Some(info) = self.shapes.get_mut(idx) {
+            info.set_span(span);
+        }
+    }
+
+    /// Position in the source of the declaration of a shape, when known
+    pub fn shape_span(&self, idx: &ShapeLabelIdx) -> Option<&SourceSpan> {
+        self.shapes.get(idx).and_then(|info| info.span())
+    }
+
     pub fn find_shape_idx(&self, idx: &ShapeLabelIdx) -> Option<&ShapeExprInfo> {
         self.shapes.get(idx)
     }
@@ -1,3 +1,4 @@
+use rudof_rdf::rdf_core::SourceSpan;
 use serde::{Deserialize, Serialize};
 
 use crate::ir::{shape_expr::ShapeExpr, shape_label::ShapeLabel, source_idx::SourceIdx};
@@ -7,17 +8,27 @@ pub struct ShapeExprInfo {
     label: Option<ShapeLabel>,
     expr: ShapeExpr,
     source: SourceIdx,
+    span: Option<SourceSpan>,
 }
 
 impl ShapeExprInfo {
     pub fn new(label: Option<ShapeLabel>, expr: ShapeExpr, source: SourceIdx) -> Self {
-        ShapeExprInfo { label, expr, source }
+        ShapeExprInfo {
+            label,
+            expr,
+            source,
+            span: None,
+        }
     }
 
     pub fn set_expr(&mut self, expr: ShapeExpr) {
         self.expr = expr;
     }
 
+    pub fn set_span(&mut self, span: SourceSpan) {
+        self.span = Some(span);
+    }
+
     pub fn label(&self) -> Option<&ShapeLabel> {
         self.label.as_ref()
     }
@@ -29,4 +40,9 @@ impl ShapeExprInfo {
     pub fn source_idx(&self) -> &SourceIdx {
         &self.source
     }
+
+    /// Position in the source of the declaration of the shape, when known
+    pub fn span(&self) -> Option<&SourceSpan> {
+        self.span.as_ref()
+    }
 }
@@ -0,0 +1,222 @@
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Proof tree explaining why a node conforms (or not) to a shape.
+///
+/// It is recorded by the ShEx validator when justifications are enabled and
+/// mirrors the steps taken during evaluation: the shape expression operators,
+/// the partition of neighbours chosen for shapes with `EXTENDS`, the triples
+/// matched by each triple constraint and the nested shape references.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Justification {
+    step: JustificationStep,
+    node: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    shape: Option<String>,
+    conforms: bool,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    detail: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    matches: Vec<TripleMatch>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    children: Vec<Justification>,
+}
+
+/// Kind of evaluation step recorded in a [`Justification`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum JustificationStep {
+    ShapeLabel,
+    Shape,
+    And,
+    Or,
+    Not,
+    NodeConstraint,
+    Ref,
+    Extends,
+    Partition,
+    TripleExpr,
+    Descendant,
+    External,
+    Empty,
+    /// A reference that was assumed to hold while proving a cyclic dependency
+    Hypothesis,
+}
+
+/// A triple of the neighbourhood of a node matched by a triple constraint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TripleMatch {
+    predicate: String,
+    value: String,
+    constraint: String,
+}
+
+impl Justification {
+    pub fn new(step: JustificationStep, node: impl Into<String>, conforms: bool) -> Self {
+        Justification {
+            step,
+            node: node.into(),
+            shape: None,
+            conforms,
+            detail: None,
+            matches: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    pub fn with_shape(mut self, shape: impl Into<String>) -> Self {
+        self.shape = Some(shape.into());
+        self
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    pub fn with_matches(mut self, matches: Vec<TripleMatch>) -> Self {
+        self.matches = matches;
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Justification>) -> Self {
+        self.children = children;
+        self
+    }
+
+    pub fn step(&self) -> JustificationStep {
+        self.step
+    }
+
+    pub fn node(&self) -> &str {
+        &self.node
+    }
+
+    pub fn shape(&self) -> Option<&str> {
+        self.shape.as_deref()
+    }
+
+    pub fn conforms(&self) -> bool {
+        self.conforms
+    }
+
+    pub fn detail(&self) -> Option<&str> {
+        self.detail.as_deref()
+    }
+
+    pub fn matches(&self) -> &[TripleMatch] {
+        &self.matches
+    }
+
+    pub fn children(&self) -> &[Justification] {
+        &self.children
+    }
+
+    fn fmt_indented(&self, f: &mut std::fmt::Formatter<'_>, level: usize) -> std::fmt::Result {
+        let indent = "  ".repeat(level);
+        let status = if self.conforms { "conforms" } else { "fails" };
+        write!(f, "{indent}{} {}", self.step, self.node)?;
+        if let Some(shape) = &self.shape {
+            write!(f, "@{shape}")?;
+        }
+        write!(f, ": {status}")?;
+        if let Some(detail) = &self.detail {
+            write!(f, " ({detail})")?;
+        }
+        writeln!(f)?;
+        for m in &self.matches {
+            writeln!(f, "{indent}  - {} {} matched by {}", m.predicate, m.value, m.constraint)?;
+        }
+        for child in &self.children {
+            child.fmt_indented(f, level + 1)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Justification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_indented(f, 0)
+    }
+}
+
+impl Display for JustificationStep {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let str = match self {
+            JustificationStep::ShapeLabel => "ShapeLabel",
+            JustificationStep::Shape => "Shape",
+            JustificationStep::And => "And",
+            JustificationStep::Or => "Or",
+            JustificationStep::Not => "Not",
+            JustificationStep::NodeConstraint => "NodeConstraint",
+            JustificationStep::Ref => "Ref",
+            JustificationStep::Extends => "Extends",
+            JustificationStep::Partition => "Partition",
+            JustificationStep::TripleExpr => "TripleExpr",
+            JustificationStep::Descendant => "Descendant",
+            JustificationStep::External => "External",
+            JustificationStep::Empty => "Empty",
+            JustificationStep::Hypothesis => "Hypothesis",
+        };
+        write!(f, "{str}")
+    }
+}
+
+impl TripleMatch {
+    pub fn new(predicate: impl Into<String>, value: impl Into<String>, constraint: impl Into<String>) -> Self {
+        TripleMatch {
+            predicate: predicate.into(),
+            value: value.into(),
+            constraint: constraint.into(),
+        }
+    }
+
+    pub fn predicate(&self) -> &str {
+        &self.predicate
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn constraint(&self) -> &str {
+        &self.constraint
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Justification {
+        Justification::new(JustificationStep::ShapeLabel, ":alice", true)
+            .with_shape(":Person")
+            .with_children(vec![
+                Justification::new(JustificationStep::TripleExpr, ":alice", true).with_matches(vec![TripleMatch::new(
+                    ":name",
+                    "\"Alice\"",
+                    "xsd:string",
+                )]),
+            ])
+    }
+
+    #[test]
+    fn display_is_indented() {
+        let expected = "\
+ShapeLabel :alice@:Person: conforms
+  TripleExpr :alice: conforms
+    - :name \"Alice\" matched by xsd:string
+";
+        assert_eq!(example().to_string(), expected);
+    }
+
+    #[test]
+    fn json_skips_empty_fields() {
+        let json = serde_json::to_value(example()).unwrap();
+        assert_eq!(json["step"], "shapeLabel");
+        assert_eq!(json["children"][0]["matches"][0]["predicate"], ":name");
+        assert!(json["children"][0].get("children").is_none());
+        let back: Justification = serde_json::from_value(json).unwrap();
+        assert_eq!(back, example());
+    }
+}
@@ -6,6 +6,7 @@
 //!
 pub mod association;
 pub mod conformant_info;
+pub mod justification;
 pub mod node_selector;
 pub mod non_conformant_info;
 pub mod pattern;
@@ -22,6 +23,7 @@ pub mod validation_status;
 
 pub use association::*;
 pub use conformant_info::*;
+pub use justification::*;
 pub use node_selector::*;
 pub use non_conformant_info::*;
 pub use pattern::*;
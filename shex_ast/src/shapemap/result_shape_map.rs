@@ -1,11 +1,13 @@
 use colored::*;
 use itertools::Itertools;
+use rudof_rdf::rdf_core::SourceSpan;
 use rudof_rdf::rdf_core::term::Object;
 use serde::Serialize;
 use tabled::settings::Modify;
 use tabled::settings::Width;
 use tabled::settings::object::Segment;
 
+use crate::shapemap::Justification;
 use crate::shapemap::ShapemapConfig;
 use crate::shapemap::ShapemapError;
 use crate::shapemap::ValidationStatus;
@@ -23,6 +25,15 @@ use tabled::{builder::Builder, settings::Style};
 pub struct ResultShapeMap {
     result: HashMap<Node, HashMap<ShapeLabel, ValidationStatus>>,
 
+    /// Proof trees per node and shape, only filled when the validator records them
+    justifications: HashMap<(Node, ShapeLabel), Justification>,
+
+    /// Positions in the data where the nodes were found, when the data records them
+    node_spans: HashMap<Node, SourceSpan>,
+
+    /// Positions in the schema where the shapes were declared, when known
+    shape_spans: HashMap<ShapeLabel, SourceSpan>,
+
     config: ShapemapConfig,
 }
 
@@ -167,6 +178,14 @@ impl ResultShapeMap {
                             ) => todo!(
                                 "Handle the case where we have two inconsistent statuses for the same node and shape"
                             ),
+                            // A decided status is more informative than a timeout
+                            (ValidationStatus::Timeout(_), status) => {
+                                if !status.is_pending() {
+                                    *cell_status = status
+                                }
+                            },
+                            (ValidationStatus::Pending, status @ ValidationStatus::Timeout(_)) => *cell_status = status,
+                            (_, ValidationStatus::Timeout(_)) => {},
                         };
                         ok()
                     },
@@ -186,6 +205,40 @@ impl ResultShapeMap {
         ok()
     }
 
+    pub fn add_justification(&mut self, node: Node, shape_label: ShapeLabel, justification: Justification) {
+        self.justifications.insert((node, shape_label), justification);
+    }
+
+    pub fn justification(&self, node: &Node, label: &ShapeLabel) -> Option<&Justification> {
+        self.justifications.get(&(node.clone(), label.clone()))
+    }
+
+    pub fn justifications(&self) -> impl Iterator<Item = (&Node, &ShapeLabel, &Justification)> {
+        self.justifications.iter().map(|((node, shape), j)| (node, shape, j))
+    }
+
+    pub fn add_node_span(&mut self, node: Node, span: SourceSpan) {
+        self.node_spans.insert(node, span);
+    }
+
+    /// Position in the data of the first triple with `node` as subject
+    pub fn node_span(&self, node: &Node) -> Option<&SourceSpan> {
+        self.node_spans.get(node)
+    }
+
+    pub fn add_shape_span(&mut self, shape_label: ShapeLabel, span: SourceSpan) {
+        self.shape_spans.insert(shape_label, span);
+    }
+
+    /// Position in the schema of the declaration of a shape
+    pub fn shape_span(&self, label: &ShapeLabel) -> Option<&SourceSpan> {
+        self.shape_spans.get(label)
+    }
+
+    fn show_location(&self, node: &Node) -> String {
+        self.node_span(node).map(|span| span.to_string()).unwrap_or_default()
+    }
+
     pub fn get_info(&self, node: &Node, label: &ShapeLabel) -> Option<ValidationStatus> {
         match self.result.get(node) {
             Some(shapes) => shapes.get(label).cloned(),
@@ -199,9 +252,18 @@ impl ResultShapeMap {
             .flat_map(|(node, shapes)| shapes.iter().map(move |(shape, status)| (node, shape, status)))
     }
 
+    /// Iterates over the entries in the order given by `sort_mode`
+    pub fn iter_sorted(&self, sort_mode: &SortMode) -> impl Iterator<Item = (&Node, &ShapeLabel, &ValidationStatus)> {
+        self.iter().sorted_by(self.get_comparator(sort_mode))
+    }
+
     pub fn as_csv<W: Write>(&self, writer: W, sort_mode: SortMode, with_details: bool) -> Result<(), Error> {
         let mut wtr = csv::Writer::from_writer(writer);
-        wtr.write_record(["node", "shape", "status", "details"])?;
+        if with_details {
+            wtr.write_record(["node", "shape", "status", "details", "location"])?;
+        } else {
+            wtr.write_record(["node", "shape", "status"])?;
+        }
 
         let cmp = self.get_comparator(&sort_mode);
         for (node, label, status) in self.iter().sorted_by(cmp) {
@@ -216,13 +278,20 @@ impl ResultShapeMap {
                     self.fail_text().color(*self.fail_color()),
                 ),
                 ValidationStatus::Pending => ("".to_owned(), "Pending".color(*self.pending_color())),
+                ValidationStatus::Timeout(reason) => (reason.clone(), "Timeout".color(*self.pending_color())),
                 ValidationStatus::Inconsistent(ci, nci) => (
                     format!("Conformant: {ci}, Non-conformant: {nci}"),
                     "Inconsistent".color(*self.pending_color()),
                 ),
             };
             if with_details {
-                wtr.write_record([node_label, shape_label, status_label.to_string(), details])?;
+                wtr.write_record([
+                    node_label,
+                    shape_label,
+                    status_label.to_string(),
+                    details,
+                    self.show_location(node),
+                ])?;
             } else {
                 wtr.write_record([node_label, shape_label, status_label.to_string()])?;
             }
@@ -244,7 +313,7 @@ impl ResultShapeMap {
 
         let mut builder = Builder::default();
         if with_details {
-            builder.push_record(["Node", "Shape", "Status", "Details"]);
+            builder.push_record(["Node", "Shape", "Status", "Details", "Location"]);
         } else {
             builder.push_record(["Node", "Shape", "Status"]);
         }
@@ -262,13 +331,20 @@ impl ResultShapeMap {
                     self.fail_text().color(*self.fail_color()),
                 ),
                 ValidationStatus::Pending => ("".to_owned(), "Pending".color(*self.pending_color())),
+                ValidationStatus::Timeout(reason) => (reason.clone(), "Timeout".color(*self.pending_color())),
                 ValidationStatus::Inconsistent(ci, nci) => (
                     format!("Conformant: {ci}, Non-conformant: {nci}"),
                     "Inconsistent".color(*self.pending_color()),
                 ),
             };
             if with_details {
-                builder.push_record([node_label, shape_label, status_label.to_string(), details]);
+                builder.push_record([
+                    node_label,
+                    shape_label,
+                    status_label.to_string(),
+                    details,
+                    self.show_location(node),
+                ]);
             } else {
                 builder.push_record([node_label, shape_label, status_label.to_string()]);
             }
@@ -329,6 +405,9 @@ struct ResultSerializer<'a> {
     node: &'a Node,
     shape: &'a ShapeLabel,
     status: &'a ValidationStatus,
+    justification: Option<&'a Justification>,
+    node_span: Option<&'a SourceSpan>,
+    shape_span: Option<&'a SourceSpan>,
 }
 
 impl Serialize for ResultSerializer<'_> {
@@ -336,12 +415,21 @@ impl Serialize for ResultSerializer<'_> {
     where
         S: serde::Serializer,
     {
-        let mut map = serializer.serialize_map(Some(4))?;
+        let mut map = serializer.serialize_map(None)?;
         map.serialize_entry("node", &self.node.to_string())?;
         map.serialize_entry("shape", &self.shape.to_string())?;
         map.serialize_entry("status", &self.status.code())?;
         map.serialize_entry("appInfo", &self.status.app_info())?;
         map.serialize_entry("reason", &self.status.reason())?;
+        if let Some(justification) = self.justification {
+            map.serialize_entry("justification", justification)?;
+        }
+        if let Some(span) = self.node_span {
+            map.serialize_entry("nodeLocation", span)?;
+        }
+        if let Some(span) = self.shape_span {
+            map.serialize_entry("shapeLocation", span)?;
+        }
         map.end()
     }
 }
@@ -353,7 +441,14 @@ impl Serialize for ResultShapeMap {
     {
         let mut seq = serializer.serialize_seq(Some(self.result.len()))?;
         for (node, shape, status) in self.iter() {
-            let result_aux = ResultSerializer { node, shape, status };
+            let result_aux = ResultSerializer {
+                node,
+                shape,
+                status,
+                justification: self.justification(node, shape),
+                node_span: self.node_span(node),
+                shape_span: self.shape_span(shape),
+            };
             seq.serialize_element(&result_aux)?;
         }
         seq.end()
@@ -12,6 +12,8 @@ pub enum ValidationStatus {
     NonConformant(NonConformantInfo),
     Pending,
     Inconsistent(ConformantInfo, NonConformantInfo),
+    /// Validation was interrupted before it could decide, e.g. because it ran out of time
+    Timeout(String),
 }
 
 impl ValidationStatus {
@@ -27,6 +29,10 @@ impl ValidationStatus {
         matches!(self, ValidationStatus::Pending)
     }
 
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, ValidationStatus::Timeout(_))
+    }
+
     pub fn conformant(reason: String, value: Value) -> ValidationStatus {
         ValidationStatus::Conformant(ConformantInfo::new(reason, value))
     }
@@ -39,12 +45,17 @@ impl ValidationStatus {
         ValidationStatus::Pending
     }
 
+    pub fn timeout(reason: String) -> ValidationStatus {
+        ValidationStatus::Timeout(reason)
+    }
+
     pub fn code(&self) -> String {
         match self {
             ValidationStatus::Conformant(_) => "conformant".to_string(),
             ValidationStatus::NonConformant(_) => "nonconformant".to_string(),
             ValidationStatus::Pending => "pending".to_string(),
             ValidationStatus::Inconsistent(_, _) => "inconsistent".to_string(),
+            ValidationStatus::Timeout(_) => "timeout".to_string(),
         }
     }
 
@@ -72,6 +83,7 @@ impl ValidationStatus {
                     "non_conformant": non_conformant.app_info()
                 })
             },
+            ValidationStatus::Timeout(reason) => serde_json::json!({ "status": "timeout", "reason": reason }),
         }
     }
 
@@ -87,6 +99,7 @@ impl ValidationStatus {
                     non_conformant.reason()
                 )
             },
+            ValidationStatus::Timeout(reason) => reason.clone(),
         }
     }
 }
@@ -109,6 +122,9 @@ impl Display for ValidationStatus {
                     "Inconsistent, conformant: {conformant}, inconformant: {inconformant}"
                 )
             },
+            ValidationStatus::Timeout(reason) => {
+                write!(f, "Timeout, reason: {reason}")
+            },
         }
     }
 }
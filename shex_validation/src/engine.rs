@@ -5,19 +5,22 @@ use crate::Reasons;
 use crate::ValidatorConfig;
 use crate::ValidatorErrors;
 use crate::atom;
+use crate::justifier::{Justifier, ProofStep};
 use crate::no_match_reason::NoMatchReason;
 use crate::ref_typing::RefTyping;
 use crate::validator_error::*;
 use either::Either;
 use indexmap::IndexSet;
 use itertools::Itertools;
-// use prefixmap::PrefixMap;
+use prefixmap::PrefixMap;
+use rayon::prelude::*;
 use rbe::MatchCond;
 use rudof_iri::iri;
 use rudof_rdf::rdf_core::{
     NeighsRDF,
     query::QueryRDF,
     term::{BlankNode, Iri as _, Object},
+    utils::{CancellationToken, Interruption, Profiler, ValidationBudget},
 };
 use shex_ast::Expr;
 use shex_ast::Node;
@@ -32,9 +35,13 @@ use shex_ast::ir::sem_act::SemAct;
 use shex_ast::ir::semantic_action_context::SemanticActionContext;
 use shex_ast::ir::shape::Shape;
 use shex_ast::ir::shape_expr::ShapeExpr;
+use shex_ast::shapemap::{Justification, JustificationStep};
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::hash_map::Entry;
+use std::fmt::Display;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 use tracing::debug;
 use tracing::trace;
 
@@ -45,6 +52,23 @@ type PosAtom = (Node, ShapeLabelIdx);
 type Neighs = (Vec<(Pred, Node)>, Vec<Pred>);
 type ValidationResult = Either<Vec<ValidatorError>, Vec<Reason>>;
 
+/// A pending atom whose validation has finished
+pub(crate) enum Decided<'a> {
+    /// The atom was decided; it is negated when the node does not conform
+    Checked(&'a Atom),
+    /// The validation of the atom was interrupted before deciding it
+    Interrupted(&'a Atom, Interruption),
+}
+
+/// Called with the engine that validated it each time a pending atom is decided.
+/// With parallel validation it is called from several threads.
+pub(crate) type OnDecided<'a> = &'a (dyn Fn(&Engine, Decided<'_>) + Sync);
+
+/// Memoized results of `prove`, shared by the engines forked from the same engine.
+/// Only results that don't depend on recursion hypotheses are stored, so they hold
+/// no matter which engine, or in which order, the atoms are validated.
+type SharedTyping = Arc<RwLock<HashMap<(Node, ShapeLabelIdx), ValidationResult>>>;
+
 #[derive(Debug, Clone)]
 pub struct Engine {
     checked: IndexSet<Atom>,
@@ -53,8 +77,19 @@ pub struct Engine {
     step_counter: usize,
     reasons: HashMap<PosAtom, Vec<Reason>>,
     errors: HashMap<NegAtom, Vec<ValidatorError>>,
-    typing: HashMap<(Node, ShapeLabelIdx), ValidationResult>,
+    typing: SharedTyping,
     hyp_touched: bool,
+    justifier: Justifier,
+    /// Token of the whole run
+    run: CancellationToken,
+    /// Budget for each pending atom, counted from the moment it is popped
+    node_timeout: Option<Duration>,
+    /// Token of the atom being validated, checked while proving it
+    interrupt: CancellationToken,
+    /// Atoms whose validation was interrupted before being decided
+    interrupted: HashMap<PosAtom, Interruption>,
+    /// Profiler recording the shapes and shape expressions checked
+    profiler: Profiler,
 }
 
 impl Engine {
@@ -66,21 +101,42 @@ impl Engine {
             step_counter: 0,
             reasons: HashMap::new(),
             errors: HashMap::new(),
-            typing: HashMap::new(),
+            typing: Arc::new(RwLock::new(HashMap::new())),
             hyp_touched: false,
+            justifier: Justifier::new(config.justify()),
+            run: CancellationToken::new(),
+            node_timeout: None,
+            interrupt: CancellationToken::new(),
+            interrupted: HashMap::new(),
+            profiler: Profiler::disabled(),
         }
     }
 
+    /// Starts the time budget of a run: it applies to the atoms validated from now on
+    pub fn start_budget(&mut self, budget: &ValidationBudget) {
+        self.run = budget.start_run();
+        self.node_timeout = budget.node_timeout();
+        self.profiler = budget.profiler().clone();
+    }
+
     pub fn reset(&mut self) {
         let config = self.config.clone();
         *self = Engine::new(&config);
     }
 
-    pub(crate) fn validate_pending<R>(&mut self, rdf: &R, schema: &SchemaIR) -> Result<()>
+    /// Validates the pending atoms, calling `on_decided` as soon as each one is decided
+    pub(crate) fn validate_pending<R>(&mut self, rdf: &R, schema: &SchemaIR, on_decided: OnDecided<'_>) -> Result<()>
     where
         R: NeighsRDF + QueryRDF,
     {
         while let Some(atom) = self.pop_pending() {
+            // Once the run is interrupted the remaining atoms are only marked as such
+            if let Some(interruption) = self.run.interruption() {
+                self.add_interrupted(atom.clone(), interruption);
+                on_decided(self, Decided::Interrupted(&atom, interruption));
+                continue;
+            }
+            self.interrupt = self.run.with_timeout(self.node_timeout);
             match atom.clone() {
                 Atom::Pos((node, idx)) => {
                     if !check_start_acts(schema.start_acts(), &node, &idx, schema)? {
@@ -91,17 +147,29 @@ impl Engine {
                                 idx,
                             }],
                         );
+                        on_decided(self, Decided::Checked(&atom.negated()));
                         // We can abort validation if start actions failed
                         continue;
                     }
                     let mut hyp = Vec::new();
-                    match self.prove(&node, &idx, &mut hyp, schema, rdf)? {
-                        Either::Right(reasons) => {
-                            self.add_checked_pos(atom, reasons);
+                    match self.prove(&node, &idx, &mut hyp, schema, rdf) {
+                        Ok(Either::Right(reasons)) => {
+                            self.add_checked_pos(atom.clone(), reasons);
+                            on_decided(self, Decided::Checked(&atom));
                         },
-                        Either::Left(errors) => {
-                            self.add_checked_neg(atom, errors);
+                        Ok(Either::Left(errors)) => {
+                            self.add_checked_neg(atom.clone(), errors);
+                            on_decided(self, Decided::Checked(&atom.negated()));
                         },
+                        Err(ValidatorError::Interrupted { interruption }) => {
+                            // Nothing proved while validating this atom was cached, so the
+                            // only state to clean up is the one of the unfinished proof
+                            self.hyp_touched = false;
+                            self.justifier.abandon();
+                            self.add_interrupted(atom.clone(), interruption);
+                            on_decided(self, Decided::Interrupted(&atom, interruption));
+                        },
+                        Err(e) => return Err(e),
                     }
                 },
                 Atom::Neg((node, idx)) => {
@@ -112,6 +180,85 @@ impl Engine {
         Ok(())
     }
 
+    /// Validates the pending atoms with a pool of `threads` threads (0 uses all cores).
+    ///
+    /// The pending atoms are split in chunks, each one validated by an engine forked
+    /// from this one, and the results are merged back in chunk order.
+    pub(crate) fn validate_pending_parallel<R>(
+        &mut self,
+        rdf: &R,
+        schema: &SchemaIR,
+        threads: usize,
+        on_decided: OnDecided<'_>,
+    ) -> Result<()>
+    where
+        R: NeighsRDF + QueryRDF + Sync,
+    {
+        let pending: Vec<Atom> = std::mem::take(&mut self.pending).into_iter().collect();
+        if pending.is_empty() {
+            return Ok(());
+        }
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .map_err(|e| ValidatorError::ThreadPoolError { error: e.to_string() })?;
+        // Several chunks per thread so that threads that finish early can pick up more work
+        let chunk_size = pending.len().div_ceil(pool.current_num_threads() * 4);
+        let mut forks: Vec<Engine> = pending
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let mut fork = self.fork();
+                fork.pending.extend(chunk.iter().cloned());
+                fork
+            })
+            .collect();
+        let results: Vec<Result<()>> = pool.install(|| {
+            forks
+                .par_iter_mut()
+                .map(|fork| fork.validate_pending(rdf, schema, on_decided))
+                .collect()
+        });
+        for (fork, result) in forks.into_iter().zip(results) {
+            result?;
+            self.merge(fork);
+        }
+        Ok(())
+    }
+
+    /// Engine with the same configuration, typing cache and budget, but no pending or checked atoms
+    fn fork(&self) -> Engine {
+        Engine {
+            typing: Arc::clone(&self.typing),
+            run: self.run.clone(),
+            node_timeout: self.node_timeout,
+            profiler: self.profiler.clone(),
+            ..Engine::new(&self.config)
+        }
+    }
+
+    fn merge(&mut self, other: Engine) {
+        self.checked.extend(other.checked);
+        for (pa, rs) in other.reasons {
+            self.add_reasons(pa, rs);
+        }
+        for (na, es) in other.errors {
+            self.add_errors(na, es);
+        }
+        self.step_counter += other.step_counter;
+        self.justifier.merge(other.justifier);
+        self.interrupted.extend(other.interrupted);
+    }
+
+    fn add_interrupted(&mut self, atom: Atom, interruption: Interruption) {
+        let (node, idx) = atom.get_value();
+        self.interrupted.insert((node.clone(), *idx), interruption);
+    }
+
+    /// Atoms whose validation was interrupted, with the reason
+    pub(crate) fn interrupted(&self) -> impl Iterator<Item = (&PosAtom, &Interruption)> {
+        self.interrupted.iter()
+    }
+
     pub(crate) fn add_checked_pos(&mut self, atom: Atom, reasons: Vec<Reason>) {
         let new_atom = atom.clone();
         match atom {
@@ -160,6 +307,18 @@ impl Engine {
         }
     }
 
+    /// Proof tree recorded for `node` and the shape `idx`.
+    /// It is only available when justifications are enabled in the configuration.
+    pub fn justification(
+        &self,
+        node: &Node,
+        idx: &ShapeLabelIdx,
+        nodes_prefixmap: &PrefixMap,
+        schema: &SchemaIR,
+    ) -> Option<Justification> {
+        self.justifier.justification(node, idx, nodes_prefixmap, schema)
+    }
+
     pub(crate) fn pending(&self) -> IndexSet<Atom> {
         self.pending.clone()
     }
@@ -291,9 +450,11 @@ impl Engine {
         R: NeighsRDF + QueryRDF,
     {
         let key = (node.clone(), *label);
-        if let Some(cached) = self.typing.get(&key) {
+        if let Some(cached) = self.typing.read().unwrap().get(&key) {
+            self.profiler.record_cache_hit(&ProfileShape(schema, label));
             return Ok(cached.clone());
         }
+        self.interrupt.check()?;
 
         let saved_hyp_touched = self.hyp_touched;
         self.hyp_touched = false;
@@ -322,12 +483,14 @@ impl Engine {
         for (n, l) in hyp_as_set.iter() {
             typing.insert_passed(n.clone(), *l);
         }
+        self.justifier.enter(JustificationStep::ShapeLabel, node, Some(*label));
         let result = self.check_node_idx(node, label, schema, rdf, &mut typing, hyp)?;
+        self.justifier.exit_proof(node, label, &result);
         hyp.pop();
 
         let mine = self.hyp_touched;
         if !mine {
-            self.typing.insert(key, result.clone());
+            self.typing.write().unwrap().insert(key, result.clone());
         }
 
         self.hyp_touched = saved_hyp_touched || mine;
@@ -351,6 +514,7 @@ impl Engine {
             "Checking {node}@{idx}, typing: [{}]",
             typing.iter().map(|(n, l)| format!("{n}@{l}")).join(", ")
         );*/
+        let _span = self.profiler.span(&ProfileShape(schema, idx), None);
         if let Some(info) = schema.find_shape_idx(idx) {
             if schema.is_abstract(idx) {
                 let descendants = schema.descendants(idx);
@@ -429,7 +593,10 @@ impl Engine {
             // Use prove (not check_node_idx) so each descendant builds its own complete
             // typing from its own dependency set.  This implements the ShEx RESTRICTS
             // property: if the node conforms to a descendant, it conforms to the ancestor.
-            match self.prove(node, &desc, hyp, schema, rdf)? {
+            let result = self.prove(node, &desc, hyp, schema, rdf)?;
+            self.justifier
+                .add(ProofStep::new(JustificationStep::Descendant, node, Some(desc)).with_conforms(result.is_right()));
+            match result {
                 Either::Left(errors) => {
                     trace!(
                         "Descendant {desc} failed for node {node}\nErrors: {}",
@@ -467,6 +634,36 @@ impl Engine {
         rdf: &R,
         typing: &mut RefTyping,
     ) -> Result<ValidationResult>
+    where
+        R: NeighsRDF + QueryRDF,
+    {
+        // References record their own step in check_node_ref
+        let step = match se {
+            ShapeExpr::ShapeAnd { .. } => JustificationStep::And,
+            ShapeExpr::ShapeOr { .. } => JustificationStep::Or,
+            ShapeExpr::ShapeNot { .. } => JustificationStep::Not,
+            ShapeExpr::NodeConstraint(_) => JustificationStep::NodeConstraint,
+            ShapeExpr::Shape(_) => JustificationStep::Shape,
+            ShapeExpr::External {} => JustificationStep::External,
+            ShapeExpr::Empty => JustificationStep::Empty,
+            ShapeExpr::Ref { .. } => return self.check_node_shape_expr_step(idx, node, se, schema, rdf, typing),
+        };
+        let _span = self.profiler.span(&ProfileShape(schema, idx), Some(&step));
+        self.justifier.enter(step, node, Some(*idx));
+        let result = self.check_node_shape_expr_step(idx, node, se, schema, rdf, typing)?;
+        self.justifier.exit(&result);
+        Ok(result)
+    }
+
+    fn check_node_shape_expr_step<R>(
+        &self,
+        idx: &ShapeLabelIdx,
+        node: &Node,
+        se: &ShapeExpr,
+        schema: &SchemaIR,
+        rdf: &R,
+        typing: &mut RefTyping,
+    ) -> Result<ValidationResult>
     where
         R: NeighsRDF + QueryRDF,
     {
@@ -556,7 +753,10 @@ impl Engine {
                             nc: (**nc).clone(),
                         })
                     },
-                    Err(err) => fail(ValidatorError::RbeError(err)),
+                    Err(err) => {
+                        self.justifier.detail(err.to_string());
+                        fail(ValidatorError::RbeError(err))
+                    },
                 }
             },
             ShapeExpr::Shape(shape) => {
@@ -609,8 +809,11 @@ impl Engine {
     fn check_node_ref(&self, node: &Node, idx: &ShapeLabelIdx, typing: &mut RefTyping) -> Result<ValidationResult> {
         /*debug!("Checking node {node} with shape ref {idx}"); */
 
+        let conforms = typing.contains(&(node.clone(), *idx));
+        self.justifier
+            .add(ProofStep::new(JustificationStep::Ref, node, Some(*idx)).with_conforms(conforms));
         // If the node is already in the typing, we can return true
-        if typing.contains(&(node.clone(), *idx)) {
+        if conforms {
             pass(Reason::ShapeRef {
                 node: node.clone(),
                 idx: *idx,
@@ -666,12 +869,16 @@ impl Engine {
             })
             .collect::<Vec<_>>();
         if shape.is_closed() && !reminder.is_empty() {
+            self.justifier.detail(format!(
+                "closed shape with remaining predicates [{}]",
+                reminder.iter().map(|p| p.to_string()).join(", ")
+            ));
             return fail(ValidatorError::ClosedShapeWithRemainderPreds {
                 remainder: Preds::new(reminder),
                 declared: Preds::new(candidate_preds),
             });
         }
-        check_expr_neigh(shape.triple_expr(), &values_ctx, node, shape, idx, typing)
+        self.check_expr_neigh(shape.triple_expr(), &values_ctx, node, shape, idx, typing)
     }
 
     pub(crate) fn check_node_shape_extends<R>(
@@ -724,6 +931,10 @@ impl Engine {
                 "Closed shape {idx} with extends has remainder preds: [{}]",
                 reminder.iter().map(|p| p.to_string()).join(", ")
             );*/
+            self.justifier.detail(format!(
+                "closed shape with remaining predicates [{}]",
+                reminder.iter().map(|p| p.to_string()).join(", ")
+            ));
             return fail(ValidatorError::ClosedShapeWithRemainderPreds {
                 remainder: Preds::new(reminder),
                 declared: Preds::new(candidate_preds),
@@ -865,7 +1076,9 @@ impl Engine {
         tracing::trace!("Checking extends of shape {idx} for node {node}");
         for e in shape.extends() {
             tracing::trace!("Checking extends of shape {idx} for node {node} with extends {e}");
+            self.justifier.enter(JustificationStep::Extends, node, Some(*e));
             let result_parents = self.check_node_extends_main_shape(node, e, shape, schema, rdf, typing)?;
+            self.justifier.exit(&result_parents);
             match result_parents {
                 Either::Left(errors) => {
                     /*debug!(
@@ -902,6 +1115,8 @@ impl Engine {
         for rbes in triple_exprs.values() {
             for rbe in rbes {
                 if !rbe.feasible_neighs(&values_ctx) {
+                    self.justifier
+                        .detail("no assignment of the neighbourhood can satisfy the triple expressions");
                     return fail(ValidatorError::TripleExprRefuted {
                         node: Box::new(node.clone()),
                         idx: *idx,
@@ -927,13 +1142,15 @@ impl Engine {
             for (npart, partition) in parts_peekable.enumerate() {
                 let partition_display = create_partitions_display(&partition);
                 //debug!("Partition {npart}: {}", partition_display);
+                self.justifier.enter(JustificationStep::Partition, node, None);
+                self.justifier.detail(format!("partition {npart}"));
                 let mut ok_partition = true;
                 let mut errors_in_loop = Vec::new();
                 let mut reasons_in_loop = Vec::new();
                 let mut allocated: HashMap<Option<ShapeLabelIdx>, Vec<_>> = HashMap::new();
                 for (maybe_label, rbes, neighs_subset) in partition.iter() {
                     allocated.insert(*maybe_label, neighs_subset.clone());
-                    let result = check_exprs_neigh(rbes, neighs_subset, node, shape, idx, typing)?;
+                    let result = self.check_exprs_neigh(rbes, neighs_subset, node, shape, idx, typing)?;
                     match result {
                         Either::Right(reasons) => {
                             /*debug!(
@@ -987,7 +1204,7 @@ impl Engine {
                             }
                         }
                         for te in constraint_tes {
-                            match check_exprs_neigh(
+                            match self.check_exprs_neigh(
                                 std::slice::from_ref(te),
                                 &constraint_neighs,
                                 node,
@@ -1011,6 +1228,17 @@ impl Engine {
                         }
                     }
                 }
+                // Only the chosen partition and the first rejected one are kept, the
+                // number of partitions tried can be large
+                if ok_partition || errors_in_partitions.is_empty() {
+                    self.justifier.exit(&if ok_partition {
+                        Either::Right(())
+                    } else {
+                        Either::Left(())
+                    });
+                } else {
+                    self.justifier.discard();
+                }
                 if ok_partition {
                     // debug!(" Part {npart}| Partition succeeded",);
                     return pass(Reason::ShapeExtends {
@@ -1039,6 +1267,7 @@ impl Engine {
             })
         } else {
             debug!("No partitions to check for node {node} and shape {idx}");
+            self.justifier.detail("no partition of the neighbourhood to check");
             fail(ValidatorError::ShapeFailedNoPartitions {
                 node: Box::new(node.clone()),
                 shape: Box::new(shape.clone()),
@@ -1145,6 +1374,10 @@ impl Engine {
 
         let (values, reminder) = self.neighs(node, candidate_preds.clone(), rdf)?;
         if shape.is_closed() && !reminder.is_empty() {
+            self.justifier.detail(format!(
+                "closed shape with remaining predicates [{}]",
+                reminder.iter().map(|p| p.to_string()).join(", ")
+            ));
             return fail(ValidatorError::ClosedShapeWithRemainderPreds {
                 remainder: Preds::new(reminder),
                 declared: Preds::new(candidate_preds),
@@ -1247,7 +1480,7 @@ impl Engine {
             let mut allocated: HashMap<Option<ShapeLabelIdx>, Vec<_>> = HashMap::new();
             for (maybe_label, rbes, neighs_subset) in partition.iter() {
                 allocated.insert(*maybe_label, neighs_subset.clone());
-                let result = check_exprs_neigh(rbes, neighs_subset, node, shape, idx, typing)?;
+                let result = self.check_exprs_neigh(rbes, neighs_subset, node, shape, idx, typing)?;
                 match result {
                     Either::Right(rs) => {
                         reasons_in_loop.push(Reason::PartitionComponent {
@@ -1288,8 +1521,14 @@ impl Engine {
                         }
                     }
                     for te in tes {
-                        match check_exprs_neigh(std::slice::from_ref(te), &constraint_neighs, node, shape, idx, typing)?
-                        {
+                        match self.check_exprs_neigh(
+                            std::slice::from_ref(te),
+                            &constraint_neighs,
+                            node,
+                            shape,
+                            idx,
+                            typing,
+                        )? {
                             Either::Right(_) => {},
                             Either::Left(errs) => {
                                 errors_in_loop.push(ValidatorError::ShapeExtendsError {
@@ -1466,7 +1705,7 @@ impl Engine {
                         })
                         .cloned()
                         .collect();
-                    match check_expr_neigh(main_shape.triple_expr(), &filtered, node, shape, idx, typing)? {
+                    match self.check_expr_neigh(main_shape.triple_expr(), &filtered, node, shape, idx, typing)? {
                         Either::Left(errs) => {
                             errors.push(ValidatorError::ParentShapeMainShapeFailed {
                                 node: Box::new(node.clone()),
@@ -1801,6 +2040,20 @@ fn collect_constraint_exprs(schema: &SchemaIR, se: &ShapeExpr, node: &Node, out:
     }
 }
 
+/// Shape shown with its label in profiles, or as a blank node when it has none.
+/// It is only rendered when the profiler is enabled.
+struct ProfileShape<'a>(&'a SchemaIR, &'a ShapeLabelIdx);
+
+impl Display for ProfileShape<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let ProfileShape(schema, idx) = self;
+        match schema.shape_label_from_idx(idx) {
+            Some(label) => write!(f, "{}", schema.show_label(label)),
+            None => write!(f, "_:{idx}"),
+        }
+    }
+}
+
 /// The transitive EXTENDS closure of `roots`, deduplicated (a diamond's shared ancestor
 /// appears once) and traversed through ShapeAnd declarations via their main conjunct —
 /// the schema's inheritance graph records no edges for ShapeAnd declarations, so
@@ -1832,189 +2085,247 @@ fn fail(err: ValidatorError) -> Result<ValidationResult> {
     Ok(Either::Left(vec![err]))
 }
 
-fn check_exprs_neigh(
-    exprs: &[Expr],
-    neighs: &[(Pred, Node, SemanticActionContext)],
-    node: &Node,
-    shape: &Shape,
-    idx: &ShapeLabelIdx,
-    typing: &RefTyping,
-) -> Result<ValidationResult> {
-    for rbe in exprs.iter() {
-        let result = check_expr_neigh(rbe, neighs, node, shape, idx, typing)?;
-        if result.is_left() {
-            return fail(ValidatorError::ShapeFailed {
-                node: Box::new(node.clone()),
-                shape: Box::new(shape.clone()),
-                idx: *idx,
-                errors: result.left().unwrap().clone(),
-            });
+impl Engine {
+    fn check_exprs_neigh(
+        &self,
+        exprs: &[Expr],
+        neighs: &[(Pred, Node, SemanticActionContext)],
+        node: &Node,
+        shape: &Shape,
+        idx: &ShapeLabelIdx,
+        typing: &RefTyping,
+    ) -> Result<ValidationResult> {
+        for rbe in exprs.iter() {
+            let result = self.check_expr_neigh(rbe, neighs, node, shape, idx, typing)?;
+            if result.is_left() {
+                return fail(ValidatorError::ShapeFailed {
+                    node: Box::new(node.clone()),
+                    shape: Box::new(shape.clone()),
+                    idx: *idx,
+                    errors: result.left().unwrap().clone(),
+                });
+            }
         }
+        pass(Reason::Shape {
+            node: node.clone(),
+            shape: Box::new(shape.clone()),
+            idx: *idx,
+        })
     }
-    pass(Reason::Shape {
-        node: node.clone(),
-        shape: Box::new(shape.clone()),
-        idx: *idx,
-    })
-}
 
-fn check_expr_neigh(
-    expr: &Expr,
-    neighs: &[(Pred, Node, SemanticActionContext)],
-    node: &Node,
-    shape: &Shape,
-    idx: &ShapeLabelIdx,
-    typing: &RefTyping,
-) -> Result<ValidationResult> {
-    /*trace!(
-        "Checking expr {} with neighs: [{}]",
-        expr,
-        neighs.iter().map(|(p, o, _ctx)| format!("{p} {o}")).join(", ")
-    );*/
-    let mut result_iter = expr.matches(neighs.to_vec())?;
-    let first_result = result_iter.next();
-    if first_result.is_none() {
-        /*debug!(
-            "expr {expr} produced no candidates for neighs: [{}]",
+    fn check_expr_neigh(
+        &self,
+        expr: &Expr,
+        neighs: &[(Pred, Node, SemanticActionContext)],
+        node: &Node,
+        shape: &Shape,
+        idx: &ShapeLabelIdx,
+        typing: &RefTyping,
+    ) -> Result<ValidationResult> {
+        /*trace!(
+            "Checking expr {} with neighs: [{}]",
+            expr,
             neighs.iter().map(|(p, o, _ctx)| format!("{p} {o}")).join(", ")
         );*/
-        let mut reasons: Vec<NoMatchReason> = result_iter
-            .failed_candidates()
-            .iter()
-            .map(|(candidate, predicate, value, error)| NoMatchReason::ConditionFailed {
-                candidate: candidate.clone(),
-                predicate: predicate.clone(),
-                value: value.clone(),
-                error: error.clone(),
-            })
-            .collect();
-        for (candidate, err) in result_iter.failed_cardinality() {
-            match expr.cardinality_violations(err) {
-                Ok(violations) => {
-                    for (predicate, expected, current) in violations {
-                        reasons.push(NoMatchReason::CardinalityFailed {
-                            candidate: candidate.clone(),
-                            predicate,
-                            expected,
-                            current,
-                        });
-                    }
-                },
-                Err(detail) => reasons.push(NoMatchReason::Other {
+        let mut result_iter = expr.matches(neighs.to_vec())?;
+        let first_result = result_iter.next();
+        if first_result.is_none() {
+            /*debug!(
+                "expr {expr} produced no candidates for neighs: [{}]",
+                neighs.iter().map(|(p, o, _ctx)| format!("{p} {o}")).join(", ")
+            );*/
+            let mut reasons: Vec<NoMatchReason> = result_iter
+                .failed_candidates()
+                .iter()
+                .map(|(candidate, predicate, value, error)| NoMatchReason::ConditionFailed {
                     candidate: candidate.clone(),
-                    detail,
-                }),
+                    predicate: predicate.clone(),
+                    value: value.clone(),
+                    error: error.clone(),
+                })
+                .collect();
+            for (candidate, err) in result_iter.failed_cardinality() {
+                match expr.cardinality_violations(err) {
+                    Ok(violations) => {
+                        for (predicate, expected, current) in violations {
+                            reasons.push(NoMatchReason::CardinalityFailed {
+                                candidate: candidate.clone(),
+                                predicate,
+                                expected,
+                                current,
+                            });
+                        }
+                    },
+                    Err(detail) => reasons.push(NoMatchReason::Other {
+                        candidate: candidate.clone(),
+                        detail,
+                    }),
+                }
             }
+            self.justifier.add(
+                ProofStep::new(JustificationStep::TripleExpr, node, None)
+                    .with_conforms(false)
+                    .with_no_matches(reasons.clone()),
+            );
+            return fail(ValidatorError::NoMatchesFound {
+                node: Box::new(node.clone()),
+                shape: Box::new(shape.clone()),
+                idx: *idx,
+                reasons,
+            });
         }
-        return fail(ValidatorError::NoMatchesFound {
-            node: Box::new(node.clone()),
-            shape: Box::new(shape.clone()),
-            idx: *idx,
-            reasons,
-        });
-    }
-    let mut errors = Vec::new();
-    for result in first_result.into_iter().chain(result_iter.by_ref()) {
-        /*trace!(
-            "Result of {expr} with neighs: {}: {:?}",
-            neighs.iter().map(|(p, o, _ctx)| format!("{p} {o}")).join(", "),
-            result
-        );*/
-        match result {
-            Ok(pending_values) => {
-                if !pending_values.is_empty() {
-                    /*tracing::trace!(
-                        "Pending values for expr {expr} with neighs: [{}]:\n{pending_values}",
-                        neighs.iter().map(|(p, o, _ctx)| format!("{p} {o}")).join(", "),
-                    );*/
-                    let mut failed_pending = Vec::new();
-                    // Check if all pending values are in typing
-                    for (n, idx, ks) in pending_values.iter_vr() {
-                        let pair = (n.clone(), *idx);
-                        if !typing.contains(&pair) {
+        let mut errors = Vec::new();
+        // Driven by hand instead of chaining the iterator, so the assignment behind
+        // each match can be read from `result_iter` while justifying it
+        let mut next_result = first_result;
+        while let Some(result) = next_result {
+            // The candidates of a triple expression can be many, so the budget is checked between them
+            self.interrupt.check()?;
+            /*trace!(
+                "Result of {expr} with neighs: {}: {:?}",
+                neighs.iter().map(|(p, o, _ctx)| format!("{p} {o}")).join(", "),
+                result
+            );*/
+            match result {
+                Ok(pending_values) => {
+                    if !pending_values.is_empty() {
+                        /*tracing::trace!(
+                            "Pending values for expr {expr} with neighs: [{}]:\n{pending_values}",
+                            neighs.iter().map(|(p, o, _ctx)| format!("{p} {o}")).join(", "),
+                        );*/
+                        let mut failed_pending = Vec::new();
+                        // Check if all pending values are in typing
+                        for (n, idx, ks) in pending_values.iter_vr() {
+                            let pair = (n.clone(), *idx);
+                            if !typing.contains(&pair) {
+                                /*tracing::trace!(
+                                    "Pending value ({},{}) is not in typing, keys: [{}]",
+                                    n.clone(),
+                                    *idx,
+                                    ks.iter().map(|k| k.to_string()).join(", ")
+                                );*/
+                                failed_pending.push((
+                                    n.clone(),
+                                    *idx,
+                                    ks.iter().cloned().collect::<Vec<_>>(),
+                                    typing.errors_for(&pair),
+                                ))
+                                // TODO: if (stop_at_first) break
+                                // We don't need to compute all the failed pending values once we find the first pair
+                            }
+                        }
+                        if self.justifier.enabled() {
+                            let refs = pending_values
+                                .iter_vr()
+                                .map(|(n, idx, _)| {
+                                    ProofStep::new(JustificationStep::Ref, n, Some(*idx))
+                                        .with_conforms(typing.contains(&(n.clone(), *idx)))
+                                })
+                                .collect();
+                            self.justifier.add(
+                                ProofStep::new(JustificationStep::TripleExpr, node, None)
+                                    .with_conforms(failed_pending.is_empty())
+                                    .with_matches(matched_triples(expr, result_iter.last_match()))
+                                    .with_children(refs),
+                            );
+                        }
+                        if failed_pending.is_empty() {
+                            //tracing::trace!("All pending values were in typing {pending_values}");
+                            return pass(Reason::Shape {
+                                node: node.clone(),
+                                shape: Box::new(shape.clone()),
+                                idx: *idx,
+                                // TODO: Add pending_values to reason
+                            });
+                        } else {
                             /*tracing::trace!(
-                                "Pending value ({},{}) is not in typing, keys: [{}]",
-                                n.clone(),
-                                *idx,
-                                ks.iter().map(|k| k.to_string()).join(", ")
+                                "Failed pending values: {}",
+                                failed_pending
+                                    .iter()
+                                    .map(|(n, idx, _ks)| format!("{n}@{idx}"))
+                                    .join(", ")
                             );*/
-                            failed_pending.push((
-                                n.clone(),
-                                *idx,
-                                ks.iter().cloned().collect::<Vec<_>>(),
-                                typing.errors_for(&pair),
-                            ))
-                            // TODO: if (stop_at_first) break
-                            // We don't need to compute all the failed pending values once we find the first pair
+                            errors.push(ValidatorError::FailedPending {
+                                failed_pending: failed_pending.clone(),
+                            })
+                        }
+                    } else {
+                        // No Pending values
+                        if self.justifier.enabled() {
+                            self.justifier.add(
+                                ProofStep::new(JustificationStep::TripleExpr, node, None)
+                                    .with_matches(matched_triples(expr, result_iter.last_match())),
+                            );
                         }
-                    }
-                    if failed_pending.is_empty() {
-                        //tracing::trace!("All pending values were in typing {pending_values}");
                         return pass(Reason::Shape {
                             node: node.clone(),
                             shape: Box::new(shape.clone()),
                             idx: *idx,
-                            // TODO: Add pending_values to reason
                         });
-                    } else {
-                        /*tracing::trace!(
-                            "Failed pending values: {}",
-                            failed_pending
-                                .iter()
-                                .map(|(n, idx, _ks)| format!("{n}@{idx}"))
-                                .join(", ")
-                        );*/
-                        errors.push(ValidatorError::FailedPending {
-                            failed_pending: failed_pending.clone(),
-                        })
                     }
-                } else {
-                    // No Pending values
-                    return pass(Reason::Shape {
-                        node: node.clone(),
-                        shape: Box::new(shape.clone()),
-                        idx: *idx,
-                    });
-                }
-            },
-            Err(err) => {
-                // debug!("Result with error: {err}");
-                // The failing candidate's (predicate, value) pair is recorded in
-                // `failed_candidates` as soon as its condition is checked (see
-                // `IterCartesianProduct::next` in rbe_table.rs), even though we
-                // bailed out here instead of exhausting the iterator. Recover it
-                // so the reported error can name the node and property involved,
-                // not just the bare condition failure.
-                if let Some((candidate, predicate, value, error)) = result_iter.failed_candidates().last() {
-                    return fail(ValidatorError::NoMatchesFound {
-                        node: Box::new(node.clone()),
-                        shape: Box::new(shape.clone()),
-                        idx: *idx,
-                        reasons: vec![NoMatchReason::ConditionFailed {
+                },
+                Err(err) => {
+                    // debug!("Result with error: {err}");
+                    // The failing candidate's (predicate, value) pair is recorded in
+                    // `failed_candidates` as soon as its condition is checked (see
+                    // `IterCartesianProduct::next` in rbe_table.rs), even though we
+                    // bailed out here instead of exhausting the iterator. Recover it
+                    // so the reported error can name the node and property involved,
+                    // not just the bare condition failure.
+                    if let Some((candidate, predicate, value, error)) = result_iter.failed_candidates().last() {
+                        let reason = NoMatchReason::ConditionFailed {
                             candidate: candidate.clone(),
                             predicate: predicate.clone(),
                             value: value.clone(),
                             error: error.clone(),
-                        }],
-                    });
-                }
-                return fail(ValidatorError::RbeError(err));
-            },
+                        };
+                        self.justifier.add(
+                            ProofStep::new(JustificationStep::TripleExpr, node, None)
+                                .with_conforms(false)
+                                .with_no_matches(vec![reason.clone()]),
+                        );
+                        return fail(ValidatorError::NoMatchesFound {
+                            node: Box::new(node.clone()),
+                            shape: Box::new(shape.clone()),
+                            idx: *idx,
+                            reasons: vec![reason],
+                        });
+                    }
+                    self.justifier.add(
+                        ProofStep::new(JustificationStep::TripleExpr, node, None)
+                            .with_conforms(false)
+                            .with_detail(err.to_string()),
+                    );
+                    return fail(ValidatorError::RbeError(err));
+                },
+            }
+            next_result = result_iter.next();
         }
+        // If we reach this point, all results have been processed and all of them have pending values that are not in typing, so the shape failed
+        // We can collect all the failed pending values from all the results and return them as errors
+        /*debug!(
+            "expr failed {expr} with neighs: [{}]. No matching found. Errors: [{}]",
+            neighs.iter().map(|(p, o, _ctx)| format!("{p} {o}")).join(", "),
+            errors.iter().map(|e| format!("{e}")).join(", ")
+        );*/
+        fail(ValidatorError::ShapeFailed {
+            node: Box::new(node.clone()),
+            shape: Box::new(shape.clone()),
+            idx: *idx,
+            errors,
+        })
     }
-    // If we reach this point, all results have been processed and all of them have pending values that are not in typing, so the shape failed
-    // We can collect all the failed pending values from all the results and return them as errors
-    /*debug!(
-        "expr failed {expr} with neighs: [{}]. No matching found. Errors: [{}]",
-        neighs.iter().map(|(p, o, _ctx)| format!("{p} {o}")).join(", "),
-        errors.iter().map(|e| format!("{e}")).join(", ")
-    );*/
-    fail(ValidatorError::ShapeFailed {
-        node: Box::new(node.clone()),
-        shape: Box::new(shape.clone()),
-        idx: *idx,
-        errors,
-    })
+}
+
+/// Pairs each triple of the last match of `expr` with the constraint that matched it
+fn matched_triples(expr: &Expr, last_match: &[(Pred, Node, rbe::Component)]) -> Vec<(Pred, Node, String)> {
+    last_match
+        .iter()
+        .map(|(pred, value, c)| {
+            let constraint = expr.get_condition(c).map(|cond| cond.show()).unwrap_or_default();
+            (pred.clone(), value.clone(), constraint)
+        })
+        .collect()
 }
 
 type PartitionInfo = (
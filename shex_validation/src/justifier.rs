@@ -0,0 +1,234 @@
+use crate::no_match_reason::NoMatchReason;
+use either::Either;
+use prefixmap::PrefixMap;
+use shex_ast::Node;
+use shex_ast::Pred;
+use shex_ast::ShapeLabelIdx;
+use shex_ast::ir::schema_ir::SchemaIR;
+use shex_ast::shapemap::{Justification, JustificationStep, TripleMatch};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// A proof step as recorded by the engine, before nodes and shapes are rendered.
+///
+/// `Ref` and `Descendant` steps point to the proof of another `(node, shape)` pair,
+/// which is looked up when the step is rendered as a [`Justification`].
+#[derive(Debug, Clone)]
+pub(crate) struct ProofStep {
+    step: JustificationStep,
+    node: Node,
+    idx: Option<ShapeLabelIdx>,
+    conforms: bool,
+    detail: Option<String>,
+    no_matches: Vec<NoMatchReason>,
+    matches: Vec<(Pred, Node, String)>,
+    children: Vec<ProofStep>,
+}
+
+impl ProofStep {
+    pub(crate) fn new(step: JustificationStep, node: &Node, idx: Option<ShapeLabelIdx>) -> Self {
+        ProofStep {
+            step,
+            node: node.clone(),
+            idx,
+            conforms: true,
+            detail: None,
+            no_matches: Vec::new(),
+            matches: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    pub(crate) fn with_conforms(mut self, conforms: bool) -> Self {
+        self.conforms = conforms;
+        self
+    }
+
+    pub(crate) fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    pub(crate) fn with_no_matches(mut self, reasons: Vec<NoMatchReason>) -> Self {
+        self.no_matches = reasons;
+        self
+    }
+
+    pub(crate) fn with_matches(mut self, matches: Vec<(Pred, Node, String)>) -> Self {
+        self.matches = matches;
+        self
+    }
+
+    pub(crate) fn with_children(mut self, children: Vec<ProofStep>) -> Self {
+        self.children = children;
+        self
+    }
+}
+
+/// Records proof trees while the engine evaluates shapes.
+///
+/// The `check_*` methods of the engine only borrow it immutably, so the frames
+/// being built live behind `RefCell`s. When it is disabled every method is a
+/// no-op, which keeps the default validation path free of bookkeeping.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Justifier {
+    enabled: bool,
+    stack: RefCell<Vec<ProofStep>>,
+    proofs: RefCell<HashMap<(Node, ShapeLabelIdx), ProofStep>>,
+}
+
+impl Justifier {
+    pub(crate) fn new(enabled: bool) -> Self {
+        Justifier {
+            enabled,
+            ..Default::default()
+        }
+    }
+
+    pub(crate) fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Opens a frame whose children are the steps recorded until the matching `exit`
+    pub(crate) fn enter(&self, step: JustificationStep, node: &Node, idx: Option<ShapeLabelIdx>) {
+        if self.enabled {
+            self.stack.borrow_mut().push(ProofStep::new(step, node, idx));
+        }
+    }
+
+    /// Closes the current frame and attaches it to its parent
+    pub(crate) fn exit<E, R>(&self, result: &Either<E, R>) {
+        if let Some(step) = self.pop(result) {
+            self.add(step);
+        }
+    }
+
+    /// Closes the current frame and keeps it as the proof of `(node, idx)`
+    pub(crate) fn exit_proof<E, R>(&self, node: &Node, idx: &ShapeLabelIdx, result: &Either<E, R>) {
+        if let Some(step) = self.pop(result) {
+            self.proofs.borrow_mut().insert((node.clone(), *idx), step);
+        }
+    }
+
+    /// Closes the current frame without recording it
+    pub(crate) fn discard(&self) {
+        if self.enabled {
+            self.stack.borrow_mut().pop();
+        }
+    }
+
+    /// Drops the frames of a proof that won't be finished, e.g. because it was interrupted
+    pub(crate) fn abandon(&self) {
+        if self.enabled {
+            self.stack.borrow_mut().clear();
+        }
+    }
+
+    /// Adds a complete step to the current frame
+    pub(crate) fn add(&self, step: ProofStep) {
+        if self.enabled
+            && let Some(parent) = self.stack.borrow_mut().last_mut()
+        {
+            parent.children.push(step);
+        }
+    }
+
+    /// Sets the detail of the current frame
+    pub(crate) fn detail(&self, detail: impl Into<String>) {
+        if self.enabled
+            && let Some(current) = self.stack.borrow_mut().last_mut()
+        {
+            current.detail = Some(detail.into());
+        }
+    }
+
+    fn pop<E, R>(&self, result: &Either<E, R>) -> Option<ProofStep> {
+        if !self.enabled {
+            return None;
+        }
+        let mut step = self.stack.borrow_mut().pop()?;
+        step.conforms = result.is_right();
+        Some(step)
+    }
+
+    /// Adds the proofs recorded by another justifier, e.g. one of a forked engine
+    pub(crate) fn merge(&self, other: Justifier) {
+        if self.enabled {
+            self.proofs.borrow_mut().extend(other.proofs.into_inner());
+        }
+    }
+
+    /// Renders the proof of `(node, idx)`, expanding the references it contains
+    pub(crate) fn justification(
+        &self,
+        node: &Node,
+        idx: &ShapeLabelIdx,
+        nodes_prefixmap: &PrefixMap,
+        schema: &SchemaIR,
+    ) -> Option<Justification> {
+        let proofs = self.proofs.borrow();
+        let proof = proofs.get(&(node.clone(), *idx))?;
+        let mut path = vec![(node.clone(), *idx)];
+        Some(render(proof, &proofs, &mut path, nodes_prefixmap, schema))
+    }
+}
+
+fn render(
+    step: &ProofStep,
+    proofs: &HashMap<(Node, ShapeLabelIdx), ProofStep>,
+    path: &mut Vec<(Node, ShapeLabelIdx)>,
+    nodes_prefixmap: &PrefixMap,
+    schema: &SchemaIR,
+) -> Justification {
+    let mut kind = step.step;
+    let mut children: Vec<Justification> = step
+        .children
+        .iter()
+        .map(|child| render(child, proofs, path, nodes_prefixmap, schema))
+        .collect();
+    if matches!(kind, JustificationStep::Ref | JustificationStep::Descendant)
+        && let Some(idx) = step.idx
+    {
+        let key = (step.node.clone(), idx);
+        match proofs.get(&key) {
+            // The reference is being proved higher up in the tree: it was assumed to hold
+            _ if path.contains(&key) => kind = JustificationStep::Hypothesis,
+            None => kind = JustificationStep::Hypothesis,
+            Some(proof) => {
+                path.push(key);
+                children.push(render(proof, proofs, path, nodes_prefixmap, schema));
+                path.pop();
+            },
+        }
+    }
+    let mut details: Vec<String> = step.detail.iter().cloned().collect();
+    details.extend(step.no_matches.iter().map(|r| r.show_qualified(nodes_prefixmap)));
+    let matches = step
+        .matches
+        .iter()
+        .map(|(pred, value, constraint)| {
+            TripleMatch::new(
+                nodes_prefixmap.qualify(pred.iri()),
+                value.show_qualified(nodes_prefixmap),
+                constraint.clone(),
+            )
+        })
+        .collect();
+    let mut justification = Justification::new(kind, step.node.show_qualified(nodes_prefixmap), step.conforms)
+        .with_matches(matches)
+        .with_children(children);
+    if let Some(idx) = step.idx {
+        justification = justification.with_shape(show_idx(&idx, schema));
+    }
+    if !details.is_empty() {
+        justification = justification.with_detail(details.join("; "));
+    }
+    justification
+}
+
+fn show_idx(idx: &ShapeLabelIdx, schema: &SchemaIR) -> String {
+    match schema.shape_label_from_idx(idx) {
+        Some(label) => schema.show_label(label),
+        None => format!("_:{idx}"),
+    }
+}
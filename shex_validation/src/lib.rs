@@ -4,6 +4,7 @@
 pub mod atom;
 pub mod class_partitions;
 pub mod engine;
+mod justifier;
 pub mod k_partitions;
 pub mod no_match_reason;
 pub mod partition;
@@ -18,6 +19,7 @@ pub mod shex_config;
 pub mod shex_format;
 pub mod solver;
 pub mod typing;
+pub mod validation_event;
 pub mod validator;
 pub mod validator_config;
 pub mod validator_error;
@@ -36,6 +38,7 @@ pub use crate::schema_without_imports::*;
 pub use crate::schema_without_imports_error::*;
 pub use crate::shex_config::*;
 pub use crate::typing::*;
+pub use crate::validation_event::*;
 pub use crate::validator::*;
 pub use crate::validator_config::*;
 pub use crate::validator_error::*;
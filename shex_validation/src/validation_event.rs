@@ -0,0 +1,78 @@
+use serde::ser::SerializeMap;
+use serde::{Serialize, Serializer};
+use shex_ast::Node;
+use shex_ast::ir::shape_label::ShapeLabel;
+use shex_ast::shapemap::ValidationStatus;
+
+/// Events emitted while a shapemap is being validated, so that callers can show
+/// the results as they arrive instead of waiting for the whole [`ResultShapeMap`].
+///
+/// Each entry produces a `Result` followed by a `Progress` event. With parallel
+/// validation the entries are reported in the order they are decided, which is
+/// not the order of the shapemap.
+///
+/// [`ResultShapeMap`]: shex_ast::shapemap::ResultShapeMap
+#[derive(Debug, Clone, PartialEq)]
+pub enum ShExValidationEvent {
+    /// The shapemap was resolved into `entries` node-shape pairs
+    EntriesResolved { entries: usize },
+
+    /// The status of an entry was decided
+    Result {
+        node: Node,
+        shape: ShapeLabel,
+        status: Box<ValidationStatus>,
+    },
+
+    /// `decided` of the `entries` entries have been decided so far
+    Progress { decided: usize, entries: usize },
+}
+
+/// Serialized as a JSON object with a `type` field, one per line in NDJSON output.
+/// Results have the same fields as the entries of the JSON result shapemap.
+impl Serialize for ShExValidationEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(None)?;
+        match self {
+            ShExValidationEvent::EntriesResolved { entries } => {
+                map.serialize_entry("type", "entries")?;
+                map.serialize_entry("entries", entries)?;
+            },
+            ShExValidationEvent::Result { node, shape, status } => {
+                map.serialize_entry("type", "result")?;
+                map.serialize_entry("node", &node.to_string())?;
+                map.serialize_entry("shape", &shape.to_string())?;
+                map.serialize_entry("status", &status.code())?;
+                map.serialize_entry("appInfo", &status.app_info())?;
+                map.serialize_entry("reason", &status.reason())?;
+            },
+            ShExValidationEvent::Progress { decided, entries } => {
+                map.serialize_entry("type", "progress")?;
+                map.serialize_entry("decided", decided)?;
+                map.serialize_entry("entries", entries)?;
+            },
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ShExValidationEvent;
+    use serde_json::json;
+
+    #[test]
+    fn progress_serializes_with_its_type() {
+        let event = ShExValidationEvent::Progress {
+            decided: 3,
+            entries: 10,
+        };
+        assert_eq!(
+            serde_json::to_value(&event).unwrap(),
+            json!({"type": "progress", "decided": 3, "entries": 10})
+        );
+    }
+}
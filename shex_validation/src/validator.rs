@@ -1,10 +1,15 @@
 use crate::Reason;
 use crate::ValidatorConfig;
 use crate::atom;
-use crate::engine::Engine;
+use crate::engine::{Decided, Engine};
+use crate::validation_event::ShExValidationEvent;
 use crate::validator_error::*;
 use prefixmap::PrefixMap;
-use rudof_rdf::rdf_core::{NeighsRDF, query::QueryRDF};
+use rudof_rdf::rdf_core::{
+    NeighsRDF,
+    query::QueryRDF,
+    utils::{Interruption, ValidationBudget},
+};
 use serde_json::Value;
 use shex_ast::Node;
 use shex_ast::ShapeExprLabel;
@@ -14,6 +19,8 @@ use shex_ast::ir::shape_label::ShapeLabel;
 use shex_ast::shapemap::ResultShapeMap;
 use shex_ast::shapemap::ValidationStatus;
 use shex_ast::shapemap::query_shape_map::QueryShapeMap;
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use tracing::trace;
 
 type Result<T> = std::result::Result<T, ValidatorError>;
@@ -104,11 +111,13 @@ impl Validator {
         S: NeighsRDF + QueryRDF,
     {
         let mut engine = Engine::new(&self.config);
+        engine.start_budget(&self.config.budget());
         let shape_expr_label: ShapeExprLabel = shape.into();
         let idx = self.get_shape_expr_label(&shape_expr_label, schema)?;
         engine.add_pending(node.clone(), idx);
-        engine.validate_pending(rdf, schema)?;
-        let result = self.result_map(&mut engine, maybe_nodes_prefixmap)?;
+        engine.validate_pending(rdf, schema, &|_, _| {})?;
+        let mut result = self.result_map(&mut engine, maybe_nodes_prefixmap)?;
+        add_spans(&mut result, rdf, schema);
         Ok(result)
     }
 
@@ -121,6 +130,7 @@ impl Validator {
             })
     }
 
+    /// Validates a shapemap within the time limits of the configuration
     pub fn validate_shapemap<S>(
         &self,
         shapemap: &QueryShapeMap,
@@ -129,9 +139,50 @@ impl Validator {
         maybe_nodes_prefixmap: &Option<PrefixMap>,
     ) -> Result<ResultShapeMap>
     where
-        S: NeighsRDF + QueryRDF,
+        S: NeighsRDF + QueryRDF + Sync,
+    {
+        self.validate_shapemap_with_budget(shapemap, rdf, schema, maybe_nodes_prefixmap, &self.config.budget())
+    }
+
+    /// Validates a shapemap within the time limits of `budget`, which also carries
+    /// a token to cancel the validation from another thread.
+    ///
+    /// The entries that are not decided when the budget runs out, or when the token
+    /// is cancelled, get a timeout status in the result.
+    pub fn validate_shapemap_with_budget<S>(
+        &self,
+        shapemap: &QueryShapeMap,
+        rdf: &S,
+        schema: &SchemaIR,
+        maybe_nodes_prefixmap: &Option<PrefixMap>,
+        budget: &ValidationBudget,
+    ) -> Result<ResultShapeMap>
+    where
+        S: NeighsRDF + QueryRDF + Sync,
+    {
+        self.validate_shapemap_with_events(shapemap, rdf, schema, maybe_nodes_prefixmap, budget, &|_| {})
+    }
+
+    /// Validates a shapemap like [`Validator::validate_shapemap_with_budget`], calling
+    /// `on_event` with the status of each entry as soon as it is decided and with the
+    /// progress of the validation.
+    ///
+    /// With parallel validation `on_event` is called from several threads.
+    pub fn validate_shapemap_with_events<S>(
+        &self,
+        shapemap: &QueryShapeMap,
+        rdf: &S,
+        schema: &SchemaIR,
+        maybe_nodes_prefixmap: &Option<PrefixMap>,
+        budget: &ValidationBudget,
+        on_event: &(dyn Fn(ShExValidationEvent) + Sync),
+    ) -> Result<ResultShapeMap>
+    where
+        S: NeighsRDF + QueryRDF + Sync,
     {
         let mut engine = Engine::new(&self.config);
+        engine.start_budget(budget);
+        let nodes_prefixmap = maybe_nodes_prefixmap.clone().unwrap_or_default();
 
         // Fill the engine's pending atoms with the node-shape pairs from the QueryShapeMap,
         // converting shape labels to indices and nodes to objects as needed.
@@ -139,8 +190,39 @@ impl Validator {
         // so that they can be reported in the final ResultShapeMap.
         let failures = self.fill_pending(&mut engine, shapemap, rdf, schema)?;
 
+        let entries = engine.pending().len() + failures.len();
+        on_event(ShExValidationEvent::EntriesResolved { entries });
+        let decided = AtomicUsize::new(0);
+        let report = |node: Node, shape: ShapeLabel, status: ValidationStatus| {
+            on_event(ShExValidationEvent::Result {
+                node,
+                shape,
+                status: Box::new(status),
+            });
+            let decided = decided.fetch_add(1, Ordering::Relaxed) + 1;
+            on_event(ShExValidationEvent::Progress { decided, entries });
+        };
+        for (node, shape_label, error_msg) in failures.iter() {
+            report(
+                node.clone(),
+                shape_label.clone(),
+                ValidationStatus::non_conformant(error_msg.clone(), Value::Null),
+            );
+        }
+        // Entries whose status cannot be built are not reported while validating,
+        // building the result map below fails with the same error
+        let on_decided = |engine: &Engine, decided: Decided<'_>| {
+            if let Ok((node, shape, status)) = self.decided_status(engine, decided, &nodes_prefixmap) {
+                report(node, shape, status);
+            }
+        };
+
         // Validate the pending atoms in the engine, which will process the valid node-shape pairs
-        engine.validate_pending(rdf, schema)?;
+        if self.config.threads() == 1 {
+            engine.validate_pending(rdf, schema, &on_decided)?;
+        } else {
+            engine.validate_pending_parallel(rdf, schema, self.config.threads(), &on_decided)?;
+        }
 
         let mut result = self.result_map(&mut engine, maybe_nodes_prefixmap)?;
         for (node, shape_label, error_msg) in failures {
@@ -155,6 +237,7 @@ impl Validator {
                     error: format!("{e}"),
                 })?;
         }
+        add_spans(&mut result, rdf, schema);
         Ok(result)
     }
 
@@ -239,55 +322,84 @@ impl Validator {
         for atom in &engine.checked() {
             let (node, idx) = atom.get_value();
             let label = self.get_shape_label(idx)?;
-            match atom {
-                Atom::Pos(positive_atom) => {
-                    let reasons = engine.find_reasons(positive_atom);
-                    let json_reasons = json_reasons(&reasons)?;
-                    let str_reasons = show_reasons(&reasons, &nodes_prefixmap, &self.schema, self.config.width())?;
-                    let status = ValidationStatus::conformant(str_reasons, json_reasons);
-                    result.add_result((*node).clone(), label.clone(), status).map_err(|e| {
-                        ValidatorError::AddingConformantError {
-                            node: node.to_string(),
-                            label: label.to_string(),
-                            error: format!("{e}"),
-                        }
-                    })?;
-                },
-                Atom::Neg(negative_atom) => {
-                    let errors = engine.find_errors(negative_atom);
-                    let json_errors = json_errors(&errors)?;
-                    let str_errors = show_errors(&errors, &nodes_prefixmap, &self.schema, self.config.width())?;
-                    let status = ValidationStatus::non_conformant(str_errors, json_errors);
-                    result.add_result((*node).clone(), label.clone(), status).map_err(|e| {
-                        ValidatorError::AddingNonConformantError {
-                            node: node.to_string(),
-                            label: label.to_string(),
-                            error: format!("{e}"),
-                        }
-                    })?;
-                },
+            if let Some(justification) = engine.justification(node, idx, &nodes_prefixmap, &self.schema) {
+                result.add_justification((*node).clone(), label.clone(), justification);
             }
+            let (node, label, status) = self.decided_status(engine, Decided::Checked(atom), &nodes_prefixmap)?;
+            add_status(&mut result, node, label, status)?;
+        }
+        for ((node, idx), interruption) in engine.interrupted() {
+            let atom = Atom::pos(&(node.clone(), *idx));
+            let (node, label, status) =
+                self.decided_status(engine, Decided::Interrupted(&atom, *interruption), &nodes_prefixmap)?;
+            add_status(&mut result, node, label, status)?;
         }
         for atom in &engine.pending() {
             let (node, idx) = atom.get_value();
             let label = self.get_shape_label(idx)?;
-            let status = ValidationStatus::pending();
-            result.add_result((*node).clone(), label.clone(), status).map_err(|e| {
-                ValidatorError::AddingPendingError {
-                    node: node.to_string(),
-                    label: label.to_string(),
-                    error: format!("{e}"),
-                }
-            })?;
+            add_status(&mut result, (*node).clone(), label.clone(), ValidationStatus::pending())?;
         }
         Ok(result)
     }
 
+    /// Node, shape label and status of an atom whose validation has finished
+    fn decided_status(
+        &self,
+        engine: &Engine,
+        decided: Decided<'_>,
+        nodes_prefixmap: &PrefixMap,
+    ) -> Result<(Node, ShapeLabel, ValidationStatus)> {
+        let (atom, status) = match decided {
+            Decided::Checked(atom @ Atom::Pos(positive_atom)) => {
+                let reasons = engine.find_reasons(positive_atom);
+                let json_reasons = json_reasons(&reasons)?;
+                let str_reasons = show_reasons(&reasons, nodes_prefixmap, &self.schema, self.config.width())?;
+                (atom, ValidationStatus::conformant(str_reasons, json_reasons))
+            },
+            Decided::Checked(atom @ Atom::Neg(negative_atom)) => {
+                let errors = engine.find_errors(negative_atom);
+                let json_errors = json_errors(&errors)?;
+                let str_errors = show_errors(&errors, nodes_prefixmap, &self.schema, self.config.width())?;
+                (atom, ValidationStatus::non_conformant(str_errors, json_errors))
+            },
+            Decided::Interrupted(atom, interruption) => {
+                (atom, ValidationStatus::timeout(show_interruption(&interruption)))
+            },
+        };
+        let (node, idx) = atom.get_value();
+        let label = self.get_shape_label(idx)?;
+        Ok((node.clone(), label.clone(), status))
+    }
+
     pub fn shapes_prefixmap(&self) -> PrefixMap {
         self.schema.prefixmap()
     }
 }
 
+fn add_status(result: &mut ResultShapeMap, node: Node, label: ShapeLabel, status: ValidationStatus) -> Result<()> {
+    let (node_str, label_str) = (node.to_string(), label.to_string());
+    let adding_error: fn(String, String, String) -> ValidatorError = match &status {
+        ValidationStatus::Conformant(..) => {
+            |node, label, error| ValidatorError::AddingConformantError { node, label, error }
+        },
+        ValidationStatus::NonConformant(..) => {
+            |node, label, error| ValidatorError::AddingNonConformantError { node, label, error }
+        },
+        ValidationStatus::Timeout(_) => |node, label, error| ValidatorError::AddingTimeoutError { node, label, error },
+        _ => |node, label, error| ValidatorError::AddingPendingError { node, label, error },
+    };
+    result
+        .add_result(node, label, status)
+        .map_err(|e| adding_error(node_str, label_str, format!("{e}")))
+}
+
+fn show_interruption(interruption: &Interruption) -> String {
+    match interruption {
+        Interruption::TimedOut => "Validation ran out of time before deciding".to_string(),
+        Interruption::Cancelled => "Validation was cancelled before deciding".to_string(),
+    }
+}
+
 fn show_errors(
     errors: &[ValidatorError],
     nodes_prefixmap: &PrefixMap,
@@ -384,5 +496,29 @@ fn show_reasons(reasons: &[Reason], nodes_prefixmap: &PrefixMap, schema: &Schema
     Ok(result)
 }
 
+/// Adds to `result` the positions where its nodes appear in the data and where its
+/// shapes are declared in the schema, when they were recorded while parsing them
+fn add_spans<S: NeighsRDF>(result: &mut ResultShapeMap, rdf: &S, schema: &SchemaIR) {
+    let nodes: HashSet<Node> = result.iter().map(|(node, _, _)| node.clone()).collect();
+    let labels: HashSet<ShapeLabel> = result.iter().map(|(_, label, _)| label.clone()).collect();
+    for node in nodes {
+        let span = S::Subject::try_from(node.as_object().clone())
+            .ok()
+            .and_then(|subject| rdf.subject_span(&subject));
+        if let Some(span) = span {
+            result.add_node_span(node, span);
+        }
+    }
+    for label in labels {
+        let span = schema
+            .find_shape_label_idx(&label)
+            .and_then(|idx| schema.shape_span(idx))
+            .cloned();
+        if let Some(span) = span {
+            result.add_shape_span(label, span);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {}
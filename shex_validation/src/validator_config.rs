@@ -1,9 +1,11 @@
 use rudof_config::TomlConfig;
 use rudof_rdf::rdf_core::RdfDataConfig;
+use rudof_rdf::rdf_core::utils::ValidationBudget;
 use serde::{Deserialize, Serialize};
 use shex_ast::ir::external_resolver::{ExternalShapeResolver, ExternalShapeResolverRegistry};
 use shex_ast::shapemap::ShapemapConfig;
 use std::sync::Arc;
+use std::time::Duration;
 
 use crate::ShExConfig;
 
@@ -15,6 +17,15 @@ pub struct ValidatorConfig {
     #[serde(rename = "max_steps", skip_serializing_if = "Option::is_none")]
     pub(crate) max_steps: Option<usize>,
 
+    /// Wall-clock budget for a whole validation run, in milliseconds.
+    /// Entries not decided when it runs out get a timeout status.
+    #[serde(rename = "timeout_ms", skip_serializing_if = "Option::is_none")]
+    pub(crate) timeout_ms: Option<u64>,
+
+    /// Wall-clock budget for the validation of each shapemap entry, in milliseconds
+    #[serde(rename = "node_timeout_ms", skip_serializing_if = "Option::is_none")]
+    pub(crate) node_timeout_ms: Option<u64>,
+
     /// Configuration of RDF data readers
     #[serde(rename = "rdf", skip_serializing)]
     pub(crate) rdf_data: RdfDataConfig,
@@ -31,6 +42,16 @@ pub struct ValidatorConfig {
     #[serde(rename = "check_negation")]
     pub(crate) check_negation_requirement: bool,
 
+    /// Whether to record justification trees during validation (default: false).
+    /// Recording has a cost, so it is only enabled on request.
+    #[serde(rename = "justify")]
+    pub(crate) justify: bool,
+
+    /// Number of threads used to validate the entries of a shapemap (default: 1).
+    /// With 1 the entries are validated sequentially, 0 uses all available cores.
+    #[serde(rename = "threads")]
+    pub(crate) threads: usize,
+
     /// Width for pretty printing
     // TODO - This should be in rudof_lib
     #[serde(rename = "width")]
@@ -47,10 +68,14 @@ pub struct ValidatorConfig {
 impl PartialEq for ValidatorConfig {
     fn eq(&self, other: &Self) -> bool {
         self.max_steps == other.max_steps
+            && self.timeout_ms == other.timeout_ms
+            && self.node_timeout_ms == other.node_timeout_ms
             && self.rdf_data == other.rdf_data
             && self.shex == other.shex
             && self.shapemap == other.shapemap
             && self.check_negation_requirement == other.check_negation_requirement
+            && self.justify == other.justify
+            && self.threads == other.threads
             && self.width == other.width
     }
 }
@@ -59,11 +84,15 @@ impl ValidatorConfig {
     pub fn new() -> Self {
         Self {
             max_steps: Self::default_max_steps(),
+            timeout_ms: Self::default_timeout_ms(),
+            node_timeout_ms: Self::default_timeout_ms(),
             width: Self::default_width(),
             rdf_data: Self::default_rdf_data(),
             shex: Self::default_shex(),
             shapemap: Self::default_shapemap(),
             check_negation_requirement: Self::default_check_negation_requirement(),
+            justify: Self::default_justify(),
+            threads: Self::default_threads(),
             external_resolvers: ExternalShapeResolverRegistry::default(),
         }
     }
@@ -73,6 +102,16 @@ impl ValidatorConfig {
         self
     }
 
+    pub fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout_ms = timeout.map(as_millis);
+        self
+    }
+
+    pub fn with_node_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.node_timeout_ms = timeout.map(as_millis);
+        self
+    }
+
     pub fn with_rdf_data(mut self, cfg: RdfDataConfig) -> Self {
         self.rdf_data = cfg;
         self
@@ -93,6 +132,16 @@ impl ValidatorConfig {
         self
     }
 
+    pub fn with_justify(mut self, flag: bool) -> Self {
+        self.justify = flag;
+        self
+    }
+
+    pub fn with_threads(mut self, threads: usize) -> Self {
+        self.threads = threads;
+        self
+    }
+
     pub fn with_width(mut self, width: usize) -> Self {
         self.width = width;
         self
@@ -121,6 +170,21 @@ impl ValidatorConfig {
         self.max_steps
     }
 
+    pub fn timeout(&self) -> Option<Duration> {
+        self.timeout_ms.map(Duration::from_millis)
+    }
+
+    pub fn node_timeout(&self) -> Option<Duration> {
+        self.node_timeout_ms.map(Duration::from_millis)
+    }
+
+    /// Time limits configured for validation runs, without a cancellation token
+    pub fn budget(&self) -> ValidationBudget {
+        ValidationBudget::new()
+            .with_timeout(self.timeout())
+            .with_node_timeout(self.node_timeout())
+    }
+
     pub fn rdf_data(&self) -> &RdfDataConfig {
         &self.rdf_data
     }
@@ -137,6 +201,14 @@ impl ValidatorConfig {
         self.check_negation_requirement
     }
 
+    pub fn justify(&self) -> bool {
+        self.justify
+    }
+
+    pub fn threads(&self) -> usize {
+        self.threads
+    }
+
     pub fn width(&self) -> usize {
         self.width
     }
@@ -151,13 +223,20 @@ impl ValidatorConfig {
 #[rustfmt::skip]
 impl ValidatorConfig {
     #[inline] fn default_max_steps() -> Option<usize> { None }
+    #[inline] fn default_timeout_ms() -> Option<u64> { None }
     #[inline] fn default_rdf_data() -> RdfDataConfig { RdfDataConfig::new() }
     #[inline] fn default_shex() -> ShExConfig { ShExConfig::new() }
     #[inline] fn default_shapemap() -> ShapemapConfig { ShapemapConfig::new() }
     #[inline] fn default_check_negation_requirement() -> bool { true }
+    #[inline] fn default_justify() -> bool { false }
+    #[inline] fn default_threads() -> usize { 1 }
     #[inline] fn default_width() -> usize { 80 }
 }
 
+fn as_millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
 impl Default for ValidatorConfig {
     fn default() -> Self {
         Self::new()
@@ -179,6 +258,10 @@ mod tests {
             ValidatorConfig::default_check_negation_requirement()
         );
         assert_eq!(c.width(), ValidatorConfig::default_width());
+        assert!(!c.justify());
+        assert_eq!(c.threads(), ValidatorConfig::default_threads());
+        assert_eq!(c.timeout(), None);
+        assert_eq!(c.node_timeout(), None);
     }
 
     #[test]
@@ -187,17 +270,27 @@ mod tests {
             r#"
             max_steps = 7
             check_negation = false
+            justify = true
+            threads = 4
+            node_timeout_ms = 250
         "#,
         )
         .unwrap();
         assert_eq!(c.max_steps(), Some(7));
         assert!(!c.check_negation_requirement());
+        assert!(c.justify());
+        assert_eq!(c.threads(), 4);
+        assert_eq!(c.node_timeout(), Some(std::time::Duration::from_millis(250)));
+        assert_eq!(c.timeout(), None);
         assert_eq!(c.width(), ValidatorConfig::default_width());
     }
 
     #[test]
     fn toml_round_trip() {
-        let c = ValidatorConfig::default().with_max_steps(Some(42)).with_width(120);
+        let c = ValidatorConfig::default()
+            .with_max_steps(Some(42))
+            .with_width(120)
+            .with_timeout(Some(std::time::Duration::from_secs(3)));
         let s = toml::to_string(&c).unwrap();
         let d: ValidatorConfig = toml::from_str(&s).unwrap();
         assert_eq!(c, d);
@@ -7,6 +7,7 @@ use prefixmap::error::PrefixMapError;
 use rbe::RbeError;
 use rudof_iri::IriS;
 use rudof_rdf::rdf_core::term::Object;
+use rudof_rdf::rdf_core::utils::Interruption;
 use serde::Serialize;
 use serde::ser::SerializeMap;
 use shex_ast::ir::node_constraint::NodeConstraint;
@@ -204,6 +205,9 @@ pub enum ValidatorError {
     #[error("Adding pending {node}@{label} error: {error}")]
     AddingPendingError { node: String, label: String, error: String },
 
+    #[error("Adding timeout {node}@{label} error: {error}")]
+    AddingTimeoutError { node: String, label: String, error: String },
+
     #[error("Shape not found for index {idx}")]
     ShapeExprNotFound { idx: ShapeLabelIdx },
 
@@ -246,6 +250,15 @@ pub enum ValidatorError {
 
     #[error("EXTERNAL shape {idx} for node {node} could not be resolved by any registered resolver")]
     ExternalShapeUnresolved { node: Box<Node>, idx: ShapeLabelIdx },
+
+    #[error("Error creating the thread pool for parallel validation: {error}")]
+    ThreadPoolError { error: String },
+
+    #[error("Validation interrupted: {interruption}")]
+    Interrupted {
+        #[from]
+        interruption: Interruption,
+    },
 }
 
 fn add_errors_to_tree(
@@ -406,6 +419,10 @@ impl ValidatorError {
                 "Adding pending for node: {} and label: {}, error: {}",
                 node, label, error
             ),
+            ValidatorError::AddingTimeoutError { node, label, error } => format!(
+                "Adding timeout for node: {} and label: {}, error: {}",
+                node, label, error
+            ),
             ValidatorError::ShapeExprNotFound { idx } => {
                 format!("Shape expression {} not found", show_label(idx, schema, width))
             },
@@ -431,6 +448,10 @@ impl ValidatorError {
                 show_label(idx, schema, width),
                 show_node(node),
             ),
+            ValidatorError::ThreadPoolError { error } => {
+                format!("Error creating the thread pool for parallel validation: {error}")
+            },
+            ValidatorError::Interrupted { interruption } => format!("Validation interrupted: {interruption}"),
         };
         Ok(s)
     }
@@ -549,9 +570,12 @@ impl ValidatorError {
             | ValidatorError::AddingNonConformantError { .. }
             | ValidatorError::AddingConformantError { .. }
             | ValidatorError::AddingPendingError { .. }
+            | ValidatorError::AddingTimeoutError { .. }
             | ValidatorError::ShapeExprNotFound { .. }
             | ValidatorError::ExternalShapeRejected { .. }
             | ValidatorError::ExternalShapeUnresolved { .. }
+            | ValidatorError::ThreadPoolError { .. }
+            | ValidatorError::Interrupted { .. }
             | ValidatorError::StartActFailed { .. } => Ok(()),
         }
     }
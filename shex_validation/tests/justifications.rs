@@ -0,0 +1,130 @@
+//! End-to-end tests for the justification trees recorded when `ValidatorConfig::justify`
+//! is enabled: matched triples per constraint, nested references, the partition chosen
+//! for shapes with `EXTENDS` and the reason of a failing cardinality.
+
+use rudof_iri::IriS;
+use rudof_rdf::rdf_core::RDFFormat;
+use rudof_rdf::rdf_impl::{OxigraphInMemory, ReaderMode};
+use shex_ast::ir::shape_label::ShapeLabel;
+use shex_ast::ir::{map_state::MapState, schema_ir::SchemaIR, semantic_actions_registry::SemanticActionsRegistry};
+use shex_ast::shapemap::{Justification, JustificationStep, ResultShapeMap};
+use shex_ast::{Node, ResolveMethod, ShExParser, ir::ast2ir::AST2IR};
+use shex_validation::{Validator, ValidatorConfig};
+
+const SCHEMA: &str = r#"
+PREFIX :    <http://a.example/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+:Person { :name xsd:string ; :knows @:Person * }
+:Base   { :code xsd:integer }
+:Child  EXTENDS @:Base { :label xsd:string }
+"#;
+
+const DATA: &str = r#"
+PREFIX : <http://a.example/>
+:alice :name "Alice" ; :knows :bob .
+:bob   :name "Bob" .
+:carol :knows :bob .
+:item  :code 1 ; :label "one" .
+"#;
+
+fn compile(schema_src: &str, config: &ValidatorConfig) -> SchemaIR {
+    let base = IriS::new_unchecked("http://a.example/");
+    let ast = ShExParser::parse(schema_src, Some(base.clone()), &base).expect("parse schema");
+    let mut map_state = MapState::default();
+    let registry = SemanticActionsRegistry::default();
+    registry.set_map_state(&mut map_state);
+    let mut compiler = AST2IR::new(&ResolveMethod::default(), map_state);
+    let mut compiled = SchemaIR::new(registry);
+    compiler
+        .compile(
+            &ast,
+            &base,
+            &Some(base.clone()),
+            &mut compiled,
+            config.external_resolvers(),
+        )
+        .expect("compile to IR");
+    compiled
+}
+
+fn validate(config: &ValidatorConfig, focus: &str, shape: &str) -> (ResultShapeMap, Node, ShapeLabel) {
+    let compiled = compile(SCHEMA, config);
+    let mut validator = Validator::new(&compiled, config).expect("validator");
+    let graph = OxigraphInMemory::from_str(DATA, &RDFFormat::Turtle, None, &ReaderMode::Strict).expect("parse graph");
+    let node = Node::parse(&format!("http://a.example/{focus}"), None).expect("parse focus");
+    let shape_label = ShapeLabel::iri(IriS::new_unchecked(&format!("http://a.example/{shape}")));
+    let result = validator
+        .validate_node_shape(&node, &shape_label, &graph, &compiled, &Some(graph.prefixmap().clone()))
+        .expect("validate");
+    (result, node, shape_label)
+}
+
+fn justify(focus: &str, shape: &str) -> Justification {
+    let config = ValidatorConfig::default().with_justify(true);
+    let (result, node, shape_label) = validate(&config, focus, shape);
+    result
+        .justification(&node, &shape_label)
+        .cloned()
+        .expect("justification")
+}
+
+fn find_all<'a>(j: &'a Justification, step: JustificationStep, out: &mut Vec<&'a Justification>) {
+    if j.step() == step {
+        out.push(j);
+    }
+    for child in j.children() {
+        find_all(child, step, out);
+    }
+}
+
+#[test]
+fn no_justification_by_default() {
+    let (result, node, shape_label) = validate(&ValidatorConfig::default(), "alice", "Person");
+    assert!(result.justification(&node, &shape_label).is_none());
+}
+
+#[test]
+fn matched_triples_and_nested_refs() {
+    let j = justify("alice", "Person");
+    assert!(j.conforms());
+    assert_eq!(j.node(), ":alice");
+    assert_eq!(j.shape(), Some(":Person"));
+    let mut triple_exprs = Vec::new();
+    find_all(&j, JustificationStep::TripleExpr, &mut triple_exprs);
+    let alice = triple_exprs
+        .iter()
+        .find(|t| t.node() == ":alice")
+        .expect("alice triples");
+    let preds: Vec<_> = alice.matches().iter().map(|m| m.predicate()).collect();
+    assert!(preds.contains(&":name"));
+    assert!(preds.contains(&":knows"));
+    // The reference to :bob is expanded with bob's own proof
+    let mut refs = Vec::new();
+    find_all(&j, JustificationStep::Ref, &mut refs);
+    let bob = refs.iter().find(|r| r.node() == ":bob").expect("ref to bob");
+    assert!(bob.conforms());
+    assert_eq!(bob.children()[0].step(), JustificationStep::ShapeLabel);
+}
+
+#[test]
+fn failing_cardinality_is_reported() {
+    let j = justify("carol", "Person");
+    assert!(!j.conforms());
+    let mut triple_exprs = Vec::new();
+    find_all(&j, JustificationStep::TripleExpr, &mut triple_exprs);
+    let failed = triple_exprs.iter().find(|t| !t.conforms()).expect("failed triple expr");
+    assert!(failed.detail().unwrap().contains(":name"));
+    assert!(j.to_string().contains("fails"));
+}
+
+#[test]
+fn chosen_partition_is_recorded() {
+    let j = justify("item", "Child");
+    assert!(j.conforms());
+    let mut partitions = Vec::new();
+    find_all(&j, JustificationStep::Partition, &mut partitions);
+    assert_eq!(partitions.len(), 1);
+    assert!(partitions[0].conforms());
+    let json = serde_json::to_value(&j).unwrap();
+    assert_eq!(json["step"], "shapeLabel");
+}
@@ -0,0 +1,113 @@
+//! Parallel validation of query shapemaps (`ValidatorConfig::threads`): the results must
+//! be the same as the sequential ones, including recursive references and negation,
+//! whose outcome depends on the shared typing cache.
+
+use rudof_iri::IriS;
+use rudof_rdf::rdf_core::RDFFormat;
+use rudof_rdf::rdf_impl::{OxigraphInMemory, ReaderMode};
+use shex_ast::ir::{map_state::MapState, schema_ir::SchemaIR, semantic_actions_registry::SemanticActionsRegistry};
+use shex_ast::shapemap::ResultShapeMap;
+use shex_ast::{ResolveMethod, ShExParser, ShapeMapParser, ir::ast2ir::AST2IR};
+use shex_validation::{Validator, ValidatorConfig};
+
+const SCHEMA: &str = r#"
+PREFIX :    <http://a.example/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+:Person   { :name xsd:string ; :knows @:Person * }
+:Loner    NOT { :knows . + }
+:Employee @:Person AND { :worksFor @:Company }
+:Company  { :employee @:Employee * }
+"#;
+
+fn compile(schema_src: &str, config: &ValidatorConfig) -> SchemaIR {
+    let base = IriS::new_unchecked("http://a.example/");
+    let ast = ShExParser::parse(schema_src, Some(base.clone()), &base).expect("parse schema");
+    let mut map_state = MapState::default();
+    let registry = SemanticActionsRegistry::default();
+    registry.set_map_state(&mut map_state);
+    let mut compiler = AST2IR::new(&ResolveMethod::default(), map_state);
+    let mut compiled = SchemaIR::new(registry);
+    compiler
+        .compile(
+            &ast,
+            &base,
+            &Some(base.clone()),
+            &mut compiled,
+            config.external_resolvers(),
+        )
+        .expect("compile to IR");
+    compiled
+}
+
+/// Chains of people that know each other, some of them working for companies that
+/// point back to them, plus some people without a name that break their chain
+fn data(size: usize) -> String {
+    let mut ttl = String::from("PREFIX : <http://a.example/>\n");
+    for i in 0..size {
+        if i % 7 != 3 {
+            ttl.push_str(&format!(":p{i} :name \"P{i}\" .\n"));
+        }
+        if i % 5 != 4 && i + 1 < size {
+            ttl.push_str(&format!(":p{i} :knows :p{} .\n", i + 1));
+        }
+        if i % 3 == 0 {
+            ttl.push_str(&format!(":p{i} :worksFor :c{i} . :c{i} :employee :p{i} .\n"));
+        }
+    }
+    ttl
+}
+
+fn shapemap(size: usize) -> String {
+    (0..size)
+        .flat_map(|i| {
+            [
+                format!(":p{i}@:Person"),
+                format!(":p{i}@:Loner"),
+                format!(":p{i}@:Employee"),
+            ]
+        })
+        .collect::<Vec<_>>()
+        .join(",\n")
+}
+
+fn validate(threads: usize, size: usize) -> ResultShapeMap {
+    let config = ValidatorConfig::default().with_threads(threads);
+    let compiled = compile(SCHEMA, &config);
+    let validator = Validator::new(&compiled, &config).expect("validator");
+    let graph =
+        OxigraphInMemory::from_str(&data(size), &RDFFormat::Turtle, None, &ReaderMode::Strict).expect("parse graph");
+    let pm = graph.prefixmap().clone();
+    let shapemap = ShapeMapParser::parse(&shapemap(size), &Some(pm.clone()), &None, &Some(pm.clone()), &None)
+        .expect("parse shapemap");
+    validator
+        .validate_shapemap(&shapemap, &graph, &compiled, &Some(pm))
+        .expect("validate")
+}
+
+fn statuses(result: &ResultShapeMap) -> Vec<(String, String, bool)> {
+    let mut statuses: Vec<_> = result
+        .iter()
+        .map(|(node, shape, status)| (node.to_string(), shape.to_string(), status.is_conformant()))
+        .collect();
+    statuses.sort();
+    statuses
+}
+
+#[test]
+fn parallel_results_match_sequential() {
+    let sequential = statuses(&validate(1, 40));
+    assert_eq!(sequential.len(), 120);
+    // Some entries of each shape conform and some don't
+    for shape in ["Person", "Loner", "Employee"] {
+        let of_shape: Vec<_> = sequential.iter().filter(|(_, s, _)| s.ends_with(shape)).collect();
+        assert!(of_shape.iter().any(|(_, _, ok)| *ok), "no {shape} conforms");
+        assert!(of_shape.iter().any(|(_, _, ok)| !*ok), "every {shape} conforms");
+    }
+    assert_eq!(statuses(&validate(4, 40)), sequential);
+    assert_eq!(statuses(&validate(0, 40)), sequential);
+}
+
+#[test]
+fn parallel_with_a_single_entry() {
+    assert_eq!(statuses(&validate(4, 1)), statuses(&validate(1, 1)));
+}
@@ -0,0 +1,136 @@
+//! Time budgets and cancellation of shapemap validation: the entries that are not
+//! decided when the budget runs out, or when the run is cancelled, get a timeout
+//! status instead of making the whole validation fail.
+//! The budget also carries the profiler that records the shapes checked.
+
+use rudof_iri::IriS;
+use rudof_rdf::rdf_core::RDFFormat;
+use rudof_rdf::rdf_core::utils::{CancellationToken, Profiler, ValidationBudget};
+use rudof_rdf::rdf_impl::{OxigraphInMemory, ReaderMode};
+use shex_ast::ir::{map_state::MapState, schema_ir::SchemaIR, semantic_actions_registry::SemanticActionsRegistry};
+use shex_ast::shapemap::{ResultShapeMap, ValidationStatus};
+use shex_ast::{ResolveMethod, ShExParser, ShapeMapParser, ir::ast2ir::AST2IR};
+use shex_validation::{Validator, ValidatorConfig};
+use std::time::Duration;
+
+const SCHEMA: &str = r#"
+PREFIX :    <http://a.example/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+:Person { :name xsd:string ; :knows @:Person * }
+"#;
+
+const DATA: &str = r#"
+PREFIX : <http://a.example/>
+:alice :name "Alice" ; :knows :bob .
+:bob   :name "Bob" .
+:carol :knows :bob .
+"#;
+
+const SHAPEMAP: &str = ":alice@:Person, :bob@:Person, :carol@:Person";
+
+fn compile(schema_src: &str, config: &ValidatorConfig) -> SchemaIR {
+    let base = IriS::new_unchecked("http://a.example/");
+    let ast = ShExParser::parse(schema_src, Some(base.clone()), &base).expect("parse schema");
+    let mut map_state = MapState::default();
+    let registry = SemanticActionsRegistry::default();
+    registry.set_map_state(&mut map_state);
+    let mut compiler = AST2IR::new(&ResolveMethod::default(), map_state);
+    let mut compiled = SchemaIR::new(registry);
+    compiler
+        .compile(
+            &ast,
+            &base,
+            &Some(base.clone()),
+            &mut compiled,
+            config.external_resolvers(),
+        )
+        .expect("compile to IR");
+    compiled
+}
+
+fn validate(config: &ValidatorConfig, budget: Option<&ValidationBudget>) -> ResultShapeMap {
+    let compiled = compile(SCHEMA, config);
+    let validator = Validator::new(&compiled, config).expect("validator");
+    let graph = OxigraphInMemory::from_str(DATA, &RDFFormat::Turtle, None, &ReaderMode::Strict).expect("parse graph");
+    let pm = graph.prefixmap().clone();
+    let shapemap =
+        ShapeMapParser::parse(SHAPEMAP, &Some(pm.clone()), &None, &Some(pm.clone()), &None).expect("parse shapemap");
+    match budget {
+        Some(budget) => validator.validate_shapemap_with_budget(&shapemap, &graph, &compiled, &Some(pm), budget),
+        None => validator.validate_shapemap(&shapemap, &graph, &compiled, &Some(pm)),
+    }
+    .expect("validate")
+}
+
+fn codes(result: &ResultShapeMap) -> Vec<String> {
+    let mut codes: Vec<_> = result
+        .iter()
+        .map(|(node, _, status)| format!("{node} {}", status.code()))
+        .collect();
+    codes.sort();
+    codes
+}
+
+#[test]
+fn without_budget_every_entry_is_decided() {
+    let result = validate(&ValidatorConfig::default(), None);
+    assert_eq!(result.iter().count(), 3);
+    assert!(result.iter().all(|(_, _, status)| !status.is_timeout()));
+}
+
+#[test]
+fn exhausted_run_budget_marks_entries_as_timeout() {
+    let config = ValidatorConfig::default().with_timeout(Some(Duration::ZERO));
+    let result = validate(&config, None);
+    assert_eq!(result.iter().count(), 3);
+    for (_, _, status) in result.iter() {
+        assert!(status.is_timeout(), "unexpected status {status}");
+        assert!(status.reason().contains("ran out of time"));
+    }
+}
+
+#[test]
+fn exhausted_node_budget_marks_entries_as_timeout() {
+    let budget = ValidationBudget::new().with_node_timeout(Some(Duration::ZERO));
+    let result = validate(&ValidatorConfig::default(), Some(&budget));
+    assert!(result.iter().all(|(_, _, status)| status.is_timeout()));
+}
+
+#[test]
+fn cancelled_run_reports_cancellation() {
+    let token = CancellationToken::new();
+    token.cancel();
+    let budget = ValidationBudget::new().with_cancellation(token);
+    let result = validate(&ValidatorConfig::default().with_threads(2), Some(&budget));
+    assert_eq!(result.iter().count(), 3);
+    for (_, _, status) in result.iter() {
+        assert!(matches!(status, ValidationStatus::Timeout(reason) if reason.contains("cancelled")));
+    }
+}
+
+#[test]
+fn generous_budget_gives_the_same_results() {
+    let budget = ValidationBudget::new()
+        .with_timeout(Some(Duration::from_secs(600)))
+        .with_node_timeout(Some(Duration::from_secs(600)));
+    assert_eq!(
+        codes(&validate(&ValidatorConfig::default(), Some(&budget))),
+        codes(&validate(&ValidatorConfig::default(), None))
+    );
+}
+
+#[test]
+fn profiler_records_the_shapes_checked() {
+    let profiler = Profiler::new();
+    let budget = ValidationBudget::new().with_profiler(profiler.clone());
+    validate(&ValidatorConfig::default(), Some(&budget));
+    let entries = profiler.entries();
+    let person = entries
+        .iter()
+        .find(|entry| entry.shape() == ":Person" && entry.component().is_none())
+        .expect("entry of :Person");
+    assert!(person.evaluations() >= 3);
+    assert!(person.cache_hits() >= 1);
+    assert!(entries.iter().any(|entry| entry.component() == Some("Shape")));
+    assert!(profiler.folded().iter().any(|(stack, _)| stack == ":Person;Shape"));
+}
@@ -0,0 +1,124 @@
+//! Streaming of shapemap validation: every entry is reported with its final status
+//! as soon as it is decided, followed by the progress of the validation.
+
+use rudof_iri::IriS;
+use rudof_rdf::rdf_core::RDFFormat;
+use rudof_rdf::rdf_core::utils::ValidationBudget;
+use rudof_rdf::rdf_impl::{OxigraphInMemory, ReaderMode};
+use shex_ast::ir::{map_state::MapState, schema_ir::SchemaIR, semantic_actions_registry::SemanticActionsRegistry};
+use shex_ast::shapemap::ResultShapeMap;
+use shex_ast::{ResolveMethod, ShExParser, ShapeMapParser, ir::ast2ir::AST2IR};
+use shex_validation::{ShExValidationEvent, Validator, ValidatorConfig};
+use std::sync::Mutex;
+use std::time::Duration;
+
+const SCHEMA: &str = r#"
+PREFIX :    <http://a.example/>
+PREFIX xsd: <http://www.w3.org/2001/XMLSchema#>
+:Person { :name xsd:string ; :knows @:Person * }
+"#;
+
+const DATA: &str = r#"
+PREFIX : <http://a.example/>
+:alice :name "Alice" ; :knows :bob .
+:bob   :name "Bob" .
+:carol :knows :bob .
+:dave  :name "Dave" ; :knows :carol .
+"#;
+
+const SHAPEMAP: &str = ":alice@:Person, :bob@:Person, :carol@:Person, :dave@:Person";
+
+fn compile(config: &ValidatorConfig) -> SchemaIR {
+    let base = IriS::new_unchecked("http://a.example/");
+    let ast = ShExParser::parse(SCHEMA, Some(base.clone()), &base).expect("parse schema");
+    let mut map_state = MapState::default();
+    let registry = SemanticActionsRegistry::default();
+    registry.set_map_state(&mut map_state);
+    let mut compiler = AST2IR::new(&ResolveMethod::default(), map_state);
+    let mut compiled = SchemaIR::new(registry);
+    compiler
+        .compile(
+            &ast,
+            &base,
+            &Some(base.clone()),
+            &mut compiled,
+            config.external_resolvers(),
+        )
+        .expect("compile to IR");
+    compiled
+}
+
+fn validate(config: &ValidatorConfig, budget: &ValidationBudget) -> (ResultShapeMap, Vec<ShExValidationEvent>) {
+    let compiled = compile(config);
+    let validator = Validator::new(&compiled, config).expect("validator");
+    let graph = OxigraphInMemory::from_str(DATA, &RDFFormat::Turtle, None, &ReaderMode::Strict).expect("parse graph");
+    let pm = graph.prefixmap().clone();
+    let shapemap =
+        ShapeMapParser::parse(SHAPEMAP, &Some(pm.clone()), &None, &Some(pm.clone()), &None).expect("parse shapemap");
+    let events = Mutex::new(Vec::new());
+    let result = validator
+        .validate_shapemap_with_events(&shapemap, &graph, &compiled, &Some(pm), budget, &|event| {
+            events.lock().unwrap().push(event)
+        })
+        .expect("validate");
+    (result, events.into_inner().unwrap())
+}
+
+fn streamed_codes(events: &[ShExValidationEvent]) -> Vec<String> {
+    let mut codes: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event {
+            ShExValidationEvent::Result { node, shape, status } => Some(format!("{node} {shape} {}", status.code())),
+            _ => None,
+        })
+        .collect();
+    codes.sort();
+    codes
+}
+
+fn result_codes(result: &ResultShapeMap) -> Vec<String> {
+    let mut codes: Vec<_> = result
+        .iter()
+        .map(|(node, shape, status)| format!("{node} {shape} {}", status.code()))
+        .collect();
+    codes.sort();
+    codes
+}
+
+fn check_events(config: &ValidatorConfig, budget: &ValidationBudget) {
+    let (result, events) = validate(config, budget);
+    assert_eq!(
+        events.first(),
+        Some(&ShExValidationEvent::EntriesResolved { entries: 4 })
+    );
+    assert_eq!(streamed_codes(&events), result_codes(&result));
+    let progress: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event {
+            ShExValidationEvent::Progress { decided, entries } => Some((*decided, *entries)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(progress.len(), 4);
+    assert!(progress.iter().all(|(_, entries)| *entries == 4));
+    assert_eq!(progress.iter().map(|(decided, _)| *decided).max(), Some(4));
+}
+
+#[test]
+fn every_entry_is_streamed_with_its_final_status() {
+    check_events(&ValidatorConfig::default(), &ValidationBudget::new());
+}
+
+#[test]
+fn parallel_validation_streams_every_entry() {
+    check_events(&ValidatorConfig::default().with_threads(3), &ValidationBudget::new());
+}
+
+#[test]
+fn timed_out_entries_are_streamed() {
+    let budget = ValidationBudget::new().with_timeout(Some(Duration::ZERO));
+    let (_, events) = validate(&ValidatorConfig::default(), &budget);
+    let codes = streamed_codes(&events);
+    assert_eq!(codes.len(), 4);
+    assert!(codes.iter().all(|code| code.ends_with("timeout")), "{codes:?}");
+}
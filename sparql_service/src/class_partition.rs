@@ -1,6 +1,6 @@
 use crate::PropertyPartition;
 use rudof_iri::IriS;
-use rudof_rdf::rdf_core::term::IriOrBlankNode;
+use rudof_rdf::rdf_core::term::{IriOrBlankNode, literal::NumericLiteral};
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
@@ -9,6 +9,8 @@ pub struct ClassPartition {
     #[serde(skip_serializing_if = "Option::is_none")]
     id: Option<IriOrBlankNode>,
     class: IriS,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entities: Option<NumericLiteral>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     property_partition: Vec<PropertyPartition>,
 }
@@ -18,6 +20,7 @@ impl ClassPartition {
         ClassPartition {
             id: None,
             class: class.clone(),
+            entities: None,
             property_partition: Vec::new(),
         }
     }
@@ -27,15 +30,28 @@ impl ClassPartition {
         self
     }
 
+    pub fn with_entities(mut self, entities: Option<NumericLiteral>) -> Self {
+        self.entities = entities;
+        self
+    }
+
     pub fn with_property_partition(mut self, property_partition: Vec<PropertyPartition>) -> Self {
         self.property_partition = property_partition;
         self
     }
 
+    pub fn id(&self) -> Option<&IriOrBlankNode> {
+        self.id.as_ref()
+    }
+
     pub fn class(&self) -> &IriS {
         &self.class
     }
 
+    pub fn entities(&self) -> Option<NumericLiteral> {
+        self.entities.clone()
+    }
+
     pub fn property_partition(&self) -> &Vec<PropertyPartition> {
         &self.property_partition
     }
@@ -37,6 +37,18 @@ impl Dataset {
         self.named_graphs = named_graphs;
         self
     }
+
+    pub fn id(&self) -> Option<&IriOrBlankNode> {
+        self.id.as_ref()
+    }
+
+    pub fn default_graph(&self) -> Option<&GraphDescription> {
+        self.default_graph.as_ref()
+    }
+
+    pub fn named_graphs(&self) -> &Vec<NamedGraphDescription> {
+        &self.named_graphs
+    }
 }
 
 impl Display for Dataset {
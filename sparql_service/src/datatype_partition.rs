@@ -1,6 +1,63 @@
 use rudof_iri::IriS;
+use rudof_rdf::rdf_core::term::{IriOrBlankNode, literal::NumericLiteral};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
 
-#[derive(Clone, PartialEq, Eq, Default, Debug, Hash)]
+/// Subset of the triples of a property partition whose objects are literals of a datatype.
+///
+/// Datatype partitions are not part of VoID, they are described with the
+/// [VoID extension](http://ldf.fi/void-ext) vocabulary.
+#[derive(Clone, PartialEq, Eq, Default, Debug, Hash, Serialize, Deserialize)]
 pub struct DatatypePartition {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<IriOrBlankNode>,
     datatype: IriS,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    triples: Option<NumericLiteral>,
+}
+
+impl DatatypePartition {
+    pub fn new(datatype: &IriS) -> Self {
+        DatatypePartition {
+            id: None,
+            datatype: datatype.clone(),
+            triples: None,
+        }
+    }
+
+    pub fn with_id(mut self, id: &IriOrBlankNode) -> Self {
+        self.id = Some(id.clone());
+        self
+    }
+
+    pub fn with_triples(mut self, triples: Option<NumericLiteral>) -> Self {
+        self.triples = triples;
+        self
+    }
+
+    pub fn id(&self) -> Option<&IriOrBlankNode> {
+        self.id.as_ref()
+    }
+
+    pub fn datatype(&self) -> &IriS {
+        &self.datatype
+    }
+
+    pub fn triples(&self) -> Option<NumericLiteral> {
+        self.triples.clone()
+    }
+}
+
+impl Display for DatatypePartition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Datatype partition: datatype: {}{}",
+            self.datatype,
+            self.triples
+                .as_ref()
+                .map(|n| format!(", triples: {n}"))
+                .unwrap_or_default()
+        )
+    }
 }
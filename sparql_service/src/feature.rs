@@ -1,3 +1,7 @@
+use crate::{
+    SD_BASIC_FEDERATED_QUERY_STR, SD_DEREFERENCES_URIS_STR, SD_EMPTY_GRAPHS_STR, SD_REQUIRES_DATASET_STR,
+    SD_UNION_DEFAULT_GRAPH_STR,
+};
 use rudof_iri::IriS;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
@@ -13,6 +17,19 @@ pub enum Feature {
     Other(IriS),
 }
 
+impl Feature {
+    pub fn iri(&self) -> IriS {
+        match self {
+            Feature::DereferencesURIs => IriS::new_unchecked(SD_DEREFERENCES_URIS_STR),
+            Feature::UnionDefaultGraph => IriS::new_unchecked(SD_UNION_DEFAULT_GRAPH_STR),
+            Feature::RequiresDataset => IriS::new_unchecked(SD_REQUIRES_DATASET_STR),
+            Feature::EmptyGraphs => IriS::new_unchecked(SD_EMPTY_GRAPHS_STR),
+            Feature::BasicFederatedQuery => IriS::new_unchecked(SD_BASIC_FEDERATED_QUERY_STR),
+            Feature::Other(iri) => iri.clone(),
+        }
+    }
+}
+
 impl Display for Feature {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -24,6 +24,10 @@ impl GraphCollection {
         self
     }
 
+    pub fn id(&self) -> &IriOrBlankNode {
+        &self.id
+    }
+
     pub fn named_graph_descriptions(&self) -> impl Iterator<Item = &NamedGraphDescription> {
         self.collection.iter()
     }
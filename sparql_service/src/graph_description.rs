@@ -16,6 +16,10 @@ pub struct GraphDescription {
     entities: Option<NumericLiteral>,
     #[serde(skip_serializing_if = "Option::is_none")]
     documents: Option<NumericLiteral>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    distinct_subjects: Option<NumericLiteral>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    distinct_objects: Option<NumericLiteral>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     property_partition: Vec<PropertyPartition>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
@@ -33,6 +37,8 @@ impl GraphDescription {
             properties: None,
             entities: None,
             documents: None,
+            distinct_subjects: None,
+            distinct_objects: None,
         }
     }
 
@@ -61,6 +67,16 @@ impl GraphDescription {
         self
     }
 
+    pub fn with_distinct_subjects(mut self, distinct_subjects: Option<NumericLiteral>) -> Self {
+        self.distinct_subjects = distinct_subjects;
+        self
+    }
+
+    pub fn with_distinct_objects(mut self, distinct_objects: Option<NumericLiteral>) -> Self {
+        self.distinct_objects = distinct_objects;
+        self
+    }
+
     pub fn with_property_partition(mut self, property_partition: Vec<PropertyPartition>) -> Self {
         self.property_partition = property_partition;
         self
@@ -70,6 +86,46 @@ impl GraphDescription {
         self.class_partition = class_partition;
         self
     }
+
+    pub fn id(&self) -> &IriOrBlankNode {
+        &self.id
+    }
+
+    pub fn triples(&self) -> Option<NumericLiteral> {
+        self.triples.clone()
+    }
+
+    pub fn classes(&self) -> Option<NumericLiteral> {
+        self.classes.clone()
+    }
+
+    pub fn properties(&self) -> Option<NumericLiteral> {
+        self.properties.clone()
+    }
+
+    pub fn entities(&self) -> Option<NumericLiteral> {
+        self.entities.clone()
+    }
+
+    pub fn documents(&self) -> Option<NumericLiteral> {
+        self.documents.clone()
+    }
+
+    pub fn distinct_subjects(&self) -> Option<NumericLiteral> {
+        self.distinct_subjects.clone()
+    }
+
+    pub fn distinct_objects(&self) -> Option<NumericLiteral> {
+        self.distinct_objects.clone()
+    }
+
+    pub fn property_partition(&self) -> &Vec<PropertyPartition> {
+        &self.property_partition
+    }
+
+    pub fn class_partition(&self) -> &Vec<ClassPartition> {
+        &self.class_partition
+    }
 }
 
 impl Display for GraphDescription {
@@ -90,6 +146,12 @@ impl Display for GraphDescription {
         if let Some(documents) = &self.documents {
             writeln!(f, "  documents: {documents}")?;
         }
+        if let Some(distinct_subjects) = &self.distinct_subjects {
+            writeln!(f, "  distinct subjects: {distinct_subjects}")?;
+        }
+        if let Some(distinct_objects) = &self.distinct_objects {
+            writeln!(f, "  distinct objects: {distinct_objects}")?;
+        }
         let mut class_partition = self.class_partition.iter().peekable();
         if class_partition.peek().is_some() {
             writeln!(
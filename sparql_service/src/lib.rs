@@ -20,10 +20,12 @@ pub mod service_description_error;
 pub mod service_description_format;
 pub mod service_description_parser;
 pub mod service_description_vocab;
+pub mod service_description_writer;
 pub mod sparql_result_format;
 #[cfg(all(not(target_family = "wasm"), feature = "sparql"))]
 pub mod srdf_data;
 pub mod supported_language;
+pub mod void_profiler;
 
 pub use crate::class_partition::*;
 pub use crate::dataset::*;
@@ -44,7 +46,9 @@ pub use crate::service_description_error::*;
 pub use crate::service_description_format::*;
 pub use crate::service_description_parser::*;
 pub use crate::service_description_vocab::*;
+pub use crate::service_description_writer::*;
 pub use crate::sparql_result_format::*;
 #[cfg(all(not(target_family = "wasm"), feature = "sparql"))]
 pub use crate::srdf_data::*;
 pub use crate::supported_language::*;
+pub use crate::void_profiler::*;
@@ -40,6 +40,10 @@ impl NamedGraphDescription {
     pub fn name(&self) -> &IriS {
         &self.name
     }
+
+    pub fn graphs(&self) -> &Vec<GraphDescription> {
+        &self.graphs
+    }
 }
 
 impl Display for NamedGraphDescription {
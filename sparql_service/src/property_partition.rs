@@ -1,3 +1,4 @@
+use crate::DatatypePartition;
 use rudof_iri::IriS;
 use rudof_rdf::rdf_core::term::{IriOrBlankNode, literal::NumericLiteral};
 use serde::{Deserialize, Serialize};
@@ -9,6 +10,12 @@ pub struct PropertyPartition {
     property: IriS,
     #[serde(skip_serializing_if = "Option::is_none")]
     triples: Option<NumericLiteral>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    distinct_subjects: Option<NumericLiteral>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    distinct_objects: Option<NumericLiteral>,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    datatype_partition: Vec<DatatypePartition>,
 }
 
 impl PropertyPartition {
@@ -17,6 +24,9 @@ impl PropertyPartition {
             id: None,
             property: property.clone(),
             triples: None,
+            distinct_subjects: None,
+            distinct_objects: None,
+            datatype_partition: Vec::new(),
         }
     }
 
@@ -30,6 +40,25 @@ impl PropertyPartition {
         self
     }
 
+    pub fn with_distinct_subjects(mut self, distinct_subjects: Option<NumericLiteral>) -> Self {
+        self.distinct_subjects = distinct_subjects;
+        self
+    }
+
+    pub fn with_distinct_objects(mut self, distinct_objects: Option<NumericLiteral>) -> Self {
+        self.distinct_objects = distinct_objects;
+        self
+    }
+
+    pub fn with_datatype_partition(mut self, datatype_partition: Vec<DatatypePartition>) -> Self {
+        self.datatype_partition = datatype_partition;
+        self
+    }
+
+    pub fn id(&self) -> Option<&IriOrBlankNode> {
+        self.id.as_ref()
+    }
+
     pub fn property(&self) -> &IriS {
         &self.property
     }
@@ -37,6 +66,18 @@ impl PropertyPartition {
     pub fn triples(&self) -> Option<NumericLiteral> {
         self.triples.clone()
     }
+
+    pub fn distinct_subjects(&self) -> Option<NumericLiteral> {
+        self.distinct_subjects.clone()
+    }
+
+    pub fn distinct_objects(&self) -> Option<NumericLiteral> {
+        self.distinct_objects.clone()
+    }
+
+    pub fn datatype_partition(&self) -> &Vec<DatatypePartition> {
+        &self.datatype_partition
+    }
 }
 
 impl Display for PropertyPartition {
@@ -49,6 +90,10 @@ impl Display for PropertyPartition {
                 .as_ref()
                 .map(|n| format!(", triples: {n}"))
                 .unwrap_or_default()
-        )
+        )?;
+        for datatype_partition in &self.datatype_partition {
+            write!(f, "\n   {datatype_partition}")?;
+        }
+        Ok(())
     }
 }
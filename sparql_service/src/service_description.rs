@@ -1,17 +1,18 @@
 //! A set whose elements can be repeated. The set tracks how many times each element appears
 //!
 use crate::{
-    Dataset, Feature, GraphCollection, ServiceDescriptionError, ServiceDescriptionFormat, ServiceDescriptionParser,
-    SparqlResultFormat, SupportedLanguage,
+    Dataset, Feature, GraphCollection, GraphDescription, ServiceDescriptionError, ServiceDescriptionFormat,
+    ServiceDescriptionParser, ServiceDescriptionWriter, SparqlResultFormat, SupportedLanguage,
 };
 use itertools::Itertools;
-use mie::Mie;
+use mie::{DataStatistics, Mie};
 use prefixmap::PrefixMap;
 use rudof_iri::IriS;
 use rudof_rdf::{
-    rdf_core::RDFFormat,
+    rdf_core::{RDFFormat, term::literal::NumericLiteral},
     rdf_impl::{OxigraphInMemory, ReaderMode},
 };
+use rust_decimal::prelude::ToPrimitive;
 use serde::{Deserialize, Serialize};
 #[cfg(not(target_family = "wasm"))]
 use std::path::Path;
@@ -91,6 +92,30 @@ impl ServiceDescription {
         &self.endpoint
     }
 
+    pub fn default_dataset(&self) -> Option<&Dataset> {
+        self.default_dataset.as_ref()
+    }
+
+    pub fn supported_languages(&self) -> impl Iterator<Item = &SupportedLanguage> {
+        self.supported_language.iter()
+    }
+
+    pub fn features(&self) -> impl Iterator<Item = &Feature> {
+        self.feature.iter()
+    }
+
+    pub fn result_formats(&self) -> impl Iterator<Item = &SparqlResultFormat> {
+        self.result_format.iter()
+    }
+
+    pub fn available_graphs(&self) -> &Vec<GraphCollection> {
+        &self.available_graphs
+    }
+
+    pub fn prefixmap(&self) -> Option<&PrefixMap> {
+        self.prefixmap.as_ref()
+    }
+
     /// Returns the number of graphs listed as available in this service description.
     pub fn available_graphs_count(&self) -> usize {
         self.available_graphs.len()
@@ -170,6 +195,17 @@ impl ServiceDescription {
         if let Some(prefixmap) = &self.prefixmap {
             mie.add_prefixes(cnv_prefixmap(prefixmap))
         }
+
+        if let Some(dataset) = &self.default_dataset {
+            if let Some(default_graph) = dataset.default_graph() {
+                mie.add_data_statistics("default", graph2statistics(default_graph));
+            }
+            for named_graph in dataset.named_graphs() {
+                for graph in named_graph.graphs() {
+                    mie.add_data_statistics(named_graph.name().as_str(), graph2statistics(graph));
+                }
+            }
+        }
         mie
     }
 
@@ -189,8 +225,36 @@ impl ServiceDescription {
                     .map_err(|e| io::Error::other(format!("Error converting ServiceDescription to JSON: {e}")))?;
                 writer.write_all(json.as_bytes())
             },
+            ServiceDescriptionFormat::Rdf(rdf_format) => {
+                let mut rdf_writer: ServiceDescriptionWriter<OxigraphInMemory> = ServiceDescriptionWriter::new();
+                rdf_writer
+                    .register(self)
+                    .and_then(|_| rdf_writer.serialize(rdf_format, writer))
+                    .map_err(|e| io::Error::other(format!("Error converting ServiceDescription to RDF: {e}")))
+            },
+        }
+    }
+}
+
+fn graph2statistics(graph: &GraphDescription) -> DataStatistics {
+    let mut statistics = DataStatistics::new()
+        .with_classes(count(graph.classes()))
+        .with_properties(count(graph.properties()));
+    for class_partition in graph.class_partition() {
+        if let Some(entities) = count(class_partition.entities()) {
+            statistics.add_class_partition(class_partition.class().as_str(), entities);
+        }
+    }
+    for property_partition in graph.property_partition() {
+        if let Some(triples) = count(property_partition.triples()) {
+            statistics.add_property_partition(property_partition.property().as_str(), triples);
         }
     }
+    statistics
+}
+
+fn count(n: Option<NumericLiteral>) -> Option<isize> {
+    n.and_then(|n| n.to_decimal()).and_then(|d| d.to_isize())
 }
 
 fn cnv_prefixmap(pm: &PrefixMap) -> HashMap<String, IriS> {
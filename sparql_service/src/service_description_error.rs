@@ -12,6 +12,12 @@ pub enum ServiceDescriptionError {
     #[error("Expected IRI as value for property: {property} but got {term}")]
     ExpectedIRIAsValueForProperty { property: String, term: String },
 
+    #[error("Error running profiling query {query}: {error}")]
+    Profiling { query: String, error: String },
+
+    #[error("Unable to write service description as RDF: {error}")]
+    Writer { error: String },
+
     #[error(transparent)]
     SRDFGraphError {
         #[from]
@@ -1,6 +1,10 @@
+use rudof_rdf::rdf_core::RDFFormat;
+
 pub enum ServiceDescriptionFormat {
     // Internal representation
     Internal,
     Mie,
     Json,
+    // RDF using the Service Description and VoID vocabularies
+    Rdf(RDFFormat),
 }
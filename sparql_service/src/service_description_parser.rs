@@ -1,11 +1,12 @@
 use crate::{
-    ClassPartition, Dataset, Feature, GraphCollection, GraphDescription, NamedGraphDescription, PropertyPartition,
-    SD_BASIC_FEDERATED_QUERY_STR, SD_DEREFERENCES_URIS_STR, SD_EMPTY_GRAPHS_STR, SD_REQUIRES_DATASET_STR,
-    SD_SPARQL10_QUERY_STR, SD_SPARQL11_QUERY_STR, SD_SPARQL11_UPDATE_STR, SD_UNION_DEFAULT_GRAPH_STR,
-    ServiceDescription, ServiceDescriptionError, SparqlResultFormat, SupportedLanguage, dct_title, sd_available_graphs,
-    sd_default_dataset, sd_default_graph, sd_endpoint, sd_feature, sd_graph, sd_name, sd_named_graph, sd_result_format,
-    sd_service, sd_supported_language, void_class, void_class_partition, void_classes, void_property,
-    void_property_partition, void_triples,
+    ClassPartition, Dataset, DatatypePartition, Feature, GraphCollection, GraphDescription, NamedGraphDescription,
+    PropertyPartition, SD_BASIC_FEDERATED_QUERY_STR, SD_DEREFERENCES_URIS_STR, SD_EMPTY_GRAPHS_STR,
+    SD_REQUIRES_DATASET_STR, SD_SPARQL10_QUERY_STR, SD_SPARQL11_QUERY_STR, SD_SPARQL11_UPDATE_STR,
+    SD_UNION_DEFAULT_GRAPH_STR, ServiceDescription, ServiceDescriptionError, SparqlResultFormat, SupportedLanguage,
+    dct_title, sd_available_graphs, sd_default_dataset, sd_default_graph, sd_endpoint, sd_feature, sd_graph, sd_name,
+    sd_named_graph, sd_result_format, sd_service, sd_supported_language, void_class, void_class_partition,
+    void_classes, void_distinct_objects, void_distinct_subjects, void_documents, void_entities, void_properties,
+    void_property, void_property_partition, void_triples, voidext_datatype, voidext_datatype_partition,
 };
 use rudof_iri::IriS;
 use rudof_rdf::rdf_core::{
@@ -312,13 +313,32 @@ where
         FocusParser::new()
             .and(parse_void_triples(node_term.clone()))
             .and(parse_void_classes(node_term.clone()))
+            .and(parse_void_integer(node_term.clone(), void_properties().clone()))
+            .and(parse_void_integer(node_term.clone(), void_entities().clone()))
+            .and(parse_void_integer(node_term.clone(), void_documents().clone()))
+            .and(parse_void_integer(node_term.clone(), void_distinct_subjects().clone()))
+            .and(parse_void_integer(node_term.clone(), void_distinct_objects().clone()))
             .and(parse_void_class_partition(node_term.clone()))
             .and(parse_void_property_partition(node_term.clone()))
             .map(
-                move |((((_, triples), classes), class_partition), property_partition)| {
+                move |(
+                    (
+                        (
+                            ((((((_, triples), classes), properties), entities), documents), distinct_subjects),
+                            distinct_objects,
+                        ),
+                        class_partition,
+                    ),
+                    property_partition,
+                )| {
                     let d = GraphDescription::new(&node)
                         .with_triples(triples)
                         .with_classes(classes)
+                        .with_properties(properties)
+                        .with_entities(entities)
+                        .with_documents(documents)
+                        .with_distinct_subjects(distinct_subjects)
+                        .with_distinct_objects(distinct_objects)
                         .with_class_partition(class_partition)
                         .with_property_partition(property_partition);
                     trace!("parsed graph_description: {d}");
@@ -392,6 +412,19 @@ where
     })
 }
 
+/// Parses the optional integer value of a VoID statistic of a node
+fn parse_void_integer<RDF>(node: RDF::Term, property: IriS) -> impl RDFNodeParse<RDF, Output = Option<NumericLiteral>>
+where
+    RDF: FocusRDF + 'static,
+    RDF::Term: Clone,
+{
+    SetFocusParser::new(node).with(
+        SingleIntegerPropertyParser::new(property)
+            .map(|n: isize| NumericLiteral::Integer(n as i128))
+            .optional(),
+    )
+}
+
 pub fn parse_void_triples<RDF>(node: RDF::Term) -> impl RDFNodeParse<RDF, Output = Option<NumericLiteral>>
 where
     RDF: FocusRDF + 'static,
@@ -443,16 +476,18 @@ where
     trace!("parsing class_partition");
     FocusParser::new().then(move |focus: RDF::Term| {
         let focus_clone = focus.clone();
-        SuccessParser::new(focus)
+        SuccessParser::new(focus.clone())
             .and(SingleIriPropertyParser::new(void_class().clone()))
-            .and(property_partition().map_property(void_property().clone()))
-            .map(move |((_, class), property_partition)| {
+            .and(parse_void_integer(focus.clone(), void_entities().clone()))
+            .and(SetFocusParser::new(focus).with(property_partition().map_property(void_property_partition().clone())))
+            .map(move |(((_, class), entities), property_partition)| {
                 let focus_iri: IriOrBlankNode = focus_clone
                     .clone()
                     .try_into()
                     .expect("Failed to convert Term to IriOrBlankNode");
                 ClassPartition::new(&class)
                     .with_id(&focus_iri)
+                    .with_entities(entities)
                     .with_property_partition(property_partition)
             })
     })
@@ -464,19 +499,45 @@ where
     RDF::Term: TryInto<IriOrBlankNode> + Clone,
     <RDF::Term as TryInto<IriOrBlankNode>>::Error: Debug,
 {
-    FocusParser::new()
-        .and(SingleIriPropertyParser::new(void_property().clone()).map(|p| p.clone()))
-        .and(
-            SingleIntegerPropertyParser::new(void_triples().clone())
-                .optional()
-                .map(|opt: Option<isize>| opt.map(|n| NumericLiteral::Integer(n as i128))),
-        )
-        .map(
-            |((focus, property), triples): ((RDF::Term, IriS), Option<NumericLiteral>)| {
+    FocusParser::new().then(|focus: RDF::Term| {
+        SuccessParser::new(focus.clone())
+            .and(SingleIriPropertyParser::new(void_property().clone()))
+            .and(parse_void_triples(focus.clone()))
+            .and(parse_void_integer(focus.clone(), void_distinct_subjects().clone()))
+            .and(parse_void_integer(focus.clone(), void_distinct_objects().clone()))
+            .and(
+                SetFocusParser::new(focus)
+                    .with(datatype_partition().map_property(voidext_datatype_partition().clone())),
+            )
+            .map(
+                |(((((focus, property), triples), distinct_subjects), distinct_objects), datatype_partition)| {
+                    let focus_iri: IriOrBlankNode = focus.try_into().expect("Failed to convert Term to IriOrBlankNode");
+                    PropertyPartition::new(&property)
+                        .with_id(&focus_iri)
+                        .with_triples(triples)
+                        .with_distinct_subjects(distinct_subjects)
+                        .with_distinct_objects(distinct_objects)
+                        .with_datatype_partition(datatype_partition)
+                },
+            )
+    })
+}
+
+pub fn datatype_partition<RDF>() -> impl RDFNodeParse<RDF, Output = DatatypePartition>
+where
+    RDF: FocusRDF + 'static,
+    RDF::Term: TryInto<IriOrBlankNode> + Clone,
+    <RDF::Term as TryInto<IriOrBlankNode>>::Error: Debug,
+{
+    FocusParser::new().then(|focus: RDF::Term| {
+        SuccessParser::new(focus.clone())
+            .and(SingleIriPropertyParser::new(voidext_datatype().clone()))
+            .and(parse_void_triples(focus))
+            .map(|((focus, datatype), triples)| {
                 let focus_iri: IriOrBlankNode = focus.try_into().expect("Failed to convert Term to IriOrBlankNode");
-                PropertyPartition::new(&property)
+                DatatypePartition::new(&datatype)
                     .with_id(&focus_iri)
                     .with_triples(triples)
-            },
-        )
+            })
+    })
 }
@@ -6,6 +6,10 @@ pub const DCT_STR: &str = "http://purl.org/dc/terms/";
 pub const DCT_TITLE_STR: &str = concatcp!(DCT_STR, "title");
 pub const SD_STR: &str = "http://www.w3.org/ns/sparql-service-description#";
 pub const SD_SERVICE_STR: &str = concatcp!(SD_STR, "Service");
+pub const SD_DATASET_STR: &str = concatcp!(SD_STR, "Dataset");
+pub const SD_GRAPH_CLASS_STR: &str = concatcp!(SD_STR, "Graph");
+pub const SD_NAMED_GRAPH_CLASS_STR: &str = concatcp!(SD_STR, "NamedGraph");
+pub const SD_GRAPH_COLLECTION_STR: &str = concatcp!(SD_STR, "GraphCollection");
 pub const SD_DEFAULT_GRAPH_STR: &str = concatcp!(SD_STR, "defaultGraph");
 pub const SD_NAMED_GRAPH_STR: &str = concatcp!(SD_STR, "namedGraph");
 pub const SD_NAME_STR: &str = concatcp!(SD_STR, "name");
@@ -30,6 +34,7 @@ pub const SD_DEREFERENCES_URIS_STR: &str = concatcp!(SD_STR, "DereferencesURIs")
 pub const SD_AVAILABLE_GRAPHS_STR: &str = concatcp!(SD_STR, "availableGraphs");
 
 pub const VOID_STR: &str = "http://rdfs.org/ns/void#";
+pub const VOID_DATASET_STR: &str = concatcp!(VOID_STR, "Dataset");
 pub const VOID_TRIPLES_STR: &str = concatcp!(VOID_STR, "triples");
 pub const VOID_ENTITIES_STR: &str = concatcp!(VOID_STR, "entities");
 pub const VOID_PROPERTIES_STR: &str = concatcp!(VOID_STR, "properties");
@@ -41,10 +46,21 @@ pub const VOID_CLASS_PARTITION_STR: &str = concatcp!(VOID_STR, "classPartition")
 pub const VOID_PROPERTY_PARTITION_STR: &str = concatcp!(VOID_STR, "propertyPartition");
 pub const VOID_DISJOINT_SUBJECTS_STR: &str = concatcp!(VOID_STR, "disjointSubjects");
 pub const VOID_DISJOINT_OBJECTS_STR: &str = concatcp!(VOID_STR, "disjointObjects");
+pub const VOID_DISTINCT_SUBJECTS_STR: &str = concatcp!(VOID_STR, "distinctSubjects");
+pub const VOID_DISTINCT_OBJECTS_STR: &str = concatcp!(VOID_STR, "distinctObjects");
+
+// VoID extension for datatype partitions: http://ldf.fi/void-ext
+pub const VOIDEXT_STR: &str = "http://ldf.fi/void-ext#";
+pub const VOIDEXT_DATATYPE_PARTITION_STR: &str = concatcp!(VOIDEXT_STR, "datatypePartition");
+pub const VOIDEXT_DATATYPE_STR: &str = concatcp!(VOIDEXT_STR, "datatype");
 
 iri_once!(dct_title, DCT_TITLE_STR);
 iri_once!(sd, SD_STR);
 iri_once!(sd_service, SD_SERVICE_STR);
+iri_once!(sd_dataset, SD_DATASET_STR);
+iri_once!(sd_graph_class, SD_GRAPH_CLASS_STR);
+iri_once!(sd_named_graph_class, SD_NAMED_GRAPH_CLASS_STR);
+iri_once!(sd_graph_collection, SD_GRAPH_COLLECTION_STR);
 iri_once!(sd_available_graphs, SD_AVAILABLE_GRAPHS_STR);
 iri_once!(sd_default_graph, SD_DEFAULT_GRAPH_STR);
 iri_once!(sd_name, SD_NAME_STR);
@@ -75,3 +91,9 @@ iri_once!(void_class_partition, VOID_CLASS_PARTITION_STR);
 iri_once!(void_property_partition, VOID_PROPERTY_PARTITION_STR);
 iri_once!(void_disjoint_subjects, VOID_DISJOINT_SUBJECTS_STR);
 iri_once!(void_disjoint_objects, VOID_DISJOINT_OBJECTS_STR);
+iri_once!(void_distinct_subjects, VOID_DISTINCT_SUBJECTS_STR);
+iri_once!(void_distinct_objects, VOID_DISTINCT_OBJECTS_STR);
+iri_once!(void_dataset, VOID_DATASET_STR);
+iri_once!(voidext, VOIDEXT_STR);
+iri_once!(voidext_datatype_partition, VOIDEXT_DATATYPE_PARTITION_STR);
+iri_once!(voidext_datatype, VOIDEXT_DATATYPE_STR);
@@ -0,0 +1,241 @@
+use crate::{
+    ClassPartition, DCT_STR, Dataset, DatatypePartition, GraphCollection, GraphDescription, NamedGraphDescription,
+    PropertyPartition, SD_STR, ServiceDescription, ServiceDescriptionError, VOID_STR, VOIDEXT_STR, dct_title,
+    sd_available_graphs, sd_dataset, sd_default_dataset, sd_default_graph, sd_endpoint, sd_feature, sd_graph,
+    sd_graph_class, sd_graph_collection, sd_name, sd_named_graph, sd_named_graph_class, sd_result_format, sd_service,
+    sd_supported_language, void_class, void_class_partition, void_classes, void_distinct_objects,
+    void_distinct_subjects, void_documents, void_entities, void_properties, void_property, void_property_partition,
+    void_triples, voidext_datatype, voidext_datatype_partition,
+};
+use rudof_iri::IriS;
+use rudof_rdf::rdf_core::{
+    BuildRDF, RDFFormat,
+    term::{IriOrBlankNode, literal::ConcreteLiteral, literal::NumericLiteral},
+    vocabs::{RdfVocab, RdfVocabulary, XsdVocab},
+};
+use std::collections::HashMap;
+use std::io::Write;
+
+/// Writes service descriptions as RDF, using the
+/// [Service Description](https://www.w3.org/TR/sparql11-service-description/) and
+/// [VoID](https://www.w3.org/TR/void/) vocabularies.
+///
+/// It is the inverse of the [`ServiceDescriptionParser`](crate::ServiceDescriptionParser):
+/// the graphs it writes can be parsed back.
+pub struct ServiceDescriptionWriter<RDF: BuildRDF> {
+    rdf: RDF,
+    bnodes: HashMap<String, RDF::Subject>,
+}
+
+impl<RDF: BuildRDF> ServiceDescriptionWriter<RDF> {
+    pub fn new() -> Self {
+        Self {
+            rdf: RDF::empty(),
+            bnodes: HashMap::new(),
+        }
+    }
+
+    pub fn register(&mut self, service: &ServiceDescription) -> Result<(), ServiceDescriptionError> {
+        if let Some(prefixmap) = service.prefixmap() {
+            self.rdf.merge_prefixes(prefixmap.clone());
+        }
+        self.rdf.add_prefix("rdf", &RdfVocab::base_iri());
+        self.rdf.add_prefix("xsd", &XsdVocab::base_iri());
+        self.rdf.add_prefix("sd", &IriS::new_unchecked(SD_STR));
+        self.rdf.add_prefix("void", &IriS::new_unchecked(VOID_STR));
+        self.rdf.add_prefix("voidext", &IriS::new_unchecked(VOIDEXT_STR));
+        self.rdf.add_prefix("dct", &IriS::new_unchecked(DCT_STR));
+
+        let node = self.node(None)?;
+        self.add_iri(&node, RdfVocab::rdf_type_ref(), sd_service())?;
+        if let Some(endpoint) = service.endpoint() {
+            self.add_iri(&node, sd_endpoint(), endpoint)?;
+        }
+        if let Some(title) = service.title() {
+            let title: RDF::Literal = title.clone().into();
+            self.add(&node, dct_title(), title)?;
+        }
+        for language in service.supported_languages() {
+            self.add_iri(&node, sd_supported_language(), &language.iri())?;
+        }
+        for feature in service.features() {
+            self.add_iri(&node, sd_feature(), &feature.iri())?;
+        }
+        for result_format in service.result_formats() {
+            self.add_iri(&node, sd_result_format(), &result_format.iri())?;
+        }
+        if let Some(dataset) = service.default_dataset() {
+            let dataset = self.dataset(dataset)?;
+            self.add(&node, sd_default_dataset(), dataset)?;
+        }
+        for graph_collection in service.available_graphs() {
+            let graph_collection = self.graph_collection(graph_collection)?;
+            self.add(&node, sd_available_graphs(), graph_collection)?;
+        }
+        Ok(())
+    }
+
+    pub fn serialize<W: Write>(&self, format: &RDFFormat, writer: &mut W) -> Result<(), ServiceDescriptionError> {
+        self.rdf
+            .serialize(format, writer)
+            .map_err(|e| ServiceDescriptionError::Writer { error: e.to_string() })
+    }
+
+    fn dataset(&mut self, dataset: &Dataset) -> Result<RDF::Subject, ServiceDescriptionError> {
+        let node = self.node(dataset.id())?;
+        self.add_iri(&node, RdfVocab::rdf_type_ref(), sd_dataset())?;
+        if let Some(default_graph) = dataset.default_graph() {
+            let graph = self.graph_description(default_graph)?;
+            self.add(&node, sd_default_graph(), graph)?;
+        }
+        for named_graph in dataset.named_graphs() {
+            let named_graph = self.named_graph(named_graph)?;
+            self.add(&node, sd_named_graph(), named_graph)?;
+        }
+        Ok(node)
+    }
+
+    fn graph_collection(
+        &mut self,
+        graph_collection: &GraphCollection,
+    ) -> Result<RDF::Subject, ServiceDescriptionError> {
+        let node = self.node(Some(graph_collection.id()))?;
+        self.add_iri(&node, RdfVocab::rdf_type_ref(), sd_graph_collection())?;
+        for named_graph in graph_collection.named_graph_descriptions() {
+            let named_graph = self.named_graph(named_graph)?;
+            self.add(&node, sd_named_graph(), named_graph)?;
+        }
+        Ok(node)
+    }
+
+    fn named_graph(&mut self, named_graph: &NamedGraphDescription) -> Result<RDF::Subject, ServiceDescriptionError> {
+        let node = self.node(named_graph.id().as_ref())?;
+        self.add_iri(&node, RdfVocab::rdf_type_ref(), sd_named_graph_class())?;
+        self.add_iri(&node, sd_name(), named_graph.name())?;
+        for graph in named_graph.graphs() {
+            let graph = self.graph_description(graph)?;
+            self.add(&node, sd_graph(), graph)?;
+        }
+        Ok(node)
+    }
+
+    fn graph_description(&mut self, graph: &GraphDescription) -> Result<RDF::Subject, ServiceDescriptionError> {
+        let node = self.node(Some(graph.id()))?;
+        self.add_iri(&node, RdfVocab::rdf_type_ref(), sd_graph_class())?;
+        self.add_number(&node, void_triples(), graph.triples())?;
+        self.add_number(&node, void_classes(), graph.classes())?;
+        self.add_number(&node, void_properties(), graph.properties())?;
+        self.add_number(&node, void_entities(), graph.entities())?;
+        self.add_number(&node, void_documents(), graph.documents())?;
+        self.add_number(&node, void_distinct_subjects(), graph.distinct_subjects())?;
+        self.add_number(&node, void_distinct_objects(), graph.distinct_objects())?;
+        for class_partition in graph.class_partition() {
+            let class_partition = self.class_partition(class_partition)?;
+            self.add(&node, void_class_partition(), class_partition)?;
+        }
+        for property_partition in graph.property_partition() {
+            let property_partition = self.property_partition(property_partition)?;
+            self.add(&node, void_property_partition(), property_partition)?;
+        }
+        Ok(node)
+    }
+
+    fn class_partition(&mut self, class_partition: &ClassPartition) -> Result<RDF::Subject, ServiceDescriptionError> {
+        let node = self.node(class_partition.id())?;
+        self.add_iri(&node, void_class(), class_partition.class())?;
+        self.add_number(&node, void_entities(), class_partition.entities())?;
+        for property_partition in class_partition.property_partition() {
+            let property_partition = self.property_partition(property_partition)?;
+            self.add(&node, void_property_partition(), property_partition)?;
+        }
+        Ok(node)
+    }
+
+    fn property_partition(
+        &mut self,
+        property_partition: &PropertyPartition,
+    ) -> Result<RDF::Subject, ServiceDescriptionError> {
+        let node = self.node(property_partition.id())?;
+        self.add_iri(&node, void_property(), property_partition.property())?;
+        self.add_number(&node, void_triples(), property_partition.triples())?;
+        self.add_number(&node, void_distinct_subjects(), property_partition.distinct_subjects())?;
+        self.add_number(&node, void_distinct_objects(), property_partition.distinct_objects())?;
+        for datatype_partition in property_partition.datatype_partition() {
+            let datatype_partition = self.datatype_partition(datatype_partition)?;
+            self.add(&node, voidext_datatype_partition(), datatype_partition)?;
+        }
+        Ok(node)
+    }
+
+    fn datatype_partition(
+        &mut self,
+        datatype_partition: &DatatypePartition,
+    ) -> Result<RDF::Subject, ServiceDescriptionError> {
+        let node = self.node(datatype_partition.id())?;
+        self.add_iri(&node, voidext_datatype(), datatype_partition.datatype())?;
+        self.add_number(&node, void_triples(), datatype_partition.triples())?;
+        Ok(node)
+    }
+
+    /// Returns the node of an identifier, creating a fresh blank node when it is
+    /// missing. Blank node identifiers are mapped to fresh blank nodes too, so they
+    /// cannot clash with the generated ones.
+    fn node(&mut self, id: Option<&IriOrBlankNode>) -> Result<RDF::Subject, ServiceDescriptionError> {
+        match id {
+            Some(IriOrBlankNode::Iri(iri)) => Ok(iri.clone().into()),
+            Some(IriOrBlankNode::BlankNode(label)) => {
+                if let Some(node) = self.bnodes.get(label) {
+                    return Ok(node.clone());
+                }
+                let node = self.fresh_bnode()?;
+                self.bnodes.insert(label.clone(), node.clone());
+                Ok(node)
+            },
+            None => self.fresh_bnode(),
+        }
+    }
+
+    fn fresh_bnode(&mut self) -> Result<RDF::Subject, ServiceDescriptionError> {
+        let bnode = self
+            .rdf
+            .add_bnode()
+            .map_err(|e| ServiceDescriptionError::Writer { error: e.to_string() })?;
+        Ok(bnode.into())
+    }
+
+    fn add_number(
+        &mut self,
+        node: &RDF::Subject,
+        predicate: &IriS,
+        value: Option<NumericLiteral>,
+    ) -> Result<(), ServiceDescriptionError> {
+        match value {
+            Some(value) => {
+                let literal: RDF::Literal = ConcreteLiteral::NumericLiteral(value).into();
+                self.add(node, predicate, literal)
+            },
+            None => Ok(()),
+        }
+    }
+
+    fn add_iri(&mut self, node: &RDF::Subject, predicate: &IriS, value: &IriS) -> Result<(), ServiceDescriptionError> {
+        self.add(node, predicate, value.clone())
+    }
+
+    fn add<O: Into<RDF::Term>>(
+        &mut self,
+        node: &RDF::Subject,
+        predicate: &IriS,
+        value: O,
+    ) -> Result<(), ServiceDescriptionError> {
+        self.rdf
+            .add_triple(node.clone(), predicate.clone(), value)
+            .map_err(|e| ServiceDescriptionError::Writer { error: e.to_string() })
+    }
+}
+
+impl<RDF: BuildRDF> Default for ServiceDescriptionWriter<RDF> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -15,6 +15,22 @@ pub enum SparqlResultFormat {
     Other(IriS),
 }
 
+impl SparqlResultFormat {
+    pub fn iri(&self) -> IriS {
+        match self {
+            SparqlResultFormat::Xml => IriS::new_unchecked("http://www.w3.org/ns/formats/SPARQL_Results_XML"),
+            SparqlResultFormat::Turtle => IriS::new_unchecked("http://www.w3.org/ns/formats/Turtle"),
+            SparqlResultFormat::Tsv => IriS::new_unchecked("http://www.w3.org/ns/formats/SPARQL_Results_TSV"),
+            SparqlResultFormat::RdfXml => IriS::new_unchecked("http://www.w3.org/ns/formats/RDF_XML"),
+            SparqlResultFormat::Json => IriS::new_unchecked("http://www.w3.org/ns/formats/SPARQL_Results_JSON"),
+            SparqlResultFormat::NTriples => IriS::new_unchecked("http://www.w3.org/ns/formats/N-Triples"),
+            SparqlResultFormat::Csv => IriS::new_unchecked("http://www.w3.org/ns/formats/SPARQL_Results_CSV"),
+            SparqlResultFormat::JsonLD => IriS::new_unchecked("http://www.w3.org/ns/formats/JSON-LD"),
+            SparqlResultFormat::Other(iri) => iri.clone(),
+        }
+    }
+}
+
 impl Display for SparqlResultFormat {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -10,10 +10,11 @@ use rudof_iri::IriS;
 use rudof_rdf::rdf_impl::QleverGraphContainer;
 use rudof_rdf::{
     rdf_core::{
-        BuildRDF, FocusRDF, Matcher, NeighsRDF, RDFFormat, Rdf, RdfDataConfig,
+        BuildRDF, FocusRDF, Matcher, NeighsRDF, RDFFormat, Rdf, RdfDataConfig, SourceSpan,
         query::{QueryRDF, QueryResultFormat, QuerySolution, QuerySolutions},
+        utils::CancellationToken,
     },
-    rdf_impl::{OxigraphEndpoint, OxigraphInMemory, RdfBackend, ReaderMode},
+    rdf_impl::{OxigraphEndpoint, OxigraphInMemory, RdfBackend, ReaderMode, RecoveredError},
 };
 use serde::Serialize;
 use serde::ser::SerializeStruct;
@@ -154,6 +155,21 @@ impl RdfData {
         format: &RDFFormat,
         base: Option<&str>,
         reader_mode: &ReaderMode,
+    ) -> Result<(), RdfDataError> {
+        self.merge_from_reader_with_header("", read, source_name, format, base, reader_mode)
+    }
+
+    /// Merge the in-memory primary with the graph read from a reader, parsing
+    /// `header` before it, see [`OxigraphInMemory::merge_from_reader_with_header`].
+    /// Errors if the primary is not an in-memory backend.
+    pub fn merge_from_reader_with_header<R: io::Read>(
+        &mut self,
+        header: &str,
+        read: &mut R,
+        source_name: &str,
+        format: &RDFFormat,
+        base: Option<&str>,
+        reader_mode: &ReaderMode,
     ) -> Result<(), RdfDataError> {
         let backend_name = match &self.primary {
             RdfBackend::InMemory(_) => "in-memory",
@@ -166,12 +182,32 @@ impl RdfData {
             return Err(RdfDataError::NotInMemoryBackend { backend: backend_name });
         };
         graph
-            .merge_from_reader(read, source_name, format, base, reader_mode)
+            .merge_from_reader_with_header(header, read, source_name, format, base, reader_mode)
             .map_err(|e| RdfDataError::Backend {
                 err: Box::new(rudof_rdf::rdf_impl::RdfBackendError::from(e)),
             })
     }
 
+    /// Sets how many syntax errors can be skipped in each source merged in lax
+    /// mode, see [`OxigraphInMemory::set_max_errors`]. Only the in-memory
+    /// backend reads sources, so other backends ignore it.
+    pub fn set_max_errors(&mut self, max_errors: Option<usize>) {
+        if let RdfBackend::InMemory(graph) = &mut self.primary {
+            graph.set_max_errors(max_errors);
+        }
+    }
+
+    /// Syntax errors skipped while merging sources in lax mode
+    pub fn recovered_errors(&self) -> &[RecoveredError] {
+        match &self.primary {
+            RdfBackend::InMemory(graph) => graph.recovered_errors(),
+            #[cfg(all(not(target_family = "wasm"), feature = "sparql"))]
+            RdfBackend::Endpoint(_) => &[],
+            #[cfg(all(not(target_family = "wasm"), feature = "qlever"))]
+            RdfBackend::Qlever(_) => &[],
+        }
+    }
+
     /// Creates an RdfData from a single endpoint (registered as both catalog
     /// and active federation member; primary is left as an empty in-memory
     /// backend).
@@ -208,6 +244,18 @@ impl RdfData {
         self.use_endpoints.remove(name);
     }
 
+    /// Sets the token checked by the SPARQL endpoints behind this data, so that
+    /// their requests are abandoned when the operation using them is interrupted.
+    pub fn set_cancellation(&mut self, token: &CancellationToken) {
+        #[cfg(all(not(target_family = "wasm"), feature = "sparql"))]
+        if let RdfBackend::Endpoint(endpoint) = &mut self.primary {
+            endpoint.set_cancellation(token.clone());
+        }
+        for endpoint in self.use_endpoints.values_mut() {
+            endpoint.set_cancellation(token.clone());
+        }
+    }
+
     pub fn endpoints_to_use(&self) -> impl Iterator<Item = (&str, &OxigraphEndpoint)> {
         self.use_endpoints
             .iter()
@@ -364,6 +412,15 @@ impl Rdf for RdfData {
             prefixmap: Box::new(PrefixMap::new()),
         })
     }
+
+    /// Triples are only located in the in-memory primary
+    fn subject_span(&self, subject: &Self::Subject) -> Option<SourceSpan> {
+        self.primary.subject_span(subject)
+    }
+
+    fn triple_span(&self, subject: &Self::Subject, predicate: &Self::IRI, object: &Self::Term) -> Option<SourceSpan> {
+        self.primary.triple_span(subject, predicate, object)
+    }
 }
 
 impl QueryRDF for RdfData {
@@ -1,3 +1,5 @@
+use crate::{SD_SPARQL10_QUERY_STR, SD_SPARQL11_QUERY_STR, SD_SPARQL11_UPDATE_STR};
+use rudof_iri::IriS;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
 
@@ -11,6 +13,16 @@ pub enum SupportedLanguage {
     SPARQL11Update,
 }
 
+impl SupportedLanguage {
+    pub fn iri(&self) -> IriS {
+        match self {
+            SupportedLanguage::SPARQL10Query => IriS::new_unchecked(SD_SPARQL10_QUERY_STR),
+            SupportedLanguage::SPARQL11Query => IriS::new_unchecked(SD_SPARQL11_QUERY_STR),
+            SupportedLanguage::SPARQL11Update => IriS::new_unchecked(SD_SPARQL11_UPDATE_STR),
+        }
+    }
+}
+
 impl Display for SupportedLanguage {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
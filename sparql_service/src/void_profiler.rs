@@ -0,0 +1,316 @@
+use crate::{
+    ClassPartition, Dataset, DatatypePartition, GraphDescription, PropertyPartition, ServiceDescription,
+    ServiceDescriptionError,
+};
+use rudof_iri::IriS;
+use rudof_rdf::rdf_core::{
+    query::QueryRDF,
+    term::{
+        IriOrBlankNode,
+        literal::{Literal, NumericLiteral},
+    },
+};
+use std::collections::BTreeMap;
+
+const TOTALS_QUERY: &str = r#"
+SELECT (COUNT(*) AS ?triples) (COUNT(DISTINCT ?s) AS ?subjects)
+       (COUNT(DISTINCT ?p) AS ?properties) (COUNT(DISTINCT ?o) AS ?objects)
+WHERE { ?s ?p ?o }"#;
+
+const CLASSES_QUERY: &str = r#"
+SELECT ?class (COUNT(DISTINCT ?s) AS ?entities)
+WHERE { ?s a ?class }
+GROUP BY ?class"#;
+
+const PROPERTIES_QUERY: &str = r#"
+SELECT ?p (COUNT(*) AS ?triples) (COUNT(DISTINCT ?s) AS ?subjects) (COUNT(DISTINCT ?o) AS ?objects)
+WHERE { ?s ?p ?o }
+GROUP BY ?p"#;
+
+const DATATYPES_QUERY: &str = r#"
+SELECT ?p ?datatype (COUNT(*) AS ?triples)
+WHERE { ?s ?p ?o FILTER(isLiteral(?o)) BIND(DATATYPE(?o) AS ?datatype) }
+GROUP BY ?p ?datatype"#;
+
+const CLASS_PROPERTIES_QUERY: &str = r#"
+SELECT ?class ?p (COUNT(*) AS ?triples)
+WHERE { ?s a ?class ; ?p ?o }
+GROUP BY ?class ?p"#;
+
+/// Computes a [VoID](https://www.w3.org/TR/void/) and
+/// [Service Description](https://www.w3.org/TR/sparql11-service-description/)
+/// of RDF data.
+///
+/// The statistics are obtained with SPARQL aggregate queries, so the data can be
+/// in memory or behind a SPARQL endpoint. When the results of a query come from
+/// several sources, their counts are added, so distinct counts are upper bounds
+/// if the sources share nodes.
+///
+/// The description has a default dataset whose default graph contains the
+/// triple, subject, object, class and property counts, a class partition per
+/// class with its property partitions, and a property partition per property
+/// with its datatype partitions.
+#[derive(Clone, Debug, Default)]
+pub struct VoidProfiler {
+    endpoint: Option<IriS>,
+    title: Option<String>,
+}
+
+impl VoidProfiler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the endpoint of the described service
+    pub fn with_endpoint(mut self, endpoint: Option<IriS>) -> Self {
+        self.endpoint = endpoint;
+        self
+    }
+
+    /// Sets the title of the described service
+    pub fn with_title(mut self, title: Option<String>) -> Self {
+        self.title = title;
+        self
+    }
+
+    pub fn profile<RDF: QueryRDF>(&self, rdf: &RDF) -> Result<ServiceDescription, ServiceDescriptionError> {
+        let totals = aggregate(
+            rdf,
+            TOTALS_QUERY,
+            &[],
+            &["triples", "subjects", "properties", "objects"],
+        )?;
+        let totals = totals.get(&Vec::new());
+        let total = |index: usize| totals.map(|counts| integer(counts[index]));
+        let classes = aggregate(rdf, CLASSES_QUERY, &["class"], &["entities"])?;
+        let properties = aggregate(rdf, PROPERTIES_QUERY, &["p"], &["triples", "subjects", "objects"])?;
+        let datatypes = aggregate(rdf, DATATYPES_QUERY, &["p", "datatype"], &["triples"])?;
+        let class_properties = aggregate(rdf, CLASS_PROPERTIES_QUERY, &["class", "p"], &["triples"])?;
+
+        let class_partition = classes
+            .iter()
+            .map(|(key, counts)| {
+                let class = &key[0];
+                let property_partition = class_properties
+                    .iter()
+                    .filter(|(key, _)| &key[0] == class)
+                    .map(|(key, counts)| PropertyPartition::new(&key[1]).with_triples(Some(integer(counts[0]))))
+                    .collect();
+                ClassPartition::new(class)
+                    .with_entities(Some(integer(counts[0])))
+                    .with_property_partition(property_partition)
+            })
+            .collect::<Vec<_>>();
+        let property_partition = properties
+            .iter()
+            .map(|(key, counts)| {
+                let property = &key[0];
+                let datatype_partition = datatypes
+                    .iter()
+                    .filter(|(key, _)| &key[0] == property)
+                    .map(|(key, counts)| DatatypePartition::new(&key[1]).with_triples(Some(integer(counts[0]))))
+                    .collect();
+                PropertyPartition::new(property)
+                    .with_triples(Some(integer(counts[0])))
+                    .with_distinct_subjects(Some(integer(counts[1])))
+                    .with_distinct_objects(Some(integer(counts[2])))
+                    .with_datatype_partition(datatype_partition)
+            })
+            .collect();
+
+        let graph = GraphDescription::new(&IriOrBlankNode::BlankNode("default_graph".to_string()))
+            .with_triples(total(0))
+            .with_distinct_subjects(total(1))
+            .with_properties(total(2))
+            .with_distinct_objects(total(3))
+            .with_classes(Some(integer(class_partition.len() as u128)))
+            .with_class_partition(class_partition)
+            .with_property_partition(property_partition);
+        let dataset = Dataset::new(&IriOrBlankNode::BlankNode("dataset".to_string())).with_default_graph(Some(graph));
+        let mut service = ServiceDescription::new()
+            .with_endpoint(self.endpoint.clone())
+            .with_default_dataset(Some(dataset))
+            .with_prefixmap(rdf.prefixmap());
+        service.add_title(self.title.as_deref());
+        Ok(service)
+    }
+}
+
+/// Runs an aggregate query and returns its counts indexed by the IRIs of the keys of its solutions.
+///
+/// The counts of solutions with the same keys are added. Solutions whose keys
+/// are not IRIs, like blank node classes, are skipped.
+fn aggregate<RDF: QueryRDF>(
+    rdf: &RDF,
+    query: &str,
+    keys: &[&str],
+    counts: &[&str],
+) -> Result<BTreeMap<Vec<IriS>, Vec<u128>>, ServiceDescriptionError> {
+    let solutions = rdf
+        .query_select(query)
+        .map_err(|e| ServiceDescriptionError::Profiling {
+            query: query.trim().to_string(),
+            error: e.to_string(),
+        })?;
+    let mut result: BTreeMap<Vec<IriS>, Vec<u128>> = BTreeMap::new();
+    for solution in solutions.iter() {
+        let Some(key) = keys
+            .iter()
+            .map(|var| {
+                solution
+                    .find_solution(*var)
+                    .and_then(|term| RDF::term_as_iris(term).ok())
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+        let values = result.entry(key).or_insert_with(|| vec![0; counts.len()]);
+        for (value, var) in values.iter_mut().zip(counts) {
+            *value += solution
+                .find_solution(*var)
+                .and_then(|term| RDF::term_as_literal(term).ok())
+                .and_then(|literal| literal.lexical_form().parse::<u128>().ok())
+                .unwrap_or_default();
+        }
+    }
+    Ok(result)
+}
+
+fn integer(n: u128) -> NumericLiteral {
+    NumericLiteral::integer(n as i128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ServiceDescriptionFormat;
+    use rudof_rdf::rdf_core::RDFFormat;
+    use rudof_rdf::rdf_impl::{OxigraphInMemory, ReaderMode};
+
+    const PEOPLE: &str = r#"
+        prefix : <http://example.org/>
+        prefix xsd: <http://www.w3.org/2001/XMLSchema#>
+        :alice a :Person ; :name "Alice" ; :age 23 ; :knows :bob .
+        :bob a :Person ; :name "Bob" ; :age "unknown" .
+        :acme a :Company ; :name "ACME" .
+    "#;
+
+    fn profile() -> ServiceDescription {
+        let mut rdf = OxigraphInMemory::from_str(PEOPLE, &RDFFormat::Turtle, None, &ReaderMode::Strict).unwrap();
+        rdf.ensure_store().unwrap();
+        VoidProfiler::new()
+            .with_title(Some("People".to_string()))
+            .profile(&rdf)
+            .unwrap()
+    }
+
+    fn iri(local: &str) -> IriS {
+        IriS::new_unchecked(format!("http://example.org/{local}").as_str())
+    }
+
+    fn default_graph(service: &ServiceDescription) -> &GraphDescription {
+        service.default_dataset().unwrap().default_graph().unwrap()
+    }
+
+    #[test]
+    fn counts_triples_classes_and_properties() {
+        let service = profile();
+        let graph = default_graph(&service);
+        assert_eq!(graph.triples(), Some(NumericLiteral::integer(9)));
+        assert_eq!(graph.distinct_subjects(), Some(NumericLiteral::integer(3)));
+        assert_eq!(graph.properties(), Some(NumericLiteral::integer(4)));
+        assert_eq!(graph.classes(), Some(NumericLiteral::integer(2)));
+
+        let person = graph
+            .class_partition()
+            .iter()
+            .find(|c| c.class() == &iri("Person"))
+            .unwrap();
+        assert_eq!(person.entities(), Some(NumericLiteral::integer(2)));
+        let knows = person
+            .property_partition()
+            .iter()
+            .find(|p| p.property() == &iri("knows"))
+            .unwrap();
+        assert_eq!(knows.triples(), Some(NumericLiteral::integer(1)));
+    }
+
+    #[test]
+    fn partitions_property_values_by_datatype() {
+        let service = profile();
+        let age = default_graph(&service)
+            .property_partition()
+            .iter()
+            .find(|p| p.property() == &iri("age"))
+            .unwrap();
+        assert_eq!(age.triples(), Some(NumericLiteral::integer(2)));
+        assert_eq!(age.distinct_subjects(), Some(NumericLiteral::integer(2)));
+        let mut datatypes: Vec<_> = age
+            .datatype_partition()
+            .iter()
+            .map(|d| (d.datatype().as_str().to_string(), d.triples()))
+            .collect();
+        datatypes.sort_by(|a, b| a.0.cmp(&b.0));
+        assert_eq!(
+            datatypes,
+            vec![
+                (
+                    "http://www.w3.org/2001/XMLSchema#integer".to_string(),
+                    Some(NumericLiteral::integer(1))
+                ),
+                (
+                    "http://www.w3.org/2001/XMLSchema#string".to_string(),
+                    Some(NumericLiteral::integer(1))
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn written_description_is_parsed_back() {
+        let service = profile();
+        let mut buffer = Vec::new();
+        service
+            .serialize(Some(&ServiceDescriptionFormat::Rdf(RDFFormat::Turtle)), &mut buffer)
+            .unwrap();
+        let parsed = ServiceDescription::from_reader(
+            &mut buffer.as_slice(),
+            "profile",
+            &RDFFormat::Turtle,
+            None,
+            &ReaderMode::Strict,
+        )
+        .unwrap();
+
+        assert_eq!(parsed.title(), &Some("People".to_string()));
+        let (graph, parsed_graph) = (default_graph(&service), default_graph(&parsed));
+        assert_eq!(parsed_graph.triples(), graph.triples());
+        assert_eq!(parsed_graph.distinct_objects(), graph.distinct_objects());
+        assert_eq!(parsed_graph.class_partition().len(), 2);
+        let age = parsed_graph
+            .property_partition()
+            .iter()
+            .find(|p| p.property() == &iri("age"))
+            .unwrap();
+        assert_eq!(age.datatype_partition().len(), 2);
+        let person = parsed_graph
+            .class_partition()
+            .iter()
+            .find(|c| c.class() == &iri("Person"))
+            .unwrap();
+        assert_eq!(person.property_partition().len(), 4);
+    }
+
+    #[test]
+    fn description_is_converted_to_mie() {
+        let mie = profile().service2mie();
+        let statistics = mie.data_statistics("default").unwrap();
+        assert_eq!(statistics.classes(), Some(2));
+        assert_eq!(statistics.class_partitions().get("http://example.org/Person"), Some(&2));
+        assert_eq!(
+            statistics.property_partitions().get("http://example.org/name"),
+            Some(&3)
+        );
+    }
+}